@@ -0,0 +1,137 @@
+import {
+  Context,
+  isOption,
+  none,
+  publicKey,
+  TransactionBuilder,
+  transactionBuilder,
+  wrapNullable,
+} from '@metaplex-foundation/umi';
+import { array, base64 } from '@metaplex-foundation/umi/serializers';
+import { DefaultGuardSetMintArgs } from './defaultGuards';
+import { EligibilityCheckFailedError } from './errors';
+import {
+  checkEligibility as baseCheckEligibility,
+  CheckEligibilityInstructionAccounts,
+} from './generated/instructions/checkEligibility';
+import {
+  getGroupEligibilitySerializer,
+  GroupEligibility,
+} from './generated/types/groupEligibility';
+import {
+  CandyGuardProgram,
+  GuardRepository,
+  GuardSetMintArgs,
+  MintContext,
+  parseGuardRemainingAccounts,
+  parseMintArgs,
+} from './guards';
+import { findCandyGuardPda } from './hooked';
+import { MintV2InstructionDataArgs } from './mintV1';
+
+export { CheckEligibilityInstructionAccounts };
+
+export type CheckEligibilityInput<MA extends GuardSetMintArgs> =
+  CheckEligibilityInstructionAccounts &
+    MintV2InstructionDataArgs<
+      MA extends undefined ? DefaultGuardSetMintArgs : MA
+    >;
+
+/**
+ * Evaluates the guards of a mint without minting. It accepts the
+ * same accounts and mint settings as the `mintV1` instruction.
+ *
+ * The result of the evaluation is set as the return data of the
+ * transaction, so the instruction is intended to be simulated.
+ * See {@link getEligibility} to simulate it and parse its result.
+ */
+export function checkEligibility<
+  MA extends GuardSetMintArgs = DefaultGuardSetMintArgs
+>(
+  context: Parameters<typeof baseCheckEligibility>[0] & {
+    coreGuards: GuardRepository;
+  },
+  input: CheckEligibilityInput<MA>
+): TransactionBuilder {
+  const { mintArgs = {}, group = none(), ...rest } = input;
+
+  // Parsing mint data.
+  const program = context.programs.get<CandyGuardProgram>('mplCoreCandyGuard');
+  const candyMachine = publicKey(input.candyMachine, false);
+  const mintContext: MintContext = {
+    minter: input.minter ?? context.identity,
+    payer: input.payer ?? context.payer,
+    asset: publicKey(input.asset, false),
+    candyMachine,
+    candyGuard: publicKey(
+      input.candyGuard ?? findCandyGuardPda(context, { base: candyMachine }),
+      false
+    ),
+    group: isOption(group) ? group : wrapNullable(group),
+  };
+  const { data, remainingAccounts } = parseMintArgs<
+    MA extends undefined ? DefaultGuardSetMintArgs : MA
+  >(context, program, mintContext, mintArgs);
+
+  const ix = baseCheckEligibility(context, {
+    ...rest,
+    mintArgs: data,
+    group,
+  }).items[0];
+
+  const [keys, signers] = parseGuardRemainingAccounts(remainingAccounts);
+  ix.instruction.keys.push(...keys);
+  ix.signers.push(...signers);
+
+  return transactionBuilder([ix]);
+}
+
+type SimulateTransactionResult = {
+  value: {
+    err: unknown;
+    logs: string[] | null;
+    returnData: { programId: string; data: [string, string] } | null;
+  };
+};
+
+/**
+ * Simulates the `checkEligibility` instruction and returns the result
+ * of the evaluation of each group. When a group is provided, or resolved
+ * from the active phase of the `phaseSchedule` guard, only that group is
+ * evaluated. Otherwise, all the groups are evaluated, or the default
+ * guard set when the Candy Guard has no groups.
+ *
+ * Signatures are not verified during the simulation, so the payer
+ * and minter do not need to sign the transaction.
+ */
+export async function getEligibility<
+  MA extends GuardSetMintArgs = DefaultGuardSetMintArgs
+>(
+  context: Parameters<typeof checkEligibility>[0] &
+    Pick<Context, 'rpc' | 'transactions'>,
+  input: CheckEligibilityInput<MA>
+): Promise<GroupEligibility[]> {
+  const transaction = await checkEligibility<MA>(
+    context,
+    input
+  ).buildWithLatestBlockhash(context);
+  const serializedTransaction = base64.deserialize(
+    context.transactions.serialize(transaction)
+  )[0];
+
+  const { value } = await context.rpc.call<SimulateTransactionResult>(
+    'simulateTransaction',
+    [
+      serializedTransaction,
+      { encoding: 'base64', sigVerify: false, replaceRecentBlockhash: true },
+    ]
+  );
+  if (value.err || !value.returnData) {
+    throw new EligibilityCheckFailedError(value.err, value.logs ?? []);
+  }
+
+  const [groups] = array(getGroupEligibilitySerializer()).deserialize(
+    base64.serialize(value.returnData.data[0])
+  );
+  return groups;
+}
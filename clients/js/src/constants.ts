@@ -30,6 +30,10 @@ export const CANDY_MACHINE_HIDDEN_SECTION =
   32; // hash
 
 export const CANDY_GUARD_LABEL_SIZE = 6;
+export const CANDY_GUARD_MAX_LABEL_SIZE = 32;
+export const CANDY_GUARD_LEGACY_LAYOUT_VERSION = 0;
+export const CANDY_GUARD_LAYOUT_VERSION = 2;
+export const CANDY_GUARD_DISABLED_GUARDS_VERSION = 2;
 export const CANDY_GUARD_DATA =
   8 + // discriminator
   32 + // base
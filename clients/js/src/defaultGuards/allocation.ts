@@ -6,7 +6,7 @@ import {
   Allocation,
   AllocationArgs,
 } from '../generated';
-import { GuardManifest } from '../guards';
+import { CLOSE_GUARD_PDA_DATA, GuardManifest } from '../guards';
 
 /**
  * Guard to specify the maximum number of mints in a guard set.
@@ -33,21 +33,32 @@ export const allocationGuardManifest: GuardManifest<
       },
     ],
   }),
-  routeParser: (context, routeContext, args) => ({
-    data: new Uint8Array(),
-    remainingAccounts: [
-      {
-        isWritable: true,
-        publicKey: findAllocationTrackerPda(context, {
-          id: args.id,
-          candyMachine: routeContext.candyMachine,
-          candyGuard: routeContext.candyGuard,
-        })[0],
-      },
-      { isWritable: false, signer: args.candyGuardAuthority },
-      { isWritable: false, publicKey: getSplSystemProgramId(context) },
-    ],
-  }),
+  routeParser: (context, routeContext, args) => {
+    const [allocationTracker] = findAllocationTrackerPda(context, {
+      id: args.id,
+      candyMachine: routeContext.candyMachine,
+      candyGuard: routeContext.candyGuard,
+    });
+
+    if (args.path === 'close') {
+      return {
+        data: CLOSE_GUARD_PDA_DATA,
+        remainingAccounts: [
+          { isWritable: true, publicKey: allocationTracker },
+          { isWritable: true, signer: args.candyGuardAuthority },
+        ],
+      };
+    }
+
+    return {
+      data: new Uint8Array(),
+      remainingAccounts: [
+        { isWritable: true, publicKey: allocationTracker },
+        { isWritable: false, signer: args.candyGuardAuthority },
+        { isWritable: false, publicKey: getSplSystemProgramId(context) },
+      ],
+    };
+  },
 };
 
 export type AllocationMintArgs = Omit<AllocationArgs, 'limit'>;
@@ -55,8 +66,16 @@ export type AllocationMintArgs = Omit<AllocationArgs, 'limit'>;
 /**
  * The allocation guard arguments that should be provided
  * when accessing the guard's special "route" instruction.
+ *
+ * By default, the route instruction initializes the allocation
+ * tracker. The `close` path closes the tracker and transfers its
+ * lamports to the Candy Guard authority once the Candy Machine
+ * was withdrawn.
  */
 export type AllocationRouteArgs = Omit<AllocationArgs, 'limit'> & {
+  /** Selects the path to execute in the route instruction. */
+  path?: 'initialize' | 'close';
+
   /** The authority of the Candy Guard as a Signer. */
   candyGuardAuthority: Signer;
 };
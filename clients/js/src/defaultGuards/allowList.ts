@@ -1,13 +1,13 @@
 import { getSplSystemProgramId } from '@metaplex-foundation/mpl-toolbox';
 import { PublicKey, Signer, publicKey } from '@metaplex-foundation/umi';
-import { array, bytes } from '@metaplex-foundation/umi/serializers';
+import { array, bytes, option } from '@metaplex-foundation/umi/serializers';
 import {
   AllowList,
   AllowListArgs,
   findAllowListProofPda,
   getAllowListSerializer,
 } from '../generated';
-import { GuardManifest } from '../guards';
+import { CLOSE_GUARD_PDA_DATA, GuardManifest } from '../guards';
 
 /**
  * The allowList guard validates the minting wallet against
@@ -38,8 +38,10 @@ import { GuardManifest } from '../guards';
  *
  * Note that you will need to provide the Merkle Proof for the
  * minting wallet before calling the mint instruction via the
- * special "route" instruction of the guard.
- * See {@link AllowListRouteArgs} for more information.
+ * special "route" instruction of the guard, unless the proof is
+ * included in the mint settings.
+ * See {@link AllowListRouteArgs} and {@link AllowListMintArgs}
+ * for more information.
  */
 export const allowListGuardManifest: GuardManifest<
   AllowListArgs,
@@ -49,46 +51,99 @@ export const allowListGuardManifest: GuardManifest<
 > = {
   name: 'allowList',
   serializer: getAllowListSerializer,
-  mintParser: (context, mintContext, args) => ({
-    data: new Uint8Array(),
-    remainingAccounts: [
-      {
-        isWritable: false,
-        publicKey: findAllowListProofPda(context, {
-          merkleRoot: args.merkleRoot,
-          user: mintContext.minter.publicKey,
-          candyMachine: mintContext.candyMachine,
-          candyGuard: mintContext.candyGuard,
-        })[0],
-      },
-    ],
-  }),
-  routeParser: (context, routeContext, args) => ({
-    data: array(bytes({ size: 32 })).serialize(args.merkleProof),
-    remainingAccounts: [
-      {
-        isWritable: true,
-        publicKey: findAllowListProofPda(context, {
-          merkleRoot: args.merkleRoot,
-          user: publicKey(args.minter ?? routeContext.payer),
-          candyMachine: routeContext.candyMachine,
-          candyGuard: routeContext.candyGuard,
-        })[0],
-      },
-      { isWritable: false, publicKey: getSplSystemProgramId(context) },
-      ...(args.minter !== undefined
-        ? [{ isWritable: false, publicKey: publicKey(args.minter) }]
-        : []),
-    ],
-  }),
+  mintParser: (context, mintContext, args) => {
+    // The option tag is always included so guards reading
+    // mint arguments after this one are not affected.
+    const data = option(array(bytes({ size: 32 }))).serialize(
+      args.merkleProof ?? null
+    );
+
+    if (args.merkleProof) {
+      return { data, remainingAccounts: [] };
+    }
+
+    return {
+      data,
+      remainingAccounts: [
+        {
+          isWritable: false,
+          publicKey: findAllowListProofPda(context, {
+            merkleRoot: args.merkleRoot,
+            user: mintContext.minter.publicKey,
+            candyMachine: mintContext.candyMachine,
+            candyGuard: mintContext.candyGuard,
+          })[0],
+        },
+      ],
+    };
+  },
+  routeParser: (context, routeContext, args) => {
+    if (args.path === 'close') {
+      return {
+        data: CLOSE_GUARD_PDA_DATA,
+        remainingAccounts: [
+          {
+            isWritable: true,
+            publicKey: findAllowListProofPda(context, {
+              merkleRoot: args.merkleRoot,
+              user: args.minter ?? args.authority.publicKey,
+              candyMachine: routeContext.candyMachine,
+              candyGuard: routeContext.candyGuard,
+            })[0],
+          },
+          { isWritable: true, signer: args.authority },
+          ...(args.minter !== undefined
+            ? [{ isWritable: false, publicKey: args.minter }]
+            : []),
+        ],
+      };
+    }
+
+    return {
+      data: array(bytes({ size: 32 })).serialize(args.merkleProof),
+      remainingAccounts: [
+        {
+          isWritable: true,
+          publicKey: findAllowListProofPda(context, {
+            merkleRoot: args.merkleRoot,
+            user: publicKey(args.minter ?? routeContext.payer),
+            candyMachine: routeContext.candyMachine,
+            candyGuard: routeContext.candyGuard,
+          })[0],
+        },
+        { isWritable: false, publicKey: getSplSystemProgramId(context) },
+        ...(args.minter !== undefined
+          ? [{ isWritable: false, publicKey: publicKey(args.minter) }]
+          : []),
+      ],
+    };
+  },
 };
 
-export type AllowListMintArgs = AllowListArgs;
+/**
+ * The settings for the allowList guard that could
+ * be provided to the mint instruction.
+ */
+export type AllowListMintArgs = AllowListArgs & {
+  /**
+   * The Proof that the minting wallet is part of the
+   * Merkle Tree-based allow list. When provided, the proof
+   * is validated by the mint instruction and the "route"
+   * instruction is not required, but large proofs might not
+   * fit in the mint transaction.
+   */
+  merkleProof?: Uint8Array[];
+};
 
 /**
  * The settings for the allowList guard that should be provided
  * when accessing the guard's special "route" instruction.
- *
+ */
+export type AllowListRouteArgs =
+  | AllowListRouteArgsProof
+  | AllowListRouteArgsClose;
+
+/**
  * ## Proof
  * The `proof` path allows you to provide a Merkle Proof
  * for a specific wallet in order to allow minting for that wallet.
@@ -109,7 +164,7 @@ export type AllowListMintArgs = AllowListArgs;
  * // You are now allows to mint with this wallet.
  * ```
  */
-export type AllowListRouteArgs = AllowListArgs & {
+export type AllowListRouteArgsProof = AllowListArgs & {
   /** Selects the path to execute in the route instruction. */
   path: 'proof';
 
@@ -127,3 +182,32 @@ export type AllowListRouteArgs = AllowListArgs & {
    */
   minter?: PublicKey | Signer;
 };
+
+/**
+ * ## Close
+ * The `close` path closes the proof PDA of a wallet and transfers
+ * its lamports to the `authority`. The wallet can close its own
+ * proof once the Candy Machine is sold out, whereas the Candy Guard
+ * authority can only close proofs once the Candy Machine was withdrawn.
+ *
+ * ```ts
+ * route(umi, {
+ *   // ...
+ *   guard: 'allowList',
+ *   routeArgs: { path: 'close', merkleRoot, authority: umi.identity },
+ * });
+ * ```
+ */
+export type AllowListRouteArgsClose = AllowListArgs & {
+  /** Selects the path to execute in the route instruction. */
+  path: 'close';
+
+  /** The wallet or the Candy Guard authority, which receives the lamports. */
+  authority: Signer;
+
+  /**
+   * The wallet of the proof, when it is
+   * closed by the Candy Guard authority.
+   */
+  minter?: PublicKey;
+};
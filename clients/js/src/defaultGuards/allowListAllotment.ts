@@ -0,0 +1,209 @@
+import { getSplSystemProgramId } from '@metaplex-foundation/mpl-toolbox';
+import {
+  OptionOrNullable,
+  PublicKey,
+  Signer,
+  publicKey,
+} from '@metaplex-foundation/umi';
+import {
+  AllowListAllotment,
+  AllowListAllotmentArgs,
+  findAllotmentProofPda,
+  getAllotmentProofArgsSerializer,
+  getAllowListAllotmentSerializer,
+} from '../generated';
+import {
+  CLOSE_GUARD_PDA_DATA,
+  GuardManifest,
+  GuardRemainingAccount,
+} from '../guards';
+
+/**
+ * The allowListAllotment guard validates the minting wallet against
+ * a predefined list of wallets where each wallet has its own allotment
+ * (maximum number of mints) and, optionally, its own price in lamports.
+ *
+ * Like the allowList guard, this guard accepts the Root of a Merkle Tree.
+ * The leaves of the tree are the serialized entries of each wallet,
+ * which can be created using the `getAllotmentEntrySerializer` helper.
+ *
+ * ```ts
+ * const entries = [
+ *   { address: walletA, allotment: 3, price: none() },
+ *   { address: walletB, allotment: 1, price: some(sol(1).basisPoints) },
+ * ].map((entry) => getAllotmentEntrySerializer().serialize(entry));
+ * const merkleRoot = getMerkleRoot(entries);
+ * const merkleProof = getMerkleProof(entries, entries[0]);
+ * ```
+ *
+ * The entry of the minting wallet must be validated via the
+ * special "route" instruction of the guard before minting.
+ * See {@link AllowListAllotmentRouteArgs} for more information.
+ */
+export const allowListAllotmentGuardManifest: GuardManifest<
+  AllowListAllotmentArgs,
+  AllowListAllotment,
+  AllowListAllotmentMintArgs,
+  AllowListAllotmentRouteArgs
+> = {
+  name: 'allowListAllotment',
+  serializer: getAllowListAllotmentSerializer,
+  mintParser: (context, mintContext, args) => {
+    const remainingAccounts: GuardRemainingAccount[] = [
+      {
+        isWritable: true,
+        publicKey: findAllotmentProofPda(context, {
+          merkleRoot: args.merkleRoot,
+          user: mintContext.minter.publicKey,
+          candyMachine: mintContext.candyMachine,
+          candyGuard: mintContext.candyGuard,
+        })[0],
+      },
+    ];
+    if (args.destination) {
+      remainingAccounts.push({ isWritable: true, publicKey: args.destination });
+    }
+    return { data: new Uint8Array(), remainingAccounts };
+  },
+  routeParser: (context, routeContext, args) => {
+    if (args.path === 'close') {
+      return {
+        data: CLOSE_GUARD_PDA_DATA,
+        remainingAccounts: [
+          {
+            isWritable: true,
+            publicKey: findAllotmentProofPda(context, {
+              merkleRoot: args.merkleRoot,
+              user: args.minter ?? args.authority.publicKey,
+              candyMachine: routeContext.candyMachine,
+              candyGuard: routeContext.candyGuard,
+            })[0],
+          },
+          { isWritable: true, signer: args.authority },
+          ...(args.minter !== undefined
+            ? [{ isWritable: false, publicKey: args.minter }]
+            : []),
+        ],
+      };
+    }
+
+    return {
+      data: getAllotmentProofArgsSerializer().serialize({
+        allotment: args.allotment,
+        price: args.price,
+        proof: args.merkleProof,
+      }),
+      remainingAccounts: [
+        {
+          isWritable: true,
+          publicKey: findAllotmentProofPda(context, {
+            merkleRoot: args.merkleRoot,
+            user: publicKey(args.minter ?? routeContext.payer),
+            candyMachine: routeContext.candyMachine,
+            candyGuard: routeContext.candyGuard,
+          })[0],
+        },
+        { isWritable: false, publicKey: getSplSystemProgramId(context) },
+        ...(args.minter !== undefined
+          ? [{ isWritable: false, publicKey: publicKey(args.minter) }]
+          : []),
+      ],
+    };
+  },
+};
+
+/**
+ * The settings for the allowListAllotment guard that could
+ * be provided to the mint instruction.
+ */
+export type AllowListAllotmentMintArgs = Pick<
+  AllowListAllotmentArgs,
+  'merkleRoot'
+> & {
+  /**
+   * The address that receives the price of the mint. It must
+   * match the `destination` setting of the guard and is only
+   * required when the entry of the minting wallet has a price.
+   */
+  destination?: PublicKey;
+};
+
+/**
+ * The settings for the allowListAllotment guard that should be
+ * provided when accessing the guard's special "route" instruction.
+ */
+export type AllowListAllotmentRouteArgs =
+  | AllowListAllotmentRouteArgsProof
+  | AllowListAllotmentRouteArgsClose;
+
+/**
+ * ## Proof
+ * The `proof` path validates the entry of a wallet against the
+ * Merkle Tree and creates a small PDA account on the Program that
+ * tracks the allotment and price of the wallet, as well as the
+ * number of mints it used.
+ *
+ * ```ts
+ * route(umi, {
+ *   // ...
+ *   guard: 'allowListAllotment',
+ *   routeArgs: {
+ *     path: 'proof',
+ *     merkleRoot,
+ *     allotment: 3,
+ *     price: none(),
+ *     merkleProof: getMerkleProof(entries, entries[0]),
+ *   },
+ * });
+ * ```
+ */
+export type AllowListAllotmentRouteArgsProof = Pick<
+  AllowListAllotmentArgs,
+  'merkleRoot'
+> & {
+  /** Selects the path to execute in the route instruction. */
+  path: 'proof';
+
+  /** The maximum number of mints of the wallet. */
+  allotment: number;
+
+  /** The price of each mint of the wallet in lamports, if any. */
+  price: OptionOrNullable<number | bigint>;
+
+  /**
+   * The Proof that the entry of the wallet is part of the
+   * Merkle Tree. You may use the `getMerkleProof` helper
+   * function to generate this.
+   */
+  merkleProof: Uint8Array[];
+
+  /**
+   * The address of the minter to validate if it is not the payer.
+   * The account will not be used as a signer.
+   */
+  minter?: PublicKey | Signer;
+};
+
+/**
+ * ## Close
+ * The `close` path closes the proof PDA of a wallet and transfers
+ * its lamports to the `authority`. The wallet can close its own
+ * proof once the Candy Machine is sold out, whereas the Candy Guard
+ * authority can only close proofs once the Candy Machine was withdrawn.
+ */
+export type AllowListAllotmentRouteArgsClose = Pick<
+  AllowListAllotmentArgs,
+  'merkleRoot'
+> & {
+  /** Selects the path to execute in the route instruction. */
+  path: 'close';
+
+  /** The wallet or the Candy Guard authority, which receives the lamports. */
+  authority: Signer;
+
+  /**
+   * The wallet of the proof, when it is
+   * closed by the Candy Guard authority.
+   */
+  minter?: PublicKey;
+};
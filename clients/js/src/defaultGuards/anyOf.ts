@@ -0,0 +1,63 @@
+import { fixSerializer } from '@metaplex-foundation/umi/serializers';
+import { UnregisteredCandyGuardError } from '../errors';
+import {
+  AnyOf,
+  AnyOfArgs,
+  getAnyOfSerializer,
+  getGuardTypeSerializer,
+  GuardType,
+} from '../generated';
+import { GuardManifest, noopParser } from '../guards';
+
+/**
+ * The anyOf guard evaluates a list of guards as alternatives:
+ * minting is allowed when any of the listed guards validates.
+ * The listed guards must be enabled on the guard set and are
+ * only evaluated by this guard.
+ *
+ * @see {@link AnyOfMintArgs} to learn how to select the
+ * alternative to satisfy when minting.
+ */
+export const anyOfGuardManifest: GuardManifest<
+  AnyOfArgs,
+  AnyOf,
+  AnyOfMintArgs
+> = {
+  name: 'anyOf',
+  serializer: () => fixSerializer(getAnyOfSerializer(), 4 + 5),
+  mintParser: (context, mintContext, args) => {
+    const name = args.guard.charAt(0).toUpperCase() + args.guard.slice(1);
+    const guardType = GuardType[name as keyof typeof GuardType];
+    if (guardType === undefined) {
+      throw new UnregisteredCandyGuardError(args.guard);
+    }
+    return {
+      data: getGuardTypeSerializer().serialize(guardType),
+      remainingAccounts: [],
+    };
+  },
+  routeParser: noopParser,
+};
+
+/**
+ * The anyOf guard arguments that should be provided when minting.
+ *
+ * The mint arguments of the selected alternative are provided as usual
+ * and are moved to the position of the anyOf guard, which only evaluates
+ * the selected alternative and fails with its error when it does not
+ * validate.
+ *
+ * ```ts
+ * mintV1(umi, {
+ *   // ...
+ *   mintArgs: {
+ *     anyOf: some({ guard: 'solPayment' }),
+ *     solPayment: some({ destination }),
+ *   },
+ * });
+ * ```
+ */
+export type AnyOfMintArgs = {
+  /** The name of the alternative guard the minter intends to satisfy. */
+  guard: string;
+};
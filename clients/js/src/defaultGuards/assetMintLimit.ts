@@ -1,11 +1,11 @@
-import { PublicKey } from '@metaplex-foundation/umi';
+import { PublicKey, Signer } from '@metaplex-foundation/umi';
 import {
   findAssetMintCounterPda,
   getAssetMintLimitSerializer,
   AssetMintLimit,
   AssetMintLimitArgs,
 } from '../generated';
-import { GuardManifest, noopParser } from '../guards';
+import { CLOSE_GUARD_PDA_DATA, GuardManifest } from '../guards';
 
 /**
  * The assetMintLimit guard allows to specify a limit on the
@@ -20,7 +20,8 @@ import { GuardManifest, noopParser } from '../guards';
 export const assetMintLimitGuardManifest: GuardManifest<
   AssetMintLimitArgs,
   AssetMintLimit,
-  AssetMintLimitMintArgs
+  AssetMintLimitMintArgs,
+  AssetMintLimitRouteArgs
 > = {
   name: 'assetMintLimit',
   serializer: getAssetMintLimitSerializer,
@@ -40,7 +41,22 @@ export const assetMintLimitGuardManifest: GuardManifest<
       ],
     };
   },
-  routeParser: noopParser,
+  routeParser: (context, routeContext, args) => ({
+    data: CLOSE_GUARD_PDA_DATA,
+    remainingAccounts: [
+      {
+        publicKey: findAssetMintCounterPda(context, {
+          id: args.id,
+          asset: args.asset,
+          candyMachine: routeContext.candyMachine,
+          candyGuard: routeContext.candyGuard,
+        })[0],
+        isWritable: true,
+      },
+      { signer: args.authority, isWritable: true },
+      { publicKey: args.asset, isWritable: false },
+    ],
+  }),
 };
 
 export type AssetMintLimitMintArgs = {
@@ -55,3 +71,26 @@ export type AssetMintLimitMintArgs = {
    */
   asset: PublicKey;
 };
+
+/**
+ * The settings for the assetMintLimit guard that should be provided
+ * when accessing the guard's special "route" instruction.
+ *
+ * ## Close
+ * The `close` path closes a mint counter and transfers its
+ * lamports to the Candy Guard authority once the Candy Machine
+ * was withdrawn.
+ */
+export type AssetMintLimitRouteArgs = {
+  /** Selects the path to execute in the route instruction. */
+  path: 'close';
+
+  /** The id of the mint limit. */
+  id: number;
+
+  /** The address of the asset of the mint counter. */
+  asset: PublicKey;
+
+  /** The Candy Guard authority, which receives the lamports. */
+  authority: Signer;
+};
@@ -0,0 +1,52 @@
+import { PublicKey } from '@metaplex-foundation/umi';
+import { fixSerializer } from '@metaplex-foundation/umi/serializers';
+import {
+  AssetTraitGate,
+  AssetTraitGateArgs,
+  getAssetTraitGateSerializer,
+} from '../generated';
+import { GuardManifest, GuardRemainingAccount, noopParser } from '../guards';
+
+/**
+ * The assetTraitGate guard restricts minting to holders of an
+ * Asset from a specified collection that has the required traits.
+ *
+ * Traits are read from the `Attributes` plugin of the Asset and,
+ * when `includeCollection` is set, from the `Attributes` plugin of
+ * the collection. The attributes of the Asset take precedence over
+ * the ones of the collection. Up to 4 traits can be required, with
+ * keys and values of up to 32 bytes each.
+ */
+export const assetTraitGateGuardManifest: GuardManifest<
+  AssetTraitGateArgs,
+  AssetTraitGate,
+  AssetTraitGateMintArgs
+> = {
+  name: 'assetTraitGate',
+  serializer: () =>
+    fixSerializer(getAssetTraitGateSerializer(), 32 + 1 + 4 + 4 * (4 + 32) * 2),
+  mintParser: (context, mintContext, args) => {
+    const remainingAccounts: GuardRemainingAccount[] = [
+      { publicKey: args.asset, isWritable: false },
+    ];
+    if (args.collection) {
+      remainingAccounts.push({ publicKey: args.collection, isWritable: false });
+    }
+    return { data: new Uint8Array(), remainingAccounts };
+  },
+  routeParser: noopParser,
+};
+
+export type AssetTraitGateMintArgs = {
+  /**
+   * The address of an Asset from the required
+   * collection that belongs to the minter.
+   */
+  asset: PublicKey;
+
+  /**
+   * The address of the required collection. It is
+   * only required when `includeCollection` is set.
+   */
+  collection?: PublicKey;
+};
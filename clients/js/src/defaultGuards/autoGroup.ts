@@ -0,0 +1,21 @@
+import { AutoGroup, AutoGroupArgs, getAutoGroupSerializer } from '../generated';
+import { GuardManifest, noopParser } from '../guards';
+
+/**
+ * The autoGroup guard selects the first eligible group when
+ * minting without a group label. Groups are attempted in the
+ * order they are declared and the bot tax of the default guard
+ * set is only charged when none of them validates, unless one
+ * of them failed because of the rate limit, in which case the
+ * mint fails with a `RateLimitExceeded` error instead.
+ *
+ * The mint arguments of the group the minter intends to use
+ * must be provided. This guard can only be enabled on the
+ * default guard set.
+ */
+export const autoGroupGuardManifest: GuardManifest<AutoGroupArgs, AutoGroup> = {
+  name: 'autoGroup',
+  serializer: getAutoGroupSerializer,
+  mintParser: noopParser,
+  routeParser: noopParser,
+};
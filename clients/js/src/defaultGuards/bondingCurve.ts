@@ -0,0 +1,101 @@
+import {
+  findAssociatedTokenPda,
+  getSplTokenProgramId,
+} from '@metaplex-foundation/mpl-toolbox';
+import {
+  isOption,
+  PublicKey,
+  publicKey,
+  unwrapOption,
+  wrapNullable,
+} from '@metaplex-foundation/umi';
+import {
+  fixSerializer,
+  publicKey as publicKeySerializer,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  BondingCurve,
+  BondingCurveArgs,
+  getBondingCurveSerializer,
+} from '../generated';
+import { GuardManifest, noopParser } from '../guards';
+
+const SPL_TOKEN_2022_PROGRAM_ID = publicKey(
+  'TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb'
+);
+
+/**
+ * The bondingCurve guard charges a price that increases as the
+ * Candy Machine sells out. The price is a function of the ratio
+ * between the items redeemed and the items available, following
+ * either a linear or an exponential curve.
+ *
+ * The price can be paid in SOL or in tokens of a certain mint
+ * account, using the SPL Token program or Token2022.
+ */
+export const bondingCurveGuardManifest: GuardManifest<
+  BondingCurveArgs,
+  BondingCurve,
+  BondingCurveMintArgs
+> = {
+  name: 'bondingCurve',
+  serializer: () =>
+    fixSerializer(getBondingCurveSerializer(), 1 + 8 + 8 + 9 + 33 + 32),
+  mintParser: (context, mintContext, args) => {
+    const mint = unwrapOption(
+      isOption(args.mint) ? args.mint : wrapNullable(args.mint)
+    );
+    if (!mint) {
+      return {
+        data: new Uint8Array(),
+        remainingAccounts: [{ publicKey: args.destination, isWritable: true }],
+      };
+    }
+
+    const tokenProgram = args.tokenProgram ?? getSplTokenProgramId(context);
+    if (tokenProgram !== SPL_TOKEN_2022_PROGRAM_ID) {
+      const [sourceAta] = findAssociatedTokenPda(context, {
+        mint,
+        owner: mintContext.minter.publicKey,
+      });
+      return {
+        data: new Uint8Array(),
+        remainingAccounts: [
+          { publicKey: sourceAta, isWritable: true },
+          { publicKey: args.destination, isWritable: true },
+          { publicKey: tokenProgram, isWritable: false },
+        ],
+      };
+    }
+
+    const associatedTokenProgramId =
+      context.programs.get('splAssociatedToken').publicKey;
+    const sourceAta = context.eddsa.findPda(associatedTokenProgramId, [
+      publicKeySerializer().serialize(mintContext.minter.publicKey),
+      publicKeySerializer().serialize(SPL_TOKEN_2022_PROGRAM_ID),
+      publicKeySerializer().serialize(mint),
+    ])[0];
+
+    return {
+      data: new Uint8Array(),
+      remainingAccounts: [
+        { publicKey: sourceAta, isWritable: true },
+        { publicKey: args.destination, isWritable: true },
+        { publicKey: mint, isWritable: false },
+        { publicKey: SPL_TOKEN_2022_PROGRAM_ID, isWritable: false },
+      ],
+    };
+  },
+  routeParser: noopParser,
+};
+
+export type BondingCurveMintArgs = Pick<
+  BondingCurveArgs,
+  'mint' | 'destination'
+> & {
+  /**
+   * The token program of the mint used for the payment.
+   * Defaults to the SPL Token program.
+   */
+  tokenProgram?: PublicKey;
+};
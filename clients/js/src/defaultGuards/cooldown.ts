@@ -0,0 +1,86 @@
+import { PublicKey, Signer } from '@metaplex-foundation/umi';
+import {
+  Cooldown,
+  CooldownArgs,
+  findCooldownTrackerPda,
+  getCooldownSerializer,
+} from '../generated';
+import { CLOSE_GUARD_PDA_DATA, GuardManifest } from '../guards';
+
+/**
+ * The cooldown guard sets a minimum interval, in seconds,
+ * between two mints of the same wallet.
+ *
+ * The timestamp of the last mint of each wallet is kept in a
+ * PDA identified by the wallet, the Candy Machine and the
+ * identifier provided in the settings. This allows multiple
+ * cooldowns within a Candy Machine, e.g. one per group.
+ */
+export const cooldownGuardManifest: GuardManifest<
+  CooldownArgs,
+  Cooldown,
+  CooldownMintArgs,
+  CooldownRouteArgs
+> = {
+  name: 'cooldown',
+  serializer: getCooldownSerializer,
+  mintParser: (context, mintContext, args) => ({
+    data: new Uint8Array(),
+    remainingAccounts: [
+      {
+        publicKey: findCooldownTrackerPda(context, {
+          id: args.id,
+          user: mintContext.minter.publicKey,
+          candyMachine: mintContext.candyMachine,
+          candyGuard: mintContext.candyGuard,
+        })[0],
+        isWritable: true,
+      },
+    ],
+  }),
+  routeParser: (context, routeContext, args) => ({
+    data: CLOSE_GUARD_PDA_DATA,
+    remainingAccounts: [
+      {
+        publicKey: findCooldownTrackerPda(context, {
+          id: args.id,
+          user: args.minter ?? args.authority.publicKey,
+          candyMachine: routeContext.candyMachine,
+          candyGuard: routeContext.candyGuard,
+        })[0],
+        isWritable: true,
+      },
+      { signer: args.authority, isWritable: true },
+      ...(args.minter !== undefined
+        ? [{ publicKey: args.minter, isWritable: false }]
+        : []),
+    ],
+  }),
+};
+
+export type CooldownMintArgs = Omit<CooldownArgs, 'interval'>;
+
+/**
+ * The settings for the cooldown guard that should be provided
+ * when accessing the guard's special "route" instruction.
+ *
+ * ## Close
+ * The `close` path closes the cooldown tracker of a wallet and
+ * transfers its lamports to the `authority`. The wallet can
+ * close its own tracker once the Candy Machine is sold out,
+ * whereas the Candy Guard authority can only close trackers
+ * once the Candy Machine was withdrawn.
+ */
+export type CooldownRouteArgs = Omit<CooldownArgs, 'interval'> & {
+  /** Selects the path to execute in the route instruction. */
+  path: 'close';
+
+  /** The wallet or the Candy Guard authority, which receives the lamports. */
+  authority: Signer;
+
+  /**
+   * The wallet of the cooldown tracker, when it is
+   * closed by the Candy Guard authority.
+   */
+  minter?: PublicKey;
+};
@@ -5,7 +5,11 @@ import {
   Allocation,
   AllocationArgs,
   AllowList,
+  AllowListAllotment,
+  AllowListAllotmentArgs,
   AllowListArgs,
+  AnyOf,
+  AnyOfArgs,
   AssetBurn,
   AssetBurnArgs,
   AssetBurnMulti,
@@ -18,18 +22,36 @@ import {
   AssetPaymentArgs,
   AssetPaymentMulti,
   AssetPaymentMultiArgs,
+  AssetTraitGate,
+  AssetTraitGateArgs,
+  AutoGroup,
+  AutoGroupArgs,
+  BondingCurve,
+  BondingCurveArgs,
   BotTax,
   BotTaxArgs,
+  Cooldown,
+  CooldownArgs,
+  DutchAuction,
+  DutchAuctionArgs,
   Edition,
   EditionArgs,
   EndDate,
   EndDateArgs,
+  EndSlot,
+  EndSlotArgs,
+  ExternalGuard,
+  ExternalGuardArgs,
   FreezeSolPayment,
   FreezeSolPaymentArgs,
   FreezeTokenPayment,
   FreezeTokenPaymentArgs,
+  GateUsageLimit,
+  GateUsageLimitArgs,
   Gatekeeper,
   GatekeeperArgs,
+  HolderDiscount,
+  HolderDiscountArgs,
   MintLimit,
   MintLimitArgs,
   NftBurn,
@@ -40,16 +62,32 @@ import {
   NftMintLimitArgs,
   NftPayment,
   NftPaymentArgs,
+  PhaseSchedule,
+  PhaseScheduleArgs,
   ProgramGate,
   ProgramGateArgs,
+  RateLimit,
+  RateLimitArgs,
+  Receipt,
+  ReceiptArgs,
+  RecurringWindow,
+  RecurringWindowArgs,
   RedeemedAmount,
   RedeemedAmountArgs,
+  SignedVoucher,
+  SignedVoucherArgs,
   SolFixedFee,
   SolFixedFeeArgs,
+  SolGate,
+  SolGateArgs,
   SolPayment,
   SolPaymentArgs,
+  SolPaymentSplit,
+  SolPaymentSplitArgs,
   StartDate,
   StartDateArgs,
+  StartSlot,
+  StartSlotArgs,
   ThirdPartySigner,
   ThirdPartySignerArgs,
   Token2022Payment,
@@ -60,6 +98,8 @@ import {
   TokenGateArgs,
   TokenPayment,
   TokenPaymentArgs,
+  TokenPaymentSplit,
+  TokenPaymentSplitArgs,
   VanityMint,
   VanityMintArgs,
 } from '../generated';
@@ -80,7 +120,7 @@ import {
   FreezeTokenPaymentRouteArgs,
 } from './freezeTokenPayment';
 import { GatekeeperMintArgs } from './gatekeeper';
-import { MintLimitMintArgs } from './mintLimit';
+import { MintLimitMintArgs, MintLimitRouteArgs } from './mintLimit';
 import { NftBurnMintArgs } from './nftBurn';
 import { NftGateMintArgs } from './nftGate';
 import { NftPaymentMintArgs } from './nftPayment';
@@ -91,13 +131,41 @@ import { TokenBurnMintArgs } from './tokenBurn';
 import { TokenGateMintArgs } from './tokenGate';
 import { TokenPaymentMintArgs } from './tokenPayment';
 import { SolFixedFeeMintArgs } from './solFixedFee';
-import { NftMintLimitMintArgs } from './nftMintLimit';
+import { NftMintLimitMintArgs, NftMintLimitRouteArgs } from './nftMintLimit';
 import { AssetPaymentMintArgs } from './assetPayment';
 import { AssetBurnMintArgs } from './assetBurn';
-import { AssetMintLimitMintArgs } from './assetMintLimit';
+import {
+  AssetMintLimitMintArgs,
+  AssetMintLimitRouteArgs,
+} from './assetMintLimit';
 import { AssetBurnMultiMintArgs } from './assetBurnMulti';
 import { AssetPaymentMultiMintArgs } from './assetPaymentMulti';
 import { AssetGateMintArgs } from './assetGate';
+import { ReceiptMintArgs, ReceiptRouteArgs } from './receipt';
+import { DutchAuctionMintArgs, DutchAuctionRouteArgs } from './dutchAuction';
+import { BondingCurveMintArgs } from './bondingCurve';
+import { SolPaymentSplitMintArgs } from './solPaymentSplit';
+import { TokenPaymentSplitMintArgs } from './tokenPaymentSplit';
+import { AnyOfMintArgs } from './anyOf';
+import { PhaseScheduleRouteArgs } from './phaseSchedule';
+import { ExternalGuardMintArgs } from './externalGuard';
+import { SignedVoucherMintArgs } from './signedVoucher';
+import {
+  AllowListAllotmentMintArgs,
+  AllowListAllotmentRouteArgs,
+} from './allowListAllotment';
+import { CooldownMintArgs, CooldownRouteArgs } from './cooldown';
+import { RateLimitMintArgs, RateLimitRouteArgs } from './rateLimit';
+import { SolGateMintArgs, SolGateRouteArgs } from './solGate';
+import { AssetTraitGateMintArgs } from './assetTraitGate';
+import {
+  HolderDiscountMintArgs,
+  HolderDiscountRouteArgs,
+} from './holderDiscount';
+import {
+  GateUsageLimitMintArgs,
+  GateUsageLimitRouteArgs,
+} from './gateUsageLimit';
 
 /**
  * The arguments for all default Candy Machine guards.
@@ -134,6 +202,26 @@ export type DefaultGuardSetArgs = GuardSetArgs & {
   assetPaymentMulti: OptionOrNullable<AssetPaymentMultiArgs>;
   assetGate: OptionOrNullable<AssetGateArgs>;
   vanityMint: OptionOrNullable<VanityMintArgs>;
+  receipt: OptionOrNullable<ReceiptArgs>;
+  dutchAuction: OptionOrNullable<DutchAuctionArgs>;
+  bondingCurve: OptionOrNullable<BondingCurveArgs>;
+  solPaymentSplit: OptionOrNullable<SolPaymentSplitArgs>;
+  tokenPaymentSplit: OptionOrNullable<TokenPaymentSplitArgs>;
+  anyOf: OptionOrNullable<AnyOfArgs>;
+  autoGroup: OptionOrNullable<AutoGroupArgs>;
+  phaseSchedule: OptionOrNullable<PhaseScheduleArgs>;
+  externalGuard: OptionOrNullable<ExternalGuardArgs>;
+  signedVoucher: OptionOrNullable<SignedVoucherArgs>;
+  allowListAllotment: OptionOrNullable<AllowListAllotmentArgs>;
+  cooldown: OptionOrNullable<CooldownArgs>;
+  rateLimit: OptionOrNullable<RateLimitArgs>;
+  startSlot: OptionOrNullable<StartSlotArgs>;
+  endSlot: OptionOrNullable<EndSlotArgs>;
+  recurringWindow: OptionOrNullable<RecurringWindowArgs>;
+  solGate: OptionOrNullable<SolGateArgs>;
+  assetTraitGate: OptionOrNullable<AssetTraitGateArgs>;
+  holderDiscount: OptionOrNullable<HolderDiscountArgs>;
+  gateUsageLimit: OptionOrNullable<GateUsageLimitArgs>;
 };
 
 /**
@@ -171,6 +259,26 @@ export type DefaultGuardSet = GuardSet & {
   assetPaymentMulti: Option<AssetPaymentMulti>;
   assetGate: Option<AssetGate>;
   vanityMint: Option<VanityMint>;
+  receipt: Option<Receipt>;
+  dutchAuction: Option<DutchAuction>;
+  bondingCurve: Option<BondingCurve>;
+  solPaymentSplit: Option<SolPaymentSplit>;
+  tokenPaymentSplit: Option<TokenPaymentSplit>;
+  anyOf: Option<AnyOf>;
+  autoGroup: Option<AutoGroup>;
+  phaseSchedule: Option<PhaseSchedule>;
+  externalGuard: Option<ExternalGuard>;
+  signedVoucher: Option<SignedVoucher>;
+  allowListAllotment: Option<AllowListAllotment>;
+  cooldown: Option<Cooldown>;
+  rateLimit: Option<RateLimit>;
+  startSlot: Option<StartSlot>;
+  endSlot: Option<EndSlot>;
+  recurringWindow: Option<RecurringWindow>;
+  solGate: Option<SolGate>;
+  assetTraitGate: Option<AssetTraitGate>;
+  holderDiscount: Option<HolderDiscount>;
+  gateUsageLimit: Option<GateUsageLimit>;
 };
 
 /**
@@ -207,6 +315,26 @@ export type DefaultGuardSetMintArgs = GuardSetMintArgs & {
   assetBurnMulti: OptionOrNullable<AssetBurnMultiMintArgs>;
   assetPaymentMulti: OptionOrNullable<AssetPaymentMultiMintArgs>;
   assetGate: OptionOrNullable<AssetGateMintArgs>;
+  receipt: OptionOrNullable<ReceiptMintArgs>;
+  dutchAuction: OptionOrNullable<DutchAuctionMintArgs>;
+  bondingCurve: OptionOrNullable<BondingCurveMintArgs>;
+  solPaymentSplit: OptionOrNullable<SolPaymentSplitMintArgs>;
+  tokenPaymentSplit: OptionOrNullable<TokenPaymentSplitMintArgs>;
+  anyOf: OptionOrNullable<AnyOfMintArgs>;
+  // autoGroup: no mint settings
+  // phaseSchedule: no mint settings
+  externalGuard: OptionOrNullable<ExternalGuardMintArgs>;
+  signedVoucher: OptionOrNullable<SignedVoucherMintArgs>;
+  allowListAllotment: OptionOrNullable<AllowListAllotmentMintArgs>;
+  cooldown: OptionOrNullable<CooldownMintArgs>;
+  rateLimit: OptionOrNullable<RateLimitMintArgs>;
+  // startSlot: no mint settings
+  // endSlot: no mint settings
+  // recurringWindow: no mint settings
+  solGate: OptionOrNullable<SolGateMintArgs>;
+  assetTraitGate: OptionOrNullable<AssetTraitGateMintArgs>;
+  holderDiscount: OptionOrNullable<HolderDiscountMintArgs>;
+  gateUsageLimit: OptionOrNullable<GateUsageLimitMintArgs>;
 };
 
 /**
@@ -222,7 +350,7 @@ export type DefaultGuardSetRouteArgs = GuardSetRouteArgs & {
   // gatekeeper: no route settings
   // endDate: no route settings
   allowList: AllowListRouteArgs;
-  // mintLimit: no route settings
+  mintLimit: MintLimitRouteArgs;
   // nftPayment: no route settings
   // redeemedAmount: no route settings
   // addressGate: no route settings
@@ -234,6 +362,28 @@ export type DefaultGuardSetRouteArgs = GuardSetRouteArgs & {
   // programGate: no route settings
   allocation: AllocationRouteArgs;
   // token2022Payment: no route settings
+  nftMintLimit: NftMintLimitRouteArgs;
+  assetMintLimit: AssetMintLimitRouteArgs;
+  receipt: ReceiptRouteArgs;
+  dutchAuction: DutchAuctionRouteArgs;
+  // bondingCurve: no route settings
+  // solPaymentSplit: no route settings
+  // tokenPaymentSplit: no route settings
+  // anyOf: no route settings
+  // autoGroup: no route settings
+  phaseSchedule: PhaseScheduleRouteArgs;
+  // externalGuard: no route settings
+  // signedVoucher: no route settings
+  allowListAllotment: AllowListAllotmentRouteArgs;
+  cooldown: CooldownRouteArgs;
+  rateLimit: RateLimitRouteArgs;
+  // startSlot: no route settings
+  // endSlot: no route settings
+  // recurringWindow: no route settings
+  solGate: SolGateRouteArgs;
+  // assetTraitGate: no route settings
+  holderDiscount: HolderDiscountRouteArgs;
+  gateUsageLimit: GateUsageLimitRouteArgs;
 };
 
 /** @internal */
@@ -269,6 +419,26 @@ export const defaultCandyGuardNames: string[] = [
   'assetPaymentMulti',
   'assetGate',
   'vanityMint',
+  'receipt',
+  'dutchAuction',
+  'bondingCurve',
+  'solPaymentSplit',
+  'tokenPaymentSplit',
+  'anyOf',
+  'autoGroup',
+  'phaseSchedule',
+  'externalGuard',
+  'signedVoucher',
+  'allowListAllotment',
+  'cooldown',
+  'rateLimit',
+  'startSlot',
+  'endSlot',
+  'recurringWindow',
+  'solGate',
+  'assetTraitGate',
+  'holderDiscount',
+  'gateUsageLimit',
 ];
 
 /** @internal */
@@ -0,0 +1,270 @@
+import { getSplSystemProgramId } from '@metaplex-foundation/mpl-toolbox';
+import { PublicKey, Signer, unwrapOption } from '@metaplex-foundation/umi';
+import { UnrecognizePathForRouteInstructionError } from '../errors';
+import {
+  DutchAuction,
+  DutchAuctionArgs,
+  DutchAuctionInstruction,
+  findAuctionBidPda,
+  findAuctionEscrowPda,
+  getDutchAuctionInstructionSerializer,
+  getDutchAuctionSerializer,
+} from '../generated';
+import {
+  GuardManifest,
+  GuardRemainingAccount,
+  RouteContext,
+  RouteParser,
+} from '../guards';
+
+/**
+ * The dutchAuction guard charges a descending price in SOL for the mint.
+ * The price starts at `startPrice` and drops by `decayStep` every
+ * `interval` seconds until it reaches the `floorPrice`.
+ *
+ * When `rebate` is enabled, payments are held in an escrow account and
+ * buyers can claim the difference between the price they paid and the
+ * clearing price of the auction once it is settled.
+ *
+ * Escrow and bid accounts are derived from the label of the group used,
+ * so the same group must be provided when minting and routing.
+ *
+ * @see {@link DutchAuctionRouteArgs} to learn more about
+ * the instructions that can be executed against this guard.
+ */
+export const dutchAuctionGuardManifest: GuardManifest<
+  DutchAuctionArgs,
+  DutchAuction,
+  DutchAuctionMintArgs,
+  DutchAuctionRouteArgs
+> = {
+  name: 'dutchAuction',
+  serializer: getDutchAuctionSerializer,
+  mintParser: (context, mintContext, args) => {
+    if (!args.rebate) {
+      return {
+        data: new Uint8Array(),
+        remainingAccounts: [{ publicKey: args.destination, isWritable: true }],
+      };
+    }
+
+    const [auctionEscrow] = findAuctionEscrowPda(
+      context,
+      escrowSeeds(mintContext, args.destination)
+    );
+    const [auctionBid] = findAuctionBidPda(context, {
+      minter: mintContext.minter.publicKey,
+      candyGuard: mintContext.candyGuard,
+      candyMachine: mintContext.candyMachine,
+      group: unwrapOption(mintContext.group) ?? '',
+    });
+
+    return {
+      data: new Uint8Array(),
+      remainingAccounts: [
+        { publicKey: auctionEscrow, isWritable: true },
+        { publicKey: auctionBid, isWritable: true },
+      ],
+    };
+  },
+  routeParser: (context, routeContext, args) => {
+    const { path } = args;
+    switch (path) {
+      case 'initialize':
+        return initializeRouteInstruction(context, routeContext, args);
+      case 'claim':
+        return claimRouteInstruction(context, routeContext, args);
+      case 'withdraw':
+        return withdrawRouteInstruction(context, routeContext, args);
+      default:
+        throw new UnrecognizePathForRouteInstructionError(
+          'dutchAuction',
+          path
+        );
+    }
+  },
+};
+
+export type DutchAuctionMintArgs = Pick<
+  DutchAuctionArgs,
+  'destination' | 'rebate'
+>;
+
+/**
+ * The settings for the dutchAuction guard that should be provided
+ * when accessing the guard's special "route" instruction.
+ */
+export type DutchAuctionRouteArgs =
+  | DutchAuctionRouteArgsInitialize
+  | DutchAuctionRouteArgsClaim
+  | DutchAuctionRouteArgsWithdraw;
+
+/**
+ * The `initialize` path creates the escrow account that holds the
+ * payments of an auction with rebates. It must be called before
+ * any asset can be minted.
+ *
+ * ```ts
+ * route(umi, {
+ *   // ...
+ *   guard: 'dutchAuction',
+ *   routeArgs: {
+ *     path: 'initialize',
+ *     destination,
+ *     candyGuardAuthority,
+ *   },
+ * });
+ * ```
+ */
+export type DutchAuctionRouteArgsInitialize = Pick<
+  DutchAuctionArgs,
+  'destination'
+> & {
+  /** Selects the path to execute in the route instruction. */
+  path: 'initialize';
+
+  /** The authority of the Candy Guard as a Signer. */
+  candyGuardAuthority: Signer;
+};
+
+/**
+ * The `claim` path transfers the rebate of a buyer once the auction
+ * is settled and closes its bid account, refunding the rent to the
+ * wallet that paid for it.
+ *
+ * Anyone can call this instruction on behalf of the buyer.
+ *
+ * ```ts
+ * route(umi, {
+ *   // ...
+ *   guard: 'dutchAuction',
+ *   routeArgs: {
+ *     path: 'claim',
+ *     destination,
+ *     minter,
+ *     bidPayer,
+ *   },
+ * });
+ * ```
+ */
+export type DutchAuctionRouteArgsClaim = Pick<
+  DutchAuctionArgs,
+  'destination'
+> & {
+  /** Selects the path to execute in the route instruction. */
+  path: 'claim';
+
+  /** The address of the buyer, which receives the rebate. */
+  minter: PublicKey;
+
+  /** The address that paid for the bid account, which receives the rent. */
+  bidPayer: PublicKey;
+};
+
+/**
+ * The `withdraw` path transfers the proceeds of the auction at the
+ * clearing price to the configured destination address. Once the
+ * mint is over and all rebates were claimed, the escrow account
+ * is closed.
+ *
+ * ```ts
+ * route(umi, {
+ *   // ...
+ *   guard: 'dutchAuction',
+ *   routeArgs: {
+ *     path: 'withdraw',
+ *     destination,
+ *     candyGuardAuthority,
+ *   },
+ * });
+ * ```
+ */
+export type DutchAuctionRouteArgsWithdraw = Pick<
+  DutchAuctionArgs,
+  'destination'
+> & {
+  /** Selects the path to execute in the route instruction. */
+  path: 'withdraw';
+
+  /** The authority of the Candy Guard as a Signer. */
+  candyGuardAuthority: Signer;
+};
+
+const escrowSeeds = (
+  context: Pick<RouteContext, 'candyGuard' | 'candyMachine' | 'group'>,
+  destination: PublicKey
+) => ({
+  destination,
+  candyGuard: context.candyGuard,
+  candyMachine: context.candyMachine,
+  group: unwrapOption(context.group) ?? '',
+});
+
+const initializeRouteInstruction: RouteParser<
+  DutchAuctionRouteArgsInitialize
+> = (context, routeContext, args) => {
+  const [auctionEscrow] = findAuctionEscrowPda(
+    context,
+    escrowSeeds(routeContext, args.destination)
+  );
+  return {
+    data: getDutchAuctionInstructionSerializer().serialize(
+      DutchAuctionInstruction.Initialize
+    ),
+    remainingAccounts: [
+      { publicKey: auctionEscrow, isWritable: true },
+      { signer: args.candyGuardAuthority, isWritable: false },
+      { publicKey: getSplSystemProgramId(context), isWritable: false },
+    ],
+  };
+};
+
+const claimRouteInstruction: RouteParser<DutchAuctionRouteArgsClaim> = (
+  context,
+  routeContext,
+  args
+) => {
+  const [auctionEscrow] = findAuctionEscrowPda(
+    context,
+    escrowSeeds(routeContext, args.destination)
+  );
+  const [auctionBid] = findAuctionBidPda(context, {
+    minter: args.minter,
+    candyGuard: routeContext.candyGuard,
+    candyMachine: routeContext.candyMachine,
+    group: unwrapOption(routeContext.group) ?? '',
+  });
+
+  const data = getDutchAuctionInstructionSerializer().serialize(
+    DutchAuctionInstruction.Claim
+  );
+  const remainingAccounts: GuardRemainingAccount[] = [
+    { publicKey: auctionEscrow, isWritable: true },
+    { publicKey: auctionBid, isWritable: true },
+    { publicKey: args.minter, isWritable: true },
+    { publicKey: args.bidPayer, isWritable: true },
+  ];
+
+  return { data, remainingAccounts };
+};
+
+const withdrawRouteInstruction: RouteParser<DutchAuctionRouteArgsWithdraw> = (
+  context,
+  routeContext,
+  args
+) => {
+  const [auctionEscrow] = findAuctionEscrowPda(
+    context,
+    escrowSeeds(routeContext, args.destination)
+  );
+  return {
+    data: getDutchAuctionInstructionSerializer().serialize(
+      DutchAuctionInstruction.Withdraw
+    ),
+    remainingAccounts: [
+      { publicKey: auctionEscrow, isWritable: true },
+      { signer: args.candyGuardAuthority, isWritable: false },
+      { publicKey: args.destination, isWritable: true },
+    ],
+  };
+};
@@ -0,0 +1,13 @@
+import { getEndSlotSerializer, EndSlot, EndSlotArgs } from '../generated';
+import { GuardManifest, noopParser } from '../guards';
+
+/**
+ * The endSlot guard is used to specify a slot to end the mint.
+ * Any transaction received from this slot onwards will fail.
+ */
+export const endSlotGuardManifest: GuardManifest<EndSlotArgs, EndSlot> = {
+  name: 'endSlot',
+  serializer: getEndSlotSerializer,
+  mintParser: noopParser,
+  routeParser: noopParser,
+};
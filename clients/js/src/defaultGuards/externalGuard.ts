@@ -0,0 +1,54 @@
+import { PublicKey } from '@metaplex-foundation/umi';
+import { fixSerializer } from '@metaplex-foundation/umi/serializers';
+import {
+  ExternalGuard,
+  ExternalGuardArgs,
+  getExternalGuardSerializer,
+} from '../generated';
+import { GuardManifest, noopParser } from '../guards';
+
+/**
+ * The externalGuard guard delegates the validation of the mint
+ * to another program. That program is invoked via CPI with the
+ * `validate_mint` and `pre_mint` instructions and the mint fails
+ * if any of these instructions fails.
+ *
+ * The external program is only invoked once all guards validated,
+ * so it is not consulted when selecting an anyOf alternative or an
+ * autoGroup group, nor when checking the eligibility of a mint.
+ *
+ * The configuration bytes of the guard are forwarded to the
+ * external program, along with the number of accounts defined
+ * by the `accounts` setting. The configuration is limited to
+ * 64 bytes.
+ */
+export const externalGuardGuardManifest: GuardManifest<
+  ExternalGuardArgs,
+  ExternalGuard,
+  ExternalGuardMintArgs
+> = {
+  name: 'externalGuard',
+  serializer: () =>
+    fixSerializer(getExternalGuardSerializer(), 32 + 4 + 64 + 1),
+  mintParser: (context, mintContext, args) => ({
+    data: new Uint8Array(),
+    remainingAccounts: [
+      { publicKey: args.programId, isWritable: false },
+      ...(args.accounts ?? []),
+    ],
+  }),
+  routeParser: noopParser,
+};
+
+export type ExternalGuardMintArgs = {
+  /** The address of the external program. */
+  programId: PublicKey;
+
+  /**
+   * The accounts forwarded to the external program. Their number
+   * must match the `accounts` setting of the guard.
+   *
+   * @defaultValue `[]`
+   */
+  accounts?: { publicKey: PublicKey; isWritable: boolean }[];
+};
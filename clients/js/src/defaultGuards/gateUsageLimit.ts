@@ -0,0 +1,97 @@
+import { PublicKey, Signer } from '@metaplex-foundation/umi';
+import {
+  findGateUsagePda,
+  GateUsageLimit,
+  GateUsageLimitArgs,
+  getGateUsageLimitSerializer,
+} from '../generated';
+import {
+  CLOSE_GUARD_PDA_DATA,
+  GuardManifest,
+  GuardRemainingAccount,
+} from '../guards';
+
+/**
+ * The gateUsageLimit guard limits the number of mints each gating
+ * Asset of the assetGate guard and each gating NFT of the nftGate
+ * guard of the guard set can be used for, regardless of who holds it.
+ *
+ * The number of mints of each gating Asset or NFT is kept in a PDA
+ * identified by the Asset or NFT mint and the Candy Machine, so
+ * transferring it to another wallet does not reset its usage.
+ */
+export const gateUsageLimitGuardManifest: GuardManifest<
+  GateUsageLimitArgs,
+  GateUsageLimit,
+  GateUsageLimitMintArgs,
+  GateUsageLimitRouteArgs
+> = {
+  name: 'gateUsageLimit',
+  serializer: getGateUsageLimitSerializer,
+  mintParser: (context, mintContext, args) => {
+    const gatingKeys = [args.asset, args.mint].filter(
+      (gating): gating is PublicKey => gating !== undefined
+    );
+    const remainingAccounts: GuardRemainingAccount[] = gatingKeys.map(
+      (gating) => ({
+        publicKey: findGateUsagePda(context, {
+          asset: gating,
+          candyMachine: mintContext.candyMachine,
+          candyGuard: mintContext.candyGuard,
+        })[0],
+        isWritable: true,
+      })
+    );
+    return { data: new Uint8Array(), remainingAccounts };
+  },
+  routeParser: (context, routeContext, args) => ({
+    data: CLOSE_GUARD_PDA_DATA,
+    remainingAccounts: [
+      {
+        publicKey: findGateUsagePda(context, {
+          asset: args.asset,
+          candyMachine: routeContext.candyMachine,
+          candyGuard: routeContext.candyGuard,
+        })[0],
+        isWritable: true,
+      },
+      { signer: args.candyGuardAuthority, isWritable: true },
+      { publicKey: args.asset, isWritable: false },
+    ],
+  }),
+};
+
+/**
+ * The settings for the gateUsageLimit guard that should
+ * be provided to the mint instruction.
+ *
+ * The gating Asset and NFT must match the ones provided
+ * to the assetGate and nftGate guards respectively.
+ */
+export type GateUsageLimitMintArgs = {
+  /** The address of the gating Asset, when the assetGate guard is enabled. */
+  asset?: PublicKey;
+
+  /** The mint address of the gating NFT, when the nftGate guard is enabled. */
+  mint?: PublicKey;
+};
+
+/**
+ * The settings for the gateUsageLimit guard that should be provided
+ * when accessing the guard's special "route" instruction.
+ *
+ * ## Close
+ * The `close` path closes the usage PDA of a gating Asset or NFT
+ * and transfers its lamports to the Candy Guard authority once
+ * the Candy Machine was withdrawn.
+ */
+export type GateUsageLimitRouteArgs = {
+  /** Selects the path to execute in the route instruction. */
+  path: 'close';
+
+  /** The address of the gating Asset or the mint address of the NFT. */
+  asset: PublicKey;
+
+  /** The authority of the Candy Guard as a Signer. */
+  candyGuardAuthority: Signer;
+};
@@ -0,0 +1,142 @@
+import { findAssociatedTokenPda } from '@metaplex-foundation/mpl-toolbox';
+import { findMetadataPda } from '@metaplex-foundation/mpl-token-metadata';
+import { PublicKey, Signer } from '@metaplex-foundation/umi';
+import { bool, fixSerializer } from '@metaplex-foundation/umi/serializers';
+import {
+  findDiscountUsagePda,
+  getHolderDiscountSerializer,
+  HolderDiscount,
+  HolderDiscountArgs,
+} from '../generated';
+import {
+  CLOSE_GUARD_PDA_DATA,
+  GuardManifest,
+  GuardRemainingAccount,
+} from '../guards';
+
+/**
+ * The holderDiscount guard applies a discount to the solPayment,
+ * tokenPayment and token2022Payment guards of the guard set when
+ * the minter holds an Asset or a legacy NFT from a specified collection.
+ *
+ * The discount is either a percentage of the price, in basis points,
+ * or a fixed amount deducted from the price in the units of each
+ * payment guard. The discount is only applied when the guard's mint
+ * settings are provided, otherwise the full price is charged.
+ *
+ * When `singleUse` is set, each gating Asset or NFT can only be
+ * used once for the discount, which is recorded in a PDA.
+ */
+export const holderDiscountGuardManifest: GuardManifest<
+  HolderDiscountArgs,
+  HolderDiscount,
+  HolderDiscountMintArgs,
+  HolderDiscountRouteArgs
+> = {
+  name: 'holderDiscount',
+  serializer: () =>
+    fixSerializer(getHolderDiscountSerializer(), 32 + 1 + 8 + 1),
+  mintParser: (context, mintContext, args) => {
+    const remainingAccounts: GuardRemainingAccount[] = [];
+    let asset: PublicKey;
+    if ('asset' in args) {
+      asset = args.asset;
+      remainingAccounts.push({ publicKey: asset, isWritable: false });
+    } else {
+      asset = args.mint;
+      const tokenAccount =
+        args.tokenAccount ??
+        findAssociatedTokenPda(context, {
+          mint: args.mint,
+          owner: mintContext.minter.publicKey,
+        })[0];
+      const [tokenMetadata] = findMetadataPda(context, { mint: args.mint });
+      remainingAccounts.push(
+        { publicKey: tokenAccount, isWritable: false },
+        { publicKey: tokenMetadata, isWritable: false }
+      );
+    }
+    if (args.singleUse) {
+      remainingAccounts.push({
+        publicKey: findDiscountUsagePda(context, {
+          asset,
+          candyMachine: mintContext.candyMachine,
+          candyGuard: mintContext.candyGuard,
+        })[0],
+        isWritable: true,
+      });
+    }
+    return { data: bool().serialize(true), remainingAccounts };
+  },
+  routeParser: (context, routeContext, args) => ({
+    data: CLOSE_GUARD_PDA_DATA,
+    remainingAccounts: [
+      {
+        publicKey: findDiscountUsagePda(context, {
+          asset: args.asset,
+          candyMachine: routeContext.candyMachine,
+          candyGuard: routeContext.candyGuard,
+        })[0],
+        isWritable: true,
+      },
+      { signer: args.candyGuardAuthority, isWritable: true },
+      { publicKey: args.asset, isWritable: false },
+    ],
+  }),
+};
+
+/**
+ * The settings for the holderDiscount guard that could
+ * be provided to the mint instruction to claim the discount.
+ *
+ * The gating asset is either an Asset or a legacy NFT and the
+ * `singleUse` setting of the guard must be provided so the
+ * usage of the gating asset can be recorded.
+ */
+export type HolderDiscountMintArgs = Pick<HolderDiscountArgs, 'singleUse'> &
+  (HolderDiscountMintArgsAsset | HolderDiscountMintArgsNft);
+
+export type HolderDiscountMintArgsAsset = {
+  /**
+   * The address of an Asset from the required
+   * collection that belongs to the minter.
+   */
+  asset: PublicKey;
+};
+
+export type HolderDiscountMintArgsNft = {
+  /**
+   * The mint address of an NFT from the required
+   * collection that belongs to the minter.
+   */
+  mint: PublicKey;
+
+  /**
+   * The token account linking the NFT with its owner.
+   *
+   * @defaultValue
+   * Defaults to the associated token address using the
+   * mint address of the NFT and the minter's address.
+   */
+  tokenAccount?: PublicKey;
+};
+
+/**
+ * The settings for the holderDiscount guard that should be provided
+ * when accessing the guard's special "route" instruction.
+ *
+ * ## Close
+ * The `close` path closes the discount usage PDA of a gating
+ * Asset or NFT and transfers its lamports to the Candy Guard
+ * authority once the Candy Machine was withdrawn.
+ */
+export type HolderDiscountRouteArgs = {
+  /** Selects the path to execute in the route instruction. */
+  path: 'close';
+
+  /** The address of the gating Asset or the mint address of the NFT. */
+  asset: PublicKey;
+
+  /** The authority of the Candy Guard as a Signer. */
+  candyGuardAuthority: Signer;
+};
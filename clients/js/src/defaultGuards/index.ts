@@ -30,3 +30,23 @@ export * from './assetBurnMulti';
 export * from './assetPaymentMulti';
 export * from './assetGate';
 export * from './vanityMint';
+export * from './receipt';
+export * from './dutchAuction';
+export * from './bondingCurve';
+export * from './solPaymentSplit';
+export * from './tokenPaymentSplit';
+export * from './anyOf';
+export * from './autoGroup';
+export * from './phaseSchedule';
+export * from './externalGuard';
+export * from './signedVoucher';
+export * from './allowListAllotment';
+export * from './cooldown';
+export * from './rateLimit';
+export * from './startSlot';
+export * from './endSlot';
+export * from './recurringWindow';
+export * from './solGate';
+export * from './assetTraitGate';
+export * from './holderDiscount';
+export * from './gateUsageLimit';
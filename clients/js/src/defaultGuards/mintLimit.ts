@@ -1,10 +1,11 @@
+import { PublicKey, Signer } from '@metaplex-foundation/umi';
 import {
   findMintCounterPda,
   getMintLimitSerializer,
   MintLimit,
   MintLimitArgs,
 } from '../generated';
-import { GuardManifest, noopParser } from '../guards';
+import { CLOSE_GUARD_PDA_DATA, GuardManifest } from '../guards';
 
 /**
  * The mintLimit guard allows to specify a limit on the
@@ -19,7 +20,8 @@ import { GuardManifest, noopParser } from '../guards';
 export const mintLimitGuardManifest: GuardManifest<
   MintLimitArgs,
   MintLimit,
-  MintLimitMintArgs
+  MintLimitMintArgs,
+  MintLimitRouteArgs
 > = {
   name: 'mintLimit',
   serializer: getMintLimitSerializer,
@@ -37,7 +39,49 @@ export const mintLimitGuardManifest: GuardManifest<
       },
     ],
   }),
-  routeParser: noopParser,
+  routeParser: (context, routeContext, args) => ({
+    data: CLOSE_GUARD_PDA_DATA,
+    remainingAccounts: [
+      {
+        publicKey: findMintCounterPda(context, {
+          id: args.id,
+          user: args.minter ?? args.authority.publicKey,
+          candyMachine: routeContext.candyMachine,
+          candyGuard: routeContext.candyGuard,
+        })[0],
+        isWritable: true,
+      },
+      { signer: args.authority, isWritable: true },
+      ...(args.minter !== undefined
+        ? [{ publicKey: args.minter, isWritable: false }]
+        : []),
+    ],
+  }),
 };
 
 export type MintLimitMintArgs = Omit<MintLimitArgs, 'limit'>;
+
+/**
+ * The settings for the mintLimit guard that should be provided
+ * when accessing the guard's special "route" instruction.
+ *
+ * ## Close
+ * The `close` path closes the mint counter of a wallet and
+ * transfers its lamports to the `authority`. The wallet can
+ * close its own counter once the Candy Machine is sold out,
+ * whereas the Candy Guard authority can only close counters
+ * once the Candy Machine was withdrawn.
+ */
+export type MintLimitRouteArgs = Omit<MintLimitArgs, 'limit'> & {
+  /** Selects the path to execute in the route instruction. */
+  path: 'close';
+
+  /** The wallet or the Candy Guard authority, which receives the lamports. */
+  authority: Signer;
+
+  /**
+   * The wallet of the mint counter, when it is
+   * closed by the Candy Guard authority.
+   */
+  minter?: PublicKey;
+};
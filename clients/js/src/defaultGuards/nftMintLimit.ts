@@ -1,13 +1,13 @@
 import { findAssociatedTokenPda } from '@metaplex-foundation/mpl-toolbox';
 import { findMetadataPda } from '@metaplex-foundation/mpl-token-metadata';
-import { PublicKey } from '@metaplex-foundation/umi';
+import { PublicKey, Signer } from '@metaplex-foundation/umi';
 import {
   findNftMintCounterPda,
   getNftMintLimitSerializer,
   NftMintLimit,
   NftMintLimitArgs,
 } from '../generated';
-import { GuardManifest, noopParser } from '../guards';
+import { CLOSE_GUARD_PDA_DATA, GuardManifest } from '../guards';
 
 /**
  * The nftMintLimit guard allows to specify a limit on the
@@ -22,7 +22,8 @@ import { GuardManifest, noopParser } from '../guards';
 export const nftMintLimitGuardManifest: GuardManifest<
   NftMintLimitArgs,
   NftMintLimit,
-  NftMintLimitMintArgs
+  NftMintLimitMintArgs,
+  NftMintLimitRouteArgs
 > = {
   name: 'nftMintLimit',
   serializer: getNftMintLimitSerializer,
@@ -50,7 +51,22 @@ export const nftMintLimitGuardManifest: GuardManifest<
       ],
     };
   },
-  routeParser: noopParser,
+  routeParser: (context, routeContext, args) => ({
+    data: CLOSE_GUARD_PDA_DATA,
+    remainingAccounts: [
+      {
+        publicKey: findNftMintCounterPda(context, {
+          id: args.id,
+          mint: args.mint,
+          candyMachine: routeContext.candyMachine,
+          candyGuard: routeContext.candyGuard,
+        })[0],
+        isWritable: true,
+      },
+      { signer: args.authority, isWritable: true },
+      { publicKey: args.mint, isWritable: false },
+    ],
+  }),
 };
 
 export type NftMintLimitMintArgs = {
@@ -74,3 +90,26 @@ export type NftMintLimitMintArgs = {
    */
   tokenAccount?: PublicKey;
 };
+
+/**
+ * The settings for the nftMintLimit guard that should be provided
+ * when accessing the guard's special "route" instruction.
+ *
+ * ## Close
+ * The `close` path closes a mint counter and transfers its
+ * lamports to the Candy Guard authority once the Candy Machine
+ * was withdrawn.
+ */
+export type NftMintLimitRouteArgs = {
+  /** Selects the path to execute in the route instruction. */
+  path: 'close';
+
+  /** The id of the mint limit. */
+  id: number;
+
+  /** The mint address of the NFT of the mint counter. */
+  mint: PublicKey;
+
+  /** The Candy Guard authority, which receives the lamports. */
+  authority: Signer;
+};
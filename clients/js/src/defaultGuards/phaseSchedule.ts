@@ -0,0 +1,50 @@
+import { fixSerializer } from '@metaplex-foundation/umi/serializers';
+import {
+  getPhaseScheduleSerializer,
+  PhaseSchedule,
+  PhaseScheduleArgs,
+} from '../generated';
+import { GuardManifest, noopParser } from '../guards';
+
+/**
+ * The phaseSchedule guard maps time ranges to groups. When
+ * minting without a group label, the group of the active phase
+ * is used; groups that are part of the schedule can only be
+ * used while one of their phases is active.
+ *
+ * This guard can only be enabled on the default guard set.
+ *
+ * @see {@link PhaseScheduleRouteArgs} to learn more about
+ * the instruction that can be executed against this guard.
+ */
+export const phaseScheduleGuardManifest: GuardManifest<
+  PhaseScheduleArgs,
+  PhaseSchedule,
+  {},
+  PhaseScheduleRouteArgs
+> = {
+  name: 'phaseSchedule',
+  serializer: () =>
+    fixSerializer(getPhaseScheduleSerializer(), 4 + (4 + 32 + 8 + 8) * 5),
+  mintParser: noopParser,
+  routeParser: noopParser,
+};
+
+/**
+ * The phaseSchedule guard arguments that should be provided when
+ * inspecting the schedule through the guard's "route" instruction.
+ *
+ * The status of the schedule is set as the return data of the
+ * instruction and can be deserialized using the
+ * `getPhaseScheduleStatusSerializer` serializer. When none of the
+ * phases is active, a group must be provided to route the instruction.
+ *
+ * ```ts
+ * route(umi, {
+ *   // ...
+ *   guard: 'phaseSchedule',
+ *   routeArgs: {},
+ * });
+ * ```
+ */
+export type PhaseScheduleRouteArgs = {};
@@ -0,0 +1,75 @@
+import { Signer } from '@metaplex-foundation/umi';
+import {
+  findRateLimitTrackerPda,
+  getRateLimitSerializer,
+  RateLimit,
+  RateLimitArgs,
+} from '../generated';
+import { CLOSE_GUARD_PDA_DATA, GuardManifest } from '../guards';
+
+/**
+ * The rateLimit guard limits the number of mints across all
+ * wallets within a fixed time window, in seconds.
+ *
+ * The start of the current window and the number of mints within
+ * it are kept in a PDA identified by the Candy Machine and the
+ * identifier provided in the settings. When the limit of the current
+ * window is reached, minting fails with a `RateLimitExceeded` error.
+ * This error is not subject to the bot tax since the transaction
+ * can be retried once the window elapses.
+ */
+export const rateLimitGuardManifest: GuardManifest<
+  RateLimitArgs,
+  RateLimit,
+  RateLimitMintArgs,
+  RateLimitRouteArgs
+> = {
+  name: 'rateLimit',
+  serializer: getRateLimitSerializer,
+  mintParser: (context, mintContext, args) => ({
+    data: new Uint8Array(),
+    remainingAccounts: [
+      {
+        publicKey: findRateLimitTrackerPda(context, {
+          id: args.id,
+          candyMachine: mintContext.candyMachine,
+          candyGuard: mintContext.candyGuard,
+        })[0],
+        isWritable: true,
+      },
+    ],
+  }),
+  routeParser: (context, routeContext, args) => ({
+    data: CLOSE_GUARD_PDA_DATA,
+    remainingAccounts: [
+      {
+        publicKey: findRateLimitTrackerPda(context, {
+          id: args.id,
+          candyMachine: routeContext.candyMachine,
+          candyGuard: routeContext.candyGuard,
+        })[0],
+        isWritable: true,
+      },
+      { signer: args.candyGuardAuthority, isWritable: true },
+    ],
+  }),
+};
+
+export type RateLimitMintArgs = Pick<RateLimitArgs, 'id'>;
+
+/**
+ * The settings for the rateLimit guard that should be provided
+ * when accessing the guard's special "route" instruction.
+ *
+ * ## Close
+ * The `close` path closes the rate limit tracker and transfers
+ * its lamports to the Candy Guard authority once the Candy Machine
+ * was withdrawn.
+ */
+export type RateLimitRouteArgs = Pick<RateLimitArgs, 'id'> & {
+  /** Selects the path to execute in the route instruction. */
+  path: 'close';
+
+  /** The authority of the Candy Guard as a Signer. */
+  candyGuardAuthority: Signer;
+};
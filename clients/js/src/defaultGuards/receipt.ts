@@ -0,0 +1,86 @@
+import { PublicKey, Signer } from '@metaplex-foundation/umi';
+import {
+  findMintReceiptPda,
+  getReceiptSerializer,
+  Receipt,
+  ReceiptArgs,
+} from '../generated';
+import { GuardManifest } from '../guards';
+
+/**
+ * The receipt guard creates a receipt account for each mint,
+ * recording who minted which item, in which group and for
+ * what price.
+ *
+ * The rent of the receipt is paid by the payer of the mint
+ * and can be recovered by closing the receipt through the
+ * route instruction.
+ *
+ * @see {@link ReceiptRouteArgs} to learn more about
+ * the instruction that can be executed against this guard.
+ */
+export const receiptGuardManifest: GuardManifest<
+  ReceiptArgs,
+  Receipt,
+  ReceiptMintArgs,
+  ReceiptRouteArgs
+> = {
+  name: 'receipt',
+  serializer: getReceiptSerializer,
+  mintParser: (context, mintContext) => ({
+    data: new Uint8Array(),
+    remainingAccounts: [
+      {
+        publicKey: findMintReceiptPda(context, {
+          candyMachine: mintContext.candyMachine,
+          asset: mintContext.asset,
+        })[0],
+        isWritable: true,
+      },
+    ],
+  }),
+  routeParser: (context, routeContext, args) => ({
+    data: new Uint8Array(),
+    remainingAccounts: [
+      {
+        publicKey: findMintReceiptPda(context, {
+          candyMachine: routeContext.candyMachine,
+          asset: args.asset,
+        })[0],
+        isWritable: true,
+      },
+      { signer: args.authority, isWritable: false },
+      { publicKey: args.receiptPayer, isWritable: true },
+    ],
+  }),
+};
+
+export type ReceiptMintArgs = {};
+
+/**
+ * The receipt guard arguments that should be provided when
+ * closing a receipt through the guard's "route" instruction.
+ *
+ * The receipt can be closed by its original payer once the Candy
+ * Machine is fully minted or withdrawn, or by the authority of the
+ * Candy Guard once the Candy Machine is withdrawn. In both cases,
+ * the rent is returned to the original payer.
+ *
+ * ```ts
+ * route(umi, {
+ *   // ...
+ *   guard: 'receipt',
+ *   routeArgs: { asset, authority, receiptPayer },
+ * });
+ * ```
+ */
+export type ReceiptRouteArgs = {
+  /** The address of the Asset minted. */
+  asset: PublicKey;
+
+  /** The authority of the Candy Guard or the original payer of the receipt. */
+  authority: Signer;
+
+  /** The original payer of the receipt, which receives the rent. */
+  receiptPayer: PublicKey;
+};
@@ -0,0 +1,24 @@
+import {
+  getRecurringWindowSerializer,
+  RecurringWindow,
+  RecurringWindowArgs,
+} from '../generated';
+import { GuardManifest, noopParser } from '../guards';
+
+/**
+ * The recurringWindow guard only allows minting during windows
+ * that repeat on a fixed schedule, e.g. every day between
+ * 18:00 and 20:00 UTC.
+ *
+ * The windows start every `period` seconds counting from the
+ * `offset` date and each of them lasts `duration` seconds.
+ */
+export const recurringWindowGuardManifest: GuardManifest<
+  RecurringWindowArgs,
+  RecurringWindow
+> = {
+  name: 'recurringWindow',
+  serializer: getRecurringWindowSerializer,
+  mintParser: noopParser,
+  routeParser: noopParser,
+};
@@ -0,0 +1,67 @@
+import { PublicKey } from '@metaplex-foundation/umi';
+import {
+  findVoucherNoncePda,
+  getSignedVoucherSerializer,
+  SignedVoucher,
+  SignedVoucherArgs,
+} from '../generated';
+import { GuardManifest, GuardRemainingAccount, noopParser } from '../guards';
+
+/**
+ * The signedVoucher guard requires a voucher signed by the configured
+ * signer. Unlike the thirdPartySigner guard, the signer does not need
+ * to sign the mint transaction: the voucher is verified by an Ed25519
+ * instruction that must immediately precede the mint instruction.
+ *
+ * The voucher binds the minter, the Candy Machine, the group label,
+ * an optional price and an expiry date. Each voucher can only be
+ * used once since its nonce is recorded in a PDA on mint.
+ *
+ * You may use the `signVoucher` helper to create the Ed25519 instruction.
+ *
+ * ```ts
+ * transactionBuilder()
+ *   .add(await signVoucher({ signer, voucher }))
+ *   .add(mintV1(umi, {
+ *     // ...
+ *     mintArgs: { signedVoucher: some({ nonce: voucher.nonce }) },
+ *   }));
+ * ```
+ */
+export const signedVoucherGuardManifest: GuardManifest<
+  SignedVoucherArgs,
+  SignedVoucher,
+  SignedVoucherMintArgs
+> = {
+  name: 'signedVoucher',
+  serializer: getSignedVoucherSerializer,
+  mintParser: (context, mintContext, args) => {
+    const remainingAccounts: GuardRemainingAccount[] = [
+      {
+        publicKey: findVoucherNoncePda(context, {
+          nonce: args.nonce,
+          candyGuard: mintContext.candyGuard,
+          candyMachine: mintContext.candyMachine,
+        })[0],
+        isWritable: true,
+      },
+    ];
+    if (args.destination) {
+      remainingAccounts.push({ publicKey: args.destination, isWritable: true });
+    }
+    return { data: new Uint8Array(), remainingAccounts };
+  },
+  routeParser: noopParser,
+};
+
+export type SignedVoucherMintArgs = {
+  /** The nonce of the voucher. */
+  nonce: number | bigint;
+
+  /**
+   * The address that receives the price of the voucher.
+   * It must match the `destination` setting of the guard
+   * and is only required when the voucher specifies a price.
+   */
+  destination?: PublicKey;
+};
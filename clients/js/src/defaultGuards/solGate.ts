@@ -0,0 +1,159 @@
+import { getSplSystemProgramId } from '@metaplex-foundation/mpl-toolbox';
+import {
+  isOption,
+  isSome,
+  publicKey,
+  PublicKey,
+  Signer,
+  wrapNullable,
+} from '@metaplex-foundation/umi';
+import { fixSerializer } from '@metaplex-foundation/umi/serializers';
+import {
+  findSolGateRegistrationPda,
+  getSolGateSerializer,
+  SolGate,
+  SolGateArgs,
+} from '../generated';
+import {
+  CLOSE_GUARD_PDA_DATA,
+  GuardManifest,
+  GuardRemainingAccount,
+} from '../guards';
+
+/**
+ * The solGate guard requires the minter to hold a minimum balance
+ * of lamports, both before and after paying for the mint. Funding
+ * a separate payer does not satisfy the guard.
+ *
+ * Optionally, it can also require the minter to register its wallet
+ * a minimum amount of time before minting. Registering a wallet is
+ * done via the special "route" instruction of the guard.
+ * See {@link SolGateRouteArgs} for more information.
+ */
+export const solGateGuardManifest: GuardManifest<
+  SolGateArgs,
+  SolGate,
+  SolGateMintArgs,
+  SolGateRouteArgs
+> = {
+  name: 'solGate',
+  serializer: () => fixSerializer(getSolGateSerializer(), 8 + 1 + 8),
+  mintParser: (context, mintContext, args) => {
+    const minimumAge = isOption(args.minimumAge)
+      ? args.minimumAge
+      : wrapNullable(args.minimumAge);
+    const remainingAccounts: GuardRemainingAccount[] = [];
+    if (isSome(minimumAge)) {
+      remainingAccounts.push({
+        publicKey: findSolGateRegistrationPda(context, {
+          user: mintContext.minter.publicKey,
+          candyMachine: mintContext.candyMachine,
+          candyGuard: mintContext.candyGuard,
+        })[0],
+        isWritable: false,
+      });
+    }
+    return { data: new Uint8Array(), remainingAccounts };
+  },
+  routeParser: (context, routeContext, args) => {
+    if (args.path === 'close') {
+      return {
+        data: CLOSE_GUARD_PDA_DATA,
+        remainingAccounts: [
+          {
+            publicKey: findSolGateRegistrationPda(context, {
+              user: args.minter ?? args.authority.publicKey,
+              candyMachine: routeContext.candyMachine,
+              candyGuard: routeContext.candyGuard,
+            })[0],
+            isWritable: true,
+          },
+          { signer: args.authority, isWritable: true },
+          ...(args.minter !== undefined
+            ? [{ publicKey: args.minter, isWritable: false }]
+            : []),
+        ],
+      };
+    }
+
+    return {
+      data: new Uint8Array(),
+      remainingAccounts: [
+        {
+          publicKey: findSolGateRegistrationPda(context, {
+            user: publicKey(args.minter ?? routeContext.payer),
+            candyMachine: routeContext.candyMachine,
+            candyGuard: routeContext.candyGuard,
+          })[0],
+          isWritable: true,
+        },
+        { publicKey: getSplSystemProgramId(context), isWritable: false },
+        ...(args.minter !== undefined
+          ? [{ signer: args.minter, isWritable: false }]
+          : []),
+      ],
+    };
+  },
+};
+
+/**
+ * The settings for the solGate guard that could
+ * be provided to the mint instruction.
+ *
+ * The `minimumAge` setting of the guard must be provided
+ * so the registration of the minter can be checked.
+ */
+export type SolGateMintArgs = Pick<SolGateArgs, 'minimumAge'>;
+
+/**
+ * The settings for the solGate guard that should be provided
+ * when accessing the guard's special "route" instruction.
+ */
+export type SolGateRouteArgs = SolGateRouteArgsRegister | SolGateRouteArgsClose;
+
+/**
+ * ## Register
+ * The `register` path creates a small PDA account on the Program
+ * that records the time at which a wallet was registered.
+ *
+ * ```ts
+ * route(umi, {
+ *   // ...
+ *   guard: 'solGate',
+ *   routeArgs: { path: 'register' },
+ * });
+ * ```
+ */
+export type SolGateRouteArgsRegister = {
+  /** Selects the path to execute in the route instruction. */
+  path: 'register';
+
+  /**
+   * The minter to register if it is not the payer.
+   * It must sign the transaction since only the owner
+   * of a wallet can register it.
+   */
+  minter?: Signer;
+};
+
+/**
+ * ## Close
+ * The `close` path closes the registration PDA of a wallet and
+ * transfers its lamports to the `authority`. The wallet can close
+ * its own registration once the Candy Machine is sold out, whereas
+ * the Candy Guard authority can only close registrations once the
+ * Candy Machine was withdrawn.
+ */
+export type SolGateRouteArgsClose = {
+  /** Selects the path to execute in the route instruction. */
+  path: 'close';
+
+  /** The wallet or the Candy Guard authority, which receives the lamports. */
+  authority: Signer;
+
+  /**
+   * The wallet of the registration, when it is
+   * closed by the Candy Guard authority.
+   */
+  minter?: PublicKey;
+};
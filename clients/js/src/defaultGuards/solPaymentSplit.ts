@@ -0,0 +1,32 @@
+import { fixSerializer } from '@metaplex-foundation/umi/serializers';
+import {
+  getSolPaymentSplitSerializer,
+  SolPaymentSplit,
+  SolPaymentSplitArgs,
+} from '../generated';
+import { GuardManifest, noopParser } from '../guards';
+
+/**
+ * The solPaymentSplit guard is used to charge an amount in SOL
+ * for the minted NFT, splitting it across multiple destinations
+ * according to their share in basis points.
+ */
+export const solPaymentSplitGuardManifest: GuardManifest<
+  SolPaymentSplitArgs,
+  SolPaymentSplit,
+  SolPaymentSplitMintArgs
+> = {
+  name: 'solPaymentSplit',
+  serializer: () =>
+    fixSerializer(getSolPaymentSplitSerializer(), 8 + 4 + 34 * 5),
+  mintParser: (context, mintContext, args) => ({
+    data: new Uint8Array(),
+    remainingAccounts: args.destinations.map((destination) => ({
+      publicKey: destination.address,
+      isWritable: true,
+    })),
+  }),
+  routeParser: noopParser,
+};
+
+export type SolPaymentSplitMintArgs = Omit<SolPaymentSplitArgs, 'lamports'>;
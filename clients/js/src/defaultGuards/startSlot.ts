@@ -0,0 +1,13 @@
+import { getStartSlotSerializer, StartSlot, StartSlotArgs } from '../generated';
+import { GuardManifest, noopParser } from '../guards';
+
+/**
+ * The startSlot guard determines the slot from which minting
+ * is allowed. Before this slot, minting is not allowed.
+ */
+export const startSlotGuardManifest: GuardManifest<StartSlotArgs, StartSlot> = {
+  name: 'startSlot',
+  serializer: getStartSlotSerializer,
+  mintParser: noopParser,
+  routeParser: noopParser,
+};
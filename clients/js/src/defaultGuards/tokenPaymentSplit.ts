@@ -0,0 +1,67 @@
+import { getSplTokenProgramId } from '@metaplex-foundation/mpl-toolbox';
+import { PublicKey } from '@metaplex-foundation/umi';
+import {
+  fixSerializer,
+  publicKey as publicKeySerializer,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  getTokenPaymentSplitSerializer,
+  TokenPaymentSplit,
+  TokenPaymentSplitArgs,
+} from '../generated';
+import { GuardManifest, noopParser } from '../guards';
+
+/**
+ * The tokenPaymentSplit guard allows minting by charging the
+ * payer a specific amount of tokens from a certain mint acount,
+ * splitting it across multiple destinations according to their
+ * share in basis points. The tokens are transferred to the
+ * associated token account of each destination wallet.
+ *
+ * The mint can belong to the SPL Token program or to Token2022.
+ */
+export const tokenPaymentSplitGuardManifest: GuardManifest<
+  TokenPaymentSplitArgs,
+  TokenPaymentSplit,
+  TokenPaymentSplitMintArgs
+> = {
+  name: 'tokenPaymentSplit',
+  serializer: () =>
+    fixSerializer(getTokenPaymentSplitSerializer(), 8 + 32 + 4 + 34 * 5),
+  mintParser: (context, mintContext, args) => {
+    const tokenProgram = args.tokenProgram ?? getSplTokenProgramId(context);
+    const associatedTokenProgramId =
+      context.programs.get('splAssociatedToken').publicKey;
+    const findAta = (owner: PublicKey) =>
+      context.eddsa.findPda(associatedTokenProgramId, [
+        publicKeySerializer().serialize(owner),
+        publicKeySerializer().serialize(tokenProgram),
+        publicKeySerializer().serialize(args.mint),
+      ])[0];
+
+    return {
+      data: new Uint8Array(),
+      remainingAccounts: [
+        { publicKey: findAta(mintContext.minter.publicKey), isWritable: true },
+        { publicKey: args.mint, isWritable: false },
+        { publicKey: tokenProgram, isWritable: false },
+        ...args.destinations.map((destination) => ({
+          publicKey: findAta(destination.address),
+          isWritable: true,
+        })),
+      ],
+    };
+  },
+  routeParser: noopParser,
+};
+
+export type TokenPaymentSplitMintArgs = Omit<
+  TokenPaymentSplitArgs,
+  'amount'
+> & {
+  /**
+   * The token program of the mint used for the payment.
+   * Defaults to the SPL Token program.
+   */
+  tokenProgram?: PublicKey;
+};
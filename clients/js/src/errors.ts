@@ -1,5 +1,5 @@
 import { UmiError } from '@metaplex-foundation/umi';
-import { CANDY_GUARD_LABEL_SIZE } from './constants';
+import { CANDY_GUARD_MAX_LABEL_SIZE } from './constants';
 
 export class CandyMachineError extends UmiError {
   readonly name: string = 'CandyMachineError';
@@ -123,7 +123,7 @@ export class GuardGroupLabelTooLongError extends CandyMachineError {
   constructor(label: string) {
     const message =
       `The provided group label [${label}] is too long. ` +
-      `Group labels cannot be longer than ${CANDY_GUARD_LABEL_SIZE} characters. ` +
+      `Group labels cannot be longer than ${CANDY_GUARD_MAX_LABEL_SIZE} bytes. ` +
       'Please provide a shorter group label.';
     super(message);
   }
@@ -172,3 +172,15 @@ export class ExceededRegexLengthError extends CandyMachineError {
     super(message);
   }
 }
+
+export class EligibilityCheckFailedError extends CandyMachineError {
+  readonly name: string = 'EligibilityCheckFailedError';
+
+  constructor(error: unknown, logs: string[]) {
+    const message =
+      `The simulation of the check eligibility instruction failed with [${JSON.stringify(error)}]. ` +
+      'Please make sure the accounts and the mint settings of the guards are valid. ' +
+      `Program logs: [${logs.join(', ')}]`;
+    super(message);
+  }
+}
@@ -0,0 +1,213 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Account,
+  Context,
+  Option,
+  OptionOrNullable,
+  Pda,
+  PublicKey,
+  RpcAccount,
+  RpcGetAccountOptions,
+  RpcGetAccountsOptions,
+  assertAccountExists,
+  deserializeAccount,
+  gpaBuilder,
+  publicKey as toPublicKey,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  bytes,
+  option,
+  publicKey as publicKeySerializer,
+  string,
+  struct,
+  u16,
+  u64,
+} from '@metaplex-foundation/umi/serializers';
+
+/**
+ * PDA to track the entry of a validated address and the number of mints used. The data
+ * is padded to `SIZE` bytes when the entry has no price.
+ */
+export type AllotmentProof = Account<AllotmentProofAccountData>;
+
+export type AllotmentProofAccountData = {
+  /** Maximum number of mints of the address. */
+  allotment: number;
+  /** Price of each mint in lamports, if any. */
+  price: Option<bigint>;
+  /** Number of mints used. */
+  minted: number;
+};
+
+export type AllotmentProofAccountDataArgs = {
+  /** Maximum number of mints of the address. */
+  allotment: number;
+  /** Price of each mint in lamports, if any. */
+  price: OptionOrNullable<number | bigint>;
+  /** Number of mints used. */
+  minted: number;
+};
+
+export function getAllotmentProofAccountDataSerializer(): Serializer<
+  AllotmentProofAccountDataArgs,
+  AllotmentProofAccountData
+> {
+  return struct<AllotmentProofAccountData>(
+    [
+      ['allotment', u16()],
+      ['price', option(u64())],
+      ['minted', u16()],
+    ],
+    { description: 'AllotmentProofAccountData' }
+  ) as Serializer<AllotmentProofAccountDataArgs, AllotmentProofAccountData>;
+}
+
+export function deserializeAllotmentProof(
+  rawAccount: RpcAccount
+): AllotmentProof {
+  return deserializeAccount(
+    rawAccount,
+    getAllotmentProofAccountDataSerializer()
+  );
+}
+
+export async function fetchAllotmentProof(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<AllotmentProof> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  assertAccountExists(maybeAccount, 'AllotmentProof');
+  return deserializeAllotmentProof(maybeAccount);
+}
+
+export async function safeFetchAllotmentProof(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<AllotmentProof | null> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  return maybeAccount.exists ? deserializeAllotmentProof(maybeAccount) : null;
+}
+
+export async function fetchAllAllotmentProof(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<AllotmentProof[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts.map((maybeAccount) => {
+    assertAccountExists(maybeAccount, 'AllotmentProof');
+    return deserializeAllotmentProof(maybeAccount);
+  });
+}
+
+export async function safeFetchAllAllotmentProof(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<AllotmentProof[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts
+    .filter((maybeAccount) => maybeAccount.exists)
+    .map((maybeAccount) =>
+      deserializeAllotmentProof(maybeAccount as RpcAccount)
+    );
+}
+
+export function getAllotmentProofGpaBuilder(
+  context: Pick<Context, 'rpc' | 'programs'>
+) {
+  const programId = context.programs.getPublicKey(
+    'mplCoreCandyGuard',
+    'CMAGAKJ67e9hRZgfC5SFTbZH8MgEmtqazKXjmkaJjWTJ'
+  );
+  return gpaBuilder(context, programId)
+    .registerFields<{
+      allotment: number;
+      price: OptionOrNullable<number | bigint>;
+      minted: number;
+    }>({
+      allotment: [0, u16()],
+      price: [2, option(u64())],
+      minted: [null, u16()],
+    })
+    .deserializeUsing<AllotmentProof>((account) =>
+      deserializeAllotmentProof(account)
+    )
+    .whereSize(13);
+}
+
+export function getAllotmentProofSize(): number {
+  return 13;
+}
+
+export function findAllotmentProofPda(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  seeds: {
+    /** The Merkle Root used when verifying the user */
+    merkleRoot: Uint8Array;
+    /** The address of the wallet trying to mint */
+    user: PublicKey;
+    /** The address of the Candy Guard account */
+    candyGuard: PublicKey;
+    /** The address of the Candy Machine account */
+    candyMachine: PublicKey;
+  }
+): Pda {
+  const programId = context.programs.getPublicKey(
+    'mplCoreCandyGuard',
+    'CMAGAKJ67e9hRZgfC5SFTbZH8MgEmtqazKXjmkaJjWTJ'
+  );
+  return context.eddsa.findPda(programId, [
+    string({ size: 'variable' }).serialize('allow_list_allotment'),
+    bytes({ size: 32 }).serialize(seeds.merkleRoot),
+    publicKeySerializer().serialize(seeds.user),
+    publicKeySerializer().serialize(seeds.candyGuard),
+    publicKeySerializer().serialize(seeds.candyMachine),
+  ]);
+}
+
+export async function fetchAllotmentProofFromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findAllotmentProofPda>[1],
+  options?: RpcGetAccountOptions
+): Promise<AllotmentProof> {
+  return fetchAllotmentProof(
+    context,
+    findAllotmentProofPda(context, seeds),
+    options
+  );
+}
+
+export async function safeFetchAllotmentProofFromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findAllotmentProofPda>[1],
+  options?: RpcGetAccountOptions
+): Promise<AllotmentProof | null> {
+  return safeFetchAllotmentProof(
+    context,
+    findAllotmentProofPda(context, seeds),
+    options
+  );
+}
@@ -0,0 +1,193 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Account,
+  Context,
+  Pda,
+  PublicKey,
+  RpcAccount,
+  RpcGetAccountOptions,
+  RpcGetAccountsOptions,
+  assertAccountExists,
+  deserializeAccount,
+  gpaBuilder,
+  publicKey as toPublicKey,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  publicKey as publicKeySerializer,
+  string,
+  struct,
+  u32,
+  u64,
+} from '@metaplex-foundation/umi/serializers';
+
+/** PDA to track the amount paid by an individual address. */
+export type AuctionBid = Account<AuctionBidAccountData>;
+
+export type AuctionBidAccountData = {
+  /** Number of items minted. */
+  count: number;
+  /** Total amount paid. */
+  totalPaid: bigint;
+  /** Address that paid for the rent of the account. */
+  payer: PublicKey;
+};
+
+export type AuctionBidAccountDataArgs = {
+  /** Number of items minted. */
+  count: number;
+  /** Total amount paid. */
+  totalPaid: number | bigint;
+  /** Address that paid for the rent of the account. */
+  payer: PublicKey;
+};
+
+export function getAuctionBidAccountDataSerializer(): Serializer<
+  AuctionBidAccountDataArgs,
+  AuctionBidAccountData
+> {
+  return struct<AuctionBidAccountData>(
+    [
+      ['count', u32()],
+      ['totalPaid', u64()],
+      ['payer', publicKeySerializer()],
+    ],
+    { description: 'AuctionBidAccountData' }
+  ) as Serializer<AuctionBidAccountDataArgs, AuctionBidAccountData>;
+}
+
+export function deserializeAuctionBid(rawAccount: RpcAccount): AuctionBid {
+  return deserializeAccount(rawAccount, getAuctionBidAccountDataSerializer());
+}
+
+export async function fetchAuctionBid(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<AuctionBid> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  assertAccountExists(maybeAccount, 'AuctionBid');
+  return deserializeAuctionBid(maybeAccount);
+}
+
+export async function safeFetchAuctionBid(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<AuctionBid | null> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  return maybeAccount.exists ? deserializeAuctionBid(maybeAccount) : null;
+}
+
+export async function fetchAllAuctionBid(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<AuctionBid[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts.map((maybeAccount) => {
+    assertAccountExists(maybeAccount, 'AuctionBid');
+    return deserializeAuctionBid(maybeAccount);
+  });
+}
+
+export async function safeFetchAllAuctionBid(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<AuctionBid[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts
+    .filter((maybeAccount) => maybeAccount.exists)
+    .map((maybeAccount) => deserializeAuctionBid(maybeAccount as RpcAccount));
+}
+
+export function getAuctionBidGpaBuilder(
+  context: Pick<Context, 'rpc' | 'programs'>
+) {
+  const programId = context.programs.getPublicKey(
+    'mplCoreCandyGuard',
+    'CMAGAKJ67e9hRZgfC5SFTbZH8MgEmtqazKXjmkaJjWTJ'
+  );
+  return gpaBuilder(context, programId)
+    .registerFields<{
+      count: number;
+      totalPaid: number | bigint;
+      payer: PublicKey;
+    }>({
+      count: [0, u32()],
+      totalPaid: [4, u64()],
+      payer: [12, publicKeySerializer()],
+    })
+    .deserializeUsing<AuctionBid>((account) => deserializeAuctionBid(account))
+    .whereSize(44);
+}
+
+export function getAuctionBidSize(): number {
+  return 44;
+}
+
+export function findAuctionBidPda(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  seeds: {
+    /** The address of the wallet that minted */
+    minter: PublicKey;
+    /** The address of the Candy Guard account */
+    candyGuard: PublicKey;
+    /** The address of the Candy Machine account */
+    candyMachine: PublicKey;
+    /** The label of the group (empty for the default guard set) */
+    group: string;
+  }
+): Pda {
+  const programId = context.programs.getPublicKey(
+    'mplCoreCandyGuard',
+    'CMAGAKJ67e9hRZgfC5SFTbZH8MgEmtqazKXjmkaJjWTJ'
+  );
+  return context.eddsa.findPda(programId, [
+    string({ size: 'variable' }).serialize('dutch_auction_bid'),
+    publicKeySerializer().serialize(seeds.minter),
+    publicKeySerializer().serialize(seeds.candyGuard),
+    publicKeySerializer().serialize(seeds.candyMachine),
+    string({ size: 'variable' }).serialize(seeds.group),
+  ]);
+}
+
+export async function fetchAuctionBidFromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findAuctionBidPda>[1],
+  options?: RpcGetAccountOptions
+): Promise<AuctionBid> {
+  return fetchAuctionBid(context, findAuctionBidPda(context, seeds), options);
+}
+
+export async function safeFetchAuctionBidFromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findAuctionBidPda>[1],
+  options?: RpcGetAccountOptions
+): Promise<AuctionBid | null> {
+  return safeFetchAuctionBid(
+    context,
+    findAuctionBidPda(context, seeds),
+    options
+  );
+}
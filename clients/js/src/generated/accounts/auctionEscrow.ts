@@ -0,0 +1,260 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Account,
+  Context,
+  Pda,
+  PublicKey,
+  RpcAccount,
+  RpcGetAccountOptions,
+  RpcGetAccountsOptions,
+  assertAccountExists,
+  deserializeAccount,
+  gpaBuilder,
+  publicKey as toPublicKey,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  publicKey as publicKeySerializer,
+  string,
+  struct,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+
+/** PDA to hold the funds of an auction with rebates. */
+export type AuctionEscrow = Account<AuctionEscrowAccountData>;
+
+export type AuctionEscrowAccountData = {
+  discriminator: Array<number>;
+  /** Candy guard address associated with this escrow. */
+  candyGuard: PublicKey;
+  /** Candy machine address associated with this escrow. */
+  candyMachine: PublicKey;
+  /** The destination address for the auction proceeds. */
+  destination: PublicKey;
+  /** The authority that initialized the escrow. */
+  authority: PublicKey;
+  /** Price paid on the most recent mint. */
+  lastPrice: bigint;
+  /** Number of items minted through the auction. */
+  totalCount: bigint;
+  /** Total amount paid by buyers. */
+  totalPaid: bigint;
+  /** Amount of proceeds already withdrawn. */
+  withdrawn: bigint;
+  /** Number of bids that have not claimed their rebate. */
+  openBids: bigint;
+};
+
+export type AuctionEscrowAccountDataArgs = {
+  /** Candy guard address associated with this escrow. */
+  candyGuard: PublicKey;
+  /** Candy machine address associated with this escrow. */
+  candyMachine: PublicKey;
+  /** The destination address for the auction proceeds. */
+  destination: PublicKey;
+  /** The authority that initialized the escrow. */
+  authority: PublicKey;
+  /** Price paid on the most recent mint. */
+  lastPrice: number | bigint;
+  /** Number of items minted through the auction. */
+  totalCount: number | bigint;
+  /** Total amount paid by buyers. */
+  totalPaid: number | bigint;
+  /** Amount of proceeds already withdrawn. */
+  withdrawn: number | bigint;
+  /** Number of bids that have not claimed their rebate. */
+  openBids: number | bigint;
+};
+
+export function getAuctionEscrowAccountDataSerializer(): Serializer<
+  AuctionEscrowAccountDataArgs,
+  AuctionEscrowAccountData
+> {
+  return mapSerializer<
+    AuctionEscrowAccountDataArgs,
+    any,
+    AuctionEscrowAccountData
+  >(
+    struct<AuctionEscrowAccountData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['candyGuard', publicKeySerializer()],
+        ['candyMachine', publicKeySerializer()],
+        ['destination', publicKeySerializer()],
+        ['authority', publicKeySerializer()],
+        ['lastPrice', u64()],
+        ['totalCount', u64()],
+        ['totalPaid', u64()],
+        ['withdrawn', u64()],
+        ['openBids', u64()],
+      ],
+      { description: 'AuctionEscrowAccountData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [219, 16, 234, 116, 247, 132, 116, 213],
+    })
+  ) as Serializer<AuctionEscrowAccountDataArgs, AuctionEscrowAccountData>;
+}
+
+export function deserializeAuctionEscrow(
+  rawAccount: RpcAccount
+): AuctionEscrow {
+  return deserializeAccount(
+    rawAccount,
+    getAuctionEscrowAccountDataSerializer()
+  );
+}
+
+export async function fetchAuctionEscrow(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<AuctionEscrow> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  assertAccountExists(maybeAccount, 'AuctionEscrow');
+  return deserializeAuctionEscrow(maybeAccount);
+}
+
+export async function safeFetchAuctionEscrow(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<AuctionEscrow | null> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  return maybeAccount.exists ? deserializeAuctionEscrow(maybeAccount) : null;
+}
+
+export async function fetchAllAuctionEscrow(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<AuctionEscrow[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts.map((maybeAccount) => {
+    assertAccountExists(maybeAccount, 'AuctionEscrow');
+    return deserializeAuctionEscrow(maybeAccount);
+  });
+}
+
+export async function safeFetchAllAuctionEscrow(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<AuctionEscrow[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts
+    .filter((maybeAccount) => maybeAccount.exists)
+    .map((maybeAccount) =>
+      deserializeAuctionEscrow(maybeAccount as RpcAccount)
+    );
+}
+
+export function getAuctionEscrowGpaBuilder(
+  context: Pick<Context, 'rpc' | 'programs'>
+) {
+  const programId = context.programs.getPublicKey(
+    'mplCoreCandyGuard',
+    'CMAGAKJ67e9hRZgfC5SFTbZH8MgEmtqazKXjmkaJjWTJ'
+  );
+  return gpaBuilder(context, programId)
+    .registerFields<{
+      discriminator: Array<number>;
+      candyGuard: PublicKey;
+      candyMachine: PublicKey;
+      destination: PublicKey;
+      authority: PublicKey;
+      lastPrice: number | bigint;
+      totalCount: number | bigint;
+      totalPaid: number | bigint;
+      withdrawn: number | bigint;
+      openBids: number | bigint;
+    }>({
+      discriminator: [0, array(u8(), { size: 8 })],
+      candyGuard: [8, publicKeySerializer()],
+      candyMachine: [40, publicKeySerializer()],
+      destination: [72, publicKeySerializer()],
+      authority: [104, publicKeySerializer()],
+      lastPrice: [136, u64()],
+      totalCount: [144, u64()],
+      totalPaid: [152, u64()],
+      withdrawn: [160, u64()],
+      openBids: [168, u64()],
+    })
+    .deserializeUsing<AuctionEscrow>((account) =>
+      deserializeAuctionEscrow(account)
+    )
+    .whereField('discriminator', [219, 16, 234, 116, 247, 132, 116, 213]);
+}
+
+export function findAuctionEscrowPda(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  seeds: {
+    /** The address that receives the auction proceeds */
+    destination: PublicKey;
+    /** The address of the Candy Guard account */
+    candyGuard: PublicKey;
+    /** The address of the Candy Machine account */
+    candyMachine: PublicKey;
+    /** The label of the group (empty for the default guard set) */
+    group: string;
+  }
+): Pda {
+  const programId = context.programs.getPublicKey(
+    'mplCoreCandyGuard',
+    'CMAGAKJ67e9hRZgfC5SFTbZH8MgEmtqazKXjmkaJjWTJ'
+  );
+  return context.eddsa.findPda(programId, [
+    string({ size: 'variable' }).serialize('dutch_auction'),
+    publicKeySerializer().serialize(seeds.destination),
+    publicKeySerializer().serialize(seeds.candyGuard),
+    publicKeySerializer().serialize(seeds.candyMachine),
+    string({ size: 'variable' }).serialize(seeds.group),
+  ]);
+}
+
+export async function fetchAuctionEscrowFromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findAuctionEscrowPda>[1],
+  options?: RpcGetAccountOptions
+): Promise<AuctionEscrow> {
+  return fetchAuctionEscrow(
+    context,
+    findAuctionEscrowPda(context, seeds),
+    options
+  );
+}
+
+export async function safeFetchAuctionEscrowFromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findAuctionEscrowPda>[1],
+  options?: RpcGetAccountOptions
+): Promise<AuctionEscrow | null> {
+  return safeFetchAuctionEscrow(
+    context,
+    findAuctionEscrowPda(context, seeds),
+    options
+  );
+}
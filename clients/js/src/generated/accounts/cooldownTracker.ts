@@ -0,0 +1,185 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Account,
+  Context,
+  Pda,
+  PublicKey,
+  RpcAccount,
+  RpcGetAccountOptions,
+  RpcGetAccountsOptions,
+  assertAccountExists,
+  deserializeAccount,
+  gpaBuilder,
+  publicKey as toPublicKey,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  i64,
+  publicKey as publicKeySerializer,
+  string,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+
+/** PDA to track the last mint of an individual address. */
+export type CooldownTracker = Account<CooldownTrackerAccountData>;
+
+export type CooldownTrackerAccountData = {
+  /** Timestamp of the last mint. */
+  lastMint: bigint;
+};
+
+export type CooldownTrackerAccountDataArgs = {
+  /** Timestamp of the last mint. */
+  lastMint: number | bigint;
+};
+
+export function getCooldownTrackerAccountDataSerializer(): Serializer<
+  CooldownTrackerAccountDataArgs,
+  CooldownTrackerAccountData
+> {
+  return struct<CooldownTrackerAccountData>([['lastMint', i64()]], {
+    description: 'CooldownTrackerAccountData',
+  }) as Serializer<CooldownTrackerAccountDataArgs, CooldownTrackerAccountData>;
+}
+
+export function deserializeCooldownTracker(
+  rawAccount: RpcAccount
+): CooldownTracker {
+  return deserializeAccount(
+    rawAccount,
+    getCooldownTrackerAccountDataSerializer()
+  );
+}
+
+export async function fetchCooldownTracker(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<CooldownTracker> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  assertAccountExists(maybeAccount, 'CooldownTracker');
+  return deserializeCooldownTracker(maybeAccount);
+}
+
+export async function safeFetchCooldownTracker(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<CooldownTracker | null> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  return maybeAccount.exists ? deserializeCooldownTracker(maybeAccount) : null;
+}
+
+export async function fetchAllCooldownTracker(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<CooldownTracker[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts.map((maybeAccount) => {
+    assertAccountExists(maybeAccount, 'CooldownTracker');
+    return deserializeCooldownTracker(maybeAccount);
+  });
+}
+
+export async function safeFetchAllCooldownTracker(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<CooldownTracker[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts
+    .filter((maybeAccount) => maybeAccount.exists)
+    .map((maybeAccount) =>
+      deserializeCooldownTracker(maybeAccount as RpcAccount)
+    );
+}
+
+export function getCooldownTrackerGpaBuilder(
+  context: Pick<Context, 'rpc' | 'programs'>
+) {
+  const programId = context.programs.getPublicKey(
+    'mplCoreCandyGuard',
+    'CMAGAKJ67e9hRZgfC5SFTbZH8MgEmtqazKXjmkaJjWTJ'
+  );
+  return gpaBuilder(context, programId)
+    .registerFields<{ lastMint: number | bigint }>({ lastMint: [0, i64()] })
+    .deserializeUsing<CooldownTracker>((account) =>
+      deserializeCooldownTracker(account)
+    )
+    .whereSize(8);
+}
+
+export function getCooldownTrackerSize(): number {
+  return 8;
+}
+
+export function findCooldownTrackerPda(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  seeds: {
+    /** A unique identifier in the context of a Candy Machine/Candy Guard combo */
+    id: number;
+    /** The address of the wallet trying to mint */
+    user: PublicKey;
+    /** The address of the Candy Guard account */
+    candyGuard: PublicKey;
+    /** The address of the Candy Machine account */
+    candyMachine: PublicKey;
+  }
+): Pda {
+  const programId = context.programs.getPublicKey(
+    'mplCoreCandyGuard',
+    'CMAGAKJ67e9hRZgfC5SFTbZH8MgEmtqazKXjmkaJjWTJ'
+  );
+  return context.eddsa.findPda(programId, [
+    string({ size: 'variable' }).serialize('cooldown'),
+    u8().serialize(seeds.id),
+    publicKeySerializer().serialize(seeds.user),
+    publicKeySerializer().serialize(seeds.candyGuard),
+    publicKeySerializer().serialize(seeds.candyMachine),
+  ]);
+}
+
+export async function fetchCooldownTrackerFromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findCooldownTrackerPda>[1],
+  options?: RpcGetAccountOptions
+): Promise<CooldownTracker> {
+  return fetchCooldownTracker(
+    context,
+    findCooldownTrackerPda(context, seeds),
+    options
+  );
+}
+
+export async function safeFetchCooldownTrackerFromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findCooldownTrackerPda>[1],
+  options?: RpcGetAccountOptions
+): Promise<CooldownTracker | null> {
+  return safeFetchCooldownTracker(
+    context,
+    findCooldownTrackerPda(context, seeds),
+    options
+  );
+}
@@ -0,0 +1,179 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Account,
+  Context,
+  Pda,
+  PublicKey,
+  RpcAccount,
+  RpcGetAccountOptions,
+  RpcGetAccountsOptions,
+  assertAccountExists,
+  deserializeAccount,
+  gpaBuilder,
+  publicKey as toPublicKey,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  publicKey as publicKeySerializer,
+  string,
+  struct,
+} from '@metaplex-foundation/umi/serializers';
+
+/** PDA to record that a gating asset was used for the discount. */
+export type DiscountUsage = Account<DiscountUsageAccountData>;
+
+export type DiscountUsageAccountData = {
+  /** Address that used the discount. */
+  minter: PublicKey;
+};
+
+export type DiscountUsageAccountDataArgs = DiscountUsageAccountData;
+
+export function getDiscountUsageAccountDataSerializer(): Serializer<
+  DiscountUsageAccountDataArgs,
+  DiscountUsageAccountData
+> {
+  return struct<DiscountUsageAccountData>([['minter', publicKeySerializer()]], {
+    description: 'DiscountUsageAccountData',
+  }) as Serializer<DiscountUsageAccountDataArgs, DiscountUsageAccountData>;
+}
+
+export function deserializeDiscountUsage(
+  rawAccount: RpcAccount
+): DiscountUsage {
+  return deserializeAccount(
+    rawAccount,
+    getDiscountUsageAccountDataSerializer()
+  );
+}
+
+export async function fetchDiscountUsage(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<DiscountUsage> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  assertAccountExists(maybeAccount, 'DiscountUsage');
+  return deserializeDiscountUsage(maybeAccount);
+}
+
+export async function safeFetchDiscountUsage(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<DiscountUsage | null> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  return maybeAccount.exists ? deserializeDiscountUsage(maybeAccount) : null;
+}
+
+export async function fetchAllDiscountUsage(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<DiscountUsage[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts.map((maybeAccount) => {
+    assertAccountExists(maybeAccount, 'DiscountUsage');
+    return deserializeDiscountUsage(maybeAccount);
+  });
+}
+
+export async function safeFetchAllDiscountUsage(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<DiscountUsage[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts
+    .filter((maybeAccount) => maybeAccount.exists)
+    .map((maybeAccount) =>
+      deserializeDiscountUsage(maybeAccount as RpcAccount)
+    );
+}
+
+export function getDiscountUsageGpaBuilder(
+  context: Pick<Context, 'rpc' | 'programs'>
+) {
+  const programId = context.programs.getPublicKey(
+    'mplCoreCandyGuard',
+    'CMAGAKJ67e9hRZgfC5SFTbZH8MgEmtqazKXjmkaJjWTJ'
+  );
+  return gpaBuilder(context, programId)
+    .registerFields<{ minter: PublicKey }>({
+      minter: [0, publicKeySerializer()],
+    })
+    .deserializeUsing<DiscountUsage>((account) =>
+      deserializeDiscountUsage(account)
+    )
+    .whereSize(32);
+}
+
+export function getDiscountUsageSize(): number {
+  return 32;
+}
+
+export function findDiscountUsagePda(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  seeds: {
+    /** The address of the gating Asset or NFT mint */
+    asset: PublicKey;
+    /** The address of the Candy Guard account */
+    candyGuard: PublicKey;
+    /** The address of the Candy Machine account */
+    candyMachine: PublicKey;
+  }
+): Pda {
+  const programId = context.programs.getPublicKey(
+    'mplCoreCandyGuard',
+    'CMAGAKJ67e9hRZgfC5SFTbZH8MgEmtqazKXjmkaJjWTJ'
+  );
+  return context.eddsa.findPda(programId, [
+    string({ size: 'variable' }).serialize('holder_discount'),
+    publicKeySerializer().serialize(seeds.asset),
+    publicKeySerializer().serialize(seeds.candyGuard),
+    publicKeySerializer().serialize(seeds.candyMachine),
+  ]);
+}
+
+export async function fetchDiscountUsageFromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findDiscountUsagePda>[1],
+  options?: RpcGetAccountOptions
+): Promise<DiscountUsage> {
+  return fetchDiscountUsage(
+    context,
+    findDiscountUsagePda(context, seeds),
+    options
+  );
+}
+
+export async function safeFetchDiscountUsageFromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findDiscountUsagePda>[1],
+  options?: RpcGetAccountOptions
+): Promise<DiscountUsage | null> {
+  return safeFetchDiscountUsage(
+    context,
+    findDiscountUsagePda(context, seeds),
+    options
+  );
+}
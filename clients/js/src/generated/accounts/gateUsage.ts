@@ -0,0 +1,161 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Account,
+  Context,
+  Pda,
+  PublicKey,
+  RpcAccount,
+  RpcGetAccountOptions,
+  RpcGetAccountsOptions,
+  assertAccountExists,
+  deserializeAccount,
+  gpaBuilder,
+  publicKey as toPublicKey,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  publicKey as publicKeySerializer,
+  string,
+  struct,
+  u16,
+} from '@metaplex-foundation/umi/serializers';
+
+/** PDA to track the number of mints of a gating asset. */
+export type GateUsage = Account<GateUsageAccountData>;
+
+export type GateUsageAccountData = {
+  /** Number of mints. */
+  count: number;
+};
+
+export type GateUsageAccountDataArgs = GateUsageAccountData;
+
+export function getGateUsageAccountDataSerializer(): Serializer<
+  GateUsageAccountDataArgs,
+  GateUsageAccountData
+> {
+  return struct<GateUsageAccountData>([['count', u16()]], {
+    description: 'GateUsageAccountData',
+  }) as Serializer<GateUsageAccountDataArgs, GateUsageAccountData>;
+}
+
+export function deserializeGateUsage(rawAccount: RpcAccount): GateUsage {
+  return deserializeAccount(rawAccount, getGateUsageAccountDataSerializer());
+}
+
+export async function fetchGateUsage(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<GateUsage> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  assertAccountExists(maybeAccount, 'GateUsage');
+  return deserializeGateUsage(maybeAccount);
+}
+
+export async function safeFetchGateUsage(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<GateUsage | null> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  return maybeAccount.exists ? deserializeGateUsage(maybeAccount) : null;
+}
+
+export async function fetchAllGateUsage(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<GateUsage[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts.map((maybeAccount) => {
+    assertAccountExists(maybeAccount, 'GateUsage');
+    return deserializeGateUsage(maybeAccount);
+  });
+}
+
+export async function safeFetchAllGateUsage(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<GateUsage[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts
+    .filter((maybeAccount) => maybeAccount.exists)
+    .map((maybeAccount) => deserializeGateUsage(maybeAccount as RpcAccount));
+}
+
+export function getGateUsageGpaBuilder(
+  context: Pick<Context, 'rpc' | 'programs'>
+) {
+  const programId = context.programs.getPublicKey(
+    'mplCoreCandyGuard',
+    'CMAGAKJ67e9hRZgfC5SFTbZH8MgEmtqazKXjmkaJjWTJ'
+  );
+  return gpaBuilder(context, programId)
+    .registerFields<{ count: number }>({ count: [0, u16()] })
+    .deserializeUsing<GateUsage>((account) => deserializeGateUsage(account))
+    .whereSize(2);
+}
+
+export function getGateUsageSize(): number {
+  return 2;
+}
+
+export function findGateUsagePda(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  seeds: {
+    /** The address of the gating Asset or NFT mint */
+    asset: PublicKey;
+    /** The address of the Candy Guard account */
+    candyGuard: PublicKey;
+    /** The address of the Candy Machine account */
+    candyMachine: PublicKey;
+  }
+): Pda {
+  const programId = context.programs.getPublicKey(
+    'mplCoreCandyGuard',
+    'CMAGAKJ67e9hRZgfC5SFTbZH8MgEmtqazKXjmkaJjWTJ'
+  );
+  return context.eddsa.findPda(programId, [
+    string({ size: 'variable' }).serialize('gate_usage'),
+    publicKeySerializer().serialize(seeds.asset),
+    publicKeySerializer().serialize(seeds.candyGuard),
+    publicKeySerializer().serialize(seeds.candyMachine),
+  ]);
+}
+
+export async function fetchGateUsageFromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findGateUsagePda>[1],
+  options?: RpcGetAccountOptions
+): Promise<GateUsage> {
+  return fetchGateUsage(context, findGateUsagePda(context, seeds), options);
+}
+
+export async function safeFetchGateUsageFromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findGateUsagePda>[1],
+  options?: RpcGetAccountOptions
+): Promise<GateUsage | null> {
+  return safeFetchGateUsage(context, findGateUsagePda(context, seeds), options);
+}
@@ -7,9 +7,19 @@
  */
 
 export * from './allocationTracker';
+export * from './allotmentProof';
 export * from './allowListProof';
 export * from './assetMintCounter';
+export * from './auctionBid';
+export * from './auctionEscrow';
 export * from './candyMachine';
+export * from './cooldownTracker';
+export * from './discountUsage';
 export * from './freezeEscrow';
+export * from './gateUsage';
 export * from './mintCounter';
+export * from './mintReceipt';
 export * from './nftMintCounter';
+export * from './rateLimitTracker';
+export * from './solGateRegistration';
+export * from './voucherNonce';
@@ -0,0 +1,265 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Account,
+  Context,
+  Option,
+  OptionOrNullable,
+  Pda,
+  PublicKey,
+  RpcAccount,
+  RpcGetAccountOptions,
+  RpcGetAccountsOptions,
+  assertAccountExists,
+  deserializeAccount,
+  gpaBuilder,
+  publicKey as toPublicKey,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  i64,
+  mapSerializer,
+  option,
+  publicKey as publicKeySerializer,
+  string,
+  struct,
+  u32,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  MintPayment,
+  MintPaymentArgs,
+  getMintPaymentSerializer,
+} from '../types';
+
+/** PDA to record the details of a mint. */
+export type MintReceipt = Account<MintReceiptAccountData>;
+
+export type MintReceiptAccountData = {
+  discriminator: Array<number>;
+  /** Candy guard address used for the mint. */
+  candyGuard: PublicKey;
+  /** Candy machine address the asset was minted from. */
+  candyMachine: PublicKey;
+  /** Address of the asset minted. */
+  asset: PublicKey;
+  /** Address of the minter. */
+  minter: PublicKey;
+  /** Address of the owner of the asset. */
+  owner: PublicKey;
+  /** Address that paid for the mint (and for the receipt rent). */
+  payer: PublicKey;
+  /** Label of the group used for the mint. */
+  group: Option<string>;
+  /** Index of the config line minted, when reported by the candy machine. */
+  configIndex: Option<number>;
+  /** Number of the item minted (starting at 1). */
+  mintNumber: bigint;
+  /** Timestamp of the mint. */
+  timestamp: bigint;
+  /** Amounts charged by payment guards. */
+  payments: Array<MintPayment>;
+};
+
+export type MintReceiptAccountDataArgs = {
+  /** Candy guard address used for the mint. */
+  candyGuard: PublicKey;
+  /** Candy machine address the asset was minted from. */
+  candyMachine: PublicKey;
+  /** Address of the asset minted. */
+  asset: PublicKey;
+  /** Address of the minter. */
+  minter: PublicKey;
+  /** Address of the owner of the asset. */
+  owner: PublicKey;
+  /** Address that paid for the mint (and for the receipt rent). */
+  payer: PublicKey;
+  /** Label of the group used for the mint. */
+  group: OptionOrNullable<string>;
+  /** Index of the config line minted, when reported by the candy machine. */
+  configIndex: OptionOrNullable<number>;
+  /** Number of the item minted (starting at 1). */
+  mintNumber: number | bigint;
+  /** Timestamp of the mint. */
+  timestamp: number | bigint;
+  /** Amounts charged by payment guards. */
+  payments: Array<MintPaymentArgs>;
+};
+
+export function getMintReceiptAccountDataSerializer(): Serializer<
+  MintReceiptAccountDataArgs,
+  MintReceiptAccountData
+> {
+  return mapSerializer<
+    MintReceiptAccountDataArgs,
+    any,
+    MintReceiptAccountData
+  >(
+    struct<MintReceiptAccountData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['candyGuard', publicKeySerializer()],
+        ['candyMachine', publicKeySerializer()],
+        ['asset', publicKeySerializer()],
+        ['minter', publicKeySerializer()],
+        ['owner', publicKeySerializer()],
+        ['payer', publicKeySerializer()],
+        ['group', option(string())],
+        ['configIndex', option(u32())],
+        ['mintNumber', u64()],
+        ['timestamp', i64()],
+        ['payments', array(getMintPaymentSerializer())],
+      ],
+      { description: 'MintReceiptAccountData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [140, 16, 143, 24, 20, 95, 250, 15],
+    })
+  ) as Serializer<MintReceiptAccountDataArgs, MintReceiptAccountData>;
+}
+
+export function deserializeMintReceipt(rawAccount: RpcAccount): MintReceipt {
+  return deserializeAccount(rawAccount, getMintReceiptAccountDataSerializer());
+}
+
+export async function fetchMintReceipt(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<MintReceipt> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  assertAccountExists(maybeAccount, 'MintReceipt');
+  return deserializeMintReceipt(maybeAccount);
+}
+
+export async function safeFetchMintReceipt(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<MintReceipt | null> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  return maybeAccount.exists ? deserializeMintReceipt(maybeAccount) : null;
+}
+
+export async function fetchAllMintReceipt(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<MintReceipt[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts.map((maybeAccount) => {
+    assertAccountExists(maybeAccount, 'MintReceipt');
+    return deserializeMintReceipt(maybeAccount);
+  });
+}
+
+export async function safeFetchAllMintReceipt(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<MintReceipt[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts
+    .filter((maybeAccount) => maybeAccount.exists)
+    .map((maybeAccount) => deserializeMintReceipt(maybeAccount as RpcAccount));
+}
+
+export function getMintReceiptGpaBuilder(
+  context: Pick<Context, 'rpc' | 'programs'>
+) {
+  const programId = context.programs.getPublicKey(
+    'mplCoreCandyGuard',
+    'CMAGAKJ67e9hRZgfC5SFTbZH8MgEmtqazKXjmkaJjWTJ'
+  );
+  return gpaBuilder(context, programId)
+    .registerFields<{
+      discriminator: Array<number>;
+      candyGuard: PublicKey;
+      candyMachine: PublicKey;
+      asset: PublicKey;
+      minter: PublicKey;
+      owner: PublicKey;
+      payer: PublicKey;
+      group: OptionOrNullable<string>;
+      configIndex: OptionOrNullable<number>;
+      mintNumber: number | bigint;
+      timestamp: number | bigint;
+      payments: Array<MintPaymentArgs>;
+    }>({
+      discriminator: [0, array(u8(), { size: 8 })],
+      candyGuard: [8, publicKeySerializer()],
+      candyMachine: [40, publicKeySerializer()],
+      asset: [72, publicKeySerializer()],
+      minter: [104, publicKeySerializer()],
+      owner: [136, publicKeySerializer()],
+      payer: [168, publicKeySerializer()],
+      group: [200, option(string())],
+      configIndex: [null, option(u32())],
+      mintNumber: [null, u64()],
+      timestamp: [null, i64()],
+      payments: [null, array(getMintPaymentSerializer())],
+    })
+    .deserializeUsing<MintReceipt>((account) => deserializeMintReceipt(account))
+    .whereField('discriminator', [140, 16, 143, 24, 20, 95, 250, 15]);
+}
+
+export function findMintReceiptPda(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  seeds: {
+    /** The address of the Candy Machine account */
+    candyMachine: PublicKey;
+    /** The address of the Asset minted */
+    asset: PublicKey;
+  }
+): Pda {
+  const programId = context.programs.getPublicKey(
+    'mplCoreCandyGuard',
+    'CMAGAKJ67e9hRZgfC5SFTbZH8MgEmtqazKXjmkaJjWTJ'
+  );
+  return context.eddsa.findPda(programId, [
+    string({ size: 'variable' }).serialize('mint_receipt'),
+    publicKeySerializer().serialize(seeds.candyMachine),
+    publicKeySerializer().serialize(seeds.asset),
+  ]);
+}
+
+export async function fetchMintReceiptFromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findMintReceiptPda>[1],
+  options?: RpcGetAccountOptions
+): Promise<MintReceipt> {
+  return fetchMintReceipt(context, findMintReceiptPda(context, seeds), options);
+}
+
+export async function safeFetchMintReceiptFromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findMintReceiptPda>[1],
+  options?: RpcGetAccountOptions
+): Promise<MintReceipt | null> {
+  return safeFetchMintReceipt(
+    context,
+    findMintReceiptPda(context, seeds),
+    options
+  );
+}
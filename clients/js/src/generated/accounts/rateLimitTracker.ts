@@ -0,0 +1,197 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Account,
+  Context,
+  Pda,
+  PublicKey,
+  RpcAccount,
+  RpcGetAccountOptions,
+  RpcGetAccountsOptions,
+  assertAccountExists,
+  deserializeAccount,
+  gpaBuilder,
+  publicKey as toPublicKey,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  i64,
+  publicKey as publicKeySerializer,
+  string,
+  struct,
+  u32,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+
+/** PDA to track the number of mints within the current window. */
+export type RateLimitTracker = Account<RateLimitTrackerAccountData>;
+
+export type RateLimitTrackerAccountData = {
+  /** Start of the current window. */
+  windowStart: bigint;
+  /** Number of mints within the current window. */
+  count: number;
+};
+
+export type RateLimitTrackerAccountDataArgs = {
+  /** Start of the current window. */
+  windowStart: number | bigint;
+  /** Number of mints within the current window. */
+  count: number;
+};
+
+export function getRateLimitTrackerAccountDataSerializer(): Serializer<
+  RateLimitTrackerAccountDataArgs,
+  RateLimitTrackerAccountData
+> {
+  return struct<RateLimitTrackerAccountData>(
+    [
+      ['windowStart', i64()],
+      ['count', u32()],
+    ],
+    { description: 'RateLimitTrackerAccountData' }
+  ) as Serializer<RateLimitTrackerAccountDataArgs, RateLimitTrackerAccountData>;
+}
+
+export function deserializeRateLimitTracker(
+  rawAccount: RpcAccount
+): RateLimitTracker {
+  return deserializeAccount(
+    rawAccount,
+    getRateLimitTrackerAccountDataSerializer()
+  );
+}
+
+export async function fetchRateLimitTracker(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<RateLimitTracker> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  assertAccountExists(maybeAccount, 'RateLimitTracker');
+  return deserializeRateLimitTracker(maybeAccount);
+}
+
+export async function safeFetchRateLimitTracker(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<RateLimitTracker | null> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  return maybeAccount.exists ? deserializeRateLimitTracker(maybeAccount) : null;
+}
+
+export async function fetchAllRateLimitTracker(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<RateLimitTracker[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts.map((maybeAccount) => {
+    assertAccountExists(maybeAccount, 'RateLimitTracker');
+    return deserializeRateLimitTracker(maybeAccount);
+  });
+}
+
+export async function safeFetchAllRateLimitTracker(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<RateLimitTracker[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts
+    .filter((maybeAccount) => maybeAccount.exists)
+    .map((maybeAccount) =>
+      deserializeRateLimitTracker(maybeAccount as RpcAccount)
+    );
+}
+
+export function getRateLimitTrackerGpaBuilder(
+  context: Pick<Context, 'rpc' | 'programs'>
+) {
+  const programId = context.programs.getPublicKey(
+    'mplCoreCandyGuard',
+    'CMAGAKJ67e9hRZgfC5SFTbZH8MgEmtqazKXjmkaJjWTJ'
+  );
+  return gpaBuilder(context, programId)
+    .registerFields<{
+      windowStart: number | bigint;
+      count: number;
+    }>({
+      windowStart: [0, i64()],
+      count: [8, u32()],
+    })
+    .deserializeUsing<RateLimitTracker>((account) =>
+      deserializeRateLimitTracker(account)
+    )
+    .whereSize(12);
+}
+
+export function getRateLimitTrackerSize(): number {
+  return 12;
+}
+
+export function findRateLimitTrackerPda(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  seeds: {
+    /** A unique identifier in the context of a Candy Machine/Candy Guard combo */
+    id: number;
+    /** The address of the Candy Guard account */
+    candyGuard: PublicKey;
+    /** The address of the Candy Machine account */
+    candyMachine: PublicKey;
+  }
+): Pda {
+  const programId = context.programs.getPublicKey(
+    'mplCoreCandyGuard',
+    'CMAGAKJ67e9hRZgfC5SFTbZH8MgEmtqazKXjmkaJjWTJ'
+  );
+  return context.eddsa.findPda(programId, [
+    string({ size: 'variable' }).serialize('rate_limit'),
+    u8().serialize(seeds.id),
+    publicKeySerializer().serialize(seeds.candyGuard),
+    publicKeySerializer().serialize(seeds.candyMachine),
+  ]);
+}
+
+export async function fetchRateLimitTrackerFromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findRateLimitTrackerPda>[1],
+  options?: RpcGetAccountOptions
+): Promise<RateLimitTracker> {
+  return fetchRateLimitTracker(
+    context,
+    findRateLimitTrackerPda(context, seeds),
+    options
+  );
+}
+
+export async function safeFetchRateLimitTrackerFromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findRateLimitTrackerPda>[1],
+  options?: RpcGetAccountOptions
+): Promise<RateLimitTracker | null> {
+  return safeFetchRateLimitTracker(
+    context,
+    findRateLimitTrackerPda(context, seeds),
+    options
+  );
+}
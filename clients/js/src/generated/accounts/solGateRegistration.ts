@@ -0,0 +1,183 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Account,
+  Context,
+  Pda,
+  PublicKey,
+  RpcAccount,
+  RpcGetAccountOptions,
+  RpcGetAccountsOptions,
+  assertAccountExists,
+  deserializeAccount,
+  gpaBuilder,
+  publicKey as toPublicKey,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  i64,
+  publicKey as publicKeySerializer,
+  string,
+  struct,
+} from '@metaplex-foundation/umi/serializers';
+
+/** PDA to track the registration time of a wallet. */
+export type SolGateRegistration = Account<SolGateRegistrationAccountData>;
+
+export type SolGateRegistrationAccountData = {
+  /** Timestamp of the registration. */
+  timestamp: bigint;
+};
+
+export type SolGateRegistrationAccountDataArgs = {
+  /** Timestamp of the registration. */
+  timestamp: number | bigint;
+};
+
+export function getSolGateRegistrationAccountDataSerializer(): Serializer<
+  SolGateRegistrationAccountDataArgs,
+  SolGateRegistrationAccountData
+> {
+  return struct<SolGateRegistrationAccountData>([['timestamp', i64()]], {
+    description: 'SolGateRegistrationAccountData',
+  }) as Serializer<SolGateRegistrationAccountDataArgs, SolGateRegistrationAccountData>;
+}
+
+export function deserializeSolGateRegistration(
+  rawAccount: RpcAccount
+): SolGateRegistration {
+  return deserializeAccount(
+    rawAccount,
+    getSolGateRegistrationAccountDataSerializer()
+  );
+}
+
+export async function fetchSolGateRegistration(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<SolGateRegistration> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  assertAccountExists(maybeAccount, 'SolGateRegistration');
+  return deserializeSolGateRegistration(maybeAccount);
+}
+
+export async function safeFetchSolGateRegistration(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<SolGateRegistration | null> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  return maybeAccount.exists
+    ? deserializeSolGateRegistration(maybeAccount)
+    : null;
+}
+
+export async function fetchAllSolGateRegistration(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<SolGateRegistration[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts.map((maybeAccount) => {
+    assertAccountExists(maybeAccount, 'SolGateRegistration');
+    return deserializeSolGateRegistration(maybeAccount);
+  });
+}
+
+export async function safeFetchAllSolGateRegistration(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<SolGateRegistration[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts
+    .filter((maybeAccount) => maybeAccount.exists)
+    .map((maybeAccount) =>
+      deserializeSolGateRegistration(maybeAccount as RpcAccount)
+    );
+}
+
+export function getSolGateRegistrationGpaBuilder(
+  context: Pick<Context, 'rpc' | 'programs'>
+) {
+  const programId = context.programs.getPublicKey(
+    'mplCoreCandyGuard',
+    'CMAGAKJ67e9hRZgfC5SFTbZH8MgEmtqazKXjmkaJjWTJ'
+  );
+  return gpaBuilder(context, programId)
+    .registerFields<{ timestamp: number | bigint }>({ timestamp: [0, i64()] })
+    .deserializeUsing<SolGateRegistration>((account) =>
+      deserializeSolGateRegistration(account)
+    )
+    .whereSize(8);
+}
+
+export function getSolGateRegistrationSize(): number {
+  return 8;
+}
+
+export function findSolGateRegistrationPda(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  seeds: {
+    /** The address of the wallet trying to mint */
+    user: PublicKey;
+    /** The address of the Candy Guard account */
+    candyGuard: PublicKey;
+    /** The address of the Candy Machine account */
+    candyMachine: PublicKey;
+  }
+): Pda {
+  const programId = context.programs.getPublicKey(
+    'mplCoreCandyGuard',
+    'CMAGAKJ67e9hRZgfC5SFTbZH8MgEmtqazKXjmkaJjWTJ'
+  );
+  return context.eddsa.findPda(programId, [
+    string({ size: 'variable' }).serialize('sol_gate'),
+    publicKeySerializer().serialize(seeds.user),
+    publicKeySerializer().serialize(seeds.candyGuard),
+    publicKeySerializer().serialize(seeds.candyMachine),
+  ]);
+}
+
+export async function fetchSolGateRegistrationFromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findSolGateRegistrationPda>[1],
+  options?: RpcGetAccountOptions
+): Promise<SolGateRegistration> {
+  return fetchSolGateRegistration(
+    context,
+    findSolGateRegistrationPda(context, seeds),
+    options
+  );
+}
+
+export async function safeFetchSolGateRegistrationFromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findSolGateRegistrationPda>[1],
+  options?: RpcGetAccountOptions
+): Promise<SolGateRegistration | null> {
+  return safeFetchSolGateRegistration(
+    context,
+    findSolGateRegistrationPda(context, seeds),
+    options
+  );
+}
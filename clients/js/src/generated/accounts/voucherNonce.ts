@@ -0,0 +1,173 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Account,
+  Context,
+  Pda,
+  PublicKey,
+  RpcAccount,
+  RpcGetAccountOptions,
+  RpcGetAccountsOptions,
+  assertAccountExists,
+  deserializeAccount,
+  gpaBuilder,
+  publicKey as toPublicKey,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  publicKey as publicKeySerializer,
+  string,
+  struct,
+  u64,
+} from '@metaplex-foundation/umi/serializers';
+
+/** PDA to record that a voucher was used. */
+export type VoucherNonce = Account<VoucherNonceAccountData>;
+
+export type VoucherNonceAccountData = {
+  /** Address that used the voucher. */
+  minter: PublicKey;
+};
+
+export type VoucherNonceAccountDataArgs = VoucherNonceAccountData;
+
+export function getVoucherNonceAccountDataSerializer(): Serializer<
+  VoucherNonceAccountDataArgs,
+  VoucherNonceAccountData
+> {
+  return struct<VoucherNonceAccountData>([['minter', publicKeySerializer()]], {
+    description: 'VoucherNonceAccountData',
+  }) as Serializer<VoucherNonceAccountDataArgs, VoucherNonceAccountData>;
+}
+
+export function deserializeVoucherNonce(rawAccount: RpcAccount): VoucherNonce {
+  return deserializeAccount(rawAccount, getVoucherNonceAccountDataSerializer());
+}
+
+export async function fetchVoucherNonce(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<VoucherNonce> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  assertAccountExists(maybeAccount, 'VoucherNonce');
+  return deserializeVoucherNonce(maybeAccount);
+}
+
+export async function safeFetchVoucherNonce(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<VoucherNonce | null> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  return maybeAccount.exists ? deserializeVoucherNonce(maybeAccount) : null;
+}
+
+export async function fetchAllVoucherNonce(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<VoucherNonce[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts.map((maybeAccount) => {
+    assertAccountExists(maybeAccount, 'VoucherNonce');
+    return deserializeVoucherNonce(maybeAccount);
+  });
+}
+
+export async function safeFetchAllVoucherNonce(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<VoucherNonce[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts
+    .filter((maybeAccount) => maybeAccount.exists)
+    .map((maybeAccount) => deserializeVoucherNonce(maybeAccount as RpcAccount));
+}
+
+export function getVoucherNonceGpaBuilder(
+  context: Pick<Context, 'rpc' | 'programs'>
+) {
+  const programId = context.programs.getPublicKey(
+    'mplCoreCandyGuard',
+    'CMAGAKJ67e9hRZgfC5SFTbZH8MgEmtqazKXjmkaJjWTJ'
+  );
+  return gpaBuilder(context, programId)
+    .registerFields<{ minter: PublicKey }>({
+      minter: [0, publicKeySerializer()],
+    })
+    .deserializeUsing<VoucherNonce>((account) =>
+      deserializeVoucherNonce(account)
+    )
+    .whereSize(32);
+}
+
+export function getVoucherNonceSize(): number {
+  return 32;
+}
+
+export function findVoucherNoncePda(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  seeds: {
+    /** The nonce of the voucher */
+    nonce: number | bigint;
+    /** The address of the Candy Guard account */
+    candyGuard: PublicKey;
+    /** The address of the Candy Machine account */
+    candyMachine: PublicKey;
+  }
+): Pda {
+  const programId = context.programs.getPublicKey(
+    'mplCoreCandyGuard',
+    'CMAGAKJ67e9hRZgfC5SFTbZH8MgEmtqazKXjmkaJjWTJ'
+  );
+  return context.eddsa.findPda(programId, [
+    string({ size: 'variable' }).serialize('signed_voucher'),
+    u64().serialize(seeds.nonce),
+    publicKeySerializer().serialize(seeds.candyGuard),
+    publicKeySerializer().serialize(seeds.candyMachine),
+  ]);
+}
+
+export async function fetchVoucherNonceFromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findVoucherNoncePda>[1],
+  options?: RpcGetAccountOptions
+): Promise<VoucherNonce> {
+  return fetchVoucherNonce(
+    context,
+    findVoucherNoncePda(context, seeds),
+    options
+  );
+}
+
+export async function safeFetchVoucherNonceFromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findVoucherNoncePda>[1],
+  options?: RpcGetAccountOptions
+): Promise<VoucherNonce | null> {
+  return safeFetchVoucherNonce(
+    context,
+    findVoucherNoncePda(context, seeds),
+    options
+  );
+}
@@ -774,6 +774,642 @@ export class CgInvalidRegexError extends ProgramError {
 codeToErrorMap.set(0x17a6, CgInvalidRegexError);
 nameToErrorMap.set('InvalidRegex', CgInvalidRegexError);
 
+/** ReceiptAlreadyExists: The mint receipt account already exists */
+export class CgReceiptAlreadyExistsError extends ProgramError {
+  readonly name: string = 'ReceiptAlreadyExists';
+
+  readonly code: number = 0x17a7; // 6055
+
+  constructor(program: Program, cause?: Error) {
+    super('The mint receipt account already exists', program, cause);
+  }
+}
+codeToErrorMap.set(0x17a7, CgReceiptAlreadyExistsError);
+nameToErrorMap.set('ReceiptAlreadyExists', CgReceiptAlreadyExistsError);
+
+/** ReceiptCloseNotAllowed: Mint receipt cannot be closed before the mint is over */
+export class CgReceiptCloseNotAllowedError extends ProgramError {
+  readonly name: string = 'ReceiptCloseNotAllowed';
+
+  readonly code: number = 0x17a8; // 6056
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'Mint receipt cannot be closed before the mint is over',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x17a8, CgReceiptCloseNotAllowedError);
+nameToErrorMap.set('ReceiptCloseNotAllowed', CgReceiptCloseNotAllowedError);
+
+/** InvalidAuctionConfig: Invalid dutch auction configuration */
+export class CgInvalidAuctionConfigError extends ProgramError {
+  readonly name: string = 'InvalidAuctionConfig';
+
+  readonly code: number = 0x17a9; // 6057
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid dutch auction configuration', program, cause);
+  }
+}
+codeToErrorMap.set(0x17a9, CgInvalidAuctionConfigError);
+nameToErrorMap.set('InvalidAuctionConfig', CgInvalidAuctionConfigError);
+
+/** AuctionGuardNotEnabled: Dutch auction guard must be enabled */
+export class CgAuctionGuardNotEnabledError extends ProgramError {
+  readonly name: string = 'AuctionGuardNotEnabled';
+
+  readonly code: number = 0x17aa; // 6058
+
+  constructor(program: Program, cause?: Error) {
+    super('Dutch auction guard must be enabled', program, cause);
+  }
+}
+codeToErrorMap.set(0x17aa, CgAuctionGuardNotEnabledError);
+nameToErrorMap.set('AuctionGuardNotEnabled', CgAuctionGuardNotEnabledError);
+
+/** AuctionNotInitialized: Dutch auction escrow must be initialized */
+export class CgAuctionNotInitializedError extends ProgramError {
+  readonly name: string = 'AuctionNotInitialized';
+
+  readonly code: number = 0x17ab; // 6059
+
+  constructor(program: Program, cause?: Error) {
+    super('Dutch auction escrow must be initialized', program, cause);
+  }
+}
+codeToErrorMap.set(0x17ab, CgAuctionNotInitializedError);
+nameToErrorMap.set('AuctionNotInitialized', CgAuctionNotInitializedError);
+
+/** AuctionEscrowAlreadyExists: The dutch auction escrow account already exists */
+export class CgAuctionEscrowAlreadyExistsError extends ProgramError {
+  readonly name: string = 'AuctionEscrowAlreadyExists';
+
+  readonly code: number = 0x17ac; // 6060
+
+  constructor(program: Program, cause?: Error) {
+    super('The dutch auction escrow account already exists', program, cause);
+  }
+}
+codeToErrorMap.set(0x17ac, CgAuctionEscrowAlreadyExistsError);
+nameToErrorMap.set(
+  'AuctionEscrowAlreadyExists',
+  CgAuctionEscrowAlreadyExistsError
+);
+
+/** AuctionNotSettled: Dutch auction is not settled */
+export class CgAuctionNotSettledError extends ProgramError {
+  readonly name: string = 'AuctionNotSettled';
+
+  readonly code: number = 0x17ad; // 6061
+
+  constructor(program: Program, cause?: Error) {
+    super('Dutch auction is not settled', program, cause);
+  }
+}
+codeToErrorMap.set(0x17ad, CgAuctionNotSettledError);
+nameToErrorMap.set('AuctionNotSettled', CgAuctionNotSettledError);
+
+/** InvalidBondingCurveConfig: Invalid bonding curve configuration */
+export class CgInvalidBondingCurveConfigError extends ProgramError {
+  readonly name: string = 'InvalidBondingCurveConfig';
+
+  readonly code: number = 0x17ae; // 6062
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid bonding curve configuration', program, cause);
+  }
+}
+codeToErrorMap.set(0x17ae, CgInvalidBondingCurveConfigError);
+nameToErrorMap.set(
+  'InvalidBondingCurveConfig',
+  CgInvalidBondingCurveConfigError
+);
+
+/** InvalidPaymentSplit: Invalid payment split configuration */
+export class CgInvalidPaymentSplitError extends ProgramError {
+  readonly name: string = 'InvalidPaymentSplit';
+
+  readonly code: number = 0x17af; // 6063
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid payment split configuration', program, cause);
+  }
+}
+codeToErrorMap.set(0x17af, CgInvalidPaymentSplitError);
+nameToErrorMap.set('InvalidPaymentSplit', CgInvalidPaymentSplitError);
+
+/** InvalidAnyOfConfig: Invalid AnyOf guard configuration */
+export class CgInvalidAnyOfConfigError extends ProgramError {
+  readonly name: string = 'InvalidAnyOfConfig';
+
+  readonly code: number = 0x17b0; // 6064
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid AnyOf guard configuration', program, cause);
+  }
+}
+codeToErrorMap.set(0x17b0, CgInvalidAnyOfConfigError);
+nameToErrorMap.set('InvalidAnyOfConfig', CgInvalidAnyOfConfigError);
+
+/** AnyOfConditionsNotMet: None of the AnyOf guards validated */
+export class CgAnyOfConditionsNotMetError extends ProgramError {
+  readonly name: string = 'AnyOfConditionsNotMet';
+
+  readonly code: number = 0x17b1; // 6065
+
+  constructor(program: Program, cause?: Error) {
+    super('None of the AnyOf guards validated', program, cause);
+  }
+}
+codeToErrorMap.set(0x17b1, CgAnyOfConditionsNotMetError);
+nameToErrorMap.set('AnyOfConditionsNotMet', CgAnyOfConditionsNotMetError);
+
+/** AutoGroupNotInDefault: AutoGroup guard can only be enabled on the default guard set */
+export class CgAutoGroupNotInDefaultError extends ProgramError {
+  readonly name: string = 'AutoGroupNotInDefault';
+
+  readonly code: number = 0x17b2; // 6066
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'AutoGroup guard can only be enabled on the default guard set',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x17b2, CgAutoGroupNotInDefaultError);
+nameToErrorMap.set('AutoGroupNotInDefault', CgAutoGroupNotInDefaultError);
+
+/** NoEligibleGroup: None of the groups is eligible for the mint */
+export class CgNoEligibleGroupError extends ProgramError {
+  readonly name: string = 'NoEligibleGroup';
+
+  readonly code: number = 0x17b3; // 6067
+
+  constructor(program: Program, cause?: Error) {
+    super('None of the groups is eligible for the mint', program, cause);
+  }
+}
+codeToErrorMap.set(0x17b3, CgNoEligibleGroupError);
+nameToErrorMap.set('NoEligibleGroup', CgNoEligibleGroupError);
+
+/** EligibilityResultTooLarge: Eligibility result exceeds the maximum return data size */
+export class CgEligibilityResultTooLargeError extends ProgramError {
+  readonly name: string = 'EligibilityResultTooLarge';
+
+  readonly code: number = 0x17b4; // 6068
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'Eligibility result exceeds the maximum return data size',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x17b4, CgEligibilityResultTooLargeError);
+nameToErrorMap.set(
+  'EligibilityResultTooLarge',
+  CgEligibilityResultTooLargeError
+);
+
+/** InvalidGroupLabel: Invalid group label */
+export class CgInvalidGroupLabelError extends ProgramError {
+  readonly name: string = 'InvalidGroupLabel';
+
+  readonly code: number = 0x17b5; // 6069
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid group label', program, cause);
+  }
+}
+codeToErrorMap.set(0x17b5, CgInvalidGroupLabelError);
+nameToErrorMap.set('InvalidGroupLabel', CgInvalidGroupLabelError);
+
+/** InvalidDisabledGuard: Invalid disabled default guard */
+export class CgInvalidDisabledGuardError extends ProgramError {
+  readonly name: string = 'InvalidDisabledGuard';
+
+  readonly code: number = 0x17b6; // 6070
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid disabled default guard', program, cause);
+  }
+}
+codeToErrorMap.set(0x17b6, CgInvalidDisabledGuardError);
+nameToErrorMap.set('InvalidDisabledGuard', CgInvalidDisabledGuardError);
+
+/** InvalidPhaseSchedule: Invalid phase schedule */
+export class CgInvalidPhaseScheduleError extends ProgramError {
+  readonly name: string = 'InvalidPhaseSchedule';
+
+  readonly code: number = 0x17b7; // 6071
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid phase schedule', program, cause);
+  }
+}
+codeToErrorMap.set(0x17b7, CgInvalidPhaseScheduleError);
+nameToErrorMap.set('InvalidPhaseSchedule', CgInvalidPhaseScheduleError);
+
+/** PhaseScheduleNotInDefault: PhaseSchedule guard can only be enabled on the default guard set */
+export class CgPhaseScheduleNotInDefaultError extends ProgramError {
+  readonly name: string = 'PhaseScheduleNotInDefault';
+
+  readonly code: number = 0x17b8; // 6072
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'PhaseSchedule guard can only be enabled on the default guard set',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x17b8, CgPhaseScheduleNotInDefaultError);
+nameToErrorMap.set(
+  'PhaseScheduleNotInDefault',
+  CgPhaseScheduleNotInDefaultError
+);
+
+/** PhaseScheduleNotEnabled: Phase schedule guard must be enabled */
+export class CgPhaseScheduleNotEnabledError extends ProgramError {
+  readonly name: string = 'PhaseScheduleNotEnabled';
+
+  readonly code: number = 0x17b9; // 6073
+
+  constructor(program: Program, cause?: Error) {
+    super('Phase schedule guard must be enabled', program, cause);
+  }
+}
+codeToErrorMap.set(0x17b9, CgPhaseScheduleNotEnabledError);
+nameToErrorMap.set('PhaseScheduleNotEnabled', CgPhaseScheduleNotEnabledError);
+
+/** PhaseNotActive: The phase of the group is not active */
+export class CgPhaseNotActiveError extends ProgramError {
+  readonly name: string = 'PhaseNotActive';
+
+  readonly code: number = 0x17ba; // 6074
+
+  constructor(program: Program, cause?: Error) {
+    super('The phase of the group is not active', program, cause);
+  }
+}
+codeToErrorMap.set(0x17ba, CgPhaseNotActiveError);
+nameToErrorMap.set('PhaseNotActive', CgPhaseNotActiveError);
+
+/** InvalidExternalGuardConfig: Invalid external guard configuration */
+export class CgInvalidExternalGuardConfigError extends ProgramError {
+  readonly name: string = 'InvalidExternalGuardConfig';
+
+  readonly code: number = 0x17bb; // 6075
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid external guard configuration', program, cause);
+  }
+}
+codeToErrorMap.set(0x17bb, CgInvalidExternalGuardConfigError);
+nameToErrorMap.set(
+  'InvalidExternalGuardConfig',
+  CgInvalidExternalGuardConfigError
+);
+
+/** MissingVoucher: Missing signed voucher instruction */
+export class CgMissingVoucherError extends ProgramError {
+  readonly name: string = 'MissingVoucher';
+
+  readonly code: number = 0x17bc; // 6076
+
+  constructor(program: Program, cause?: Error) {
+    super('Missing signed voucher instruction', program, cause);
+  }
+}
+codeToErrorMap.set(0x17bc, CgMissingVoucherError);
+nameToErrorMap.set('MissingVoucher', CgMissingVoucherError);
+
+/** InvalidVoucher: Invalid signed voucher */
+export class CgInvalidVoucherError extends ProgramError {
+  readonly name: string = 'InvalidVoucher';
+
+  readonly code: number = 0x17bd; // 6077
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid signed voucher', program, cause);
+  }
+}
+codeToErrorMap.set(0x17bd, CgInvalidVoucherError);
+nameToErrorMap.set('InvalidVoucher', CgInvalidVoucherError);
+
+/** VoucherExpired: Signed voucher expired */
+export class CgVoucherExpiredError extends ProgramError {
+  readonly name: string = 'VoucherExpired';
+
+  readonly code: number = 0x17be; // 6078
+
+  constructor(program: Program, cause?: Error) {
+    super('Signed voucher expired', program, cause);
+  }
+}
+codeToErrorMap.set(0x17be, CgVoucherExpiredError);
+nameToErrorMap.set('VoucherExpired', CgVoucherExpiredError);
+
+/** VoucherAlreadyUsed: Signed voucher already used */
+export class CgVoucherAlreadyUsedError extends ProgramError {
+  readonly name: string = 'VoucherAlreadyUsed';
+
+  readonly code: number = 0x17bf; // 6079
+
+  constructor(program: Program, cause?: Error) {
+    super('Signed voucher already used', program, cause);
+  }
+}
+codeToErrorMap.set(0x17bf, CgVoucherAlreadyUsedError);
+nameToErrorMap.set('VoucherAlreadyUsed', CgVoucherAlreadyUsedError);
+
+/** MintNotOver: The mint is not over */
+export class CgMintNotOverError extends ProgramError {
+  readonly name: string = 'MintNotOver';
+
+  readonly code: number = 0x17c0; // 6080
+
+  constructor(program: Program, cause?: Error) {
+    super('The mint is not over', program, cause);
+  }
+}
+codeToErrorMap.set(0x17c0, CgMintNotOverError);
+nameToErrorMap.set('MintNotOver', CgMintNotOverError);
+
+/** GuardNotEnabled: The guard is not enabled */
+export class CgGuardNotEnabledError extends ProgramError {
+  readonly name: string = 'GuardNotEnabled';
+
+  readonly code: number = 0x17c1; // 6081
+
+  constructor(program: Program, cause?: Error) {
+    super('The guard is not enabled', program, cause);
+  }
+}
+codeToErrorMap.set(0x17c1, CgGuardNotEnabledError);
+nameToErrorMap.set('GuardNotEnabled', CgGuardNotEnabledError);
+
+/** InvalidCooldownConfig: Invalid cooldown configuration */
+export class CgInvalidCooldownConfigError extends ProgramError {
+  readonly name: string = 'InvalidCooldownConfig';
+
+  readonly code: number = 0x17c2; // 6082
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid cooldown configuration', program, cause);
+  }
+}
+codeToErrorMap.set(0x17c2, CgInvalidCooldownConfigError);
+nameToErrorMap.set('InvalidCooldownConfig', CgInvalidCooldownConfigError);
+
+/** CooldownNotElapsed: The cooldown interval has not elapsed */
+export class CgCooldownNotElapsedError extends ProgramError {
+  readonly name: string = 'CooldownNotElapsed';
+
+  readonly code: number = 0x17c3; // 6083
+
+  constructor(program: Program, cause?: Error) {
+    super('The cooldown interval has not elapsed', program, cause);
+  }
+}
+codeToErrorMap.set(0x17c3, CgCooldownNotElapsedError);
+nameToErrorMap.set('CooldownNotElapsed', CgCooldownNotElapsedError);
+
+/** InvalidRateLimitConfig: Invalid rate limit configuration */
+export class CgInvalidRateLimitConfigError extends ProgramError {
+  readonly name: string = 'InvalidRateLimitConfig';
+
+  readonly code: number = 0x17c4; // 6084
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid rate limit configuration', program, cause);
+  }
+}
+codeToErrorMap.set(0x17c4, CgInvalidRateLimitConfigError);
+nameToErrorMap.set('InvalidRateLimitConfig', CgInvalidRateLimitConfigError);
+
+/** RateLimitExceeded: The rate limit was reached, retry once the window elapses */
+export class CgRateLimitExceededError extends ProgramError {
+  readonly name: string = 'RateLimitExceeded';
+
+  readonly code: number = 0x17c5; // 6085
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'The rate limit was reached, retry once the window elapses',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x17c5, CgRateLimitExceededError);
+nameToErrorMap.set('RateLimitExceeded', CgRateLimitExceededError);
+
+/** AfterEndSlot: Current slot is after the set end slot */
+export class CgAfterEndSlotError extends ProgramError {
+  readonly name: string = 'AfterEndSlot';
+
+  readonly code: number = 0x17c6; // 6086
+
+  constructor(program: Program, cause?: Error) {
+    super('Current slot is after the set end slot', program, cause);
+  }
+}
+codeToErrorMap.set(0x17c6, CgAfterEndSlotError);
+nameToErrorMap.set('AfterEndSlot', CgAfterEndSlotError);
+
+/** InvalidSlotRange: The end slot must be after the start slot */
+export class CgInvalidSlotRangeError extends ProgramError {
+  readonly name: string = 'InvalidSlotRange';
+
+  readonly code: number = 0x17c7; // 6087
+
+  constructor(program: Program, cause?: Error) {
+    super('The end slot must be after the start slot', program, cause);
+  }
+}
+codeToErrorMap.set(0x17c7, CgInvalidSlotRangeError);
+nameToErrorMap.set('InvalidSlotRange', CgInvalidSlotRangeError);
+
+/** InvalidRecurringWindow: Invalid recurring window configuration */
+export class CgInvalidRecurringWindowError extends ProgramError {
+  readonly name: string = 'InvalidRecurringWindow';
+
+  readonly code: number = 0x17c8; // 6088
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid recurring window configuration', program, cause);
+  }
+}
+codeToErrorMap.set(0x17c8, CgInvalidRecurringWindowError);
+nameToErrorMap.set('InvalidRecurringWindow', CgInvalidRecurringWindowError);
+
+/** OutsideRecurringWindow: Current time is outside of the recurring window */
+export class CgOutsideRecurringWindowError extends ProgramError {
+  readonly name: string = 'OutsideRecurringWindow';
+
+  readonly code: number = 0x17c9; // 6089
+
+  constructor(program: Program, cause?: Error) {
+    super('Current time is outside of the recurring window', program, cause);
+  }
+}
+codeToErrorMap.set(0x17c9, CgOutsideRecurringWindowError);
+nameToErrorMap.set('OutsideRecurringWindow', CgOutsideRecurringWindowError);
+
+/** BelowMinimumBalance: Minter balance is below the required minimum */
+export class CgBelowMinimumBalanceError extends ProgramError {
+  readonly name: string = 'BelowMinimumBalance';
+
+  readonly code: number = 0x17ca; // 6090
+
+  constructor(program: Program, cause?: Error) {
+    super('Minter balance is below the required minimum', program, cause);
+  }
+}
+codeToErrorMap.set(0x17ca, CgBelowMinimumBalanceError);
+nameToErrorMap.set('BelowMinimumBalance', CgBelowMinimumBalanceError);
+
+/** WalletNotRegistered: Wallet is not registered */
+export class CgWalletNotRegisteredError extends ProgramError {
+  readonly name: string = 'WalletNotRegistered';
+
+  readonly code: number = 0x17cb; // 6091
+
+  constructor(program: Program, cause?: Error) {
+    super('Wallet is not registered', program, cause);
+  }
+}
+codeToErrorMap.set(0x17cb, CgWalletNotRegisteredError);
+nameToErrorMap.set('WalletNotRegistered', CgWalletNotRegisteredError);
+
+/** WalletAlreadyRegistered: Wallet is already registered */
+export class CgWalletAlreadyRegisteredError extends ProgramError {
+  readonly name: string = 'WalletAlreadyRegistered';
+
+  readonly code: number = 0x17cc; // 6092
+
+  constructor(program: Program, cause?: Error) {
+    super('Wallet is already registered', program, cause);
+  }
+}
+codeToErrorMap.set(0x17cc, CgWalletAlreadyRegisteredError);
+nameToErrorMap.set('WalletAlreadyRegistered', CgWalletAlreadyRegisteredError);
+
+/** WalletRegistrationTooRecent: Wallet registration is too recent */
+export class CgWalletRegistrationTooRecentError extends ProgramError {
+  readonly name: string = 'WalletRegistrationTooRecent';
+
+  readonly code: number = 0x17cd; // 6093
+
+  constructor(program: Program, cause?: Error) {
+    super('Wallet registration is too recent', program, cause);
+  }
+}
+codeToErrorMap.set(0x17cd, CgWalletRegistrationTooRecentError);
+nameToErrorMap.set(
+  'WalletRegistrationTooRecent',
+  CgWalletRegistrationTooRecentError
+);
+
+/** InvalidAssetTraitGate: Invalid asset trait gate configuration */
+export class CgInvalidAssetTraitGateError extends ProgramError {
+  readonly name: string = 'InvalidAssetTraitGate';
+
+  readonly code: number = 0x17ce; // 6094
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid asset trait gate configuration', program, cause);
+  }
+}
+codeToErrorMap.set(0x17ce, CgInvalidAssetTraitGateError);
+nameToErrorMap.set('InvalidAssetTraitGate', CgInvalidAssetTraitGateError);
+
+/** MissingAssetTrait: Asset does not have the required trait */
+export class CgMissingAssetTraitError extends ProgramError {
+  readonly name: string = 'MissingAssetTrait';
+
+  readonly code: number = 0x17cf; // 6095
+
+  constructor(program: Program, cause?: Error) {
+    super('Asset does not have the required trait', program, cause);
+  }
+}
+codeToErrorMap.set(0x17cf, CgMissingAssetTraitError);
+nameToErrorMap.set('MissingAssetTrait', CgMissingAssetTraitError);
+
+/** InvalidHolderDiscount: Invalid holder discount configuration */
+export class CgInvalidHolderDiscountError extends ProgramError {
+  readonly name: string = 'InvalidHolderDiscount';
+
+  readonly code: number = 0x17d0; // 6096
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid holder discount configuration', program, cause);
+  }
+}
+codeToErrorMap.set(0x17d0, CgInvalidHolderDiscountError);
+nameToErrorMap.set('InvalidHolderDiscount', CgInvalidHolderDiscountError);
+
+/** DiscountAlreadyUsed: Discount already used for the asset */
+export class CgDiscountAlreadyUsedError extends ProgramError {
+  readonly name: string = 'DiscountAlreadyUsed';
+
+  readonly code: number = 0x17d1; // 6097
+
+  constructor(program: Program, cause?: Error) {
+    super('Discount already used for the asset', program, cause);
+  }
+}
+codeToErrorMap.set(0x17d1, CgDiscountAlreadyUsedError);
+nameToErrorMap.set('DiscountAlreadyUsed', CgDiscountAlreadyUsedError);
+
+/** InvalidGateUsageLimit: Invalid gate usage limit configuration */
+export class CgInvalidGateUsageLimitError extends ProgramError {
+  readonly name: string = 'InvalidGateUsageLimit';
+
+  readonly code: number = 0x17d2; // 6098
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid gate usage limit configuration', program, cause);
+  }
+}
+codeToErrorMap.set(0x17d2, CgInvalidGateUsageLimitError);
+nameToErrorMap.set('InvalidGateUsageLimit', CgInvalidGateUsageLimitError);
+
+/** GateUsageLimitReached: Gating asset usage limit reached */
+export class CgGateUsageLimitReachedError extends ProgramError {
+  readonly name: string = 'GateUsageLimitReached';
+
+  readonly code: number = 0x17d3; // 6099
+
+  constructor(program: Program, cause?: Error) {
+    super('Gating asset usage limit reached', program, cause);
+  }
+}
+codeToErrorMap.set(0x17d3, CgGateUsageLimitReachedError);
+nameToErrorMap.set('GateUsageLimitReached', CgGateUsageLimitReachedError);
+
+/** AuctionRebateNotEnabled: Auction rebates are not enabled */
+export class CgAuctionRebateNotEnabledError extends ProgramError {
+  readonly name: string = 'AuctionRebateNotEnabled';
+
+  readonly code: number = 0x17d4; // 6100
+
+  constructor(program: Program, cause?: Error) {
+    super('Auction rebates are not enabled', program, cause);
+  }
+}
+codeToErrorMap.set(0x17d4, CgAuctionRebateNotEnabledError);
+nameToErrorMap.set('AuctionRebateNotEnabled', CgAuctionRebateNotEnabledError);
+
 /**
  * Attempts to resolve a custom program error from the provided error code.
  * @category Errors
@@ -0,0 +1,285 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Option,
+  OptionOrNullable,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  publicKey,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  bytes,
+  mapSerializer,
+  option,
+  string,
+  struct,
+  u32,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { findCandyGuardPda, findCandyMachineAuthorityPda } from '../../hooked';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type CheckEligibilityInstructionAccounts = {
+  /** Candy Guard account. */
+  candyGuard?: PublicKey | Pda;
+  /**
+   * Candy Machine program account.
+   *
+   */
+
+  candyMachineProgram?: PublicKey | Pda;
+  /** Candy machine account. */
+  candyMachine: PublicKey | Pda;
+  /**
+   * Candy Machine authority account.
+   *
+   */
+
+  candyMachineAuthorityPda?: PublicKey | Pda;
+  /** Payer for the mint (SOL) fees. */
+  payer?: Signer;
+  /** Minter account for validation and non-SOL fees. */
+  minter?: Signer;
+  /** Optionally mint to different owner */
+  owner?: PublicKey | Pda;
+  /**
+   * Mint account of the NFT. The account will be initialized if necessary.
+   *
+   * Must be a signer if:
+   * * the nft_mint account does not exist.
+   *
+   */
+
+  asset: PublicKey | Pda | Signer;
+  /**
+   * Mint account of the collection NFT.
+   *
+   */
+
+  collection: PublicKey | Pda;
+  /**
+   * Token Metadata program.
+   *
+   * Token Metadata program.
+   *
+   */
+
+  mplCoreProgram?: PublicKey | Pda;
+  /**
+   * SPL Token program.
+   * System program.
+   */
+
+  systemProgram?: PublicKey | Pda;
+  /**
+   * Instructions sysvar account.
+   *
+   */
+
+  sysvarInstructions?: PublicKey | Pda;
+  /**
+   * SlotHashes sysvar cluster data.
+   *
+   */
+
+  recentSlothashes?: PublicKey | Pda;
+};
+
+// Data.
+export type CheckEligibilityInstructionData = {
+  discriminator: Array<number>;
+  mintArgs: Uint8Array;
+  group: Option<string>;
+};
+
+export type CheckEligibilityInstructionDataArgs = {
+  mintArgs: Uint8Array;
+  group: OptionOrNullable<string>;
+};
+
+export function getCheckEligibilityInstructionDataSerializer(): Serializer<
+  CheckEligibilityInstructionDataArgs,
+  CheckEligibilityInstructionData
+> {
+  return mapSerializer<
+    CheckEligibilityInstructionDataArgs,
+    any,
+    CheckEligibilityInstructionData
+  >(
+    struct<CheckEligibilityInstructionData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['mintArgs', bytes({ size: u32() })],
+        ['group', option(string())],
+      ],
+      { description: 'CheckEligibilityInstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [36, 15, 52, 116, 158, 218, 253, 125],
+    })
+  ) as Serializer<
+    CheckEligibilityInstructionDataArgs,
+    CheckEligibilityInstructionData
+  >;
+}
+
+// Args.
+export type CheckEligibilityInstructionArgs =
+  CheckEligibilityInstructionDataArgs;
+
+// Instruction.
+export function checkEligibility(
+  context: Pick<Context, 'eddsa' | 'identity' | 'payer' | 'programs'>,
+  input: CheckEligibilityInstructionAccounts & CheckEligibilityInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplCoreCandyGuard',
+    'CMAGAKJ67e9hRZgfC5SFTbZH8MgEmtqazKXjmkaJjWTJ'
+  );
+
+  // Accounts.
+  const resolvedAccounts: ResolvedAccountsWithIndices = {
+    candyGuard: {
+      index: 0,
+      isWritable: false,
+      value: input.candyGuard ?? null,
+    },
+    candyMachineProgram: {
+      index: 1,
+      isWritable: false,
+      value: input.candyMachineProgram ?? null,
+    },
+    candyMachine: {
+      index: 2,
+      isWritable: true,
+      value: input.candyMachine ?? null,
+    },
+    candyMachineAuthorityPda: {
+      index: 3,
+      isWritable: true,
+      value: input.candyMachineAuthorityPda ?? null,
+    },
+    payer: { index: 4, isWritable: true, value: input.payer ?? null },
+    minter: { index: 5, isWritable: true, value: input.minter ?? null },
+    owner: { index: 6, isWritable: false, value: input.owner ?? null },
+    asset: { index: 7, isWritable: true, value: input.asset ?? null },
+    collection: { index: 8, isWritable: true, value: input.collection ?? null },
+    mplCoreProgram: {
+      index: 9,
+      isWritable: false,
+      value: input.mplCoreProgram ?? null,
+    },
+    systemProgram: {
+      index: 10,
+      isWritable: false,
+      value: input.systemProgram ?? null,
+    },
+    sysvarInstructions: {
+      index: 11,
+      isWritable: false,
+      value: input.sysvarInstructions ?? null,
+    },
+    recentSlothashes: {
+      index: 12,
+      isWritable: false,
+      value: input.recentSlothashes ?? null,
+    },
+  };
+
+  // Arguments.
+  const resolvedArgs: CheckEligibilityInstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.candyGuard.value) {
+    resolvedAccounts.candyGuard.value = findCandyGuardPda(context, {
+      base: expectPublicKey(resolvedAccounts.candyMachine.value),
+    });
+  }
+  if (!resolvedAccounts.candyMachineProgram.value) {
+    resolvedAccounts.candyMachineProgram.value = context.programs.getPublicKey(
+      'mplCandyMachine',
+      'CMACYFENjoBMHzapRXyo1JZkVS6EtaDDzkjMrmQLvr4J'
+    );
+    resolvedAccounts.candyMachineProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.candyMachineAuthorityPda.value) {
+    resolvedAccounts.candyMachineAuthorityPda.value =
+      findCandyMachineAuthorityPda(context, {
+        candyMachine: expectPublicKey(resolvedAccounts.candyMachine.value),
+      });
+  }
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.minter.value) {
+    resolvedAccounts.minter.value = context.identity;
+  }
+  if (!resolvedAccounts.mplCoreProgram.value) {
+    resolvedAccounts.mplCoreProgram.value = context.programs.getPublicKey(
+      'mplCore',
+      'CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d'
+    );
+    resolvedAccounts.mplCoreProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.sysvarInstructions.value) {
+    resolvedAccounts.sysvarInstructions.value = publicKey(
+      'Sysvar1nstructions1111111111111111111111111'
+    );
+  }
+  if (!resolvedAccounts.recentSlothashes.value) {
+    resolvedAccounts.recentSlothashes.value = publicKey(
+      'SysvarS1otHashes111111111111111111111111111'
+    );
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getCheckEligibilityInstructionDataSerializer().serialize(
+    resolvedArgs as CheckEligibilityInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
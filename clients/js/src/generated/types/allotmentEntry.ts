@@ -0,0 +1,50 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Option, OptionOrNullable, PublicKey } from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  option,
+  publicKey as publicKeySerializer,
+  struct,
+  u16,
+  u64,
+} from '@metaplex-foundation/umi/serializers';
+
+/** Entry of an address on the merkle tree. */
+export type AllotmentEntry = {
+  /** Address allowed to mint. */
+  address: PublicKey;
+  /** Maximum number of mints of the address. */
+  allotment: number;
+  /** Price of each mint in lamports, if any. */
+  price: Option<bigint>;
+};
+
+export type AllotmentEntryArgs = {
+  /** Address allowed to mint. */
+  address: PublicKey;
+  /** Maximum number of mints of the address. */
+  allotment: number;
+  /** Price of each mint in lamports, if any. */
+  price: OptionOrNullable<number | bigint>;
+};
+
+export function getAllotmentEntrySerializer(): Serializer<
+  AllotmentEntryArgs,
+  AllotmentEntry
+> {
+  return struct<AllotmentEntry>(
+    [
+      ['address', publicKeySerializer()],
+      ['allotment', u16()],
+      ['price', option(u64())],
+    ],
+    { description: 'AllotmentEntry' }
+  ) as Serializer<AllotmentEntryArgs, AllotmentEntry>;
+}
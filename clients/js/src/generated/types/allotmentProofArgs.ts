@@ -0,0 +1,51 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Option, OptionOrNullable } from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  bytes,
+  option,
+  struct,
+  u16,
+  u64,
+} from '@metaplex-foundation/umi/serializers';
+
+/** Arguments of the merkle proof instruction. */
+export type AllotmentProofArgs = {
+  /** Maximum number of mints of the address. */
+  allotment: number;
+  /** Price of each mint in lamports, if any. */
+  price: Option<bigint>;
+  /** Merkle proof of the entry. */
+  proof: Array<Uint8Array>;
+};
+
+export type AllotmentProofArgsArgs = {
+  /** Maximum number of mints of the address. */
+  allotment: number;
+  /** Price of each mint in lamports, if any. */
+  price: OptionOrNullable<number | bigint>;
+  /** Merkle proof of the entry. */
+  proof: Array<Uint8Array>;
+};
+
+export function getAllotmentProofArgsSerializer(): Serializer<
+  AllotmentProofArgsArgs,
+  AllotmentProofArgs
+> {
+  return struct<AllotmentProofArgs>(
+    [
+      ['allotment', u16()],
+      ['price', option(u64())],
+      ['proof', array(bytes({ size: 32 }))],
+    ],
+    { description: 'AllotmentProofArgs' }
+  ) as Serializer<AllotmentProofArgsArgs, AllotmentProofArgs>;
+}
@@ -0,0 +1,51 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { PublicKey } from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  bytes,
+  publicKey as publicKeySerializer,
+  struct,
+} from '@metaplex-foundation/umi/serializers';
+
+/**
+ * Guard that uses a merkle tree to specify the addresses allowed to mint, where each
+ * address has its own allotment (maximum number of mints) and optional price in lamports.
+ *
+ * The leaves of the merkle tree are the keccak hash of the borsh-serialized
+ * `AllotmentEntry` of each address.
+ *
+ * List of accounts required:
+ *
+ * 0. `[writable]` Pda created by the merkle proof instruction (seeds `["allow_list_allotment",
+ * merke tree root, minter key, candy guard pubkey, candy machine pubkey]`).
+ * 1. `[writable]` Account to receive the funds (only when the entry specifies a price).
+ */
+
+export type AllowListAllotment = {
+  /** Merkle root of the entries of the addresses allowed to mint. */
+  merkleRoot: Uint8Array;
+  /** Account to receive the price of the mints, if any. */
+  destination: PublicKey;
+};
+
+export type AllowListAllotmentArgs = AllowListAllotment;
+
+export function getAllowListAllotmentSerializer(): Serializer<
+  AllowListAllotmentArgs,
+  AllowListAllotment
+> {
+  return struct<AllowListAllotment>(
+    [
+      ['merkleRoot', bytes({ size: 32 })],
+      ['destination', publicKeySerializer()],
+    ],
+    { description: 'AllowListAllotment' }
+  ) as Serializer<AllowListAllotmentArgs, AllowListAllotment>;
+}
@@ -0,0 +1,39 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Serializer,
+  array,
+  struct,
+} from '@metaplex-foundation/umi/serializers';
+import { GuardType, GuardTypeArgs, getGuardTypeSerializer } from '.';
+
+/**
+ * Meta-guard that evaluates a list of guards as alternatives: the mint is allowed when
+ * any of the guards validates. The guards listed must be enabled on the guard set (or
+ * on the default guard set for groups) and are only evaluated by this guard.
+ *
+ * The alternatives are attempted in the order specified, each one starting from the
+ * same position in the remaining accounts and mint arguments; the transaction must
+ * include the accounts and arguments of the alternative the minter intends to satisfy.
+ * Only the alternative that validates executes its `pre_actions` and `post_actions`.
+ *
+ * List of accounts required:
+ *
+ * 0. `[]` Accounts of the alternative guard (if any).
+ */
+
+export type AnyOf = { guards: Array<GuardType> };
+
+export type AnyOfArgs = { guards: Array<GuardTypeArgs> };
+
+export function getAnyOfSerializer(): Serializer<AnyOfArgs, AnyOf> {
+  return struct<AnyOf>([['guards', array(getGuardTypeSerializer())]], {
+    description: 'AnyOf',
+  }) as Serializer<AnyOfArgs, AnyOf>;
+}
@@ -0,0 +1,65 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { PublicKey } from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  bool,
+  publicKey as publicKeySerializer,
+  struct,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  TraitRequirement,
+  TraitRequirementArgs,
+  getTraitRequirementSerializer,
+} from '.';
+
+/**
+ * Guard that restricts the transaction to holders of an asset from a specified collection
+ * with the required traits. Traits are read from the `Attributes` plugin of the asset and,
+ * when `include_collection` is set, from the `Attributes` plugin of the collection, with
+ * the asset attributes taking precedence over the collection ones.
+ *
+ * List of accounts required:
+ *
+ * 0. `[]` Account of the Asset.
+ * 1. `[optional]` Account of the Collection (required when `include_collection` is set).
+ */
+
+export type AssetTraitGate = {
+  /** Collection of the gating asset. */
+  requiredCollection: PublicKey;
+  /** Whether the attributes of the collection are considered. */
+  includeCollection: boolean;
+  /** Traits (key/value pairs) that the gating asset must have. */
+  traits: Array<TraitRequirement>;
+};
+
+export type AssetTraitGateArgs = {
+  /** Collection of the gating asset. */
+  requiredCollection: PublicKey;
+  /** Whether the attributes of the collection are considered. */
+  includeCollection: boolean;
+  /** Traits (key/value pairs) that the gating asset must have. */
+  traits: Array<TraitRequirementArgs>;
+};
+
+export function getAssetTraitGateSerializer(): Serializer<
+  AssetTraitGateArgs,
+  AssetTraitGate
+> {
+  return struct<AssetTraitGate>(
+    [
+      ['requiredCollection', publicKeySerializer()],
+      ['includeCollection', bool()],
+      ['traits', array(getTraitRequirementSerializer())],
+    ],
+    { description: 'AssetTraitGate' }
+  ) as Serializer<AssetTraitGateArgs, AssetTraitGate>;
+}
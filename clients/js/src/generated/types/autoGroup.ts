@@ -0,0 +1,34 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Serializer, struct } from '@metaplex-foundation/umi/serializers';
+
+/**
+ * Guard that enables the automatic selection of a group when a mint transaction does
+ * not specify a label. The groups are attempted in the order they are declared and the
+ * mint goes through the first group whose guards validate; failing groups are skipped
+ * without charging the bot tax. The bot tax of the default guard set is only charged when
+ * none of the groups validates, unless one of them failed because of the rate limit, in
+ * which case the mint fails with a `RateLimitExceeded` error instead.
+ *
+ * Each group is attempted from the same position in the remaining accounts and mint
+ * arguments, therefore the transaction must include the accounts and arguments expected
+ * by the guards of the group the minter intends to use.
+ *
+ * This guard can only be enabled on the default guard set.
+ */
+
+export type AutoGroup = {};
+
+export type AutoGroupArgs = AutoGroup;
+
+export function getAutoGroupSerializer(): Serializer<AutoGroupArgs, AutoGroup> {
+  return struct<AutoGroup>([], {
+    description: 'AutoGroup',
+  }) as Serializer<AutoGroupArgs, AutoGroup>;
+}
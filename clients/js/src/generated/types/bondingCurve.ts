@@ -0,0 +1,100 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Option, OptionOrNullable, PublicKey } from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  option,
+  publicKey as publicKeySerializer,
+  struct,
+  u64,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  BondingCurveType,
+  BondingCurveTypeArgs,
+  getBondingCurveTypeSerializer,
+} from '.';
+
+/**
+ * Guard that charges a price that increases as the candy machine sells out. The price is
+ * a function of the ratio between the items redeemed and the items available, so the same
+ * configuration results in the same curve for candy machines of different sizes. The price
+ * can be paid in SOL (lamports) or in a spl-token (legacy token program or Token-2022).
+ *
+ * List of accounts required when paying in SOL:
+ *
+ * 0. `[writable]` Account to receive the funds.
+ *
+ * List of accounts required when paying with the legacy token program:
+ *
+ * 0. `[writable]` Token account holding the required amount.
+ * 1. `[writable]` Address of the ATA to receive the tokens.
+ * 2. `[]` SPL token program.
+ *
+ * List of accounts required when paying with Token-2022:
+ *
+ * 0. `[writable]` Token account holding the required amount.
+ * 1. `[writable]` Address of the ATA to receive the tokens.
+ * 2. `[]` Mint account.
+ * 3. `[]` SPL Token-2022 program account.
+ */
+
+export type BondingCurve = {
+  /** Shape of the price curve. */
+  curve: BondingCurveType;
+  /** Price of the first item. */
+  startPrice: bigint;
+  /**
+   * Amount added to the price once all items are redeemed, proportionally to the ratio
+   * of items redeemed (linear curve), or the growth of the price for each percent of
+   * items redeemed in basis points (exponential curve).
+   */
+  factor: bigint;
+  /** Maximum price of an item. */
+  maxPrice: Option<bigint>;
+  /** Token mint used for the payment (`None` to pay in SOL). */
+  mint: Option<PublicKey>;
+  /** Address to receive the funds (wallet when paying in SOL, ATA otherwise). */
+  destination: PublicKey;
+};
+
+export type BondingCurveArgs = {
+  /** Shape of the price curve. */
+  curve: BondingCurveTypeArgs;
+  /** Price of the first item. */
+  startPrice: number | bigint;
+  /**
+   * Amount added to the price once all items are redeemed, proportionally to the ratio
+   * of items redeemed (linear curve), or the growth of the price for each percent of
+   * items redeemed in basis points (exponential curve).
+   */
+  factor: number | bigint;
+  /** Maximum price of an item. */
+  maxPrice: OptionOrNullable<number | bigint>;
+  /** Token mint used for the payment (`None` to pay in SOL). */
+  mint: OptionOrNullable<PublicKey>;
+  /** Address to receive the funds (wallet when paying in SOL, ATA otherwise). */
+  destination: PublicKey;
+};
+
+export function getBondingCurveSerializer(): Serializer<
+  BondingCurveArgs,
+  BondingCurve
+> {
+  return struct<BondingCurve>(
+    [
+      ['curve', getBondingCurveTypeSerializer()],
+      ['startPrice', u64()],
+      ['factor', u64()],
+      ['maxPrice', option(u64())],
+      ['mint', option(publicKeySerializer())],
+      ['destination', publicKeySerializer()],
+    ],
+    { description: 'BondingCurve' }
+  ) as Serializer<BondingCurveArgs, BondingCurve>;
+}
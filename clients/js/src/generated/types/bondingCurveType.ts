@@ -0,0 +1,26 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Serializer, scalarEnum } from '@metaplex-foundation/umi/serializers';
+
+/** Available price curves. */
+export enum BondingCurveType {
+  Linear,
+  Exponential,
+}
+
+export type BondingCurveTypeArgs = BondingCurveType;
+
+export function getBondingCurveTypeSerializer(): Serializer<
+  BondingCurveTypeArgs,
+  BondingCurveType
+> {
+  return scalarEnum<BondingCurveType>(BondingCurveType, {
+    description: 'BondingCurveType',
+  }) as Serializer<BondingCurveTypeArgs, BondingCurveType>;
+}
@@ -0,0 +1,47 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Serializer,
+  i64,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+
+/**
+ * Guard to set a minimum interval between the mints of a wallet.
+ *
+ * List of accounts required:
+ *
+ * 0. `[writable]` Cooldown tracker PDA. The PDA is derived using the seed
+ * `["cooldown", cooldown guard id, minter key, candy guard pubkey, candy machine pubkey]`.
+ */
+
+export type Cooldown = {
+  /** Unique identifier of the cooldown. */
+  id: number;
+  /** Minimum interval between mints of a wallet (in seconds). */
+  interval: bigint;
+};
+
+export type CooldownArgs = {
+  /** Unique identifier of the cooldown. */
+  id: number;
+  /** Minimum interval between mints of a wallet (in seconds). */
+  interval: number | bigint;
+};
+
+export function getCooldownSerializer(): Serializer<CooldownArgs, Cooldown> {
+  return struct<Cooldown>(
+    [
+      ['id', u8()],
+      ['interval', i64()],
+    ],
+    { description: 'Cooldown' }
+  ) as Serializer<CooldownArgs, Cooldown>;
+}
@@ -0,0 +1,71 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  GetDataEnumKind,
+  GetDataEnumKindContent,
+  Serializer,
+  dataEnum,
+  struct,
+  tuple,
+  u16,
+  u64,
+} from '@metaplex-foundation/umi/serializers';
+
+/** Discount applied to a payment. */
+export type Discount =
+  | { __kind: 'Percentage'; fields: [number] }
+  | { __kind: 'Amount'; fields: [bigint] };
+
+export type DiscountArgs =
+  | { __kind: 'Percentage'; fields: [number] }
+  | { __kind: 'Amount'; fields: [number | bigint] };
+
+export function getDiscountSerializer(): Serializer<DiscountArgs, Discount> {
+  return dataEnum<Discount>(
+    [
+      [
+        'Percentage',
+        struct<GetDataEnumKindContent<Discount, 'Percentage'>>([
+          ['fields', tuple([u16()])],
+        ]),
+      ],
+      [
+        'Amount',
+        struct<GetDataEnumKindContent<Discount, 'Amount'>>([
+          ['fields', tuple([u64()])],
+        ]),
+      ],
+    ],
+    { description: 'Discount' }
+  ) as Serializer<DiscountArgs, Discount>;
+}
+
+// Data Enum Helpers.
+export function discount(
+  kind: 'Percentage',
+  data: GetDataEnumKindContent<DiscountArgs, 'Percentage'>['fields']
+): GetDataEnumKind<DiscountArgs, 'Percentage'>;
+export function discount(
+  kind: 'Amount',
+  data: GetDataEnumKindContent<DiscountArgs, 'Amount'>['fields']
+): GetDataEnumKind<DiscountArgs, 'Amount'>;
+export function discount<K extends DiscountArgs['__kind']>(
+  kind: K,
+  data?: any
+): Extract<DiscountArgs, { __kind: K }> {
+  return Array.isArray(data)
+    ? { __kind: kind, fields: data }
+    : { __kind: kind, ...(data ?? {}) };
+}
+export function isDiscount<K extends Discount['__kind']>(
+  kind: K,
+  value: Discount
+): value is Discount & { __kind: K } {
+  return value.__kind === kind;
+}
@@ -0,0 +1,96 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  DateTime,
+  DateTimeInput,
+  PublicKey,
+  SolAmount,
+  mapAmountSerializer,
+  mapDateTimeSerializer,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  bool,
+  i64,
+  publicKey as publicKeySerializer,
+  struct,
+  u64,
+} from '@metaplex-foundation/umi/serializers';
+
+/**
+ * Guard that charges a descending price in SOL (lamports) for the mint. The price starts
+ * at `start_price` and drops by `decay_step` lamports every `interval` seconds until it
+ * reaches the `floor_price`.
+ *
+ * When `rebate` is enabled, payments are held in an escrow PDA and buyers can claim the
+ * difference between the price they paid and the final clearing price once the auction
+ * is settled.
+ *
+ * List of accounts required:
+ *
+ * 0. `[writable]` Destination or, for rebates, escrow PDA (seeds `["dutch_auction",
+ * destination pubkey, candy guard pubkey, candy machine pubkey, group label]`).
+ * 1. `[writable]` (rebates only) Auction bid PDA (seeds `["dutch_auction_bid",
+ * minter pubkey, candy guard pubkey, candy machine pubkey, group label]`).
+ *
+ * The group label seed is empty for the default guard set, so each group has its own
+ * escrow and bids.
+ */
+
+export type DutchAuction = {
+  /** Price at the start of the auction. */
+  startPrice: SolAmount;
+  /** Minimum price of the auction. */
+  floorPrice: SolAmount;
+  /** Time when the auction starts. */
+  startTime: DateTime;
+  /** Amount (in lamports) that the price drops at each interval. */
+  decayStep: SolAmount;
+  /** Interval (in seconds) between price drops. */
+  interval: bigint;
+  /** Address to receive the funds. */
+  destination: PublicKey;
+  /** Whether buyers can claim the difference to the clearing price or not. */
+  rebate: boolean;
+};
+
+export type DutchAuctionArgs = {
+  /** Price at the start of the auction. */
+  startPrice: SolAmount;
+  /** Minimum price of the auction. */
+  floorPrice: SolAmount;
+  /** Time when the auction starts. */
+  startTime: DateTimeInput;
+  /** Amount (in lamports) that the price drops at each interval. */
+  decayStep: SolAmount;
+  /** Interval (in seconds) between price drops. */
+  interval: number | bigint;
+  /** Address to receive the funds. */
+  destination: PublicKey;
+  /** Whether buyers can claim the difference to the clearing price or not. */
+  rebate: boolean;
+};
+
+export function getDutchAuctionSerializer(): Serializer<
+  DutchAuctionArgs,
+  DutchAuction
+> {
+  return struct<DutchAuction>(
+    [
+      ['startPrice', mapAmountSerializer(u64(), 'SOL', 9)],
+      ['floorPrice', mapAmountSerializer(u64(), 'SOL', 9)],
+      ['startTime', mapDateTimeSerializer(i64())],
+      ['decayStep', mapAmountSerializer(u64(), 'SOL', 9)],
+      ['interval', i64()],
+      ['destination', publicKeySerializer()],
+      ['rebate', bool()],
+    ],
+    { description: 'DutchAuction' }
+  ) as Serializer<DutchAuctionArgs, DutchAuction>;
+}
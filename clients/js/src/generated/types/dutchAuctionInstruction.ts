@@ -0,0 +1,26 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Serializer, scalarEnum } from '@metaplex-foundation/umi/serializers';
+
+export enum DutchAuctionInstruction {
+  Initialize,
+  Claim,
+  Withdraw,
+}
+
+export type DutchAuctionInstructionArgs = DutchAuctionInstruction;
+
+export function getDutchAuctionInstructionSerializer(): Serializer<
+  DutchAuctionInstructionArgs,
+  DutchAuctionInstruction
+> {
+  return scalarEnum<DutchAuctionInstruction>(DutchAuctionInstruction, {
+    description: 'DutchAuctionInstruction',
+  }) as Serializer<DutchAuctionInstructionArgs, DutchAuctionInstruction>;
+}
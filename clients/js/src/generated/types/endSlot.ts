@@ -0,0 +1,20 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Serializer, struct, u64 } from '@metaplex-foundation/umi/serializers';
+
+/** Guard that sets a specific slot for the mint to stop. */
+export type EndSlot = { slot: bigint };
+
+export type EndSlotArgs = { slot: number | bigint };
+
+export function getEndSlotSerializer(): Serializer<EndSlotArgs, EndSlot> {
+  return struct<EndSlot>([['slot', u64()]], {
+    description: 'EndSlot',
+  }) as Serializer<EndSlotArgs, EndSlot>;
+}
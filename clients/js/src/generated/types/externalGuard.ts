@@ -0,0 +1,63 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { PublicKey } from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  bytes,
+  publicKey as publicKeySerializer,
+  struct,
+  u32,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+
+/**
+ * Guard that delegates the validation of the mint to an external program. The external
+ * program is invoked (CPI) during the `validate` and `pre_actions` steps with the
+ * following instructions, using Anchor's instruction discriminators:
+ *
+ * * `validate_mint(args: ExternalGuardArgs)`: the accounts are forwarded as read-only.
+ * * `pre_mint(args: ExternalGuardArgs)`: the accounts are forwarded with the
+ * writable flag of the transaction.
+ *
+ * The accounts of the instructions are the candy guard, candy machine, payer, minter and
+ * asset accounts followed by the accounts forwarded by the guard; signer privileges are
+ * not forwarded. An error returned by the external program fails the mint transaction;
+ * since a failed CPI aborts the transaction, the bot tax is not charged in this case.
+ *
+ * List of accounts required:
+ *
+ * 0. `[]` External program.
+ *
+ * x. `[]` Accounts forwarded to the external program (`accounts` guard setting).
+ */
+
+export type ExternalGuard = {
+  /** Program invoked to validate the mint. */
+  programId: PublicKey;
+  /** Configuration bytes forwarded to the external program. */
+  config: Uint8Array;
+  /** Number of remaining accounts forwarded to the external program. */
+  accounts: number;
+};
+
+export type ExternalGuardArgs = ExternalGuard;
+
+export function getExternalGuardSerializer(): Serializer<
+  ExternalGuardArgs,
+  ExternalGuard
+> {
+  return struct<ExternalGuard>(
+    [
+      ['programId', publicKeySerializer()],
+      ['config', bytes({ size: u32() })],
+      ['accounts', u8()],
+    ],
+    { description: 'ExternalGuard' }
+  ) as Serializer<ExternalGuardArgs, ExternalGuard>;
+}
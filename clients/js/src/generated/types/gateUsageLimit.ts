@@ -0,0 +1,44 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Serializer, struct, u16 } from '@metaplex-foundation/umi/serializers';
+
+/**
+ * Guard that limits the number of mints each gating asset of the `AssetGate` and `NftGate`
+ * guards of the guard set can be used for, regardless of who holds it. The usage is
+ * recorded per asset (`AssetGate`) or NFT mint (`NftGate`), so transferring the gating
+ * asset to another wallet does not reset it.
+ *
+ * The limit is a separate guard, instead of a setting of the gate guards, to keep the
+ * (fixed) size of the existing gate guards, which would otherwise break the deserialization
+ * of existing candy guard accounts, and to share the same limit between both gate guards.
+ * `TokenGate` is not supported since fungible tokens cannot be identified: a usage recorded
+ * per token account could be bypassed by moving the tokens to a new account.
+ *
+ * List of accounts required:
+ *
+ * 0. `[writable]` Gate usage PDA for each enabled gate guard, in the order `AssetGate`
+ * and `NftGate`. The PDA is derived using the seed `["gate_usage", asset/mint key,
+ * candy guard pubkey, candy machine pubkey]`.
+ */
+
+export type GateUsageLimit = {
+  /** Maximum number of mints per gating asset. */
+  limit: number;
+};
+
+export type GateUsageLimitArgs = GateUsageLimit;
+
+export function getGateUsageLimitSerializer(): Serializer<
+  GateUsageLimitArgs,
+  GateUsageLimit
+> {
+  return struct<GateUsageLimit>([['limit', u16()]], {
+    description: 'GateUsageLimit',
+  }) as Serializer<GateUsageLimitArgs, GateUsageLimit>;
+}
@@ -0,0 +1,61 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Option, OptionOrNullable } from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  option,
+  string,
+  struct,
+  u64,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  GuardEligibility,
+  GuardEligibilityArgs,
+  getGuardEligibilitySerializer,
+} from '.';
+
+/** Result of the evaluation of a guard set. */
+export type GroupEligibility = {
+  /** Label of the group (`None` for the default guard set). */
+  label: Option<string>;
+  /** Error code of the first validation that failed (`None` when the mint is allowed). */
+  error: Option<bigint>;
+  /**
+   * Results of the enabled guards, in the order they are evaluated. The alternative of an
+   * `AnyOf` guard that validated is listed after it.
+   */
+  guards: Array<GuardEligibility>;
+};
+
+export type GroupEligibilityArgs = {
+  /** Label of the group (`None` for the default guard set). */
+  label: OptionOrNullable<string>;
+  /** Error code of the first validation that failed (`None` when the mint is allowed). */
+  error: OptionOrNullable<number | bigint>;
+  /**
+   * Results of the enabled guards, in the order they are evaluated. The alternative of an
+   * `AnyOf` guard that validated is listed after it.
+   */
+  guards: Array<GuardEligibilityArgs>;
+};
+
+export function getGroupEligibilitySerializer(): Serializer<
+  GroupEligibilityArgs,
+  GroupEligibility
+> {
+  return struct<GroupEligibility>(
+    [
+      ['label', option(string())],
+      ['error', option(u64())],
+      ['guards', array(getGuardEligibilitySerializer())],
+    ],
+    { description: 'GroupEligibility' }
+  ) as Serializer<GroupEligibilityArgs, GroupEligibility>;
+}
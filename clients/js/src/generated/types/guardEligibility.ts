@@ -0,0 +1,56 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Option, OptionOrNullable } from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  option,
+  struct,
+  u64,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  GuardType,
+  GuardTypeArgs,
+  MintPayment,
+  MintPaymentArgs,
+  getGuardTypeSerializer,
+  getMintPaymentSerializer,
+} from '.';
+
+/** Result of the evaluation of a guard. */
+export type GuardEligibility = {
+  /** Guard evaluated. */
+  guard: GuardType;
+  /** Error code of the validation (`None` when the guard validates). */
+  error: Option<bigint>;
+  /** Amount charged by the guard, if the guard charges a payment. */
+  payment: Option<MintPayment>;
+};
+
+export type GuardEligibilityArgs = {
+  /** Guard evaluated. */
+  guard: GuardTypeArgs;
+  /** Error code of the validation (`None` when the guard validates). */
+  error: OptionOrNullable<number | bigint>;
+  /** Amount charged by the guard, if the guard charges a payment. */
+  payment: OptionOrNullable<MintPaymentArgs>;
+};
+
+export function getGuardEligibilitySerializer(): Serializer<
+  GuardEligibilityArgs,
+  GuardEligibility
+> {
+  return struct<GuardEligibility>(
+    [
+      ['guard', getGuardTypeSerializer()],
+      ['error', option(u64())],
+      ['payment', option(getMintPaymentSerializer())],
+    ],
+    { description: 'GuardEligibility' }
+  ) as Serializer<GuardEligibilityArgs, GuardEligibility>;
+}
@@ -41,6 +41,26 @@ export enum GuardType {
   AssetPaymentMulti,
   AssetGate,
   VanityMint,
+  Receipt,
+  DutchAuction,
+  BondingCurve,
+  SolPaymentSplit,
+  TokenPaymentSplit,
+  AnyOf,
+  AutoGroup,
+  PhaseSchedule,
+  ExternalGuard,
+  SignedVoucher,
+  AllowListAllotment,
+  Cooldown,
+  RateLimit,
+  StartSlot,
+  EndSlot,
+  RecurringWindow,
+  SolGate,
+  AssetTraitGate,
+  HolderDiscount,
+  GateUsageLimit,
 }
 
 export type GuardTypeArgs = GuardType;
@@ -0,0 +1,70 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { PublicKey } from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  bool,
+  publicKey as publicKeySerializer,
+  struct,
+} from '@metaplex-foundation/umi/serializers';
+import { Discount, DiscountArgs, getDiscountSerializer } from '.';
+
+/**
+ * Guard that applies a discount to the payment guards (`SolPayment`, `TokenPayment` and
+ * `Token2022Payment`) of the guard set when the minter holds an asset from the specified
+ * collection, either an mpl-core asset or a legacy NFT with a verified collection.
+ *
+ * The discount is only applied when the mint arguments include a borsh `bool` set to
+ * `true`; otherwise, no accounts are required and the full price is charged. A fixed
+ * discount is applied to the amount of each payment guard, in the units of that guard.
+ * The guard set must include one of the supported payment guards and none of the other
+ * payment guards (e.g., `DutchAuction` or `SolPaymentSplit`), which do not apply discounts.
+ * Since the discount is only known once this guard validates, the balance checks of the
+ * payment guards use the full price.
+ *
+ * List of accounts required (when the discount is claimed):
+ *
+ * 0. `[]` Account of the Asset or token account of the NFT.
+ * 1. `[optional]` Metadata account of the NFT (only for legacy NFTs).
+ * 2. `[writable, optional]` Discount usage PDA (only when `single_use` is set). The PDA
+ * is derived using the seed `["holder_discount", asset/mint key, candy guard pubkey,
+ * candy machine pubkey]`.
+ */
+
+export type HolderDiscount = {
+  /** Collection of the gating asset. */
+  requiredCollection: PublicKey;
+  /** Discount applied to the payment guards. */
+  discount: Discount;
+  /** Whether each gating asset can only be used once for the discount. */
+  singleUse: boolean;
+};
+
+export type HolderDiscountArgs = {
+  /** Collection of the gating asset. */
+  requiredCollection: PublicKey;
+  /** Discount applied to the payment guards. */
+  discount: DiscountArgs;
+  /** Whether each gating asset can only be used once for the discount. */
+  singleUse: boolean;
+};
+
+export function getHolderDiscountSerializer(): Serializer<
+  HolderDiscountArgs,
+  HolderDiscount
+> {
+  return struct<HolderDiscount>(
+    [
+      ['requiredCollection', publicKeySerializer()],
+      ['discount', getDiscountSerializer()],
+      ['singleUse', bool()],
+    ],
+    { description: 'HolderDiscount' }
+  ) as Serializer<HolderDiscountArgs, HolderDiscount>;
+}
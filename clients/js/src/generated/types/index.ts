@@ -8,38 +8,71 @@
 
 export * from './addressGate';
 export * from './allocation';
+export * from './allotmentEntry';
+export * from './allotmentProofArgs';
 export * from './allowList';
+export * from './allowListAllotment';
+export * from './anyOf';
 export * from './assetBurn';
 export * from './assetBurnMulti';
 export * from './assetGate';
 export * from './assetMintLimit';
 export * from './assetPayment';
 export * from './assetPaymentMulti';
+export * from './assetTraitGate';
+export * from './autoGroup';
+export * from './bondingCurve';
+export * from './bondingCurveType';
 export * from './botTax';
 export * from './candyMachineData';
 export * from './configLine';
 export * from './configLineSettings';
+export * from './cooldown';
+export * from './discount';
+export * from './dutchAuction';
+export * from './dutchAuctionInstruction';
 export * from './edition';
 export * from './endDate';
+export * from './endSlot';
+export * from './externalGuard';
 export * from './freezeInstruction';
 export * from './freezeSolPayment';
 export * from './freezeTokenPayment';
+export * from './gateUsageLimit';
 export * from './gatekeeper';
+export * from './groupEligibility';
+export * from './guardEligibility';
 export * from './guardType';
 export * from './hiddenSettings';
+export * from './holderDiscount';
 export * from './mintLimit';
+export * from './mintPayment';
 export * from './nftBurn';
 export * from './nftGate';
 export * from './nftMintLimit';
 export * from './nftPayment';
+export * from './phase';
+export * from './phaseSchedule';
+export * from './phaseScheduleStatus';
 export * from './programGate';
+export * from './rateLimit';
+export * from './receipt';
+export * from './recurringWindow';
 export * from './redeemedAmount';
+export * from './signedVoucher';
 export * from './solFixedFee';
+export * from './solGate';
 export * from './solPayment';
+export * from './solPaymentSplit';
+export * from './splitDestination';
 export * from './startDate';
+export * from './startSlot';
 export * from './thirdPartySigner';
 export * from './token2022Payment';
 export * from './tokenBurn';
 export * from './tokenGate';
 export * from './tokenPayment';
+export * from './tokenPaymentSplit';
+export * from './traitRequirement';
 export * from './vanityMint';
+export * from './voucher';
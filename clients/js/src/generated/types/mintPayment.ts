@@ -0,0 +1,50 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Option, OptionOrNullable, PublicKey } from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  option,
+  publicKey as publicKeySerializer,
+  struct,
+  u64,
+} from '@metaplex-foundation/umi/serializers';
+import { GuardType, GuardTypeArgs, getGuardTypeSerializer } from '.';
+
+/** Amount charged by a payment guard during a mint. */
+export type MintPayment = {
+  /** Guard that charged the amount. */
+  guard: GuardType;
+  /** Mint of the token charged (`None` when the amount is in lamports). */
+  mint: Option<PublicKey>;
+  /** Amount charged, in lamports or in the smallest unit of the token. */
+  amount: bigint;
+};
+
+export type MintPaymentArgs = {
+  /** Guard that charged the amount. */
+  guard: GuardTypeArgs;
+  /** Mint of the token charged (`None` when the amount is in lamports). */
+  mint: OptionOrNullable<PublicKey>;
+  /** Amount charged, in lamports or in the smallest unit of the token. */
+  amount: number | bigint;
+};
+
+export function getMintPaymentSerializer(): Serializer<
+  MintPaymentArgs,
+  MintPayment
+> {
+  return struct<MintPayment>(
+    [
+      ['guard', getGuardTypeSerializer()],
+      ['mint', option(publicKeySerializer())],
+      ['amount', u64()],
+    ],
+    { description: 'MintPayment' }
+  ) as Serializer<MintPaymentArgs, MintPayment>;
+}
@@ -0,0 +1,49 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  DateTime,
+  DateTimeInput,
+  mapDateTimeSerializer,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  i64,
+  string,
+  struct,
+} from '@metaplex-foundation/umi/serializers';
+
+/** A time range during which a group is active. */
+export type Phase = {
+  /** Label of the group. */
+  label: string;
+  /** Start of the phase (inclusive, unix timestamp). */
+  start: DateTime;
+  /** End of the phase (exclusive, unix timestamp). */
+  end: DateTime;
+};
+
+export type PhaseArgs = {
+  /** Label of the group. */
+  label: string;
+  /** Start of the phase (inclusive, unix timestamp). */
+  start: DateTimeInput;
+  /** End of the phase (exclusive, unix timestamp). */
+  end: DateTimeInput;
+};
+
+export function getPhaseSerializer(): Serializer<PhaseArgs, Phase> {
+  return struct<Phase>(
+    [
+      ['label', string()],
+      ['start', mapDateTimeSerializer(i64())],
+      ['end', mapDateTimeSerializer(i64())],
+    ],
+    { description: 'Phase' }
+  ) as Serializer<PhaseArgs, Phase>;
+}
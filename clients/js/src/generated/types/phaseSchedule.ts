@@ -0,0 +1,39 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Serializer,
+  array,
+  struct,
+} from '@metaplex-foundation/umi/serializers';
+import { Phase, PhaseArgs, getPhaseSerializer } from '.';
+
+/**
+ * Guard that specifies a schedule of phases, each one mapping a time range to a group.
+ * When a mint transaction does not specify a label, the group of the active phase is
+ * used; groups that are part of the schedule can only be used while one of their phases
+ * is active.
+ *
+ * Phases must be ordered by their start date and must not overlap. Groups that are not
+ * part of the schedule are not affected by it.
+ *
+ * This guard can only be enabled on the default guard set.
+ */
+
+export type PhaseSchedule = { phases: Array<Phase> };
+
+export type PhaseScheduleArgs = { phases: Array<PhaseArgs> };
+
+export function getPhaseScheduleSerializer(): Serializer<
+  PhaseScheduleArgs,
+  PhaseSchedule
+> {
+  return struct<PhaseSchedule>([['phases', array(getPhaseSerializer())]], {
+    description: 'PhaseSchedule',
+  }) as Serializer<PhaseScheduleArgs, PhaseSchedule>;
+}
@@ -0,0 +1,57 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  DateTime,
+  DateTimeInput,
+  Option,
+  OptionOrNullable,
+  mapDateTimeSerializer,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  i64,
+  option,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { Phase, PhaseArgs, getPhaseSerializer } from '.';
+
+/** Status of the phase schedule, returned by the route instruction. */
+export type PhaseScheduleStatus = {
+  /** Timestamp used to evaluate the schedule. */
+  timestamp: DateTime;
+  /** Index of the active phase, if any. */
+  active: Option<number>;
+  /** Phases of the schedule. */
+  phases: Array<Phase>;
+};
+
+export type PhaseScheduleStatusArgs = {
+  /** Timestamp used to evaluate the schedule. */
+  timestamp: DateTimeInput;
+  /** Index of the active phase, if any. */
+  active: OptionOrNullable<number>;
+  /** Phases of the schedule. */
+  phases: Array<PhaseArgs>;
+};
+
+export function getPhaseScheduleStatusSerializer(): Serializer<
+  PhaseScheduleStatusArgs,
+  PhaseScheduleStatus
+> {
+  return struct<PhaseScheduleStatus>(
+    [
+      ['timestamp', mapDateTimeSerializer(i64())],
+      ['active', option(u8())],
+      ['phases', array(getPhaseSerializer())],
+    ],
+    { description: 'PhaseScheduleStatus' }
+  ) as Serializer<PhaseScheduleStatusArgs, PhaseScheduleStatus>;
+}
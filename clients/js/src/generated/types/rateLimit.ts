@@ -0,0 +1,57 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Serializer,
+  i64,
+  struct,
+  u32,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+
+/**
+ * Guard to limit the number of mints across all minters within a fixed time window.
+ *
+ * When the limit of the current window is reached, the mint fails with a
+ * `RateLimitExceeded` error, which is not subject to the bot tax since the transaction
+ * can be retried once the window elapses.
+ *
+ * List of accounts required:
+ *
+ * 0. `[writable]` Rate limit tracker PDA. The PDA is derived using the seed
+ * `["rate_limit", rate limit guard id, candy guard pubkey, candy machine pubkey]`.
+ */
+
+export type RateLimit = {
+  /** Unique identifier of the rate limit. */
+  id: number;
+  /** Maximum number of mints within a window. */
+  limit: number;
+  /** Duration of the window (in seconds). */
+  window: bigint;
+};
+
+export type RateLimitArgs = {
+  /** Unique identifier of the rate limit. */
+  id: number;
+  /** Maximum number of mints within a window. */
+  limit: number;
+  /** Duration of the window (in seconds). */
+  window: number | bigint;
+};
+
+export function getRateLimitSerializer(): Serializer<RateLimitArgs, RateLimit> {
+  return struct<RateLimit>(
+    [
+      ['id', u8()],
+      ['limit', u32()],
+      ['window', i64()],
+    ],
+    { description: 'RateLimit' }
+  ) as Serializer<RateLimitArgs, RateLimit>;
+}
@@ -0,0 +1,29 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Serializer, struct } from '@metaplex-foundation/umi/serializers';
+
+/**
+ * Guard that creates a receipt PDA for each mint, recording who minted which item, in
+ * which group and for what price.
+ *
+ * List of accounts required:
+ *
+ * 0. `[writable]` Mint receipt PDA (seeds `["mint_receipt", candy machine pubkey,
+ * asset pubkey]`).
+ */
+
+export type Receipt = {};
+
+export type ReceiptArgs = Receipt;
+
+export function getReceiptSerializer(): Serializer<ReceiptArgs, Receipt> {
+  return struct<Receipt>([], {
+    description: 'Receipt',
+  }) as Serializer<ReceiptArgs, Receipt>;
+}
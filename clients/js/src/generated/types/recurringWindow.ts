@@ -0,0 +1,54 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  DateTime,
+  DateTimeInput,
+  mapDateTimeSerializer,
+} from '@metaplex-foundation/umi';
+import { Serializer, i64, struct } from '@metaplex-foundation/umi/serializers';
+
+/**
+ * Guard that allows the mint only during windows that repeat on a fixed schedule.
+ *
+ * The windows start every `period` seconds, counting from the `offset` timestamp, and
+ * last `duration` seconds. For example, a daily window from 18:00 to 20:00 UTC uses a
+ * `period` of `86_400`, an `offset` of `64_800` and a `duration` of `7_200`.
+ */
+
+export type RecurringWindow = {
+  /** Interval between the start of consecutive windows (in seconds). */
+  period: bigint;
+  /** Start of a window (unix timestamp), used to align the windows. */
+  offset: DateTime;
+  /** Duration of each window (in seconds). */
+  duration: bigint;
+};
+
+export type RecurringWindowArgs = {
+  /** Interval between the start of consecutive windows (in seconds). */
+  period: number | bigint;
+  /** Start of a window (unix timestamp), used to align the windows. */
+  offset: DateTimeInput;
+  /** Duration of each window (in seconds). */
+  duration: number | bigint;
+};
+
+export function getRecurringWindowSerializer(): Serializer<
+  RecurringWindowArgs,
+  RecurringWindow
+> {
+  return struct<RecurringWindow>(
+    [
+      ['period', i64()],
+      ['offset', mapDateTimeSerializer(i64())],
+      ['duration', i64()],
+    ],
+    { description: 'RecurringWindow' }
+  ) as Serializer<RecurringWindowArgs, RecurringWindow>;
+}
@@ -0,0 +1,51 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { PublicKey } from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  publicKey as publicKeySerializer,
+  struct,
+} from '@metaplex-foundation/umi/serializers';
+
+/**
+ * Guard that requires a voucher signed by a configured key. The voucher is the message
+ * of an Ed25519 program instruction, which must immediately precede the mint instruction
+ * in the transaction; the signature is verified by the Ed25519 precompile, so the signer
+ * does not need to co-sign the mint transaction.
+ *
+ * Each voucher can only be used once: its nonce is recorded in a PDA on mint.
+ *
+ * List of accounts required:
+ *
+ * 0. `[writable]` Voucher nonce PDA. The PDA is derived using the seed
+ * `["signed_voucher", nonce, candy guard pubkey, candy machine pubkey]`.
+ * 1. `[writable]` Account to receive the funds (only when the voucher specifies a price).
+ */
+
+export type SignedVoucher = {
+  /** Key that signs the vouchers. */
+  signer: PublicKey;
+  /** Account to receive the price of the voucher, if any. */
+  destination: PublicKey;
+};
+
+export type SignedVoucherArgs = SignedVoucher;
+
+export function getSignedVoucherSerializer(): Serializer<
+  SignedVoucherArgs,
+  SignedVoucher
+> {
+  return struct<SignedVoucher>(
+    [
+      ['signer', publicKeySerializer()],
+      ['destination', publicKeySerializer()],
+    ],
+    { description: 'SignedVoucher' }
+  ) as Serializer<SignedVoucherArgs, SignedVoucher>;
+}
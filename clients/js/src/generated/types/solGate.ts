@@ -0,0 +1,61 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Option,
+  OptionOrNullable,
+  SolAmount,
+  mapAmountSerializer,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  i64,
+  option,
+  struct,
+  u64,
+} from '@metaplex-foundation/umi/serializers';
+
+/**
+ * Guard that requires the minter to hold a minimum balance of lamports after paying for
+ * the mint and, optionally, the minter to have registered its wallet a minimum time before
+ * the mint.
+ *
+ * The balance of the minter is checked, since it is the wallet being gated; funding a
+ * separate payer does not satisfy the guard. The balance is checked before the payments
+ * (validation) and after them, since the payments are only charged once all guards
+ * validate.
+ *
+ * List of accounts required:
+ *
+ * 0. `[]` Registration PDA (seeds `["sol_gate", minter key, candy guard pubkey,
+ * candy machine pubkey]`). Only required when `minimum_age` is specified.
+ */
+
+export type SolGate = {
+  /** Minimum balance of the minter (in lamports). */
+  minimumBalance: SolAmount;
+  /** Minimum time between the registration of the wallet and the mint (in seconds). */
+  minimumAge: Option<bigint>;
+};
+
+export type SolGateArgs = {
+  /** Minimum balance of the minter (in lamports). */
+  minimumBalance: SolAmount;
+  /** Minimum time between the registration of the wallet and the mint (in seconds). */
+  minimumAge: OptionOrNullable<number | bigint>;
+};
+
+export function getSolGateSerializer(): Serializer<SolGateArgs, SolGate> {
+  return struct<SolGate>(
+    [
+      ['minimumBalance', mapAmountSerializer(u64(), 'SOL', 9)],
+      ['minimumAge', option(i64())],
+    ],
+    { description: 'SolGate' }
+  ) as Serializer<SolGateArgs, SolGate>;
+}
@@ -0,0 +1,53 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { SolAmount, mapAmountSerializer } from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  struct,
+  u64,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  SplitDestination,
+  SplitDestinationArgs,
+  getSplitDestinationSerializer,
+} from '.';
+
+/**
+ * Guard that charges an amount in SOL (lamports) for the mint, splitting it across
+ * multiple destinations according to their share in basis points.
+ *
+ * List of accounts required:
+ *
+ * x. `[writable]` Account to receive the funds of each destination (in the same order
+ * as the configuration).
+ */
+
+export type SolPaymentSplit = {
+  lamports: SolAmount;
+  destinations: Array<SplitDestination>;
+};
+
+export type SolPaymentSplitArgs = {
+  lamports: SolAmount;
+  destinations: Array<SplitDestinationArgs>;
+};
+
+export function getSolPaymentSplitSerializer(): Serializer<
+  SolPaymentSplitArgs,
+  SolPaymentSplit
+> {
+  return struct<SolPaymentSplit>(
+    [
+      ['lamports', mapAmountSerializer(u64(), 'SOL', 9)],
+      ['destinations', array(getSplitDestinationSerializer())],
+    ],
+    { description: 'SolPaymentSplit' }
+  ) as Serializer<SolPaymentSplitArgs, SolPaymentSplit>;
+}
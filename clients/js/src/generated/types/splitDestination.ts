@@ -0,0 +1,38 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { PublicKey } from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  publicKey as publicKeySerializer,
+  struct,
+  u16,
+} from '@metaplex-foundation/umi/serializers';
+
+/** Destination of a split payment. */
+export type SplitDestination = {
+  /** Address to receive the funds. */
+  address: PublicKey;
+  /** Share of the payment in basis points (10,000 = 100%). */
+  basisPoints: number;
+};
+
+export type SplitDestinationArgs = SplitDestination;
+
+export function getSplitDestinationSerializer(): Serializer<
+  SplitDestinationArgs,
+  SplitDestination
+> {
+  return struct<SplitDestination>(
+    [
+      ['address', publicKeySerializer()],
+      ['basisPoints', u16()],
+    ],
+    { description: 'SplitDestination' }
+  ) as Serializer<SplitDestinationArgs, SplitDestination>;
+}
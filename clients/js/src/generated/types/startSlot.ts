@@ -0,0 +1,20 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Serializer, struct, u64 } from '@metaplex-foundation/umi/serializers';
+
+/** Guard that sets a specific slot for the mint to start. */
+export type StartSlot = { slot: bigint };
+
+export type StartSlotArgs = { slot: number | bigint };
+
+export function getStartSlotSerializer(): Serializer<StartSlotArgs, StartSlot> {
+  return struct<StartSlot>([['slot', u64()]], {
+    description: 'StartSlot',
+  }) as Serializer<StartSlotArgs, StartSlot>;
+}
@@ -0,0 +1,64 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { PublicKey } from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  publicKey as publicKeySerializer,
+  struct,
+  u64,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  SplitDestination,
+  SplitDestinationArgs,
+  getSplitDestinationSerializer,
+} from '.';
+
+/**
+ * Guard that charges an amount in a specified spl-token as payment for the mint, splitting
+ * it across multiple destinations according to their share in basis points. The mint can
+ * belong to the legacy token program or to Token-2022; when the mint has a transfer fee, the
+ * fee is withheld from the amount received by each destination.
+ *
+ * List of accounts required:
+ *
+ * 0. `[writable]` Token account holding the required amount.
+ * 1. `[]` Mint account.
+ * 2. `[]` SPL token program or SPL Token-2022 program (owner of the mint).
+ * 3. `[writable]` ATA of the first destination wallet, followed by the ATA of each
+ * remaining destination (in the same order as the configuration).
+ */
+
+export type TokenPaymentSplit = {
+  amount: bigint;
+  mint: PublicKey;
+  /** Destination wallets; the tokens are transferred to their ATAs. */
+  destinations: Array<SplitDestination>;
+};
+
+export type TokenPaymentSplitArgs = {
+  amount: number | bigint;
+  mint: PublicKey;
+  /** Destination wallets; the tokens are transferred to their ATAs. */
+  destinations: Array<SplitDestinationArgs>;
+};
+
+export function getTokenPaymentSplitSerializer(): Serializer<
+  TokenPaymentSplitArgs,
+  TokenPaymentSplit
+> {
+  return struct<TokenPaymentSplit>(
+    [
+      ['amount', u64()],
+      ['mint', publicKeySerializer()],
+      ['destinations', array(getSplitDestinationSerializer())],
+    ],
+    { description: 'TokenPaymentSplit' }
+  ) as Serializer<TokenPaymentSplitArgs, TokenPaymentSplit>;
+}
@@ -0,0 +1,36 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Serializer,
+  string,
+  struct,
+} from '@metaplex-foundation/umi/serializers';
+
+/** A trait (key/value pair) of an asset. */
+export type TraitRequirement = {
+  /** Key of the attribute. */
+  key: string;
+  /** Required value of the attribute. */
+  value: string;
+};
+
+export type TraitRequirementArgs = TraitRequirement;
+
+export function getTraitRequirementSerializer(): Serializer<
+  TraitRequirementArgs,
+  TraitRequirement
+> {
+  return struct<TraitRequirement>(
+    [
+      ['key', string()],
+      ['value', string()],
+    ],
+    { description: 'TraitRequirement' }
+  ) as Serializer<TraitRequirementArgs, TraitRequirement>;
+}
@@ -0,0 +1,74 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  DateTime,
+  DateTimeInput,
+  Option,
+  OptionOrNullable,
+  PublicKey,
+  mapDateTimeSerializer,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  i64,
+  option,
+  publicKey as publicKeySerializer,
+  string,
+  struct,
+  u64,
+} from '@metaplex-foundation/umi/serializers';
+
+/**
+ * Voucher signed off-chain authorizing a mint. This is the (borsh-serialized) message
+ * signed by the voucher signer.
+ */
+
+export type Voucher = {
+  /** Address allowed to mint. */
+  minter: PublicKey;
+  /** Candy machine of the mint. */
+  candyMachine: PublicKey;
+  /** Label of the group of the mint, if any. */
+  label: Option<string>;
+  /** Price of the mint in lamports, if any. */
+  price: Option<bigint>;
+  /** Expiry of the voucher (unix timestamp). */
+  expiry: DateTime;
+  /** Unique identifier of the voucher. */
+  nonce: bigint;
+};
+
+export type VoucherArgs = {
+  /** Address allowed to mint. */
+  minter: PublicKey;
+  /** Candy machine of the mint. */
+  candyMachine: PublicKey;
+  /** Label of the group of the mint, if any. */
+  label: OptionOrNullable<string>;
+  /** Price of the mint in lamports, if any. */
+  price: OptionOrNullable<number | bigint>;
+  /** Expiry of the voucher (unix timestamp). */
+  expiry: DateTimeInput;
+  /** Unique identifier of the voucher. */
+  nonce: number | bigint;
+};
+
+export function getVoucherSerializer(): Serializer<VoucherArgs, Voucher> {
+  return struct<Voucher>(
+    [
+      ['minter', publicKeySerializer()],
+      ['candyMachine', publicKeySerializer()],
+      ['label', option(string())],
+      ['price', option(u64())],
+      ['expiry', mapDateTimeSerializer(i64())],
+      ['nonce', u64()],
+    ],
+    { description: 'Voucher' }
+  ) as Serializer<VoucherArgs, Voucher>;
+}
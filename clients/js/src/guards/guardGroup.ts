@@ -1,11 +1,23 @@
 import {
+  array,
   mapSerializer,
   Serializer,
   string,
   struct,
+  u8,
 } from '@metaplex-foundation/umi/serializers';
 import { GuardGroupLabelTooLongError } from '../errors';
-import { CANDY_GUARD_LABEL_SIZE } from '../constants';
+import {
+  CANDY_GUARD_DISABLED_GUARDS_VERSION,
+  CANDY_GUARD_LABEL_SIZE,
+  CANDY_GUARD_LEGACY_LAYOUT_VERSION,
+  CANDY_GUARD_MAX_LABEL_SIZE,
+} from '../constants';
+import {
+  GuardType,
+  GuardTypeArgs,
+  getGuardTypeSerializer,
+} from '../generated/types/guardType';
 import { CandyGuardProgram, GuardRepository } from './guardRepository';
 import { getGuardSetSerializer, GuardSet, GuardSetArgs } from './guardSet';
 
@@ -16,36 +28,80 @@ import { getGuardSetSerializer, GuardSet, GuardSetArgs } from './guardSet';
 export type GuardGroup<D extends GuardSet> = {
   label: string;
   guards: D;
+  /** Guards of the default guard set that do not apply to the group. */
+  disabled: GuardType[];
 };
 
 export type GuardGroupArgs<DA extends GuardSetArgs> = {
   label: string;
   guards: Partial<DA>;
+  /**
+   * Guards of the default guard set that do not apply to the group.
+   * The `phaseSchedule` and `autoGroup` guards cannot be disabled.
+   * @defaultValue `[]`
+   */
+  disabled?: GuardTypeArgs[];
 };
 
+/** Returns the number of bytes of a group label. */
+export const getGuardGroupLabelSize = (label: string): number =>
+  new TextEncoder().encode(label).length;
+
+/**
+ * Serializes a group using the specified layout version of the Candy Guard
+ * account. Labels are padded to 6 bytes in the legacy layout and are
+ * length-prefixed (up to 32 bytes) in the versioned layout, which also
+ * includes the default guards disabled by the group.
+ */
 export function getGuardGroupSerializer<
   DA extends GuardSetArgs,
   D extends DA & GuardSet
 >(
   context: { coreGuards: GuardRepository },
-  program: CandyGuardProgram
+  program: CandyGuardProgram,
+  version: number = CANDY_GUARD_LEGACY_LAYOUT_VERSION
 ): Serializer<GuardGroupArgs<DA>, GuardGroup<D>> {
-  return struct(
-    [
+  const legacy = version === CANDY_GUARD_LEGACY_LAYOUT_VERSION;
+  const maxLabelSize = legacy
+    ? CANDY_GUARD_LABEL_SIZE
+    : CANDY_GUARD_MAX_LABEL_SIZE;
+  const labelSerializer = mapSerializer(
+    string({ size: legacy ? CANDY_GUARD_LABEL_SIZE : u8() }),
+    (label: string): string => {
+      if (getGuardGroupLabelSize(label) > maxLabelSize) {
+        throw new GuardGroupLabelTooLongError(label);
+      }
+      return label;
+    }
+  );
+  const guardsSerializer = getGuardSetSerializer<DA, D>(context, program);
+
+  if (version < CANDY_GUARD_DISABLED_GUARDS_VERSION) {
+    return mapSerializer(
+      struct<any>(
+        [
+          ['label', labelSerializer],
+          ['guards', guardsSerializer],
+        ],
+        { description: 'GuardGroup' }
+      ),
+      (group: GuardGroupArgs<DA>) => group,
+      (group) => ({ ...group, disabled: [] })
+    ) as Serializer<GuardGroupArgs<DA>, GuardGroup<D>>;
+  }
+
+  return mapSerializer(
+    struct<any>(
       [
-        'label',
-        mapSerializer(
-          string({ size: CANDY_GUARD_LABEL_SIZE }),
-          (label: string): string => {
-            if (label.length > CANDY_GUARD_LABEL_SIZE) {
-              throw new GuardGroupLabelTooLongError(label);
-            }
-            return label;
-          }
-        ),
+        ['label', labelSerializer],
+        ['disabled', array(getGuardTypeSerializer())],
+        ['guards', guardsSerializer],
       ],
-      ['guards', getGuardSetSerializer<DA, D>(context, program)],
-    ],
-    { description: 'GuardGroup' }
+      { description: 'GuardGroup' }
+    ),
+    (group: GuardGroupArgs<DA>) => ({
+      ...group,
+      disabled: group.disabled ?? [],
+    })
   ) as Serializer<GuardGroupArgs<DA>, GuardGroup<D>>;
 }
@@ -1,4 +1,4 @@
-import { Context, PublicKey, Signer } from '@metaplex-foundation/umi';
+import { Context, Option, PublicKey, Signer } from '@metaplex-foundation/umi';
 import { Serializer } from '@metaplex-foundation/umi/serializers';
 
 export type GuardManifest<
@@ -30,6 +30,9 @@ export const noopParser: MintParser<{}> & RouteParser<{}> = () => ({
   remainingAccounts: [],
 });
 
+/** The route instruction data that closes the PDA created by a guard. */
+export const CLOSE_GUARD_PDA_DATA = new Uint8Array([255]);
+
 export type MintContext = {
   /** The wallet to use for validation and non-SOL fees, this is typically the payer. */
   minter: Signer;
@@ -41,6 +44,8 @@ export type MintContext = {
   candyMachine: PublicKey;
   /** The address of the Candy Guard we are using. */
   candyGuard: PublicKey;
+  /** The label of the group we are using, if any. */
+  group: Option<string>;
 };
 
 export type RouteContext = Omit<MintContext, 'minter' | 'asset'>;
@@ -42,6 +42,54 @@ export type GuardSetRouteArgs = {
   [name: string]: object;
 };
 
+/** Number of guard flags stored in each features word. */
+export const FEATURES_FLAGS_PER_WORD = 63;
+
+/**
+ * Serializes the feature flags of a guard set, indicating which guards
+ * are enabled. The flags are stored as a sequence of little-endian `u64`
+ * words: the lower 63 bits of each word are guard flags and the most
+ * significant bit indicates that another word follows. A guard set that
+ * only enables the first 63 guards uses a single word, which is the
+ * layout used before the encoding was extended.
+ */
+export function getFeaturesSerializer(): Serializer<boolean[]> {
+  // the bits of a word, from the least significant bit
+  const wordSerializer = reverseSerializer(bitArray(8, true));
+  return {
+    description: 'features',
+    fixedSize: null,
+    maxSize: null,
+    serialize: (features: boolean[]): Uint8Array => {
+      const lastEnabled = features.lastIndexOf(true);
+      const wordCount = Math.max(
+        1,
+        Math.ceil((lastEnabled + 1) / FEATURES_FLAGS_PER_WORD)
+      );
+      const words = [] as Uint8Array[];
+      for (let index = 0; index < wordCount; index += 1) {
+        const flags = Array.from(
+          { length: FEATURES_FLAGS_PER_WORD },
+          (_, bit) => features[index * FEATURES_FLAGS_PER_WORD + bit] ?? false
+        );
+        words.push(wordSerializer.serialize([...flags, index + 1 < wordCount]));
+      }
+      return mergeBytes(words);
+    },
+    deserialize: (bytes: Uint8Array, offset = 0): [boolean[], number] => {
+      const features = [] as boolean[];
+      let continuation = true;
+      while (continuation) {
+        const [bits, newOffset] = wordSerializer.deserialize(bytes, offset);
+        offset = newOffset;
+        features.push(...bits.slice(0, FEATURES_FLAGS_PER_WORD));
+        continuation = bits[FEATURES_FLAGS_PER_WORD] ?? false;
+      }
+      return [features, offset];
+    },
+  };
+}
+
 export function getGuardSetSerializer<
   DA extends GuardSetArgs,
   D extends DA & GuardSet
@@ -50,7 +98,7 @@ export function getGuardSetSerializer<
   program: CandyGuardProgram
 ): Serializer<Partial<DA>, D> {
   const manifests = context.coreGuards.forProgram(program);
-  const featuresSerializer = reverseSerializer(bitArray(8, true));
+  const featuresSerializer = getFeaturesSerializer();
   return {
     description: 'guardSet',
     fixedSize: null,
@@ -99,23 +147,50 @@ export function parseMintArgs<MA extends GuardSetMintArgs>(
   mintArgs: Partial<MA>
 ): GuardInstructionExtras {
   const manifests = context.coreGuards.forProgram(program);
-  return manifests.reduce(
-    (acc, manifest) => {
-      const args = mintArgs[manifest.name] ?? none();
-      const argsAsOption = isOption(args) ? args : wrapNullable(args);
-      if (isNone(argsAsOption)) return acc;
-      const { data, remainingAccounts } = manifest.mintParser(
-        context,
-        mintContext,
-        argsAsOption.value
-      );
-      return {
-        data: mergeBytes([acc.data, data]),
-        remainingAccounts: [...acc.remainingAccounts, ...remainingAccounts],
-      };
-    },
-    { data: new Uint8Array(), remainingAccounts: [] } as GuardInstructionExtras
-  );
+  const parsed = manifests.map((manifest) => {
+    const args = mintArgs[manifest.name] ?? none();
+    const argsAsOption = isOption(args) ? args : wrapNullable(args);
+    if (isNone(argsAsOption)) return null;
+    return manifest.mintParser(context, mintContext, argsAsOption.value);
+  });
+
+  // The alternative selected on the anyOf guard is evaluated by the anyOf
+  // guard, so its data and accounts are provided at the anyOf position.
+  const anyOfArgs = mintArgs.anyOf ?? none();
+  const anyOfAsOption = isOption(anyOfArgs)
+    ? anyOfArgs
+    : wrapNullable(anyOfArgs);
+  const alternative = isSome(anyOfAsOption)
+    ? manifests.findIndex(
+        (manifest) =>
+          manifest.name === (anyOfAsOption.value as { guard: string }).guard
+      )
+    : -1;
+
+  return manifests
+    .flatMap((manifest, index) => {
+      if (index === alternative) return [];
+      if (manifest.name === 'anyOf' && alternative >= 0) {
+        return [parsed[index], parsed[alternative]];
+      }
+      return [parsed[index]];
+    })
+    .reduce(
+      (acc, extras) =>
+        extras
+          ? {
+              data: mergeBytes([acc.data, extras.data]),
+              remainingAccounts: [
+                ...acc.remainingAccounts,
+                ...extras.remainingAccounts,
+              ],
+            }
+          : acc,
+      {
+        data: new Uint8Array(),
+        remainingAccounts: [],
+      } as GuardInstructionExtras
+    );
 }
 
 export function parseRouteArgs<
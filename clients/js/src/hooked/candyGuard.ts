@@ -26,10 +26,12 @@ import {
   GuardRepository,
   GuardSet,
   GuardSetArgs,
-  getGuardGroupSerializer,
-  getGuardSetSerializer,
 } from '../guards';
-import { CandyGuardData, CandyGuardDataArgs } from './candyGuardData';
+import {
+  CandyGuardData,
+  CandyGuardDataArgs,
+  getCandyGuardDataSerializer,
+} from './candyGuardData';
 
 const DISCRIMINATOR = [95, 25, 33, 117, 164, 206, 9, 250];
 
@@ -71,12 +73,16 @@ export function getCandyGuardAccountDataSerializer<
         ['base', publicKeySerializer()],
         ['bump', u8()],
         ['authority', publicKeySerializer()],
-        ['guards', getGuardSetSerializer<DA, D>(context, program)],
-        ['groups', array(getGuardGroupSerializer<DA, D>(context, program))],
+        ['data', getCandyGuardDataSerializer<DA, D>(context, program)],
       ],
       { description: 'CandyGuard' }
     ),
-    (value) => ({ ...value, discriminator: DISCRIMINATOR })
+    ({ guards, groups, ...value }: any) => ({
+      ...value,
+      discriminator: DISCRIMINATOR,
+      data: { guards, groups },
+    }),
+    ({ data, ...value }: any) => ({ ...value, ...data })
   ) as Serializer<CandyGuardAccountDataArgs<DA>, CandyGuardAccountData<D>>;
 }
 
@@ -1,8 +1,14 @@
 import {
   Serializer,
   array,
-  struct,
+  mergeBytes,
+  u8,
 } from '@metaplex-foundation/umi/serializers';
+import {
+  CANDY_GUARD_LABEL_SIZE,
+  CANDY_GUARD_LAYOUT_VERSION,
+  CANDY_GUARD_LEGACY_LAYOUT_VERSION,
+} from '../constants';
 import {
   CandyGuardProgram,
   GuardGroup,
@@ -10,10 +16,17 @@ import {
   GuardRepository,
   GuardSet,
   GuardSetArgs,
+  getGuardGroupLabelSize,
   getGuardGroupSerializer,
   getGuardSetSerializer,
 } from '../guards';
 
+/**
+ * Marker at the start of the guards data indicating a versioned layout,
+ * followed by the version of the layout (`u8`).
+ */
+const VERSIONED_LAYOUT_MARKER = new Uint8Array(8).fill(0xff);
+
 export type CandyGuardData<D extends GuardSet> = {
   guards: D;
   groups: Array<GuardGroup<D>>;
@@ -24,6 +37,17 @@ export type CandyGuardDataArgs<DA extends GuardSetArgs> = {
   groups: Array<GuardGroupArgs<DA>>;
 };
 
+const isVersionedLayoutMarker = (bytes: Uint8Array, offset: number) =>
+  bytes.length >= offset + VERSIONED_LAYOUT_MARKER.length &&
+  VERSIONED_LAYOUT_MARKER.every(
+    (byte, index) => bytes[offset + index] === byte
+  );
+
+/**
+ * Serializes the guards of a Candy Guard account. The legacy layout is used
+ * whenever possible so that existing clients can read the data; otherwise,
+ * the data starts with a header indicating the version of the layout.
+ */
 export function getCandyGuardDataSerializer<
   DA extends GuardSetArgs,
   D extends DA & GuardSet
@@ -31,11 +55,54 @@ export function getCandyGuardDataSerializer<
   context: { coreGuards: GuardRepository },
   program: CandyGuardProgram
 ): Serializer<CandyGuardDataArgs<DA>, CandyGuardData<D>> {
-  return struct<CandyGuardDataArgs<DA>, CandyGuardData<D>>(
-    [
-      ['guards', getGuardSetSerializer<DA, D>(context, program)],
-      ['groups', array(getGuardGroupSerializer<DA, D>(context, program))],
-    ],
-    { description: 'CandyGuardData' }
-  );
+  const guardSetSerializer = getGuardSetSerializer<DA, D>(context, program);
+  const groupsSerializer = (version: number) =>
+    array(getGuardGroupSerializer<DA, D>(context, program, version));
+  return {
+    description: 'CandyGuardData',
+    fixedSize: null,
+    maxSize: null,
+    serialize: (data: CandyGuardDataArgs<DA>): Uint8Array => {
+      const guards = guardSetSerializer.serialize(data.guards);
+      const legacy =
+        !isVersionedLayoutMarker(guards, 0) &&
+        data.groups.every(
+          (group) =>
+            getGuardGroupLabelSize(group.label) <= CANDY_GUARD_LABEL_SIZE &&
+            (group.disabled ?? []).length === 0
+        );
+      const version = legacy
+        ? CANDY_GUARD_LEGACY_LAYOUT_VERSION
+        : CANDY_GUARD_LAYOUT_VERSION;
+      const header = legacy
+        ? new Uint8Array()
+        : mergeBytes([VERSIONED_LAYOUT_MARKER, u8().serialize(version)]);
+      return mergeBytes([
+        header,
+        guards,
+        groupsSerializer(version).serialize(data.groups),
+      ]);
+    },
+    deserialize: (
+      bytes: Uint8Array,
+      offset = 0
+    ): [CandyGuardData<D>, number] => {
+      let version = CANDY_GUARD_LEGACY_LAYOUT_VERSION;
+      if (isVersionedLayoutMarker(bytes, offset)) {
+        [version, offset] = u8().deserialize(
+          bytes,
+          offset + VERSIONED_LAYOUT_MARKER.length
+        );
+      }
+      const [guards, guardsOffset] = guardSetSerializer.deserialize(
+        bytes,
+        offset
+      );
+      const [groups, groupsOffset] = groupsSerializer(version).deserialize(
+        bytes,
+        guardsOffset
+      );
+      return [{ guards, groups }, groupsOffset];
+    },
+  };
 }
@@ -4,6 +4,7 @@ export * from './guards';
 export * from './hooked';
 export * from './programs';
 
+export * from './checkEligibility';
 export * from './constants';
 export * from './create';
 export * from './createCandyGuard';
@@ -15,3 +16,4 @@ export * from './mintV1';
 export * from './plugin';
 export * from './route';
 export * from './updateCandyGuard';
+export * from './voucher';
@@ -2,9 +2,11 @@ import {
   Option,
   OptionOrNullable,
   TransactionBuilder,
+  isOption,
   none,
   publicKey,
   transactionBuilder,
+  wrapNullable,
 } from '@metaplex-foundation/umi';
 import { DefaultGuardSetMintArgs } from './defaultGuards';
 import {
@@ -57,6 +59,7 @@ export function mintV1<MA extends GuardSetMintArgs = DefaultGuardSetMintArgs>(
       input.candyGuard ?? findCandyGuardPda(context, { base: candyMachine }),
       false
     ),
+    group: isOption(group) ? group : wrapNullable(group),
   };
   const { data, remainingAccounts } = parseMintArgs<
     MA extends undefined ? DefaultGuardSetMintArgs : MA
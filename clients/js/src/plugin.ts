@@ -33,6 +33,26 @@ import {
   assetPaymentMultiGuardManifest,
   assetGateGuardManifest,
   vanityMintGuardManifest,
+  receiptGuardManifest,
+  dutchAuctionGuardManifest,
+  bondingCurveGuardManifest,
+  solPaymentSplitGuardManifest,
+  tokenPaymentSplitGuardManifest,
+  anyOfGuardManifest,
+  autoGroupGuardManifest,
+  phaseScheduleGuardManifest,
+  externalGuardGuardManifest,
+  signedVoucherGuardManifest,
+  allowListAllotmentGuardManifest,
+  cooldownGuardManifest,
+  rateLimitGuardManifest,
+  startSlotGuardManifest,
+  endSlotGuardManifest,
+  recurringWindowGuardManifest,
+  solGateGuardManifest,
+  assetTraitGateGuardManifest,
+  holderDiscountGuardManifest,
+  gateUsageLimitGuardManifest,
 } from './defaultGuards';
 import {
   createMplCoreCandyGuardProgram,
@@ -97,7 +117,27 @@ export const mplCandyMachine = (): UmiPlugin => ({
       assetBurnMultiGuardManifest,
       assetPaymentMultiGuardManifest,
       assetGateGuardManifest,
-      vanityMintGuardManifest
+      vanityMintGuardManifest,
+      receiptGuardManifest,
+      dutchAuctionGuardManifest,
+      bondingCurveGuardManifest,
+      solPaymentSplitGuardManifest,
+      tokenPaymentSplitGuardManifest,
+      anyOfGuardManifest,
+      autoGroupGuardManifest,
+      phaseScheduleGuardManifest,
+      externalGuardGuardManifest,
+      signedVoucherGuardManifest,
+      allowListAllotmentGuardManifest,
+      cooldownGuardManifest,
+      rateLimitGuardManifest,
+      startSlotGuardManifest,
+      endSlotGuardManifest,
+      recurringWindowGuardManifest,
+      solGateGuardManifest,
+      assetTraitGateGuardManifest,
+      holderDiscountGuardManifest,
+      gateUsageLimitGuardManifest
     );
   },
 });
@@ -1,10 +1,12 @@
 import {
+  isOption,
   none,
   Option,
   OptionOrNullable,
   publicKey,
   TransactionBuilder,
   transactionBuilder,
+  wrapNullable,
 } from '@metaplex-foundation/umi';
 import { DefaultGuardSetRouteArgs } from './defaultGuards';
 import {
@@ -65,6 +67,7 @@ export function route<
       input.candyGuard ?? findCandyGuardPda(context, { base: candyMachine }),
       false
     ),
+    group: isOption(group) ? group : wrapNullable(group),
   };
   const { data, remainingAccounts, guardIndex } = parseRouteArgs<
     G,
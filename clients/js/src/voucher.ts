@@ -0,0 +1,63 @@
+import {
+  publicKey,
+  publicKeyBytes,
+  Signer,
+  transactionBuilder,
+  TransactionBuilder,
+} from '@metaplex-foundation/umi';
+import { array, mergeBytes, u16 } from '@metaplex-foundation/umi/serializers';
+import { getVoucherSerializer, VoucherArgs } from './generated';
+
+/** The address of the Ed25519 signature verification program. */
+export const ED25519_PROGRAM_ID = publicKey(
+  'Ed25519SigVerify111111111111111111111111111'
+);
+
+// Offsets of the public key, signature and message in the instruction data,
+// after the 2-byte header and the 7 signature offsets (u16).
+const PUBLIC_KEY_OFFSET = 16;
+const SIGNATURE_OFFSET = PUBLIC_KEY_OFFSET + 32;
+const MESSAGE_OFFSET = SIGNATURE_OFFSET + 64;
+
+// Instruction index used to reference the Ed25519 instruction itself.
+const CURRENT_INSTRUCTION = 65535;
+
+/**
+ * Signs a voucher for the signedVoucher guard and returns the
+ * Ed25519 instruction that proves the signature.
+ *
+ * The returned instruction must be added immediately
+ * before the mint instruction in the transaction.
+ */
+export const signVoucher = async (input: {
+  /** The signer configured on the signedVoucher guard. */
+  signer: Signer;
+  /** The voucher to sign. */
+  voucher: VoucherArgs;
+}): Promise<TransactionBuilder> => {
+  const message = getVoucherSerializer().serialize(input.voucher);
+  const signature = await input.signer.signMessage(message);
+  const data = mergeBytes([
+    new Uint8Array([1, 0]),
+    array(u16(), { size: 7 }).serialize([
+      SIGNATURE_OFFSET,
+      CURRENT_INSTRUCTION,
+      PUBLIC_KEY_OFFSET,
+      CURRENT_INSTRUCTION,
+      MESSAGE_OFFSET,
+      message.length,
+      CURRENT_INSTRUCTION,
+    ]),
+    publicKeyBytes(input.signer.publicKey),
+    signature,
+    message,
+  ]);
+
+  return transactionBuilder([
+    {
+      instruction: { keys: [], programId: ED25519_PROGRAM_ID, data },
+      signers: [],
+      bytesCreatedOnChain: 0,
+    },
+  ]);
+};
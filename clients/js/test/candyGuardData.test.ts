@@ -0,0 +1,155 @@
+import { some } from '@metaplex-foundation/umi';
+import {
+  i64,
+  string,
+  u32,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import test from 'ava';
+import {
+  CANDY_GUARD_LAYOUT_VERSION,
+  CandyGuardProgram,
+  DefaultGuardSet,
+  DefaultGuardSetArgs,
+  GuardType,
+  getCandyGuardDataSerializer,
+} from '../src';
+import { createUmi } from './_setup';
+
+const getSerializer = async () => {
+  const umi = await createUmi();
+  const program = umi.programs.get<CandyGuardProgram>('mplCoreCandyGuard');
+  return {
+    program,
+    serializer: getCandyGuardDataSerializer<
+      DefaultGuardSetArgs,
+      DefaultGuardSet
+    >(umi, program),
+  };
+};
+
+test('it deserializes the guards of a legacy candy guard account', async (t) => {
+  // Given the data of a candy guard saved with the legacy layout: a default
+  // guard set with the start date guard and a group with a padded label.
+  const { program, serializer } = await getSerializer();
+  const startDateIndex = program.availableGuards.indexOf('startDate');
+  const bytes = new Uint8Array([
+    ...u64().serialize(2 ** startDateIndex),
+    ...i64().serialize(1000),
+    ...u32().serialize(1),
+    ...string({ size: 6 }).serialize('VIP'),
+    ...u64().serialize(0),
+  ]);
+
+  // When we deserialize it.
+  const [data, offset] = serializer.deserialize(bytes);
+
+  // Then we get the expected guards and groups.
+  t.is(offset, bytes.length);
+  t.like(data.guards, { startDate: some({ date: BigInt(1000) }) });
+  t.is(data.groups.length, 1);
+  t.is(data.groups[0].label, 'VIP');
+
+  // And it is serialized back using the legacy layout.
+  t.deepEqual(
+    serializer.serialize({
+      guards: { startDate: some({ date: 1000 }) },
+      groups: [{ label: 'VIP', guards: {} }],
+    }),
+    bytes
+  );
+});
+
+test('it serializes long group labels using the versioned layout', async (t) => {
+  // Given guards with a group label longer than 6 bytes.
+  const { serializer } = await getSerializer();
+  const label = 'early-supporters';
+
+  // When we serialize them.
+  const bytes = serializer.serialize({
+    guards: {},
+    groups: [{ label, guards: {} }],
+  });
+
+  // Then the data starts with the versioned layout header
+  // and the label is length-prefixed.
+  t.deepEqual(
+    bytes,
+    new Uint8Array([
+      ...new Uint8Array(8).fill(0xff),
+      ...u8().serialize(CANDY_GUARD_LAYOUT_VERSION),
+      ...u64().serialize(0),
+      ...u32().serialize(1),
+      ...string({ size: u8() }).serialize(label),
+      ...u32().serialize(0),
+      ...u64().serialize(0),
+    ])
+  );
+
+  // And the label can be deserialized back.
+  const [data, offset] = serializer.deserialize(bytes);
+  t.is(offset, bytes.length);
+  t.is(data.groups[0].label, label);
+});
+
+test('it fails to serialize group labels longer than 32 bytes', async (t) => {
+  // Given guards with a group label of 33 bytes.
+  const { serializer } = await getSerializer();
+  const label = 'a'.repeat(33);
+
+  // When we try to serialize them.
+  const serialize = () =>
+    serializer.serialize({ guards: {}, groups: [{ label, guards: {} }] });
+
+  // Then we expect an error.
+  t.throws(serialize, { message: /is too long/ });
+});
+
+test('it serializes groups with disabled guards using the versioned layout', async (t) => {
+  // Given a group disabling the start date guard of the default guard set.
+  const { program, serializer } = await getSerializer();
+  const startDateIndex = program.availableGuards.indexOf('startDate');
+
+  // When we serialize it.
+  const bytes = serializer.serialize({
+    guards: { startDate: some({ date: 1000 }) },
+    groups: [{ label: 'VIP', guards: {}, disabled: [GuardType.StartDate] }],
+  });
+
+  // Then the data uses the versioned layout and
+  // includes the disabled guards after the label.
+  t.deepEqual(
+    bytes,
+    new Uint8Array([
+      ...new Uint8Array(8).fill(0xff),
+      ...u8().serialize(CANDY_GUARD_LAYOUT_VERSION),
+      ...u64().serialize(2 ** startDateIndex),
+      ...i64().serialize(1000),
+      ...u32().serialize(1),
+      ...string({ size: u8() }).serialize('VIP'),
+      ...u32().serialize(1),
+      ...u8().serialize(GuardType.StartDate),
+      ...u64().serialize(0),
+    ])
+  );
+
+  // And the disabled guards can be deserialized back.
+  const [data] = serializer.deserialize(bytes);
+  t.deepEqual(data.groups[0].disabled, [GuardType.StartDate]);
+});
+
+test('it deserializes groups of the legacy layout without disabled guards', async (t) => {
+  // Given guards serialized using the legacy layout.
+  const { serializer } = await getSerializer();
+  const bytes = serializer.serialize({
+    guards: {},
+    groups: [{ label: 'VIP', guards: {} }],
+  });
+
+  // When we deserialize them.
+  const [data] = serializer.deserialize(bytes);
+
+  // Then the group does not disable any guard.
+  t.deepEqual(data.groups[0].disabled, []);
+});
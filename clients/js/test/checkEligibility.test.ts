@@ -0,0 +1,156 @@
+import {
+  generateSigner,
+  isNone,
+  none,
+  Option,
+  sol,
+  some,
+  Umi,
+} from '@metaplex-foundation/umi';
+import test from 'ava';
+import {
+  CandyGuardProgram,
+  getEligibility,
+  getMplCoreCandyGuardErrorFromCode,
+  GuardType,
+} from '../src';
+import {
+  createCollection,
+  createUmi,
+  createV2,
+  tomorrow,
+  yesterday,
+} from './_setup';
+
+const errorName = (umi: Umi, error: Option<bigint>) => {
+  if (isNone(error)) return null;
+  const program = umi.programs.get<CandyGuardProgram>('mplCoreCandyGuard');
+  return getMplCoreCandyGuardErrorFromCode(Number(error.value), program)?.name;
+};
+
+test('it reports an eligible mint and its payment', async (t) => {
+  // Given a loaded Candy Machine with a solPayment guard.
+  const umi = await createUmi();
+  const destination = generateSigner(umi).publicKey;
+  const collection = (await createCollection(umi)).publicKey;
+  const { publicKey: candyMachine } = await createV2(umi, {
+    collection,
+    configLines: [{ name: 'Degen #1', uri: 'https://example.com/degen/1' }],
+    guards: {
+      startDate: some({ date: yesterday() }),
+      solPayment: some({ lamports: sol(1), destination }),
+    },
+  });
+
+  // When we check the eligibility of the identity.
+  const eligibility = await getEligibility(umi, {
+    candyMachine,
+    asset: generateSigner(umi).publicKey,
+    collection,
+    mintArgs: { solPayment: some({ destination }) },
+  });
+
+  // Then the default guard set is eligible and reports its price.
+  t.deepEqual(eligibility, [
+    {
+      label: none(),
+      error: none(),
+      guards: [
+        {
+          guard: GuardType.SolPayment,
+          error: none(),
+          payment: some({
+            guard: GuardType.SolPayment,
+            mint: none(),
+            amount: sol(1).basisPoints,
+          }),
+        },
+        { guard: GuardType.StartDate, error: none(), payment: none() },
+      ],
+    },
+  ]);
+
+  // And nothing was paid.
+  t.false(await umi.rpc.accountExists(destination));
+});
+
+test('it reports the error of the guard that fails', async (t) => {
+  // Given a loaded Candy Machine that is not live yet.
+  const umi = await createUmi();
+  const collection = (await createCollection(umi)).publicKey;
+  const { publicKey: candyMachine } = await createV2(umi, {
+    collection,
+    configLines: [{ name: 'Degen #1', uri: 'https://example.com/degen/1' }],
+    guards: {
+      startDate: some({ date: tomorrow() }),
+    },
+  });
+
+  // When we check the eligibility of the identity.
+  const [eligibility] = await getEligibility(umi, {
+    candyMachine,
+    asset: generateSigner(umi).publicKey,
+    collection,
+  });
+
+  // Then the guard set is not eligible because of the startDate guard.
+  t.is(errorName(umi, eligibility.error), 'MintNotLive');
+  t.is(eligibility.guards.length, 1);
+  t.is(eligibility.guards[0].guard, GuardType.StartDate);
+  t.is(errorName(umi, eligibility.guards[0].error), 'MintNotLive');
+});
+
+test('it evaluates all groups when no group is provided', async (t) => {
+  // Given a loaded Candy Machine with a live and a future group.
+  const umi = await createUmi();
+  const collection = (await createCollection(umi)).publicKey;
+  const { publicKey: candyMachine } = await createV2(umi, {
+    collection,
+    configLines: [{ name: 'Degen #1', uri: 'https://example.com/degen/1' }],
+    groups: [
+      { label: 'OG', guards: { startDate: some({ date: yesterday() }) } },
+      { label: 'PUBLIC', guards: { startDate: some({ date: tomorrow() }) } },
+    ],
+  });
+
+  // When we check the eligibility of the identity without a group.
+  const eligibility = await getEligibility(umi, {
+    candyMachine,
+    asset: generateSigner(umi).publicKey,
+    collection,
+  });
+
+  // Then both groups were evaluated.
+  t.is(eligibility.length, 2);
+  t.deepEqual(eligibility[0].label, some('OG'));
+  t.deepEqual(eligibility[0].error, none());
+  t.deepEqual(eligibility[1].label, some('PUBLIC'));
+  t.is(errorName(umi, eligibility[1].error), 'MintNotLive');
+});
+
+test('it only evaluates the provided group', async (t) => {
+  // Given a loaded Candy Machine with two groups.
+  const umi = await createUmi();
+  const collection = (await createCollection(umi)).publicKey;
+  const { publicKey: candyMachine } = await createV2(umi, {
+    collection,
+    configLines: [{ name: 'Degen #1', uri: 'https://example.com/degen/1' }],
+    groups: [
+      { label: 'OG', guards: { startDate: some({ date: yesterday() }) } },
+      { label: 'PUBLIC', guards: { startDate: some({ date: tomorrow() }) } },
+    ],
+  });
+
+  // When we check the eligibility of the identity for the second group.
+  const eligibility = await getEligibility(umi, {
+    candyMachine,
+    asset: generateSigner(umi).publicKey,
+    collection,
+    group: some('PUBLIC'),
+  });
+
+  // Then only that group was evaluated.
+  t.is(eligibility.length, 1);
+  t.deepEqual(eligibility[0].label, some('PUBLIC'));
+  t.is(errorName(umi, eligibility[0].error), 'MintNotLive');
+});
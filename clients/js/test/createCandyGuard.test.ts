@@ -219,12 +219,68 @@ test('it fails to create a group with a label that is too long', async (t) => {
     createCandyGuard(umi, {
       base,
       guards: {},
-      groups: [{ label: 'IAMALABELTHATISTOOLONG', guards: {} }],
+      groups: [
+        { label: 'IAMALABELTHATISTOOLONGEVENFORTHENEWLAYOUT', guards: {} },
+      ],
     });
 
   // Then we expect a program error.
   t.throws(createInstruction, {
-    message: /The provided group label \[IAMALABELTHATISTOOLONG\] is too long/,
+    message:
+      /The provided group label \[IAMALABELTHATISTOOLONGEVENFORTHENEWLAYOUT\] is too long/,
+  });
+});
+
+test('it can create a candy guard with long group labels', async (t) => {
+  // Given a base address.
+  const umi = await createUmi();
+  const base = generateSigner(umi);
+
+  // When we create a new candy guard with group labels longer than 6 bytes.
+  await transactionBuilder()
+    .add(
+      createCandyGuard(umi, {
+        base,
+        guards: {
+          botTax: some({ lamports: sol(0.01), lastInstruction: false }),
+        },
+        groups: [
+          {
+            label: 'VIP',
+            guards: {
+              startDate: some({ date: '2022-09-05T16:00:00.000Z' }),
+            },
+          },
+          {
+            label: 'early-supporters-🍭',
+            guards: { endDate: some({ date: '2022-09-06T16:00:00.000Z' }) },
+          },
+        ],
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then the labels are stored without being truncated.
+  const candyGuard = findCandyGuardPda(umi, { base: base.publicKey });
+  const candyGuardAccount = await fetchCandyGuard(umi, candyGuard);
+  t.like(candyGuardAccount, <CandyGuard>{
+    publicKey: publicKey(candyGuard),
+    groups: [
+      {
+        label: 'VIP',
+        guards: {
+          ...emptyDefaultGuardSetArgs,
+          startDate: some({ date: dateTime('2022-09-05T16:00:00.000Z') }),
+        },
+      },
+      {
+        label: 'early-supporters-🍭',
+        guards: {
+          ...emptyDefaultGuardSetArgs,
+          endDate: some({ date: dateTime('2022-09-06T16:00:00.000Z') }),
+        },
+      },
+    ] as GuardGroup<GuardSet>[],
   });
 });
 
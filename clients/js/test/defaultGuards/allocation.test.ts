@@ -7,6 +7,7 @@ import {
 } from '@metaplex-foundation/umi';
 import test from 'ava';
 import {
+  deleteCandyMachine,
   fetchAllocationTracker,
   findCandyGuardPda,
   findAllocationTrackerPda,
@@ -290,3 +291,51 @@ test('it charges a bot tax when trying to mint after the limit', async (t) => {
   // Then we expect a bot tax error.
   await assertBotTax(t, umi, mintB, signature, /Allocation limit was reached/);
 });
+
+test('it allows the authority to close the allocation tracker once the candy machine is withdrawn', async (t) => {
+  // Given a loaded Candy Machine with an allocation limit of 5.
+  const umi = await createUmi();
+  const collection = (await createCollection(umi)).publicKey;
+  const { publicKey: candyMachine } = await createV2(umi, {
+    collection,
+    configLines: [{ name: 'Degen #1', uri: 'https://example.com/degen/1' }],
+    guards: {
+      allocation: some({ id: 1, limit: 5 }),
+    },
+  });
+
+  // And an initialized allocation PDA.
+  await transactionBuilder()
+    .add(
+      route(umi, {
+        candyMachine,
+        guard: 'allocation',
+        routeArgs: { id: 1, candyGuardAuthority: umi.identity },
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // And the Candy Machine was withdrawn.
+  await transactionBuilder()
+    .add(deleteCandyMachine(umi, { candyMachine }))
+    .sendAndConfirm(umi);
+
+  // When the authority closes the allocation PDA.
+  await transactionBuilder()
+    .add(
+      route(umi, {
+        candyMachine,
+        guard: 'allocation',
+        routeArgs: { path: 'close', id: 1, candyGuardAuthority: umi.identity },
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then the allocation PDA no longer exists.
+  const [trackerPda] = findAllocationTrackerPda(umi, {
+    id: 1,
+    candyMachine,
+    candyGuard: findCandyGuardPda(umi, { base: candyMachine })[0],
+  });
+  t.false(await umi.rpc.accountExists(trackerPda));
+});
@@ -9,6 +9,7 @@ import {
 } from '@metaplex-foundation/umi';
 import test from 'ava';
 import {
+  deleteCandyMachine,
   findAllowListProofPda,
   findCandyGuardPda,
   getMerkleProof,
@@ -471,3 +472,156 @@ test('it creates a proof for the payer when the minter is not present', async (t
     )
   );
 });
+
+test('it allows minting with a merkle proof included in the mint arguments', async (t) => {
+  // Given the identity is part of an allow list.
+  const umi = await createUmi();
+  const allowList = [
+    base58PublicKey(umi.identity),
+    'Ur1CbWSGsXCdedknRbJsEk7urwAvu1uddmQv51nAnXB',
+    'GjwcWFQYzemBtpUoN5fMAP2FZviTtMRWCmrppGuTthJS',
+  ];
+  const merkleRoot = getMerkleRoot(allowList);
+
+  // And given a loaded Candy Machine with the allow list guard.
+  const collection = (await createCollection(umi)).publicKey;
+  const { publicKey: candyMachine } = await createV2(umi, {
+    collection,
+    configLines: [{ name: 'Degen #1', uri: 'https://example.com/degen/1' }],
+    guards: {
+      allowList: some({ merkleRoot }),
+    },
+  });
+
+  // When we mint from the Candy Machine providing the merkle proof
+  // in the mint arguments, without using the route instruction.
+  const mint = generateSigner(umi);
+  await transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(
+      mintV1(umi, {
+        candyMachine,
+        asset: mint,
+        collection,
+        mintArgs: {
+          allowList: some({
+            merkleRoot,
+            merkleProof: getMerkleProof(
+              allowList,
+              base58PublicKey(umi.identity)
+            ),
+          }),
+        },
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then minting was successful.
+  await assertSuccessfulMint(t, umi, { mint, owner: umi.identity });
+});
+
+test('it forbids minting with a wrong merkle proof included in the mint arguments', async (t) => {
+  // Given the identity is not part of an allow list.
+  const umi = await createUmi();
+  const allowList = [
+    'Ur1CbWSGsXCdedknRbJsEk7urwAvu1uddmQv51nAnXB',
+    'GjwcWFQYzemBtpUoN5fMAP2FZviTtMRWCmrppGuTthJS',
+  ];
+  const merkleRoot = getMerkleRoot(allowList);
+
+  // And given a loaded Candy Machine with the allow list guard.
+  const collection = (await createCollection(umi)).publicKey;
+  const { publicKey: candyMachine } = await createV2(umi, {
+    collection,
+    configLines: [{ name: 'Degen #1', uri: 'https://example.com/degen/1' }],
+    guards: {
+      allowList: some({ merkleRoot }),
+    },
+  });
+
+  // When we try to mint providing the proof of another wallet.
+  const mint = generateSigner(umi);
+  const promise = transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(
+      mintV1(umi, {
+        candyMachine,
+        asset: mint,
+        collection,
+        mintArgs: {
+          allowList: some({
+            merkleRoot,
+            merkleProof: getMerkleProof(allowList, allowList[0]),
+          }),
+        },
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, { message: /AddressNotFoundInAllowedList/ });
+});
+
+test('it allows the authority to close an allow list proof once the candy machine is withdrawn', async (t) => {
+  // Given the identity is part of an allow list.
+  const umi = await createUmi();
+  const allowList = [
+    base58PublicKey(umi.identity),
+    'Ur1CbWSGsXCdedknRbJsEk7urwAvu1uddmQv51nAnXB',
+  ];
+  const merkleRoot = getMerkleRoot(allowList);
+
+  // And a loaded Candy Machine with the allow list guard.
+  const collection = (await createCollection(umi)).publicKey;
+  const { publicKey: candyMachine } = await createV2(umi, {
+    collection,
+    configLines: [{ name: 'Degen #1', uri: 'https://example.com/degen/1' }],
+    guards: {
+      allowList: some({ merkleRoot }),
+    },
+  });
+
+  // And the identity verified its proof.
+  await transactionBuilder()
+    .add(
+      route(umi, {
+        candyMachine,
+        guard: 'allowList',
+        routeArgs: {
+          path: 'proof',
+          merkleRoot,
+          merkleProof: getMerkleProof(allowList, base58PublicKey(umi.identity)),
+        },
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // And the Candy Machine was withdrawn.
+  await transactionBuilder()
+    .add(deleteCandyMachine(umi, { candyMachine }))
+    .sendAndConfirm(umi);
+
+  // When the authority closes the proof.
+  await transactionBuilder()
+    .add(
+      route(umi, {
+        candyMachine,
+        guard: 'allowList',
+        routeArgs: { path: 'close', merkleRoot, authority: umi.identity },
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then the proof no longer exists.
+  const [candyGuard] = findCandyGuardPda(umi, { base: candyMachine });
+  t.false(
+    await umi.rpc.accountExists(
+      findAllowListProofPda(umi, {
+        candyGuard,
+        candyMachine,
+        merkleRoot,
+        user: publicKey(umi.identity),
+      })[0]
+    )
+  );
+});
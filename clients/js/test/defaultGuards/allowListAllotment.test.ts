@@ -0,0 +1,240 @@
+import { setComputeUnitLimit } from '@metaplex-foundation/mpl-toolbox';
+import {
+  generateSigner,
+  isEqualToAmount,
+  none,
+  publicKey,
+  sol,
+  some,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import { generateSignerWithSol } from '@metaplex-foundation/umi-bundle-tests';
+import test from 'ava';
+import {
+  fetchAllotmentProofFromSeeds,
+  findCandyGuardPda,
+  getAllotmentEntrySerializer,
+  getMerkleProof,
+  getMerkleRoot,
+  mintV1,
+  route,
+} from '../../src';
+import {
+  assertSuccessfulMint,
+  createCollection,
+  createUmi,
+  createV2,
+} from '../_setup';
+
+test('it allows minting up to the allotment of the wallet', async (t) => {
+  // Given the identity is part of an allow list with an allotment of 2.
+  const umi = await createUmi();
+  const entries = [
+    { address: umi.identity.publicKey, allotment: 2, price: none<bigint>() },
+    {
+      address: publicKey('Ur1CbWSGsXCdedknRbJsEk7urwAvu1uddmQv51nAnXB'),
+      allotment: 5,
+      price: none<bigint>(),
+    },
+  ].map((entry) => getAllotmentEntrySerializer().serialize(entry));
+  const merkleRoot = getMerkleRoot(entries);
+
+  // And a loaded Candy Machine with an allowListAllotment guard.
+  const destination = generateSigner(umi).publicKey;
+  const collection = (await createCollection(umi)).publicKey;
+  const { publicKey: candyMachine } = await createV2(umi, {
+    collection,
+    configLines: [
+      { name: 'Degen #1', uri: 'https://example.com/degen/1' },
+      { name: 'Degen #2', uri: 'https://example.com/degen/2' },
+      { name: 'Degen #3', uri: 'https://example.com/degen/3' },
+    ],
+    guards: { allowListAllotment: some({ merkleRoot, destination }) },
+  });
+
+  // When we validate the entry of the identity.
+  await transactionBuilder()
+    .add(
+      route(umi, {
+        candyMachine,
+        guard: 'allowListAllotment',
+        routeArgs: {
+          path: 'proof',
+          merkleRoot,
+          allotment: 2,
+          price: none(),
+          merkleProof: getMerkleProof(entries, entries[0]),
+        },
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // And mint twice from the Candy Machine.
+  const mintFromCandyMachine = async () => {
+    const mint = generateSigner(umi);
+    await transactionBuilder()
+      .add(setComputeUnitLimit(umi, { units: 600_000 }))
+      .add(
+        mintV1(umi, {
+          candyMachine,
+          asset: mint,
+          collection,
+          mintArgs: { allowListAllotment: some({ merkleRoot }) },
+        })
+      )
+      .sendAndConfirm(umi);
+    return mint;
+  };
+  const mintA = await mintFromCandyMachine();
+  const mintB = await mintFromCandyMachine();
+  await assertSuccessfulMint(t, umi, { mint: mintA, owner: umi.identity });
+  await assertSuccessfulMint(t, umi, { mint: mintB, owner: umi.identity });
+
+  // Then the proof PDA tracks the mints of the identity.
+  const proof = await fetchAllotmentProofFromSeeds(umi, {
+    merkleRoot,
+    user: umi.identity.publicKey,
+    candyGuard: findCandyGuardPda(umi, { base: candyMachine })[0],
+    candyMachine,
+  });
+  t.is(proof.allotment, 2);
+  t.is(proof.minted, 2);
+
+  // And the identity cannot mint a third time.
+  await t.throwsAsync(mintFromCandyMachine(), {
+    message: /AllowedMintLimitReached/,
+  });
+});
+
+test('it charges the price of the entry of the wallet', async (t) => {
+  // Given a minter that is part of an allow list with a price of 1 SOL.
+  const umi = await createUmi();
+  const minter = await generateSignerWithSol(umi, sol(10));
+  const entries = [
+    {
+      address: minter.publicKey,
+      allotment: 1,
+      price: some(sol(1).basisPoints),
+    },
+    { address: umi.identity.publicKey, allotment: 1, price: none<bigint>() },
+  ].map((entry) => getAllotmentEntrySerializer().serialize(entry));
+  const merkleRoot = getMerkleRoot(entries);
+
+  // And a loaded Candy Machine with an allowListAllotment guard.
+  const destination = generateSigner(umi).publicKey;
+  const collection = (await createCollection(umi)).publicKey;
+  const { publicKey: candyMachine } = await createV2(umi, {
+    collection,
+    configLines: [{ name: 'Degen #1', uri: 'https://example.com/degen/1' }],
+    guards: { allowListAllotment: some({ merkleRoot, destination }) },
+  });
+
+  // When the minter validates its entry and mints from the Candy Machine.
+  const mint = generateSigner(umi);
+  await transactionBuilder()
+    .add(
+      route(umi, {
+        candyMachine,
+        guard: 'allowListAllotment',
+        payer: minter,
+        routeArgs: {
+          path: 'proof',
+          merkleRoot,
+          allotment: 1,
+          price: some(sol(1).basisPoints),
+          merkleProof: getMerkleProof(entries, entries[0]),
+        },
+      })
+    )
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(
+      mintV1(umi, {
+        candyMachine,
+        asset: mint,
+        minter,
+        payer: minter,
+        collection,
+        mintArgs: { allowListAllotment: some({ merkleRoot, destination }) },
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then minting was successful.
+  await assertSuccessfulMint(t, umi, { mint, owner: minter });
+
+  // And the destination received the price of the entry.
+  const destinationBalance = await umi.rpc.getBalance(destination);
+  t.true(isEqualToAmount(destinationBalance, sol(1)), 'destination got SOLs');
+});
+
+test('it forbids minting without validating the entry first', async (t) => {
+  // Given the identity is part of an allow list.
+  const umi = await createUmi();
+  const entries = [
+    { address: umi.identity.publicKey, allotment: 1, price: none<bigint>() },
+  ].map((entry) => getAllotmentEntrySerializer().serialize(entry));
+  const merkleRoot = getMerkleRoot(entries);
+
+  // And a loaded Candy Machine with an allowListAllotment guard.
+  const destination = generateSigner(umi).publicKey;
+  const collection = (await createCollection(umi)).publicKey;
+  const { publicKey: candyMachine } = await createV2(umi, {
+    collection,
+    configLines: [{ name: 'Degen #1', uri: 'https://example.com/degen/1' }],
+    guards: { allowListAllotment: some({ merkleRoot, destination }) },
+  });
+
+  // When we try to mint without validating the entry of the identity.
+  const promise = transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(
+      mintV1(umi, {
+        candyMachine,
+        asset: generateSigner(umi),
+        collection,
+        mintArgs: { allowListAllotment: some({ merkleRoot }) },
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, { message: /MissingAllowedListProof/ });
+});
+
+test('it forbids validating an entry with a different allotment', async (t) => {
+  // Given the identity is part of an allow list with an allotment of 1.
+  const umi = await createUmi();
+  const entries = [
+    { address: umi.identity.publicKey, allotment: 1, price: none<bigint>() },
+  ].map((entry) => getAllotmentEntrySerializer().serialize(entry));
+  const merkleRoot = getMerkleRoot(entries);
+
+  // And a loaded Candy Machine with an allowListAllotment guard.
+  const destination = generateSigner(umi).publicKey;
+  const collection = (await createCollection(umi)).publicKey;
+  const { publicKey: candyMachine } = await createV2(umi, {
+    collection,
+    configLines: [{ name: 'Degen #1', uri: 'https://example.com/degen/1' }],
+    guards: { allowListAllotment: some({ merkleRoot, destination }) },
+  });
+
+  // When we try to validate the entry of the identity with an allotment of 5.
+  const promise = transactionBuilder()
+    .add(
+      route(umi, {
+        candyMachine,
+        guard: 'allowListAllotment',
+        routeArgs: {
+          path: 'proof',
+          merkleRoot,
+          allotment: 5,
+          price: none(),
+          merkleProof: getMerkleProof(entries, entries[0]),
+        },
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, { message: /AddressNotFoundInAllowedList/ });
+});
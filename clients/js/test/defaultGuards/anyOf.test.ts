@@ -0,0 +1,225 @@
+import { setComputeUnitLimit } from '@metaplex-foundation/mpl-toolbox';
+import {
+  generateSigner,
+  isEqualToAmount,
+  sol,
+  some,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import { generateSignerWithSol } from '@metaplex-foundation/umi-bundle-tests';
+import test from 'ava';
+import { GuardType, mintV1 } from '../../src';
+import {
+  assertSuccessfulMint,
+  createCollection,
+  createUmi,
+  createV2,
+} from '../_setup';
+
+test('it allows minting when the first alternative validates', async (t) => {
+  // Given a Candy Machine with an anyOf guard such that minters
+  // either pay 1 SOL or are the allowed address.
+  const umi = await createUmi();
+  const destination = generateSigner(umi).publicKey;
+  const allowedAddress = generateSigner(umi).publicKey;
+  const collection = (await createCollection(umi)).publicKey;
+  const { publicKey: candyMachine } = await createV2(umi, {
+    collection,
+    configLines: [{ name: 'Degen #1', uri: 'https://example.com/degen/1' }],
+    guards: {
+      anyOf: some({ guards: [GuardType.SolPayment, GuardType.AddressGate] }),
+      solPayment: some({ lamports: sol(1), destination }),
+      addressGate: some({ address: allowedAddress }),
+    },
+  });
+
+  // When we mint from it by paying.
+  const payer = await generateSignerWithSol(umi, sol(10));
+  const mint = generateSigner(umi);
+  await transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(
+      mintV1(umi, {
+        candyMachine,
+        asset: mint,
+        payer,
+        collection,
+        mintArgs: {
+          anyOf: some({ guard: 'solPayment' }),
+          solPayment: some({ destination }),
+        },
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then minting was successful.
+  await assertSuccessfulMint(t, umi, { mint, owner: umi.identity });
+
+  // And the treasury received SOLs.
+  const treasuryBalance = await umi.rpc.getBalance(destination);
+  t.true(isEqualToAmount(treasuryBalance, sol(1)), 'treasury received SOLs');
+});
+
+test('it allows minting when another alternative validates', async (t) => {
+  // Given a Candy Machine with an anyOf guard such that minters
+  // either pay 1 SOL or are the identity.
+  const umi = await createUmi();
+  const destination = generateSigner(umi).publicKey;
+  const collection = (await createCollection(umi)).publicKey;
+  const { publicKey: candyMachine } = await createV2(umi, {
+    collection,
+    configLines: [{ name: 'Degen #1', uri: 'https://example.com/degen/1' }],
+    guards: {
+      anyOf: some({ guards: [GuardType.SolPayment, GuardType.AddressGate] }),
+      solPayment: some({ lamports: sol(1), destination }),
+      addressGate: some({ address: umi.identity.publicKey }),
+    },
+  });
+
+  // When the identity mints from it without paying.
+  const mint = generateSigner(umi);
+  await transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(
+      mintV1(umi, {
+        candyMachine,
+        asset: mint,
+        collection,
+        mintArgs: { anyOf: some({ guard: 'addressGate' }) },
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then minting was successful.
+  await assertSuccessfulMint(t, umi, { mint, owner: umi.identity });
+
+  // And the treasury did not receive any SOLs.
+  t.false(await umi.rpc.accountExists(destination));
+});
+
+test('it fails with the error of the selected alternative', async (t) => {
+  // Given a Candy Machine with an anyOf guard such that minters
+  // either pay 1 SOL or are a specific address.
+  const umi = await createUmi();
+  const destination = generateSigner(umi).publicKey;
+  const allowedAddress = generateSigner(umi).publicKey;
+  const collection = (await createCollection(umi)).publicKey;
+  const { publicKey: candyMachine } = await createV2(umi, {
+    collection,
+    configLines: [{ name: 'Degen #1', uri: 'https://example.com/degen/1' }],
+    guards: {
+      anyOf: some({ guards: [GuardType.SolPayment, GuardType.AddressGate] }),
+      solPayment: some({ lamports: sol(1), destination }),
+      addressGate: some({ address: allowedAddress }),
+    },
+  });
+
+  // When we try to mint from it without paying using another address.
+  const mint = generateSigner(umi);
+  const promise = transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(
+      mintV1(umi, {
+        candyMachine,
+        asset: mint,
+        collection,
+        mintArgs: { anyOf: some({ guard: 'addressGate' }) },
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then we expect the error of the addressGate guard.
+  await t.throwsAsync(promise, { message: /AddressNotAuthorized/ });
+});
+
+test('it fails with the payment error when the selected alternative cannot pay', async (t) => {
+  // Given a Candy Machine with an anyOf guard such that minters
+  // either pay 5 SOL or are a specific address.
+  const umi = await createUmi();
+  const destination = generateSigner(umi).publicKey;
+  const allowedAddress = generateSigner(umi).publicKey;
+  const collection = (await createCollection(umi)).publicKey;
+  const { publicKey: candyMachine } = await createV2(umi, {
+    collection,
+    configLines: [{ name: 'Degen #1', uri: 'https://example.com/degen/1' }],
+    guards: {
+      anyOf: some({ guards: [GuardType.SolPayment, GuardType.AddressGate] }),
+      solPayment: some({ lamports: sol(5), destination }),
+      addressGate: some({ address: allowedAddress }),
+    },
+  });
+
+  // When a payer with only 1 SOL tries to mint by paying.
+  const payer = await generateSignerWithSol(umi, sol(1));
+  const promise = transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(
+      mintV1(umi, {
+        candyMachine,
+        asset: generateSigner(umi),
+        payer,
+        collection,
+        mintArgs: {
+          anyOf: some({ guard: 'solPayment' }),
+          solPayment: some({ destination }),
+        },
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then we expect the error of the solPayment guard.
+  await t.throwsAsync(promise, { message: /NotEnoughSOL/ });
+});
+
+test('it forbids selecting a guard that is not an alternative', async (t) => {
+  // Given a Candy Machine with an anyOf guard listing
+  // the solPayment and addressGate guards.
+  const umi = await createUmi();
+  const destination = generateSigner(umi).publicKey;
+  const collection = (await createCollection(umi)).publicKey;
+  const { publicKey: candyMachine } = await createV2(umi, {
+    collection,
+    configLines: [{ name: 'Degen #1', uri: 'https://example.com/degen/1' }],
+    guards: {
+      anyOf: some({ guards: [GuardType.SolPayment, GuardType.AddressGate] }),
+      solPayment: some({ lamports: sol(1), destination }),
+      addressGate: some({ address: umi.identity.publicKey }),
+    },
+  });
+
+  // When we try to mint from it selecting another guard.
+  const promise = transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(
+      mintV1(umi, {
+        candyMachine,
+        asset: generateSigner(umi),
+        collection,
+        mintArgs: { anyOf: some({ guard: 'startDate' }) },
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, { message: /AnyOfConditionsNotMet/ });
+});
+
+test('it fails if an alternative is not enabled on the guard set', async (t) => {
+  // Given an anyOf guard listing a guard that is not enabled.
+  const umi = await createUmi();
+  const destination = generateSigner(umi).publicKey;
+  const collection = (await createCollection(umi)).publicKey;
+
+  // When we try to create a Candy Machine with it.
+  const promise = createV2(umi, {
+    collection,
+    configLines: [{ name: 'Degen #1', uri: 'https://example.com/degen/1' }],
+    guards: {
+      anyOf: some({ guards: [GuardType.SolPayment, GuardType.AddressGate] }),
+      solPayment: some({ lamports: sol(1), destination }),
+    },
+  });
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, { message: /InvalidAnyOfConfig/ });
+});
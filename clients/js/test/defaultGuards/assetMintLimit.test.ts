@@ -9,10 +9,12 @@ import test from 'ava';
 
 import { transferV1 } from '@metaplex-foundation/mpl-core';
 import {
+  deleteCandyMachine,
   fetchAssetMintCounter,
   findCandyGuardPda,
   findAssetMintCounterPda,
   mintV1,
+  route,
 } from '../../src';
 import {
   assertBotTax,
@@ -484,3 +486,72 @@ test('it charges a bot tax when trying to mint after the limit', async (t) => {
   // Then we expect a bot tax error.
   await assertBotTax(t, umi, mintB, signature, /AllowedMintLimitReached/);
 });
+
+test('it allows the authority to close an asset mint counter once the candy machine is withdrawn', async (t) => {
+  // Given a loaded Candy Machine with an asset mint limit.
+  const umi = await createUmi();
+  const [assetToVerify, requiredCollection] = await createAssetWithCollection(
+    umi
+  );
+
+  const collection = (await createCollection(umi)).publicKey;
+  const { publicKey: candyMachine } = await createV2(umi, {
+    collection,
+    configLines: [
+      { name: 'Degen #1', uri: 'https://example.com/degen/1' },
+      { name: 'Degen #2', uri: 'https://example.com/degen/2' },
+    ],
+    guards: {
+      assetMintLimit: some({
+        id: 1,
+        limit: 5,
+        requiredCollection: requiredCollection.publicKey,
+      }),
+    },
+  });
+
+  // And we minted from it.
+  await transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(
+      mintV1(umi, {
+        candyMachine,
+        asset: generateSigner(umi),
+        collection,
+        mintArgs: {
+          assetMintLimit: some({ id: 1, asset: assetToVerify.publicKey }),
+        },
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // And the Candy Machine was withdrawn.
+  await transactionBuilder()
+    .add(deleteCandyMachine(umi, { candyMachine }))
+    .sendAndConfirm(umi);
+
+  // When the authority closes the asset mint counter.
+  await transactionBuilder()
+    .add(
+      route(umi, {
+        candyMachine,
+        guard: 'assetMintLimit',
+        routeArgs: {
+          path: 'close',
+          id: 1,
+          asset: assetToVerify.publicKey,
+          authority: umi.identity,
+        },
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then the asset mint counter no longer exists.
+  const [counterPda] = findAssetMintCounterPda(umi, {
+    id: 1,
+    asset: assetToVerify.publicKey,
+    candyGuard: findCandyGuardPda(umi, { base: candyMachine })[0],
+    candyMachine,
+  });
+  t.false(await umi.rpc.accountExists(counterPda));
+});
@@ -0,0 +1,295 @@
+import { setComputeUnitLimit } from '@metaplex-foundation/mpl-toolbox';
+import {
+  generateSigner,
+  PublicKey,
+  sol,
+  some,
+  transactionBuilder,
+  Umi,
+} from '@metaplex-foundation/umi';
+import test from 'ava';
+import { pluginAuthorityPair } from '@metaplex-foundation/mpl-core';
+import { mintV1 } from '../../src';
+import {
+  assertBotTax,
+  assertSuccessfulMint,
+  createAsset,
+  createCollection,
+  createUmi,
+  createV2,
+} from '../_setup';
+
+const attributes = (attributeList: { key: string; value: string }[]) => [
+  pluginAuthorityPair({ type: 'Attributes', data: { attributeList } }),
+];
+
+const createGatingAsset = async (
+  umi: Umi,
+  input: {
+    assetAttributes: { key: string; value: string }[];
+    collectionAttributes?: { key: string; value: string }[];
+  }
+) => {
+  const requiredCollectionAuthority = generateSigner(umi);
+  const { publicKey: requiredCollection } = await createCollection(umi, {
+    updateAuthority: requiredCollectionAuthority.publicKey,
+    plugins: attributes(input.collectionAttributes ?? []),
+  });
+  const { publicKey: asset } = await createAsset(umi, {
+    owner: umi.identity.publicKey,
+    collection: requiredCollection,
+    authority: requiredCollectionAuthority,
+    plugins: attributes(input.assetAttributes),
+  });
+  return { asset, requiredCollection };
+};
+
+const createTraitGatedCandyMachine = async (
+  umi: Umi,
+  requiredCollection: PublicKey,
+  includeCollection = false
+) => {
+  const collection = (await createCollection(umi)).publicKey;
+  const { publicKey: candyMachine } = await createV2(umi, {
+    collection,
+    configLines: [{ name: 'Degen #1', uri: 'https://example.com/degen/1' }],
+    guards: {
+      assetTraitGate: some({
+        requiredCollection,
+        includeCollection,
+        traits: [{ key: 'Background', value: 'Gold' }],
+      }),
+    },
+  });
+  return { candyMachine, collection };
+};
+
+test('it allows minting when the payer owns an asset with the required traits', async (t) => {
+  // Given the identity owns an asset with a gold background.
+  const umi = await createUmi();
+  const { asset, requiredCollection } = await createGatingAsset(umi, {
+    assetAttributes: [
+      { key: 'Background', value: 'Gold' },
+      { key: 'Eyes', value: 'Laser' },
+    ],
+  });
+
+  // And a loaded Candy Machine requiring a gold background.
+  const { candyMachine, collection } = await createTraitGatedCandyMachine(
+    umi,
+    requiredCollection
+  );
+
+  // When we mint from it.
+  const mint = generateSigner(umi);
+  await transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(
+      mintV1(umi, {
+        candyMachine,
+        asset: mint,
+        collection,
+        mintArgs: { assetTraitGate: some({ asset }) },
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then minting was successful.
+  await assertSuccessfulMint(t, umi, { mint, owner: umi.identity });
+});
+
+test('it forbids minting when the asset has a different trait value', async (t) => {
+  // Given the identity owns an asset with a silver background.
+  const umi = await createUmi();
+  const { asset, requiredCollection } = await createGatingAsset(umi, {
+    assetAttributes: [{ key: 'Background', value: 'Silver' }],
+  });
+
+  // And a loaded Candy Machine requiring a gold background.
+  const { candyMachine, collection } = await createTraitGatedCandyMachine(
+    umi,
+    requiredCollection
+  );
+
+  // When we try to mint from it.
+  const promise = transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(
+      mintV1(umi, {
+        candyMachine,
+        asset: generateSigner(umi),
+        collection,
+        mintArgs: { assetTraitGate: some({ asset }) },
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, { message: /MissingAssetTrait/ });
+});
+
+test('it allows minting with a trait of the collection when included', async (t) => {
+  // Given the identity owns an asset from a collection with a gold background.
+  const umi = await createUmi();
+  const { asset, requiredCollection } = await createGatingAsset(umi, {
+    assetAttributes: [{ key: 'Eyes', value: 'Laser' }],
+    collectionAttributes: [{ key: 'Background', value: 'Gold' }],
+  });
+
+  // And a loaded Candy Machine requiring a gold background,
+  // including the traits of the collection.
+  const { candyMachine, collection } = await createTraitGatedCandyMachine(
+    umi,
+    requiredCollection,
+    true
+  );
+
+  // When we mint from it.
+  const mint = generateSigner(umi);
+  await transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(
+      mintV1(umi, {
+        candyMachine,
+        asset: mint,
+        collection,
+        mintArgs: {
+          assetTraitGate: some({ asset, collection: requiredCollection }),
+        },
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then minting was successful.
+  await assertSuccessfulMint(t, umi, { mint, owner: umi.identity });
+});
+
+test('it ignores the traits of the collection when not included', async (t) => {
+  // Given the identity owns an asset from a collection with a gold background.
+  const umi = await createUmi();
+  const { asset, requiredCollection } = await createGatingAsset(umi, {
+    assetAttributes: [{ key: 'Eyes', value: 'Laser' }],
+    collectionAttributes: [{ key: 'Background', value: 'Gold' }],
+  });
+
+  // And a loaded Candy Machine requiring a gold background,
+  // excluding the traits of the collection.
+  const { candyMachine, collection } = await createTraitGatedCandyMachine(
+    umi,
+    requiredCollection
+  );
+
+  // When we try to mint from it.
+  const promise = transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(
+      mintV1(umi, {
+        candyMachine,
+        asset: generateSigner(umi),
+        collection,
+        mintArgs: { assetTraitGate: some({ asset }) },
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, { message: /MissingAssetTrait/ });
+});
+
+test('the traits of the asset take precedence over the ones of the collection', async (t) => {
+  // Given the identity owns an asset with a silver background
+  // from a collection with a gold background.
+  const umi = await createUmi();
+  const { asset, requiredCollection } = await createGatingAsset(umi, {
+    assetAttributes: [{ key: 'Background', value: 'Silver' }],
+    collectionAttributes: [{ key: 'Background', value: 'Gold' }],
+  });
+
+  // And a loaded Candy Machine requiring a gold background,
+  // including the traits of the collection.
+  const { candyMachine, collection } = await createTraitGatedCandyMachine(
+    umi,
+    requiredCollection,
+    true
+  );
+
+  // When we try to mint from it.
+  const promise = transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(
+      mintV1(umi, {
+        candyMachine,
+        asset: generateSigner(umi),
+        collection,
+        mintArgs: {
+          assetTraitGate: some({ asset, collection: requiredCollection }),
+        },
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, { message: /MissingAssetTrait/ });
+});
+
+test('it forbids creating an asset trait gate without traits', async (t) => {
+  // Given an existing collection.
+  const umi = await createUmi();
+  const collection = (await createCollection(umi)).publicKey;
+
+  // When we try to create a Candy Machine without required traits.
+  const promise = createV2(umi, {
+    collection,
+    configLines: [{ name: 'Degen #1', uri: 'https://example.com/degen/1' }],
+    guards: {
+      assetTraitGate: some({
+        requiredCollection: generateSigner(umi).publicKey,
+        includeCollection: false,
+        traits: [],
+      }),
+    },
+  });
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, { message: /InvalidAssetTraitGate/ });
+});
+
+test('it charges a bot tax when the asset does not have the required traits', async (t) => {
+  // Given the identity owns an asset with a silver background.
+  const umi = await createUmi();
+  const { asset, requiredCollection } = await createGatingAsset(umi, {
+    assetAttributes: [{ key: 'Background', value: 'Silver' }],
+  });
+
+  // And a loaded Candy Machine with a bot tax, requiring a gold background.
+  const collection = (await createCollection(umi)).publicKey;
+  const { publicKey: candyMachine } = await createV2(umi, {
+    collection,
+    configLines: [{ name: 'Degen #1', uri: 'https://example.com/degen/1' }],
+    guards: {
+      botTax: some({ lamports: sol(0.01), lastInstruction: true }),
+      assetTraitGate: some({
+        requiredCollection,
+        includeCollection: false,
+        traits: [{ key: 'Background', value: 'Gold' }],
+      }),
+    },
+  });
+
+  // When we try to mint from it.
+  const mint = generateSigner(umi);
+  const { signature } = await transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(
+      mintV1(umi, {
+        candyMachine,
+        asset: mint,
+        collection,
+        mintArgs: { assetTraitGate: some({ asset }) },
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then we expect a silent bot tax error.
+  await assertBotTax(t, umi, mint, signature, /MissingAssetTrait/);
+});
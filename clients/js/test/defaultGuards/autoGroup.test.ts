@@ -0,0 +1,199 @@
+import { setComputeUnitLimit } from '@metaplex-foundation/mpl-toolbox';
+import {
+  generateSigner,
+  isEqualToAmount,
+  sol,
+  some,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import { generateSignerWithSol } from '@metaplex-foundation/umi-bundle-tests';
+import test from 'ava';
+import { mintV1 } from '../../src';
+import {
+  assertSuccessfulMint,
+  createCollection,
+  createUmi,
+  createV2,
+} from '../_setup';
+
+test('it selects the first eligible group when no group is provided', async (t) => {
+  // Given a Candy Machine with an autoGroup guard and two groups:
+  // - WL: only allows a specific address.
+  // - PUBLIC: charges 1 SOL.
+  const umi = await createUmi();
+  const destination = generateSigner(umi).publicKey;
+  const allowedAddress = generateSigner(umi).publicKey;
+  const collection = (await createCollection(umi)).publicKey;
+  const { publicKey: candyMachine } = await createV2(umi, {
+    collection,
+    configLines: [{ name: 'Degen #1', uri: 'https://example.com/degen/1' }],
+    guards: { autoGroup: some({}) },
+    groups: [
+      {
+        label: 'WL',
+        guards: { addressGate: some({ address: allowedAddress }) },
+      },
+      {
+        label: 'PUBLIC',
+        guards: { solPayment: some({ lamports: sol(1), destination }) },
+      },
+    ],
+  });
+
+  // When we mint from it without providing a group.
+  const payer = await generateSignerWithSol(umi, sol(10));
+  const mint = generateSigner(umi);
+  await transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(
+      mintV1(umi, {
+        candyMachine,
+        asset: mint,
+        payer,
+        collection,
+        mintArgs: { solPayment: some({ destination }) },
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then minting was successful.
+  await assertSuccessfulMint(t, umi, { mint, owner: umi.identity });
+
+  // And the payment of the PUBLIC group was charged.
+  const treasuryBalance = await umi.rpc.getBalance(destination);
+  t.true(isEqualToAmount(treasuryBalance, sol(1)), 'treasury received SOLs');
+});
+
+test('it selects an earlier group when the minter is eligible', async (t) => {
+  // Given a Candy Machine with an autoGroup guard and two groups:
+  // - WL: only allows the identity.
+  // - PUBLIC: charges 1 SOL.
+  const umi = await createUmi();
+  const destination = generateSigner(umi).publicKey;
+  const collection = (await createCollection(umi)).publicKey;
+  const { publicKey: candyMachine } = await createV2(umi, {
+    collection,
+    configLines: [{ name: 'Degen #1', uri: 'https://example.com/degen/1' }],
+    guards: { autoGroup: some({}) },
+    groups: [
+      {
+        label: 'WL',
+        guards: { addressGate: some({ address: umi.identity.publicKey }) },
+      },
+      {
+        label: 'PUBLIC',
+        guards: { solPayment: some({ lamports: sol(1), destination }) },
+      },
+    ],
+  });
+
+  // When the identity mints from it without providing a group.
+  const mint = generateSigner(umi);
+  await transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(mintV1(umi, { candyMachine, asset: mint, collection }))
+    .sendAndConfirm(umi);
+
+  // Then minting was successful through the WL group.
+  await assertSuccessfulMint(t, umi, { mint, owner: umi.identity });
+  t.false(await umi.rpc.accountExists(destination));
+});
+
+test('it fails when no group is eligible', async (t) => {
+  // Given a Candy Machine with an autoGroup guard and a single
+  // group that only allows a specific address.
+  const umi = await createUmi();
+  const allowedAddress = generateSigner(umi).publicKey;
+  const collection = (await createCollection(umi)).publicKey;
+  const { publicKey: candyMachine } = await createV2(umi, {
+    collection,
+    configLines: [{ name: 'Degen #1', uri: 'https://example.com/degen/1' }],
+    guards: { autoGroup: some({}) },
+    groups: [
+      {
+        label: 'WL',
+        guards: { addressGate: some({ address: allowedAddress }) },
+      },
+    ],
+  });
+
+  // When we try to mint from it without providing a group.
+  const mint = generateSigner(umi);
+  const promise = transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(mintV1(umi, { candyMachine, asset: mint, collection }))
+    .sendAndConfirm(umi);
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, { message: /NoEligibleGroup/ });
+});
+
+test('it does not charge a bot tax when a group reached its rate limit', async (t) => {
+  // Given a Candy Machine with an autoGroup guard, a bot tax and a
+  // single group with a rate limit of 1 mint.
+  const umi = await createUmi();
+  const collection = (await createCollection(umi)).publicKey;
+  const { publicKey: candyMachine } = await createV2(umi, {
+    collection,
+    configLines: [
+      { name: 'Degen #1', uri: 'https://example.com/degen/1' },
+      { name: 'Degen #2', uri: 'https://example.com/degen/2' },
+    ],
+    guards: {
+      autoGroup: some({}),
+      botTax: some({ lamports: sol(0.1), lastInstruction: true }),
+    },
+    groups: [
+      {
+        label: 'RL',
+        guards: { rateLimit: some({ id: 1, limit: 1, window: 3600 }) },
+      },
+    ],
+  });
+
+  // And the identity already minted from it without providing a group.
+  await transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(
+      mintV1(umi, {
+        candyMachine,
+        asset: generateSigner(umi),
+        collection,
+        mintArgs: { rateLimit: some({ id: 1 }) },
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // When the identity tries to mint again without providing a group.
+  const promise = transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(
+      mintV1(umi, {
+        candyMachine,
+        asset: generateSigner(umi),
+        collection,
+        mintArgs: { rateLimit: some({ id: 1 }) },
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then the transaction fails with the rate limit error instead of
+  // charging a bot tax.
+  await t.throwsAsync(promise, { message: /RateLimitExceeded/ });
+});
+
+test('it can only be enabled on the default guard set', async (t) => {
+  // Given a collection.
+  const umi = await createUmi();
+  const collection = (await createCollection(umi)).publicKey;
+
+  // When we try to create a Candy Machine with an autoGroup guard on a group.
+  const promise = createV2(umi, {
+    collection,
+    configLines: [{ name: 'Degen #1', uri: 'https://example.com/degen/1' }],
+    groups: [{ label: 'WL', guards: { autoGroup: some({}) } }],
+  });
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, { message: /AutoGroupNotInDefault/ });
+});
@@ -0,0 +1,197 @@
+import {
+  fetchToken,
+  setComputeUnitLimit,
+} from '@metaplex-foundation/mpl-toolbox';
+import {
+  generateSigner,
+  isEqualToAmount,
+  none,
+  sol,
+  some,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import { generateSignerWithSol } from '@metaplex-foundation/umi-bundle-tests';
+import test from 'ava';
+import { BondingCurveType, mintV1 } from '../../src';
+import {
+  assertSuccessfulMint,
+  createCollection,
+  createMintWithHolders,
+  createUmi,
+  createV2,
+} from '../_setup';
+
+test('it charges a price in SOL that increases linearly', async (t) => {
+  // Given a Candy Machine with 2 items and a linear bondingCurve guard
+  // starting at 1 SOL and reaching 2 SOL once all items are redeemed.
+  const umi = await createUmi();
+  const destination = generateSigner(umi).publicKey;
+  const collection = (await createCollection(umi)).publicKey;
+  const { publicKey: candyMachine } = await createV2(umi, {
+    collection,
+    configLines: [
+      { name: 'Degen #1', uri: 'https://example.com/degen/1' },
+      { name: 'Degen #2', uri: 'https://example.com/degen/2' },
+    ],
+    guards: {
+      bondingCurve: some({
+        curve: BondingCurveType.Linear,
+        startPrice: sol(1).basisPoints,
+        factor: sol(1).basisPoints,
+        maxPrice: none(),
+        mint: none(),
+        destination,
+      }),
+    },
+  });
+
+  // When we mint the first item.
+  const payer = await generateSignerWithSol(umi, sol(10));
+  const mintA = generateSigner(umi);
+  await transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(
+      mintV1(umi, {
+        candyMachine,
+        asset: mintA,
+        payer,
+        collection,
+        mintArgs: { bondingCurve: some({ mint: none(), destination }) },
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then minting was successful and the treasury received the start price.
+  await assertSuccessfulMint(t, umi, { mint: mintA, owner: umi.identity });
+  let treasuryBalance = await umi.rpc.getBalance(destination);
+  t.true(isEqualToAmount(treasuryBalance, sol(1)), 'treasury received SOLs');
+
+  // When we mint the second item.
+  const mintB = generateSigner(umi);
+  await transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(
+      mintV1(umi, {
+        candyMachine,
+        asset: mintB,
+        payer,
+        collection,
+        mintArgs: { bondingCurve: some({ mint: none(), destination }) },
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then the treasury received the price at half of the curve.
+  await assertSuccessfulMint(t, umi, { mint: mintB, owner: umi.identity });
+  treasuryBalance = await umi.rpc.getBalance(destination);
+  t.true(isEqualToAmount(treasuryBalance, sol(2.5)), 'treasury received SOLs');
+});
+
+test('it charges a price in tokens that increases exponentially', async (t) => {
+  // Given a mint account such that:
+  // - The destination treasury has 100 tokens.
+  // - The payer has 500 tokens.
+  const umi = await createUmi();
+  const destination = generateSigner(umi).publicKey;
+  const [tokenMint, destinationAta, identityAta] = await createMintWithHolders(
+    umi,
+    {
+      holders: [
+        { owner: destination, amount: 100 },
+        { owner: umi.identity, amount: 500 },
+      ],
+    }
+  );
+
+  // And a Candy Machine with 2 items and an exponential bondingCurve guard
+  // starting at 100 tokens and growing by 1% for each percent redeemed.
+  const collection = (await createCollection(umi)).publicKey;
+  const { publicKey: candyMachine } = await createV2(umi, {
+    collection,
+    configLines: [
+      { name: 'Degen #1', uri: 'https://example.com/degen/1' },
+      { name: 'Degen #2', uri: 'https://example.com/degen/2' },
+    ],
+    guards: {
+      bondingCurve: some({
+        curve: BondingCurveType.Exponential,
+        startPrice: 100,
+        factor: 100,
+        maxPrice: some(150),
+        mint: some(tokenMint.publicKey),
+        destination: destinationAta,
+      }),
+    },
+  });
+
+  // When we mint both items.
+  const mintArgs = {
+    bondingCurve: some({
+      mint: some(tokenMint.publicKey),
+      destination: destinationAta,
+    }),
+  };
+  const mintA = generateSigner(umi);
+  await transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(mintV1(umi, { candyMachine, asset: mintA, collection, mintArgs }))
+    .sendAndConfirm(umi);
+  const mintB = generateSigner(umi);
+  await transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(mintV1(umi, { candyMachine, asset: mintB, collection, mintArgs }))
+    .sendAndConfirm(umi);
+
+  // Then minting was successful.
+  await assertSuccessfulMint(t, umi, { mint: mintA, owner: umi.identity });
+  await assertSuccessfulMint(t, umi, { mint: mintB, owner: umi.identity });
+
+  // And the treasury received 100 tokens and then 150 tokens, since
+  // the price of 1.01^50 * 100 tokens is capped by the maximum price.
+  const destinationTokenAccount = await fetchToken(umi, destinationAta);
+  t.is(destinationTokenAccount.amount, 350n);
+
+  // And the payer lost 250 tokens.
+  const payerTokenAccount = await fetchToken(umi, identityAta);
+  t.is(payerTokenAccount.amount, 250n);
+});
+
+test('it fails if the payer does not have enough funds', async (t) => {
+  // Given a Candy Machine with a bondingCurve guard starting at 5 SOL.
+  const umi = await createUmi();
+  const destination = generateSigner(umi).publicKey;
+  const collection = (await createCollection(umi)).publicKey;
+  const { publicKey: candyMachine } = await createV2(umi, {
+    collection,
+    configLines: [{ name: 'Degen #1', uri: 'https://example.com/degen/1' }],
+    guards: {
+      bondingCurve: some({
+        curve: BondingCurveType.Linear,
+        startPrice: sol(5).basisPoints,
+        factor: sol(1).basisPoints,
+        maxPrice: none(),
+        mint: none(),
+        destination,
+      }),
+    },
+  });
+
+  // When we mint from it using a payer that only has 4 SOL.
+  const payer = await generateSignerWithSol(umi, sol(4));
+  const mint = generateSigner(umi);
+  const promise = transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(
+      mintV1(umi, {
+        candyMachine,
+        asset: mint,
+        payer,
+        collection,
+        mintArgs: { bondingCurve: some({ mint: none(), destination }) },
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then we expect an error.
+  await t.throwsAsync(promise, { message: /NotEnoughSOL/ });
+});
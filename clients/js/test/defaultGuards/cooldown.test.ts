@@ -0,0 +1,270 @@
+import { setComputeUnitLimit } from '@metaplex-foundation/mpl-toolbox';
+import {
+  generateSigner,
+  sol,
+  some,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import { generateSignerWithSol } from '@metaplex-foundation/umi-bundle-tests';
+import test from 'ava';
+import {
+  fetchCooldownTrackerFromSeeds,
+  findCandyGuardPda,
+  findCooldownTrackerPda,
+  mintV1,
+  route,
+} from '../../src';
+import {
+  assertBotTax,
+  assertSuccessfulMint,
+  createCollection,
+  createUmi,
+  createV2,
+} from '../_setup';
+
+test('it allows minting when the wallet has not minted yet', async (t) => {
+  // Given a loaded Candy Machine with a cooldown of one hour.
+  const umi = await createUmi();
+  const collection = (await createCollection(umi)).publicKey;
+  const { publicKey: candyMachine } = await createV2(umi, {
+    collection,
+    configLines: [
+      { name: 'Degen #1', uri: 'https://example.com/degen/1' },
+      { name: 'Degen #2', uri: 'https://example.com/degen/2' },
+    ],
+    guards: {
+      cooldown: some({ id: 1, interval: 3600 }),
+    },
+  });
+
+  // When we mint from it.
+  const mint = generateSigner(umi);
+  await transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(
+      mintV1(umi, {
+        candyMachine,
+        asset: mint,
+        collection,
+        mintArgs: { cooldown: some({ id: 1 }) },
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then minting was successful.
+  await assertSuccessfulMint(t, umi, { mint, owner: umi.identity });
+
+  // And the cooldown tracker recorded the time of the mint.
+  const tracker = await fetchCooldownTrackerFromSeeds(umi, {
+    id: 1,
+    user: umi.identity.publicKey,
+    candyGuard: findCandyGuardPda(umi, { base: candyMachine })[0],
+    candyMachine,
+  });
+  t.true(tracker.lastMint > 0n);
+});
+
+test('it forbids minting again before the cooldown interval elapsed', async (t) => {
+  // Given a loaded Candy Machine with a cooldown of one hour.
+  const umi = await createUmi();
+  const collection = (await createCollection(umi)).publicKey;
+  const { publicKey: candyMachine } = await createV2(umi, {
+    collection,
+    configLines: [
+      { name: 'Degen #1', uri: 'https://example.com/degen/1' },
+      { name: 'Degen #2', uri: 'https://example.com/degen/2' },
+    ],
+    guards: {
+      cooldown: some({ id: 1, interval: 3600 }),
+    },
+  });
+
+  // And the identity already minted from it.
+  await transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(
+      mintV1(umi, {
+        candyMachine,
+        asset: generateSigner(umi),
+        collection,
+        mintArgs: { cooldown: some({ id: 1 }) },
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // When the identity tries to mint again.
+  const promise = transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(
+      mintV1(umi, {
+        candyMachine,
+        asset: generateSigner(umi),
+        collection,
+        mintArgs: { cooldown: some({ id: 1 }) },
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, { message: /CooldownNotElapsed/ });
+});
+
+test('the cooldown is tracked independently for each wallet', async (t) => {
+  // Given a loaded Candy Machine with a cooldown of one hour.
+  const umi = await createUmi();
+  const collection = (await createCollection(umi)).publicKey;
+  const { publicKey: candyMachine } = await createV2(umi, {
+    collection,
+    configLines: [
+      { name: 'Degen #1', uri: 'https://example.com/degen/1' },
+      { name: 'Degen #2', uri: 'https://example.com/degen/2' },
+    ],
+    guards: {
+      cooldown: some({ id: 1, interval: 3600 }),
+    },
+  });
+
+  // And the identity already minted from it.
+  await transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(
+      mintV1(umi, {
+        candyMachine,
+        asset: generateSigner(umi),
+        collection,
+        mintArgs: { cooldown: some({ id: 1 }) },
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // When another wallet mints from it.
+  const minter = await generateSignerWithSol(umi, sol(10));
+  const mint = generateSigner(umi);
+  await transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(
+      mintV1(umi, {
+        candyMachine,
+        asset: mint,
+        minter,
+        payer: minter,
+        collection,
+        mintArgs: { cooldown: some({ id: 1 }) },
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then minting was successful.
+  await assertSuccessfulMint(t, umi, { mint, owner: minter });
+});
+
+test('it forbids creating a cooldown with a non-positive interval', async (t) => {
+  // Given an existing collection.
+  const umi = await createUmi();
+  const collection = (await createCollection(umi)).publicKey;
+
+  // When we try to create a Candy Machine with a cooldown of zero seconds.
+  const promise = createV2(umi, {
+    collection,
+    configLines: [{ name: 'Degen #1', uri: 'https://example.com/degen/1' }],
+    guards: {
+      cooldown: some({ id: 1, interval: 0 }),
+    },
+  });
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, { message: /InvalidCooldownConfig/ });
+});
+
+test('it charges a bot tax when trying to mint before the cooldown elapsed', async (t) => {
+  // Given a loaded Candy Machine with a cooldown and a bot tax guard.
+  const umi = await createUmi();
+  const collection = (await createCollection(umi)).publicKey;
+  const { publicKey: candyMachine } = await createV2(umi, {
+    collection,
+    configLines: [
+      { name: 'Degen #1', uri: 'https://example.com/degen/1' },
+      { name: 'Degen #2', uri: 'https://example.com/degen/2' },
+    ],
+    guards: {
+      botTax: some({ lamports: sol(0.1), lastInstruction: true }),
+      cooldown: some({ id: 1, interval: 3600 }),
+    },
+  });
+
+  // And the identity already minted from it.
+  await transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(
+      mintV1(umi, {
+        candyMachine,
+        asset: generateSigner(umi),
+        collection,
+        mintArgs: { cooldown: some({ id: 1 }) },
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // When the identity tries to mint again.
+  const mint = generateSigner(umi);
+  const { signature } = await transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(
+      mintV1(umi, {
+        candyMachine,
+        asset: mint,
+        collection,
+        mintArgs: { cooldown: some({ id: 1 }) },
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then we expect a bot tax error.
+  await assertBotTax(t, umi, mint, signature, /CooldownNotElapsed/);
+});
+
+test('it allows the minter to close its cooldown tracker once the candy machine is sold out', async (t) => {
+  // Given a loaded Candy Machine with a cooldown and a single item.
+  const umi = await createUmi();
+  const collection = (await createCollection(umi)).publicKey;
+  const { publicKey: candyMachine } = await createV2(umi, {
+    collection,
+    configLines: [{ name: 'Degen #1', uri: 'https://example.com/degen/1' }],
+    guards: {
+      cooldown: some({ id: 1, interval: 3600 }),
+    },
+  });
+
+  // And the identity minted its last item.
+  await transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(
+      mintV1(umi, {
+        candyMachine,
+        asset: generateSigner(umi),
+        collection,
+        mintArgs: { cooldown: some({ id: 1 }) },
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // When the identity closes its cooldown tracker.
+  await transactionBuilder()
+    .add(
+      route(umi, {
+        candyMachine,
+        guard: 'cooldown',
+        routeArgs: { path: 'close', id: 1, authority: umi.identity },
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then the cooldown tracker no longer exists.
+  const [trackerPda] = findCooldownTrackerPda(umi, {
+    id: 1,
+    user: umi.identity.publicKey,
+    candyMachine,
+    candyGuard: findCandyGuardPda(umi, { base: candyMachine })[0],
+  });
+  t.false(await umi.rpc.accountExists(trackerPda));
+});
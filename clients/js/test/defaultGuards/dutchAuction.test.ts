@@ -0,0 +1,336 @@
+import { setComputeUnitLimit } from '@metaplex-foundation/mpl-toolbox';
+import {
+  generateSigner,
+  isEqualToAmount,
+  sol,
+  some,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import { generateSignerWithSol } from '@metaplex-foundation/umi-bundle-tests';
+import test from 'ava';
+import {
+  AuctionEscrow,
+  fetchAuctionEscrowFromSeeds,
+  findAuctionBidPda,
+  findAuctionEscrowPda,
+  findCandyGuardPda,
+  mintV1,
+  route,
+} from '../../src';
+import {
+  assertSuccessfulMint,
+  createCollection,
+  createUmi,
+  createV2,
+  tomorrow,
+  yesterday,
+} from '../_setup';
+
+test('it charges the start price before the first price drop', async (t) => {
+  // Given a Candy Machine with a dutchAuction guard that started
+  // yesterday and only drops its price every 10 days.
+  const umi = await createUmi();
+  const destination = generateSigner(umi).publicKey;
+  const collection = (await createCollection(umi)).publicKey;
+  const { publicKey: candyMachine } = await createV2(umi, {
+    collection,
+    configLines: [{ name: 'Degen #1', uri: 'https://example.com/degen/1' }],
+    guards: {
+      dutchAuction: some({
+        startPrice: sol(2),
+        floorPrice: sol(1),
+        startTime: yesterday(),
+        decayStep: sol(0.5),
+        interval: 10 * 24 * 3600,
+        destination,
+        rebate: false,
+      }),
+    },
+  });
+
+  // When we mint from it using an explicit payer.
+  const payer = await generateSignerWithSol(umi, sol(10));
+  const mint = generateSigner(umi);
+  await transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(
+      mintV1(umi, {
+        candyMachine,
+        asset: mint,
+        payer,
+        collection,
+        mintArgs: { dutchAuction: some({ destination, rebate: false }) },
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then minting was successful.
+  await assertSuccessfulMint(t, umi, { mint, owner: umi.identity });
+
+  // And the treasury received the start price.
+  const treasuryBalance = await umi.rpc.getBalance(destination);
+  t.true(isEqualToAmount(treasuryBalance, sol(2)), 'treasury received SOLs');
+});
+
+test('it charges the floor price once the price stops dropping', async (t) => {
+  // Given a Candy Machine with a dutchAuction guard that started
+  // yesterday and drops its price by 1 SOL every hour.
+  const umi = await createUmi();
+  const destination = generateSigner(umi).publicKey;
+  const collection = (await createCollection(umi)).publicKey;
+  const { publicKey: candyMachine } = await createV2(umi, {
+    collection,
+    configLines: [{ name: 'Degen #1', uri: 'https://example.com/degen/1' }],
+    guards: {
+      dutchAuction: some({
+        startPrice: sol(5),
+        floorPrice: sol(1),
+        startTime: yesterday(),
+        decayStep: sol(1),
+        interval: 3600,
+        destination,
+        rebate: false,
+      }),
+    },
+  });
+
+  // When we mint from it.
+  const payer = await generateSignerWithSol(umi, sol(10));
+  const mint = generateSigner(umi);
+  await transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(
+      mintV1(umi, {
+        candyMachine,
+        asset: mint,
+        payer,
+        collection,
+        mintArgs: { dutchAuction: some({ destination, rebate: false }) },
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then minting was successful.
+  await assertSuccessfulMint(t, umi, { mint, owner: umi.identity });
+
+  // And the treasury received the floor price.
+  const treasuryBalance = await umi.rpc.getBalance(destination);
+  t.true(isEqualToAmount(treasuryBalance, sol(1)), 'treasury received SOLs');
+});
+
+test('it forbids minting before the auction starts', async (t) => {
+  // Given a Candy Machine with a dutchAuction guard that starts tomorrow.
+  const umi = await createUmi();
+  const destination = generateSigner(umi).publicKey;
+  const collection = (await createCollection(umi)).publicKey;
+  const { publicKey: candyMachine } = await createV2(umi, {
+    collection,
+    configLines: [{ name: 'Degen #1', uri: 'https://example.com/degen/1' }],
+    guards: {
+      dutchAuction: some({
+        startPrice: sol(2),
+        floorPrice: sol(1),
+        startTime: tomorrow(),
+        decayStep: sol(0.5),
+        interval: 3600,
+        destination,
+        rebate: false,
+      }),
+    },
+  });
+
+  // When we try to mint from it.
+  const mint = generateSigner(umi);
+  const promise = transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(
+      mintV1(umi, {
+        candyMachine,
+        asset: mint,
+        collection,
+        mintArgs: { dutchAuction: some({ destination, rebate: false }) },
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, { message: /MintNotLive/ });
+});
+
+test('it requires the escrow to be initialized for rebates', async (t) => {
+  // Given a Candy Machine with a dutchAuction guard with rebates
+  // whose escrow account was not initialized.
+  const umi = await createUmi();
+  const destination = generateSigner(umi).publicKey;
+  const collection = (await createCollection(umi)).publicKey;
+  const { publicKey: candyMachine } = await createV2(umi, {
+    collection,
+    configLines: [{ name: 'Degen #1', uri: 'https://example.com/degen/1' }],
+    guards: {
+      dutchAuction: some({
+        startPrice: sol(2),
+        floorPrice: sol(1),
+        startTime: yesterday(),
+        decayStep: sol(0.5),
+        interval: 10 * 24 * 3600,
+        destination,
+        rebate: true,
+      }),
+    },
+  });
+
+  // When we try to mint from it.
+  const mint = generateSigner(umi);
+  const promise = transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(
+      mintV1(umi, {
+        candyMachine,
+        asset: mint,
+        collection,
+        mintArgs: { dutchAuction: some({ destination, rebate: true }) },
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, { message: /AuctionNotInitialized/ });
+});
+
+test('it holds payments in a group escrow until the auction is settled', async (t) => {
+  // Given a Candy Machine with a group using a dutchAuction guard with rebates.
+  const umi = await createUmi();
+  const destination = generateSigner(umi).publicKey;
+  const collection = (await createCollection(umi)).publicKey;
+  const { publicKey: candyMachine } = await createV2(umi, {
+    collection,
+    configLines: [{ name: 'Degen #1', uri: 'https://example.com/degen/1' }],
+    groups: [
+      {
+        label: 'AUCTION',
+        guards: {
+          dutchAuction: some({
+            startPrice: sol(2),
+            floorPrice: sol(1),
+            startTime: yesterday(),
+            decayStep: sol(0.5),
+            interval: 10 * 24 * 3600,
+            destination,
+            rebate: true,
+          }),
+        },
+      },
+    ],
+  });
+  const candyGuard = findCandyGuardPda(umi, { base: candyMachine })[0];
+
+  // And given the escrow of the group is initialized.
+  await transactionBuilder()
+    .add(
+      route(umi, {
+        candyMachine,
+        guard: 'dutchAuction',
+        group: some('AUCTION'),
+        routeArgs: {
+          path: 'initialize',
+          destination,
+          candyGuardAuthority: umi.identity,
+        },
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // When we mint the only item of the Candy Machine from that group.
+  const minter = await generateSignerWithSol(umi, sol(10));
+  const mint = generateSigner(umi);
+  await transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(
+      mintV1(umi, {
+        candyMachine,
+        asset: mint,
+        minter,
+        payer: minter,
+        collection,
+        group: some('AUCTION'),
+        mintArgs: { dutchAuction: some({ destination, rebate: true }) },
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then minting was successful.
+  await assertSuccessfulMint(t, umi, { mint, owner: minter });
+
+  // And the payment is held in the escrow of the group.
+  const escrowSeeds = {
+    destination,
+    candyGuard,
+    candyMachine,
+    group: 'AUCTION',
+  };
+  const escrow = await fetchAuctionEscrowFromSeeds(umi, escrowSeeds);
+  t.like(escrow, <AuctionEscrow>{
+    candyGuard,
+    candyMachine,
+    destination,
+    authority: umi.identity.publicKey,
+    lastPrice: sol(2).basisPoints,
+    totalCount: 1n,
+    totalPaid: sol(2).basisPoints,
+    withdrawn: 0n,
+    openBids: 1n,
+  });
+  t.false(await umi.rpc.accountExists(destination));
+
+  // When the minter claims its rebate once the auction is sold out.
+  const [auctionBid] = findAuctionBidPda(umi, {
+    minter: minter.publicKey,
+    candyGuard,
+    candyMachine,
+    group: 'AUCTION',
+  });
+  t.true(await umi.rpc.accountExists(auctionBid));
+  await transactionBuilder()
+    .add(
+      route(umi, {
+        candyMachine,
+        guard: 'dutchAuction',
+        group: some('AUCTION'),
+        routeArgs: {
+          path: 'claim',
+          destination,
+          minter: minter.publicKey,
+          bidPayer: minter.publicKey,
+        },
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then the bid account was closed.
+  t.false(await umi.rpc.accountExists(auctionBid));
+
+  // When the candy guard authority withdraws the proceeds.
+  await transactionBuilder()
+    .add(
+      route(umi, {
+        candyMachine,
+        guard: 'dutchAuction',
+        group: some('AUCTION'),
+        routeArgs: {
+          path: 'withdraw',
+          destination,
+          candyGuardAuthority: umi.identity,
+        },
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then the escrow was closed and the treasury received the proceeds.
+  const [escrowAddress] = findAuctionEscrowPda(umi, escrowSeeds);
+  t.false(await umi.rpc.accountExists(escrowAddress));
+  const treasuryBalance = await umi.rpc.getBalance(destination);
+  t.true(
+    isEqualToAmount(treasuryBalance, sol(2), sol(0.01)),
+    'treasury received SOLs'
+  );
+});
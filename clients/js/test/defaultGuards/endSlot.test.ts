@@ -0,0 +1,127 @@
+import { setComputeUnitLimit } from '@metaplex-foundation/mpl-toolbox';
+import {
+  generateSigner,
+  sol,
+  some,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import test from 'ava';
+import { mintV1 } from '../../src';
+import {
+  assertBotTax,
+  assertSuccessfulMint,
+  createCollection,
+  createUmi,
+  createV2,
+} from '../_setup';
+
+test('it allows minting before the end slot', async (t) => {
+  // Given a candy machine with an end slot in the future.
+  const umi = await createUmi();
+  const slot = await umi.rpc.getSlot();
+  const collection = (await createCollection(umi)).publicKey;
+  const { publicKey: candyMachine } = await createV2(umi, {
+    collection,
+    configLines: [{ name: 'Degen #1', uri: 'https://example.com/degen/1' }],
+    guards: {
+      endSlot: some({ slot: slot + 100_000 }),
+    },
+  });
+
+  // When we mint from it.
+  const mint = generateSigner(umi);
+  await transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(
+      mintV1(umi, {
+        candyMachine,
+        asset: mint,
+        collection,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then the mint was successful.
+  await assertSuccessfulMint(t, umi, { mint, owner: umi.identity });
+});
+
+test('it forbids minting after the end slot', async (t) => {
+  // Given a candy machine with an end slot in the past.
+  const umi = await createUmi();
+  const slot = await umi.rpc.getSlot();
+  const collection = (await createCollection(umi)).publicKey;
+  const { publicKey: candyMachine } = await createV2(umi, {
+    collection,
+    configLines: [{ name: 'Degen #1', uri: 'https://example.com/degen/1' }],
+    guards: {
+      endSlot: some({ slot }),
+    },
+  });
+
+  // When we try to mint from it.
+  const mint = generateSigner(umi);
+  const promise = transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(
+      mintV1(umi, {
+        candyMachine,
+        asset: mint,
+        collection,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, { message: /AfterEndSlot/ });
+});
+
+test('it charges a bot tax when trying to mint after the end slot', async (t) => {
+  // Given a candy machine with a bot tax and an end slot in the past.
+  const umi = await createUmi();
+  const slot = await umi.rpc.getSlot();
+  const collection = (await createCollection(umi)).publicKey;
+  const { publicKey: candyMachine } = await createV2(umi, {
+    collection,
+    configLines: [{ name: 'Degen #1', uri: 'https://example.com/degen/1' }],
+    guards: {
+      botTax: some({ lamports: sol(0.01), lastInstruction: true }),
+      endSlot: some({ slot }),
+    },
+  });
+
+  // When we mint from it.
+  const mint = generateSigner(umi);
+  const { signature } = await transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(
+      mintV1(umi, {
+        candyMachine,
+        asset: mint,
+        collection,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then we expect a silent bot tax error.
+  await assertBotTax(t, umi, mint, signature, /AfterEndSlot/);
+});
+
+test('it forbids an end slot before the start slot of the same guard set', async (t) => {
+  // Given an existing collection.
+  const umi = await createUmi();
+  const slot = await umi.rpc.getSlot();
+  const collection = (await createCollection(umi)).publicKey;
+
+  // When we try to create a candy machine that ends before it starts.
+  const promise = createV2(umi, {
+    collection,
+    configLines: [{ name: 'Degen #1', uri: 'https://example.com/degen/1' }],
+    guards: {
+      startSlot: some({ slot: slot + 200_000 }),
+      endSlot: some({ slot: slot + 100_000 }),
+    },
+  });
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, { message: /InvalidSlotRange/ });
+});
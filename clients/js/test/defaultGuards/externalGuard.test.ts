@@ -0,0 +1,141 @@
+import { setComputeUnitLimit } from '@metaplex-foundation/mpl-toolbox';
+import {
+  generateSigner,
+  none,
+  publicKey,
+  some,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import test from 'ava';
+import {
+  getEligibility,
+  getMplCoreCandyGuardProgramId,
+  mintV1,
+} from '../../src';
+import { createCollection, createUmi, createV2 } from '../_setup';
+
+const SYSTEM_PROGRAM_ID = publicKey('11111111111111111111111111111111');
+
+test('it fails to mint if the external program rejects the mint', async (t) => {
+  // Given a loaded Candy Machine with an externalGuard guard that
+  // delegates the validation to a program that does not implement
+  // the external guard interface.
+  const umi = await createUmi();
+  const collection = (await createCollection(umi)).publicKey;
+  const { publicKey: candyMachine } = await createV2(umi, {
+    collection,
+    configLines: [{ name: 'Degen #1', uri: 'https://example.com/degen/1' }],
+    guards: {
+      externalGuard: some({
+        programId: SYSTEM_PROGRAM_ID,
+        config: new Uint8Array([1, 2, 3]),
+        accounts: 0,
+      }),
+    },
+  });
+
+  // When we try to mint from it.
+  const mint = generateSigner(umi);
+  const promise = transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(
+      mintV1(umi, {
+        candyMachine,
+        asset: mint,
+        collection,
+        mintArgs: { externalGuard: some({ programId: SYSTEM_PROGRAM_ID }) },
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then we expect the error of the external program.
+  await t.throwsAsync(promise, { message: /invalid instruction data/i });
+});
+
+test('it does not invoke the external program when checking the eligibility', async (t) => {
+  // Given a loaded Candy Machine with an externalGuard guard that
+  // delegates the validation to a program that rejects the mint.
+  const umi = await createUmi();
+  const collection = (await createCollection(umi)).publicKey;
+  const { publicKey: candyMachine } = await createV2(umi, {
+    collection,
+    configLines: [{ name: 'Degen #1', uri: 'https://example.com/degen/1' }],
+    guards: {
+      externalGuard: some({
+        programId: SYSTEM_PROGRAM_ID,
+        config: new Uint8Array([1, 2, 3]),
+        accounts: 0,
+      }),
+    },
+  });
+
+  // When we check the eligibility of the identity.
+  const [eligibility] = await getEligibility(umi, {
+    candyMachine,
+    asset: generateSigner(umi).publicKey,
+    collection,
+    mintArgs: { externalGuard: some({ programId: SYSTEM_PROGRAM_ID }) },
+  });
+
+  // Then the evaluation did not abort and only the accounts were checked.
+  t.deepEqual(eligibility.error, none());
+});
+
+test('it fails to mint if the wrong external program is provided', async (t) => {
+  // Given a loaded Candy Machine with an externalGuard guard.
+  const umi = await createUmi();
+  const collection = (await createCollection(umi)).publicKey;
+  const { publicKey: candyMachine } = await createV2(umi, {
+    collection,
+    configLines: [{ name: 'Degen #1', uri: 'https://example.com/degen/1' }],
+    guards: {
+      externalGuard: some({
+        programId: SYSTEM_PROGRAM_ID,
+        config: new Uint8Array(),
+        accounts: 0,
+      }),
+    },
+  });
+
+  // When we try to mint from it providing another program.
+  const mint = generateSigner(umi);
+  const promise = transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(
+      mintV1(umi, {
+        candyMachine,
+        asset: mint,
+        collection,
+        mintArgs: {
+          externalGuard: some({ programId: generateSigner(umi).publicKey }),
+        },
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, { message: /PublicKeyMismatch/ });
+});
+
+test('it cannot delegate the validation to the candy guard program', async (t) => {
+  // Given a collection.
+  const umi = await createUmi();
+  const collection = (await createCollection(umi)).publicKey;
+
+  // When we try to create a Candy Machine with an externalGuard
+  // guard that invokes the candy guard program.
+  const promise = createV2(umi, {
+    collection,
+    configLines: [{ name: 'Degen #1', uri: 'https://example.com/degen/1' }],
+    guards: {
+      externalGuard: some({
+        programId: getMplCoreCandyGuardProgramId(umi),
+        config: new Uint8Array(),
+        accounts: 0,
+      }),
+    },
+  });
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, { message: /InvalidExternalGuardConfig/ });
+});
@@ -0,0 +1,300 @@
+import { transferV1 } from '@metaplex-foundation/mpl-core';
+import { setComputeUnitLimit } from '@metaplex-foundation/mpl-toolbox';
+import {
+  generateSigner,
+  PublicKey,
+  sol,
+  some,
+  transactionBuilder,
+  Umi,
+} from '@metaplex-foundation/umi';
+import { generateSignerWithSol } from '@metaplex-foundation/umi-bundle-tests';
+import test from 'ava';
+import {
+  deleteCandyMachine,
+  fetchGateUsageFromSeeds,
+  findCandyGuardPda,
+  findGateUsagePda,
+  mintV1,
+  route,
+} from '../../src';
+import {
+  assertSuccessfulMint,
+  createAsset,
+  createCollection,
+  createCollectionNft,
+  createUmi,
+  createV2,
+  createVerifiedNft,
+} from '../_setup';
+
+const createGatingAsset = async (umi: Umi) => {
+  const requiredCollectionAuthority = generateSigner(umi);
+  const { publicKey: requiredCollection } = await createCollection(umi, {
+    updateAuthority: requiredCollectionAuthority.publicKey,
+  });
+  const { publicKey: asset } = await createAsset(umi, {
+    owner: umi.identity.publicKey,
+    collection: requiredCollection,
+    authority: requiredCollectionAuthority,
+  });
+  return { asset, requiredCollection };
+};
+
+const createLimitedCandyMachine = async (
+  umi: Umi,
+  requiredCollection: PublicKey,
+  limit: number
+) => {
+  const collection = (await createCollection(umi)).publicKey;
+  const { publicKey: candyMachine } = await createV2(umi, {
+    collection,
+    configLines: [
+      { name: 'Degen #1', uri: 'https://example.com/degen/1' },
+      { name: 'Degen #2', uri: 'https://example.com/degen/2' },
+      { name: 'Degen #3', uri: 'https://example.com/degen/3' },
+    ],
+    guards: {
+      assetGate: some({ requiredCollection }),
+      gateUsageLimit: some({ limit }),
+    },
+  });
+  return { candyMachine, collection };
+};
+
+test('it allows minting up to the usage limit of the gating asset', async (t) => {
+  // Given the identity owns an asset from a certain collection.
+  const umi = await createUmi();
+  const { asset, requiredCollection } = await createGatingAsset(umi);
+
+  // And a loaded Candy Machine allowing 2 mints per gating asset.
+  const { candyMachine, collection } = await createLimitedCandyMachine(
+    umi,
+    requiredCollection,
+    2
+  );
+
+  // When we mint twice from it using the same asset.
+  const mintFromCandyMachine = async () => {
+    const mint = generateSigner(umi);
+    await transactionBuilder()
+      .add(setComputeUnitLimit(umi, { units: 600_000 }))
+      .add(
+        mintV1(umi, {
+          candyMachine,
+          asset: mint,
+          collection,
+          mintArgs: {
+            assetGate: some({ asset }),
+            gateUsageLimit: some({ asset }),
+          },
+        })
+      )
+      .sendAndConfirm(umi);
+    return mint;
+  };
+  const mintA = await mintFromCandyMachine();
+  const mintB = await mintFromCandyMachine();
+
+  // Then both mints were successful.
+  await assertSuccessfulMint(t, umi, { mint: mintA, owner: umi.identity });
+  await assertSuccessfulMint(t, umi, { mint: mintB, owner: umi.identity });
+
+  // And the usage of the asset was recorded.
+  const usage = await fetchGateUsageFromSeeds(umi, {
+    asset,
+    candyGuard: findCandyGuardPda(umi, { base: candyMachine })[0],
+    candyMachine,
+  });
+  t.is(usage.count, 2);
+
+  // And the asset cannot be used a third time.
+  await t.throwsAsync(mintFromCandyMachine(), {
+    message: /GateUsageLimitReached/,
+  });
+});
+
+test('it forbids reusing a gating asset after transferring it to another wallet', async (t) => {
+  // Given the identity owns an asset from a certain collection.
+  const umi = await createUmi();
+  const { asset, requiredCollection } = await createGatingAsset(umi);
+
+  // And a loaded Candy Machine allowing 1 mint per gating asset.
+  const { candyMachine, collection } = await createLimitedCandyMachine(
+    umi,
+    requiredCollection,
+    1
+  );
+
+  // And the identity used the asset to mint.
+  await transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(
+      mintV1(umi, {
+        candyMachine,
+        asset: generateSigner(umi),
+        collection,
+        mintArgs: {
+          assetGate: some({ asset }),
+          gateUsageLimit: some({ asset }),
+        },
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // And transferred the asset to another wallet.
+  const minter = await generateSignerWithSol(umi, sol(10));
+  await transferV1(umi, {
+    asset,
+    collection: requiredCollection,
+    newOwner: minter.publicKey,
+  }).sendAndConfirm(umi);
+
+  // When the other wallet tries to mint using the same asset.
+  const promise = transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(
+      mintV1(umi, {
+        candyMachine,
+        asset: generateSigner(umi),
+        minter,
+        payer: minter,
+        collection,
+        mintArgs: {
+          assetGate: some({ asset }),
+          gateUsageLimit: some({ asset }),
+        },
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, { message: /GateUsageLimitReached/ });
+});
+
+test('it limits the usage of gating NFTs', async (t) => {
+  // Given the identity owns an NFT from a certain collection.
+  const umi = await createUmi();
+  const requiredCollectionAuthority = generateSigner(umi);
+  const { publicKey: requiredCollection } = await createCollectionNft(umi, {
+    authority: requiredCollectionAuthority,
+  });
+  const nft = await createVerifiedNft(umi, {
+    tokenOwner: umi.identity.publicKey,
+    collectionMint: requiredCollection,
+    collectionAuthority: requiredCollectionAuthority,
+  });
+
+  // And a loaded Candy Machine allowing 1 mint per gating NFT.
+  const collection = (await createCollection(umi)).publicKey;
+  const { publicKey: candyMachine } = await createV2(umi, {
+    collection,
+    configLines: [
+      { name: 'Degen #1', uri: 'https://example.com/degen/1' },
+      { name: 'Degen #2', uri: 'https://example.com/degen/2' },
+    ],
+    guards: {
+      nftGate: some({ requiredCollection }),
+      gateUsageLimit: some({ limit: 1 }),
+    },
+  });
+
+  // And the identity used the NFT to mint.
+  const mintFromCandyMachine = async () => {
+    const mint = generateSigner(umi);
+    await transactionBuilder()
+      .add(setComputeUnitLimit(umi, { units: 600_000 }))
+      .add(
+        mintV1(umi, {
+          candyMachine,
+          asset: mint,
+          collection,
+          mintArgs: {
+            nftGate: some({ mint: nft.publicKey }),
+            gateUsageLimit: some({ mint: nft.publicKey }),
+          },
+        })
+      )
+      .sendAndConfirm(umi);
+    return mint;
+  };
+  const mint = await mintFromCandyMachine();
+  await assertSuccessfulMint(t, umi, { mint, owner: umi.identity });
+
+  // When the identity tries to use the NFT again.
+  const promise = mintFromCandyMachine();
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, { message: /GateUsageLimitReached/ });
+});
+
+test('it forbids a gate usage limit without a gate guard', async (t) => {
+  // Given an existing collection.
+  const umi = await createUmi();
+  const collection = (await createCollection(umi)).publicKey;
+
+  // When we try to create a Candy Machine with only a gate usage limit.
+  const promise = createV2(umi, {
+    collection,
+    configLines: [{ name: 'Degen #1', uri: 'https://example.com/degen/1' }],
+    guards: {
+      gateUsageLimit: some({ limit: 1 }),
+    },
+  });
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, { message: /InvalidGateUsageLimit/ });
+});
+
+test('it allows the authority to close a gate usage once the candy machine is withdrawn', async (t) => {
+  // Given the identity owns an asset from a certain collection.
+  const umi = await createUmi();
+  const { asset, requiredCollection } = await createGatingAsset(umi);
+
+  // And a loaded Candy Machine with a gate usage limit.
+  const { candyMachine, collection } = await createLimitedCandyMachine(
+    umi,
+    requiredCollection,
+    1
+  );
+
+  // And the identity used the asset to mint.
+  await transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(
+      mintV1(umi, {
+        candyMachine,
+        asset: generateSigner(umi),
+        collection,
+        mintArgs: {
+          assetGate: some({ asset }),
+          gateUsageLimit: some({ asset }),
+        },
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // And the Candy Machine was withdrawn.
+  await transactionBuilder()
+    .add(deleteCandyMachine(umi, { candyMachine }))
+    .sendAndConfirm(umi);
+
+  // When the authority closes the gate usage.
+  await transactionBuilder()
+    .add(
+      route(umi, {
+        candyMachine,
+        guard: 'gateUsageLimit',
+        routeArgs: { path: 'close', asset, candyGuardAuthority: umi.identity },
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then the gate usage no longer exists.
+  const [usagePda] = findGateUsagePda(umi, {
+    asset,
+    candyMachine,
+    candyGuard: findCandyGuardPda(umi, { base: candyMachine })[0],
+  });
+  t.false(await umi.rpc.accountExists(usagePda));
+});
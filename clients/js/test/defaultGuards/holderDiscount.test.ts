@@ -0,0 +1,387 @@
+import { setComputeUnitLimit } from '@metaplex-foundation/mpl-toolbox';
+import {
+  generateSigner,
+  isEqualToAmount,
+  PublicKey,
+  sol,
+  some,
+  transactionBuilder,
+  Umi,
+} from '@metaplex-foundation/umi';
+import test from 'ava';
+import {
+  deleteCandyMachine,
+  discount,
+  DiscountArgs,
+  fetchDiscountUsageFromSeeds,
+  findCandyGuardPda,
+  findDiscountUsagePda,
+  mintV1,
+  route,
+} from '../../src';
+import {
+  assertSuccessfulMint,
+  createAsset,
+  createCollection,
+  createCollectionNft,
+  createUmi,
+  createV2,
+  createVerifiedNft,
+} from '../_setup';
+
+const createGatingAsset = async (umi: Umi) => {
+  const requiredCollectionAuthority = generateSigner(umi);
+  const { publicKey: requiredCollection } = await createCollection(umi, {
+    updateAuthority: requiredCollectionAuthority.publicKey,
+  });
+  const { publicKey: asset } = await createAsset(umi, {
+    owner: umi.identity.publicKey,
+    collection: requiredCollection,
+    authority: requiredCollectionAuthority,
+  });
+  return { asset, requiredCollection };
+};
+
+const createDiscountedCandyMachine = async (
+  umi: Umi,
+  input: {
+    requiredCollection: PublicKey;
+    destination: PublicKey;
+    discount: DiscountArgs;
+    singleUse?: boolean;
+  }
+) => {
+  const collection = (await createCollection(umi)).publicKey;
+  const { publicKey: candyMachine } = await createV2(umi, {
+    collection,
+    configLines: [
+      { name: 'Degen #1', uri: 'https://example.com/degen/1' },
+      { name: 'Degen #2', uri: 'https://example.com/degen/2' },
+    ],
+    guards: {
+      solPayment: some({ lamports: sol(1), destination: input.destination }),
+      holderDiscount: some({
+        requiredCollection: input.requiredCollection,
+        discount: input.discount,
+        singleUse: input.singleUse ?? false,
+      }),
+    },
+  });
+  return { candyMachine, collection };
+};
+
+test('it applies a percentage discount to holders of an asset', async (t) => {
+  // Given the identity owns an asset from a certain collection.
+  const umi = await createUmi();
+  const { asset, requiredCollection } = await createGatingAsset(umi);
+
+  // And a loaded Candy Machine costing 1 SOL with a 50% holder discount.
+  const destination = generateSigner(umi).publicKey;
+  const { candyMachine, collection } = await createDiscountedCandyMachine(
+    umi,
+    {
+      requiredCollection,
+      destination,
+      discount: discount('Percentage', [5_000]),
+    }
+  );
+
+  // When we mint from it claiming the discount.
+  const mint = generateSigner(umi);
+  await transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(
+      mintV1(umi, {
+        candyMachine,
+        asset: mint,
+        collection,
+        mintArgs: {
+          solPayment: some({ destination }),
+          holderDiscount: some({ asset, singleUse: false }),
+        },
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then minting was successful.
+  await assertSuccessfulMint(t, umi, { mint, owner: umi.identity });
+
+  // And the destination received half the price.
+  const destinationBalance = await umi.rpc.getBalance(destination);
+  t.true(isEqualToAmount(destinationBalance, sol(0.5)), 'discounted price');
+});
+
+test('it applies a fixed discount to holders of an NFT', async (t) => {
+  // Given the identity owns an NFT from a certain collection.
+  const umi = await createUmi();
+  const requiredCollectionAuthority = generateSigner(umi);
+  const { publicKey: requiredCollection } = await createCollectionNft(umi, {
+    authority: requiredCollectionAuthority,
+  });
+  const nft = await createVerifiedNft(umi, {
+    tokenOwner: umi.identity.publicKey,
+    collectionMint: requiredCollection,
+    collectionAuthority: requiredCollectionAuthority,
+  });
+
+  // And a loaded Candy Machine costing 1 SOL with a 0.25 SOL holder discount.
+  const destination = generateSigner(umi).publicKey;
+  const { candyMachine, collection } = await createDiscountedCandyMachine(
+    umi,
+    {
+      requiredCollection,
+      destination,
+      discount: discount('Amount', [sol(0.25).basisPoints]),
+    }
+  );
+
+  // When we mint from it claiming the discount.
+  const mint = generateSigner(umi);
+  await transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(
+      mintV1(umi, {
+        candyMachine,
+        asset: mint,
+        collection,
+        mintArgs: {
+          solPayment: some({ destination }),
+          holderDiscount: some({ mint: nft.publicKey, singleUse: false }),
+        },
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then minting was successful.
+  await assertSuccessfulMint(t, umi, { mint, owner: umi.identity });
+
+  // And the destination received the discounted price.
+  const destinationBalance = await umi.rpc.getBalance(destination);
+  t.true(isEqualToAmount(destinationBalance, sol(0.75)), 'discounted price');
+});
+
+test('it charges the full price when the discount is not claimed', async (t) => {
+  // Given a loaded Candy Machine costing 1 SOL with a 50% holder discount.
+  const umi = await createUmi();
+  const { requiredCollection } = await createGatingAsset(umi);
+  const destination = generateSigner(umi).publicKey;
+  const { candyMachine, collection } = await createDiscountedCandyMachine(
+    umi,
+    {
+      requiredCollection,
+      destination,
+      discount: discount('Percentage', [5_000]),
+    }
+  );
+
+  // When we mint from it without claiming the discount.
+  const mint = generateSigner(umi);
+  await transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(
+      mintV1(umi, {
+        candyMachine,
+        asset: mint,
+        collection,
+        mintArgs: { solPayment: some({ destination }) },
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then minting was successful.
+  await assertSuccessfulMint(t, umi, { mint, owner: umi.identity });
+
+  // And the destination received the full price.
+  const destinationBalance = await umi.rpc.getBalance(destination);
+  t.true(isEqualToAmount(destinationBalance, sol(1)), 'full price');
+});
+
+test('it forbids claiming the discount with an asset from another collection', async (t) => {
+  // Given the identity owns an asset from another collection.
+  const umi = await createUmi();
+  const { asset } = await createGatingAsset(umi);
+  const { requiredCollection } = await createGatingAsset(umi);
+
+  // And a loaded Candy Machine with a holder discount.
+  const destination = generateSigner(umi).publicKey;
+  const { candyMachine, collection } = await createDiscountedCandyMachine(
+    umi,
+    {
+      requiredCollection,
+      destination,
+      discount: discount('Percentage', [5_000]),
+    }
+  );
+
+  // When we try to mint from it claiming the discount.
+  const promise = transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(
+      mintV1(umi, {
+        candyMachine,
+        asset: generateSigner(umi),
+        collection,
+        mintArgs: {
+          solPayment: some({ destination }),
+          holderDiscount: some({ asset, singleUse: false }),
+        },
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, { message: /InvalidNftCollection/ });
+});
+
+test('it forbids using a single use discount twice', async (t) => {
+  // Given the identity owns an asset from a certain collection.
+  const umi = await createUmi();
+  const { asset, requiredCollection } = await createGatingAsset(umi);
+
+  // And a loaded Candy Machine with a single use holder discount.
+  const destination = generateSigner(umi).publicKey;
+  const { candyMachine, collection } = await createDiscountedCandyMachine(
+    umi,
+    {
+      requiredCollection,
+      destination,
+      discount: discount('Percentage', [5_000]),
+      singleUse: true,
+    }
+  );
+
+  // And the identity already claimed the discount with its asset.
+  const mintWithDiscount = () =>
+    transactionBuilder()
+      .add(setComputeUnitLimit(umi, { units: 600_000 }))
+      .add(
+        mintV1(umi, {
+          candyMachine,
+          asset: generateSigner(umi),
+          collection,
+          mintArgs: {
+            solPayment: some({ destination }),
+            holderDiscount: some({ asset, singleUse: true }),
+          },
+        })
+      )
+      .sendAndConfirm(umi);
+  await mintWithDiscount();
+
+  // Then the usage of the asset was recorded.
+  const usage = await fetchDiscountUsageFromSeeds(umi, {
+    asset,
+    candyGuard: findCandyGuardPda(umi, { base: candyMachine })[0],
+    candyMachine,
+  });
+  t.is(usage.minter, umi.identity.publicKey);
+
+  // And the identity cannot claim the discount again.
+  await t.throwsAsync(mintWithDiscount(), { message: /DiscountAlreadyUsed/ });
+});
+
+test('it forbids a holder discount without a supported payment guard', async (t) => {
+  // Given an existing collection.
+  const umi = await createUmi();
+  const collection = (await createCollection(umi)).publicKey;
+
+  // When we try to create a Candy Machine with only a holder discount.
+  const promise = createV2(umi, {
+    collection,
+    configLines: [{ name: 'Degen #1', uri: 'https://example.com/degen/1' }],
+    guards: {
+      holderDiscount: some({
+        requiredCollection: generateSigner(umi).publicKey,
+        discount: discount('Percentage', [5_000]),
+        singleUse: false,
+      }),
+    },
+  });
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, { message: /InvalidHolderDiscount/ });
+});
+
+test('it forbids a holder discount with a payment guard that is not discounted', async (t) => {
+  // Given an existing collection.
+  const umi = await createUmi();
+  const destination = generateSigner(umi).publicKey;
+  const collection = (await createCollection(umi)).publicKey;
+
+  // When we try to create a Candy Machine with a holder discount, a
+  // solPayment guard and a solFixedFee guard, which is not discounted.
+  const promise = createV2(umi, {
+    collection,
+    configLines: [{ name: 'Degen #1', uri: 'https://example.com/degen/1' }],
+    guards: {
+      solPayment: some({ lamports: sol(1), destination }),
+      solFixedFee: some({ lamports: sol(0.1), destination }),
+      holderDiscount: some({
+        requiredCollection: generateSigner(umi).publicKey,
+        discount: discount('Percentage', [5_000]),
+        singleUse: false,
+      }),
+    },
+  });
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, { message: /InvalidHolderDiscount/ });
+});
+
+test('it allows the authority to close a discount usage once the candy machine is withdrawn', async (t) => {
+  // Given the identity owns an asset from a certain collection.
+  const umi = await createUmi();
+  const { asset, requiredCollection } = await createGatingAsset(umi);
+
+  // And a loaded Candy Machine with a single use holder discount.
+  const destination = generateSigner(umi).publicKey;
+  const { candyMachine, collection } = await createDiscountedCandyMachine(
+    umi,
+    {
+      requiredCollection,
+      destination,
+      discount: discount('Percentage', [5_000]),
+      singleUse: true,
+    }
+  );
+
+  // And the identity claimed the discount with its asset.
+  await transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(
+      mintV1(umi, {
+        candyMachine,
+        asset: generateSigner(umi),
+        collection,
+        mintArgs: {
+          solPayment: some({ destination }),
+          holderDiscount: some({ asset, singleUse: true }),
+        },
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // And the Candy Machine was withdrawn.
+  await transactionBuilder()
+    .add(deleteCandyMachine(umi, { candyMachine }))
+    .sendAndConfirm(umi);
+
+  // When the authority closes the discount usage.
+  await transactionBuilder()
+    .add(
+      route(umi, {
+        candyMachine,
+        guard: 'holderDiscount',
+        routeArgs: { path: 'close', asset, candyGuardAuthority: umi.identity },
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then the discount usage no longer exists.
+  const [usagePda] = findDiscountUsagePda(umi, {
+    asset,
+    candyMachine,
+    candyGuard: findCandyGuardPda(umi, { base: candyMachine })[0],
+  });
+  t.false(await umi.rpc.accountExists(usagePda));
+});
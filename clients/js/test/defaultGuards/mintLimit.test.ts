@@ -5,12 +5,15 @@ import {
   some,
   transactionBuilder,
 } from '@metaplex-foundation/umi';
+import { generateSignerWithSol } from '@metaplex-foundation/umi-bundle-tests';
 import test from 'ava';
 import {
+  deleteCandyMachine,
   fetchMintCounter,
   findCandyGuardPda,
   findMintCounterPda,
   mintV1,
+  route,
 } from '../../src';
 import {
   assertBotTax,
@@ -253,3 +256,154 @@ test('it charges a bot tax when trying to mint after the limit', async (t) => {
   // Then we expect a bot tax error.
   await assertBotTax(t, umi, mintB, signature, /AllowedMintLimitReached/);
 });
+
+test('it allows the minter to close its mint counter once the candy machine is sold out', async (t) => {
+  // Given a loaded Candy Machine with a mint limit and a single item.
+  const umi = await createUmi();
+  const collection = (await createCollection(umi)).publicKey;
+  const { publicKey: candyMachine } = await createV2(umi, {
+    collection,
+    configLines: [{ name: 'Degen #1', uri: 'https://example.com/degen/1' }],
+    guards: {
+      mintLimit: some({ id: 1, limit: 5 }),
+    },
+  });
+
+  // And the identity minted its last item.
+  await transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(
+      mintV1(umi, {
+        candyMachine,
+        asset: generateSigner(umi),
+        collection,
+        mintArgs: { mintLimit: some({ id: 1 }) },
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // When the identity closes its mint counter.
+  await transactionBuilder()
+    .add(
+      route(umi, {
+        candyMachine,
+        guard: 'mintLimit',
+        routeArgs: { path: 'close', id: 1, authority: umi.identity },
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then the mint counter no longer exists.
+  const [counterPda] = findMintCounterPda(umi, {
+    id: 1,
+    user: umi.identity.publicKey,
+    candyMachine,
+    candyGuard: findCandyGuardPda(umi, { base: candyMachine })[0],
+  });
+  t.false(await umi.rpc.accountExists(counterPda));
+});
+
+test('it forbids closing a mint counter while the mint is in progress', async (t) => {
+  // Given a loaded Candy Machine with a mint limit and two items.
+  const umi = await createUmi();
+  const collection = (await createCollection(umi)).publicKey;
+  const { publicKey: candyMachine } = await createV2(umi, {
+    collection,
+    configLines: [
+      { name: 'Degen #1', uri: 'https://example.com/degen/1' },
+      { name: 'Degen #2', uri: 'https://example.com/degen/2' },
+    ],
+    guards: {
+      mintLimit: some({ id: 1, limit: 1 }),
+    },
+  });
+
+  // And the identity reached its mint limit.
+  await transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(
+      mintV1(umi, {
+        candyMachine,
+        asset: generateSigner(umi),
+        collection,
+        mintArgs: { mintLimit: some({ id: 1 }) },
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // When the identity tries to close its mint counter to reset it.
+  const promise = transactionBuilder()
+    .add(
+      route(umi, {
+        candyMachine,
+        guard: 'mintLimit',
+        routeArgs: { path: 'close', id: 1, authority: umi.identity },
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, { message: /MintNotOver/ });
+});
+
+test('it allows the authority to close a mint counter once the candy machine is withdrawn', async (t) => {
+  // Given a loaded Candy Machine with a mint limit.
+  const umi = await createUmi();
+  const collection = (await createCollection(umi)).publicKey;
+  const { publicKey: candyMachine } = await createV2(umi, {
+    collection,
+    configLines: [
+      { name: 'Degen #1', uri: 'https://example.com/degen/1' },
+      { name: 'Degen #2', uri: 'https://example.com/degen/2' },
+    ],
+    guards: {
+      mintLimit: some({ id: 1, limit: 5 }),
+    },
+  });
+
+  // And a minter that minted from it.
+  const minter = await generateSignerWithSol(umi, sol(10));
+  await transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(
+      mintV1(umi, {
+        candyMachine,
+        asset: generateSigner(umi),
+        minter,
+        payer: minter,
+        collection,
+        mintArgs: { mintLimit: some({ id: 1 }) },
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // And the Candy Machine was withdrawn.
+  await transactionBuilder()
+    .add(deleteCandyMachine(umi, { candyMachine }))
+    .sendAndConfirm(umi);
+
+  // When the authority closes the mint counter of the minter.
+  await transactionBuilder()
+    .add(
+      route(umi, {
+        candyMachine,
+        guard: 'mintLimit',
+        routeArgs: {
+          path: 'close',
+          id: 1,
+          authority: umi.identity,
+          minter: minter.publicKey,
+        },
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then the mint counter no longer exists.
+  const [counterPda] = findMintCounterPda(umi, {
+    id: 1,
+    user: minter.publicKey,
+    candyMachine,
+    candyGuard: findCandyGuardPda(umi, { base: candyMachine })[0],
+  });
+  t.false(await umi.rpc.accountExists(counterPda));
+});
@@ -16,10 +16,12 @@ import {
 } from '@metaplex-foundation/umi';
 import test from 'ava';
 import {
+  deleteCandyMachine,
   fetchNftMintCounter,
   findCandyGuardPda,
   findNftMintCounterPda,
   mintV1,
+  route,
 } from '../../src';
 import {
   assertBotTax,
@@ -538,3 +540,74 @@ test('it charges a bot tax when trying to mint after the limit', async (t) => {
   // Then we expect a bot tax error.
   await assertBotTax(t, umi, mintB, signature, /AllowedMintLimitReached/);
 });
+
+test('it allows the authority to close an nft mint counter once the candy machine is withdrawn', async (t) => {
+  // Given a loaded Candy Machine with an nft mint limit.
+  const umi = await createUmi();
+  const requiredCollectionAuthority = generateSigner(umi);
+  const { publicKey: requiredCollection } = await createCollectionNft(umi, {
+    authority: requiredCollectionAuthority,
+  });
+  const nftToVerify = await createVerifiedNft(umi, {
+    tokenOwner: umi.identity.publicKey,
+    collectionMint: requiredCollection,
+    collectionAuthority: requiredCollectionAuthority,
+  });
+
+  const collection = (await createCollection(umi)).publicKey;
+  const { publicKey: candyMachine } = await createV2(umi, {
+    collection,
+    configLines: [
+      { name: 'Degen #1', uri: 'https://example.com/degen/1' },
+      { name: 'Degen #2', uri: 'https://example.com/degen/2' },
+    ],
+    guards: {
+      nftMintLimit: some({ id: 1, limit: 5, requiredCollection }),
+    },
+  });
+
+  // And we minted from it.
+  await transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(
+      mintV1(umi, {
+        candyMachine,
+        asset: generateSigner(umi),
+        collection,
+        mintArgs: {
+          nftMintLimit: some({ id: 1, mint: nftToVerify.publicKey }),
+        },
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // And the Candy Machine was withdrawn.
+  await transactionBuilder()
+    .add(deleteCandyMachine(umi, { candyMachine }))
+    .sendAndConfirm(umi);
+
+  // When the authority closes the nft mint counter.
+  await transactionBuilder()
+    .add(
+      route(umi, {
+        candyMachine,
+        guard: 'nftMintLimit',
+        routeArgs: {
+          path: 'close',
+          id: 1,
+          mint: nftToVerify.publicKey,
+          authority: umi.identity,
+        },
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then the nft mint counter no longer exists.
+  const [counterPda] = findNftMintCounterPda(umi, {
+    id: 1,
+    mint: nftToVerify.publicKey,
+    candyGuard: findCandyGuardPda(umi, { base: candyMachine })[0],
+    candyMachine,
+  });
+  t.false(await umi.rpc.accountExists(counterPda));
+});
@@ -0,0 +1,238 @@
+import { setComputeUnitLimit } from '@metaplex-foundation/mpl-toolbox';
+import {
+  generateSigner,
+  isEqualToAmount,
+  sol,
+  some,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import { generateSignerWithSol } from '@metaplex-foundation/umi-bundle-tests';
+import test from 'ava';
+import { mintV1, route } from '../../src';
+import {
+  assertSuccessfulMint,
+  createCollection,
+  createUmi,
+  createV2,
+  tomorrow,
+  yesterday,
+} from '../_setup';
+
+const twoDaysAgo = () => yesterday() - 3600n * 24n;
+
+test('it uses the group of the active phase when no group is provided', async (t) => {
+  // Given a Candy Machine with a phase schedule such that:
+  // - The EARLY phase ended yesterday.
+  // - The PUBLIC phase is active until tomorrow.
+  const umi = await createUmi();
+  const destinationEarly = generateSigner(umi).publicKey;
+  const destinationPublic = generateSigner(umi).publicKey;
+  const collection = (await createCollection(umi)).publicKey;
+  const { publicKey: candyMachine } = await createV2(umi, {
+    collection,
+    configLines: [{ name: 'Degen #1', uri: 'https://example.com/degen/1' }],
+    guards: {
+      phaseSchedule: some({
+        phases: [
+          { label: 'EARLY', start: twoDaysAgo(), end: yesterday() },
+          { label: 'PUBLIC', start: yesterday(), end: tomorrow() },
+        ],
+      }),
+    },
+    groups: [
+      {
+        label: 'EARLY',
+        guards: {
+          solPayment: some({ lamports: sol(1), destination: destinationEarly }),
+        },
+      },
+      {
+        label: 'PUBLIC',
+        guards: {
+          solPayment: some({
+            lamports: sol(2),
+            destination: destinationPublic,
+          }),
+        },
+      },
+    ],
+  });
+
+  // When we mint from it without providing a group.
+  const payer = await generateSignerWithSol(umi, sol(10));
+  const mint = generateSigner(umi);
+  await transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(
+      mintV1(umi, {
+        candyMachine,
+        asset: mint,
+        payer,
+        collection,
+        mintArgs: { solPayment: some({ destination: destinationPublic }) },
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then minting was successful through the PUBLIC group.
+  await assertSuccessfulMint(t, umi, { mint, owner: umi.identity });
+  const treasuryBalance = await umi.rpc.getBalance(destinationPublic);
+  t.true(isEqualToAmount(treasuryBalance, sol(2)), 'treasury received SOLs');
+});
+
+test('it forbids minting from a group whose phase is not active', async (t) => {
+  // Given a Candy Machine with a phase schedule whose EARLY phase ended.
+  const umi = await createUmi();
+  const destination = generateSigner(umi).publicKey;
+  const collection = (await createCollection(umi)).publicKey;
+  const { publicKey: candyMachine } = await createV2(umi, {
+    collection,
+    configLines: [{ name: 'Degen #1', uri: 'https://example.com/degen/1' }],
+    guards: {
+      phaseSchedule: some({
+        phases: [
+          { label: 'EARLY', start: twoDaysAgo(), end: yesterday() },
+          { label: 'PUBLIC', start: yesterday(), end: tomorrow() },
+        ],
+      }),
+    },
+    groups: [
+      {
+        label: 'EARLY',
+        guards: { solPayment: some({ lamports: sol(1), destination }) },
+      },
+      {
+        label: 'PUBLIC',
+        guards: { solPayment: some({ lamports: sol(2), destination }) },
+      },
+    ],
+  });
+
+  // When we try to mint from the EARLY group.
+  const mint = generateSigner(umi);
+  const promise = transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(
+      mintV1(umi, {
+        candyMachine,
+        asset: mint,
+        collection,
+        group: some('EARLY'),
+        mintArgs: { solPayment: some({ destination }) },
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, { message: /PhaseNotActive/ });
+});
+
+test('it forbids minting from a group whose phase is not active using a padded label', async (t) => {
+  // Given a Candy Machine with a phase schedule whose EARLY phase ended.
+  const umi = await createUmi();
+  const destination = generateSigner(umi).publicKey;
+  const collection = (await createCollection(umi)).publicKey;
+  const { publicKey: candyMachine } = await createV2(umi, {
+    collection,
+    configLines: [{ name: 'Degen #1', uri: 'https://example.com/degen/1' }],
+    guards: {
+      phaseSchedule: some({
+        phases: [
+          { label: 'EARLY', start: twoDaysAgo(), end: yesterday() },
+          { label: 'PUBLIC', start: yesterday(), end: tomorrow() },
+        ],
+      }),
+    },
+    groups: [
+      {
+        label: 'EARLY',
+        guards: { solPayment: some({ lamports: sol(1), destination }) },
+      },
+      {
+        label: 'PUBLIC',
+        guards: { solPayment: some({ lamports: sol(2), destination }) },
+      },
+    ],
+  });
+
+  // When we try to mint from the EARLY group using a label padded
+  // with null characters.
+  const mint = generateSigner(umi);
+  const promise = transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(
+      mintV1(umi, {
+        candyMachine,
+        asset: mint,
+        collection,
+        group: some('EARLY\0'),
+        mintArgs: { solPayment: some({ destination }) },
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then the phase of the group still applies.
+  await t.throwsAsync(promise, { message: /PhaseNotActive/ });
+});
+
+test('it can inspect the phase schedule', async (t) => {
+  // Given a Candy Machine with a phase schedule whose PUBLIC phase is active.
+  const umi = await createUmi();
+  const collection = (await createCollection(umi)).publicKey;
+  const { publicKey: candyMachine } = await createV2(umi, {
+    collection,
+    configLines: [{ name: 'Degen #1', uri: 'https://example.com/degen/1' }],
+    guards: {
+      phaseSchedule: some({
+        phases: [{ label: 'PUBLIC', start: yesterday(), end: tomorrow() }],
+      }),
+    },
+    groups: [{ label: 'PUBLIC', guards: {} }],
+  });
+
+  // When we inspect the schedule without providing a group.
+  const { signature } = await transactionBuilder()
+    .add(
+      route(umi, {
+        candyMachine,
+        guard: 'phaseSchedule',
+        routeArgs: {},
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then the instruction was routed to the guard of the active phase.
+  const transaction = await umi.rpc.getTransaction(signature);
+  t.true(
+    transaction?.meta.logs.some((log) =>
+      log.includes('PhaseSchedule: inspect instruction')
+    )
+  );
+});
+
+test('it fails if the phases overlap', async (t) => {
+  // Given a collection.
+  const umi = await createUmi();
+  const collection = (await createCollection(umi)).publicKey;
+
+  // When we try to create a Candy Machine with overlapping phases.
+  const promise = createV2(umi, {
+    collection,
+    configLines: [{ name: 'Degen #1', uri: 'https://example.com/degen/1' }],
+    guards: {
+      phaseSchedule: some({
+        phases: [
+          { label: 'EARLY', start: twoDaysAgo(), end: tomorrow() },
+          { label: 'PUBLIC', start: yesterday(), end: tomorrow() },
+        ],
+      }),
+    },
+    groups: [
+      { label: 'EARLY', guards: {} },
+      { label: 'PUBLIC', guards: {} },
+    ],
+  });
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, { message: /InvalidPhaseSchedule/ });
+});
@@ -0,0 +1,229 @@
+import { setComputeUnitLimit } from '@metaplex-foundation/mpl-toolbox';
+import {
+  generateSigner,
+  sol,
+  some,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import { generateSignerWithSol } from '@metaplex-foundation/umi-bundle-tests';
+import test from 'ava';
+import {
+  deleteCandyMachine,
+  fetchRateLimitTrackerFromSeeds,
+  findCandyGuardPda,
+  findRateLimitTrackerPda,
+  mintV1,
+  route,
+} from '../../src';
+import {
+  assertSuccessfulMint,
+  createCollection,
+  createUmi,
+  createV2,
+} from '../_setup';
+
+test('it allows minting when the rate limit is not reached', async (t) => {
+  // Given a loaded Candy Machine with a rate limit of 2 mints per hour.
+  const umi = await createUmi();
+  const collection = (await createCollection(umi)).publicKey;
+  const { publicKey: candyMachine } = await createV2(umi, {
+    collection,
+    configLines: [
+      { name: 'Degen #1', uri: 'https://example.com/degen/1' },
+      { name: 'Degen #2', uri: 'https://example.com/degen/2' },
+    ],
+    guards: {
+      rateLimit: some({ id: 1, limit: 2, window: 3600 }),
+    },
+  });
+
+  // When we mint from it.
+  const mint = generateSigner(umi);
+  await transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(
+      mintV1(umi, {
+        candyMachine,
+        asset: mint,
+        collection,
+        mintArgs: { rateLimit: some({ id: 1 }) },
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then minting was successful.
+  await assertSuccessfulMint(t, umi, { mint, owner: umi.identity });
+
+  // And the rate limit tracker was incremented.
+  const tracker = await fetchRateLimitTrackerFromSeeds(umi, {
+    id: 1,
+    candyGuard: findCandyGuardPda(umi, { base: candyMachine })[0],
+    candyMachine,
+  });
+  t.is(tracker.count, 1);
+});
+
+test('it forbids minting across wallets once the rate limit is reached', async (t) => {
+  // Given a loaded Candy Machine with a rate limit of 1 mint per hour.
+  const umi = await createUmi();
+  const collection = (await createCollection(umi)).publicKey;
+  const { publicKey: candyMachine } = await createV2(umi, {
+    collection,
+    configLines: [
+      { name: 'Degen #1', uri: 'https://example.com/degen/1' },
+      { name: 'Degen #2', uri: 'https://example.com/degen/2' },
+    ],
+    guards: {
+      rateLimit: some({ id: 1, limit: 1, window: 3600 }),
+    },
+  });
+
+  // And the identity already minted from it.
+  await transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(
+      mintV1(umi, {
+        candyMachine,
+        asset: generateSigner(umi),
+        collection,
+        mintArgs: { rateLimit: some({ id: 1 }) },
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // When another wallet tries to mint from it.
+  const minter = await generateSignerWithSol(umi, sol(10));
+  const promise = transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(
+      mintV1(umi, {
+        candyMachine,
+        asset: generateSigner(umi),
+        minter,
+        payer: minter,
+        collection,
+        mintArgs: { rateLimit: some({ id: 1 }) },
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, { message: /RateLimitExceeded/ });
+});
+
+test('it does not charge a bot tax when the rate limit is reached', async (t) => {
+  // Given a loaded Candy Machine with a rate limit and a bot tax guard.
+  const umi = await createUmi();
+  const collection = (await createCollection(umi)).publicKey;
+  const { publicKey: candyMachine } = await createV2(umi, {
+    collection,
+    configLines: [
+      { name: 'Degen #1', uri: 'https://example.com/degen/1' },
+      { name: 'Degen #2', uri: 'https://example.com/degen/2' },
+    ],
+    guards: {
+      botTax: some({ lamports: sol(0.1), lastInstruction: true }),
+      rateLimit: some({ id: 1, limit: 1, window: 3600 }),
+    },
+  });
+
+  // And the identity already minted from it.
+  await transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(
+      mintV1(umi, {
+        candyMachine,
+        asset: generateSigner(umi),
+        collection,
+        mintArgs: { rateLimit: some({ id: 1 }) },
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // When the identity tries to mint again.
+  const promise = transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(
+      mintV1(umi, {
+        candyMachine,
+        asset: generateSigner(umi),
+        collection,
+        mintArgs: { rateLimit: some({ id: 1 }) },
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then the transaction fails instead of charging a bot tax.
+  await t.throwsAsync(promise, { message: /RateLimitExceeded/ });
+});
+
+test('it forbids creating a rate limit with a limit of zero', async (t) => {
+  // Given an existing collection.
+  const umi = await createUmi();
+  const collection = (await createCollection(umi)).publicKey;
+
+  // When we try to create a Candy Machine with a rate limit of zero mints.
+  const promise = createV2(umi, {
+    collection,
+    configLines: [{ name: 'Degen #1', uri: 'https://example.com/degen/1' }],
+    guards: {
+      rateLimit: some({ id: 1, limit: 0, window: 3600 }),
+    },
+  });
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, { message: /InvalidRateLimitConfig/ });
+});
+
+test('it allows the authority to close the rate limit tracker once the candy machine is withdrawn', async (t) => {
+  // Given a loaded Candy Machine with a rate limit.
+  const umi = await createUmi();
+  const collection = (await createCollection(umi)).publicKey;
+  const { publicKey: candyMachine } = await createV2(umi, {
+    collection,
+    configLines: [
+      { name: 'Degen #1', uri: 'https://example.com/degen/1' },
+      { name: 'Degen #2', uri: 'https://example.com/degen/2' },
+    ],
+    guards: {
+      rateLimit: some({ id: 1, limit: 2, window: 3600 }),
+    },
+  });
+
+  // And we minted from it.
+  await transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(
+      mintV1(umi, {
+        candyMachine,
+        asset: generateSigner(umi),
+        collection,
+        mintArgs: { rateLimit: some({ id: 1 }) },
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // And the Candy Machine was withdrawn.
+  await transactionBuilder()
+    .add(deleteCandyMachine(umi, { candyMachine }))
+    .sendAndConfirm(umi);
+
+  // When the authority closes the rate limit tracker.
+  await transactionBuilder()
+    .add(
+      route(umi, {
+        candyMachine,
+        guard: 'rateLimit',
+        routeArgs: { path: 'close', id: 1, candyGuardAuthority: umi.identity },
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then the rate limit tracker no longer exists.
+  const [trackerPda] = findRateLimitTrackerPda(umi, {
+    id: 1,
+    candyMachine,
+    candyGuard: findCandyGuardPda(umi, { base: candyMachine })[0],
+  });
+  t.false(await umi.rpc.accountExists(trackerPda));
+});
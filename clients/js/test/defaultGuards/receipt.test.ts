@@ -0,0 +1,291 @@
+import { setComputeUnitLimit } from '@metaplex-foundation/mpl-toolbox';
+import {
+  generateSigner,
+  none,
+  sol,
+  some,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import { generateSignerWithSol } from '@metaplex-foundation/umi-bundle-tests';
+import test from 'ava';
+import {
+  GuardType,
+  MintReceipt,
+  deleteCandyMachine,
+  fetchMintReceiptFromSeeds,
+  findCandyGuardPda,
+  findMintReceiptPda,
+  mintV1,
+  route,
+} from '../../src';
+import {
+  assertSuccessfulMint,
+  createCollection,
+  createUmi,
+  createV2,
+} from '../_setup';
+
+test('it records the details of the mint in a receipt', async (t) => {
+  // Given a loaded Candy Machine with a receipt guard and a solPayment guard.
+  const umi = await createUmi();
+  const destination = generateSigner(umi).publicKey;
+  const collection = (await createCollection(umi)).publicKey;
+  const { publicKey: candyMachine } = await createV2(umi, {
+    collection,
+    configLines: [{ name: 'Degen #1', uri: 'https://example.com/degen/1' }],
+    guards: {
+      receipt: some({}),
+      solPayment: some({ lamports: sol(1), destination }),
+    },
+  });
+
+  // When we mint from it using an explicit payer.
+  const payer = await generateSignerWithSol(umi, sol(10));
+  const minter = generateSigner(umi);
+  const mint = generateSigner(umi);
+  await transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(
+      mintV1(umi, {
+        candyMachine,
+        asset: mint,
+        minter,
+        payer,
+        collection,
+        mintArgs: {
+          receipt: some({}),
+          solPayment: some({ destination }),
+        },
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then minting was successful.
+  await assertSuccessfulMint(t, umi, { mint, owner: minter });
+
+  // And a receipt was created with the details of the mint.
+  const receipt = await fetchMintReceiptFromSeeds(umi, {
+    candyMachine,
+    asset: mint.publicKey,
+  });
+  t.like(receipt, <MintReceipt>{
+    candyGuard: findCandyGuardPda(umi, { base: candyMachine })[0],
+    candyMachine,
+    asset: mint.publicKey,
+    minter: minter.publicKey,
+    owner: minter.publicKey,
+    payer: payer.publicKey,
+    group: none(),
+    mintNumber: 1n,
+  });
+  t.deepEqual(receipt.payments, [
+    { guard: GuardType.SolPayment, mint: none(), amount: sol(1).basisPoints },
+  ]);
+});
+
+test('it allows the payer to close its receipt once the candy machine is sold out', async (t) => {
+  // Given a Candy Machine with 1 item and a receipt guard.
+  const umi = await createUmi();
+  const collection = (await createCollection(umi)).publicKey;
+  const { publicKey: candyMachine } = await createV2(umi, {
+    collection,
+    configLines: [{ name: 'Degen #1', uri: 'https://example.com/degen/1' }],
+    guards: { receipt: some({}) },
+  });
+
+  // And a payer that minted the only item.
+  const payer = await generateSignerWithSol(umi, sol(10));
+  const mint = generateSigner(umi);
+  await transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(
+      mintV1(umi, {
+        candyMachine,
+        asset: mint,
+        payer,
+        collection,
+        mintArgs: { receipt: some({}) },
+      })
+    )
+    .sendAndConfirm(umi);
+  const [receipt] = findMintReceiptPda(umi, {
+    candyMachine,
+    asset: mint.publicKey,
+  });
+  t.true(await umi.rpc.accountExists(receipt));
+
+  // When the payer closes its receipt.
+  await transactionBuilder()
+    .add(
+      route(umi, {
+        candyMachine,
+        guard: 'receipt',
+        routeArgs: {
+          asset: mint.publicKey,
+          authority: payer,
+          receiptPayer: payer.publicKey,
+        },
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then the receipt account no longer exists.
+  t.false(await umi.rpc.accountExists(receipt));
+});
+
+test('it allows the candy guard authority to close a receipt once the candy machine is withdrawn', async (t) => {
+  // Given a Candy Machine with a receipt guard that was minted from.
+  const umi = await createUmi();
+  const collection = (await createCollection(umi)).publicKey;
+  const { publicKey: candyMachine } = await createV2(umi, {
+    collection,
+    configLines: [
+      { name: 'Degen #1', uri: 'https://example.com/degen/1' },
+      { name: 'Degen #2', uri: 'https://example.com/degen/2' },
+    ],
+    guards: { receipt: some({}) },
+  });
+  const payer = await generateSignerWithSol(umi, sol(10));
+  const mint = generateSigner(umi);
+  await transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(
+      mintV1(umi, {
+        candyMachine,
+        asset: mint,
+        payer,
+        collection,
+        mintArgs: { receipt: some({}) },
+      })
+    )
+    .sendAndConfirm(umi);
+  const [receipt] = findMintReceiptPda(umi, {
+    candyMachine,
+    asset: mint.publicKey,
+  });
+  t.true(await umi.rpc.accountExists(receipt));
+
+  // And the Candy Machine was withdrawn.
+  await transactionBuilder()
+    .add(deleteCandyMachine(umi, { candyMachine }))
+    .sendAndConfirm(umi);
+
+  // When the candy guard authority closes the receipt.
+  const payerBalance = await umi.rpc.getBalance(payer.publicKey);
+  await transactionBuilder()
+    .add(
+      route(umi, {
+        candyMachine,
+        guard: 'receipt',
+        routeArgs: {
+          asset: mint.publicKey,
+          authority: umi.identity,
+          receiptPayer: payer.publicKey,
+        },
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then the receipt account no longer exists.
+  t.false(await umi.rpc.accountExists(receipt));
+
+  // And the rent was returned to the original payer.
+  const newPayerBalance = await umi.rpc.getBalance(payer.publicKey);
+  t.true(newPayerBalance.basisPoints > payerBalance.basisPoints);
+});
+
+test('it forbids the candy guard authority from closing a receipt before the candy machine is withdrawn', async (t) => {
+  // Given a Candy Machine with 2 items and a receipt guard.
+  const umi = await createUmi();
+  const collection = (await createCollection(umi)).publicKey;
+  const { publicKey: candyMachine } = await createV2(umi, {
+    collection,
+    configLines: [
+      { name: 'Degen #1', uri: 'https://example.com/degen/1' },
+      { name: 'Degen #2', uri: 'https://example.com/degen/2' },
+    ],
+    guards: { receipt: some({}) },
+  });
+
+  // And a payer that minted one of the items.
+  const payer = await generateSignerWithSol(umi, sol(10));
+  const mint = generateSigner(umi);
+  await transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(
+      mintV1(umi, {
+        candyMachine,
+        asset: mint,
+        payer,
+        collection,
+        mintArgs: { receipt: some({}) },
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // When the candy guard authority tries to close the receipt.
+  const promise = transactionBuilder()
+    .add(
+      route(umi, {
+        candyMachine,
+        guard: 'receipt',
+        routeArgs: {
+          asset: mint.publicKey,
+          authority: umi.identity,
+          receiptPayer: payer.publicKey,
+        },
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, { message: /MintNotOver/ });
+});
+
+test('it forbids the payer from closing a receipt before the mint is over', async (t) => {
+  // Given a Candy Machine with 2 items and a receipt guard.
+  const umi = await createUmi();
+  const collection = (await createCollection(umi)).publicKey;
+  const { publicKey: candyMachine } = await createV2(umi, {
+    collection,
+    configLines: [
+      { name: 'Degen #1', uri: 'https://example.com/degen/1' },
+      { name: 'Degen #2', uri: 'https://example.com/degen/2' },
+    ],
+    guards: { receipt: some({}) },
+  });
+
+  // And a payer that minted one of the items.
+  const payer = await generateSignerWithSol(umi, sol(10));
+  const mint = generateSigner(umi);
+  await transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(
+      mintV1(umi, {
+        candyMachine,
+        asset: mint,
+        payer,
+        collection,
+        mintArgs: { receipt: some({}) },
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // When the payer tries to close its receipt.
+  const promise = transactionBuilder()
+    .add(
+      route(umi, {
+        candyMachine,
+        guard: 'receipt',
+        routeArgs: {
+          asset: mint.publicKey,
+          authority: payer,
+          receiptPayer: payer.publicKey,
+        },
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, { message: /MintNotOver/ });
+});
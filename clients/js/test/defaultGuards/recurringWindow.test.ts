@@ -0,0 +1,172 @@
+import { setComputeUnitLimit } from '@metaplex-foundation/mpl-toolbox';
+import {
+  generateSigner,
+  now,
+  sol,
+  some,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import test from 'ava';
+import { mintV1 } from '../../src';
+import {
+  assertBotTax,
+  assertSuccessfulMint,
+  createCollection,
+  createUmi,
+  createV2,
+} from '../_setup';
+
+test('it allows minting during a window', async (t) => {
+  // Given a candy machine with hourly windows of 40 minutes,
+  // the current one having started 20 minutes ago.
+  const umi = await createUmi();
+  const collection = (await createCollection(umi)).publicKey;
+  const { publicKey: candyMachine } = await createV2(umi, {
+    collection,
+    configLines: [{ name: 'Degen #1', uri: 'https://example.com/degen/1' }],
+    guards: {
+      recurringWindow: some({
+        period: 3600,
+        offset: now() - 1200n,
+        duration: 2400,
+      }),
+    },
+  });
+
+  // When we mint from it.
+  const mint = generateSigner(umi);
+  await transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(
+      mintV1(umi, {
+        candyMachine,
+        asset: mint,
+        collection,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then the mint was successful.
+  await assertSuccessfulMint(t, umi, { mint, owner: umi.identity });
+});
+
+test('it allows minting during a window aligned in the past', async (t) => {
+  // Given a candy machine with hourly windows of 40 minutes,
+  // aligned on a window that started a week and 20 minutes ago.
+  const umi = await createUmi();
+  const collection = (await createCollection(umi)).publicKey;
+  const { publicKey: candyMachine } = await createV2(umi, {
+    collection,
+    configLines: [{ name: 'Degen #1', uri: 'https://example.com/degen/1' }],
+    guards: {
+      recurringWindow: some({
+        period: 3600,
+        offset: now() - 3600n * 24n * 7n - 1200n,
+        duration: 2400,
+      }),
+    },
+  });
+
+  // When we mint from it.
+  const mint = generateSigner(umi);
+  await transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(
+      mintV1(umi, {
+        candyMachine,
+        asset: mint,
+        collection,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then the mint was successful.
+  await assertSuccessfulMint(t, umi, { mint, owner: umi.identity });
+});
+
+test('it forbids minting outside of a window', async (t) => {
+  // Given a candy machine with hourly windows of 10 minutes,
+  // the next one starting in 20 minutes.
+  const umi = await createUmi();
+  const collection = (await createCollection(umi)).publicKey;
+  const { publicKey: candyMachine } = await createV2(umi, {
+    collection,
+    configLines: [{ name: 'Degen #1', uri: 'https://example.com/degen/1' }],
+    guards: {
+      recurringWindow: some({
+        period: 3600,
+        offset: now() + 1200n,
+        duration: 600,
+      }),
+    },
+  });
+
+  // When we try to mint from it.
+  const mint = generateSigner(umi);
+  const promise = transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(
+      mintV1(umi, {
+        candyMachine,
+        asset: mint,
+        collection,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, { message: /OutsideRecurringWindow/ });
+});
+
+test('it charges a bot tax when trying to mint outside of a window', async (t) => {
+  // Given a candy machine with a bot tax and hourly windows of 10 minutes,
+  // the next one starting in 20 minutes.
+  const umi = await createUmi();
+  const collection = (await createCollection(umi)).publicKey;
+  const { publicKey: candyMachine } = await createV2(umi, {
+    collection,
+    configLines: [{ name: 'Degen #1', uri: 'https://example.com/degen/1' }],
+    guards: {
+      botTax: some({ lamports: sol(0.01), lastInstruction: true }),
+      recurringWindow: some({
+        period: 3600,
+        offset: now() + 1200n,
+        duration: 600,
+      }),
+    },
+  });
+
+  // When we mint from it.
+  const mint = generateSigner(umi);
+  const { signature } = await transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(
+      mintV1(umi, {
+        candyMachine,
+        asset: mint,
+        collection,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then we expect a silent bot tax error.
+  await assertBotTax(t, umi, mint, signature, /OutsideRecurringWindow/);
+});
+
+test('it forbids windows longer than their period', async (t) => {
+  // Given an existing collection.
+  const umi = await createUmi();
+  const collection = (await createCollection(umi)).publicKey;
+
+  // When we try to create a candy machine with overlapping windows.
+  const promise = createV2(umi, {
+    collection,
+    configLines: [{ name: 'Degen #1', uri: 'https://example.com/degen/1' }],
+    guards: {
+      recurringWindow: some({ period: 3600, offset: now(), duration: 7200 }),
+    },
+  });
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, { message: /InvalidRecurringWindow/ });
+});
@@ -0,0 +1,289 @@
+import { setComputeUnitLimit } from '@metaplex-foundation/mpl-toolbox';
+import {
+  generateSigner,
+  isEqualToAmount,
+  none,
+  PublicKey,
+  sol,
+  some,
+  transactionBuilder,
+  Umi,
+} from '@metaplex-foundation/umi';
+import { generateSignerWithSol } from '@metaplex-foundation/umi-bundle-tests';
+import test from 'ava';
+import {
+  fetchVoucherNonceFromSeeds,
+  findCandyGuardPda,
+  mintV1,
+  signVoucher,
+  VoucherArgs,
+} from '../../src';
+import {
+  assertSuccessfulMint,
+  createCollection,
+  createUmi,
+  createV2,
+  tomorrow,
+  yesterday,
+} from '../_setup';
+
+const createVoucherCandyMachine = async (
+  umi: Umi,
+  signer: PublicKey,
+  destination: PublicKey
+) => {
+  const collection = (await createCollection(umi)).publicKey;
+  const { publicKey: candyMachine } = await createV2(umi, {
+    collection,
+    configLines: [
+      { name: 'Degen #1', uri: 'https://example.com/degen/1' },
+      { name: 'Degen #2', uri: 'https://example.com/degen/2' },
+    ],
+    guards: { signedVoucher: some({ signer, destination }) },
+  });
+  return { candyMachine, collection };
+};
+
+test('it allows minting with a voucher signed by the signer', async (t) => {
+  // Given a loaded Candy Machine with a signedVoucher guard.
+  const umi = await createUmi();
+  const voucherSigner = generateSigner(umi);
+  const destination = generateSigner(umi).publicKey;
+  const { candyMachine, collection } = await createVoucherCandyMachine(
+    umi,
+    voucherSigner.publicKey,
+    destination
+  );
+
+  // When we mint from it using a voucher signed by the signer.
+  const voucher: VoucherArgs = {
+    minter: umi.identity.publicKey,
+    candyMachine,
+    label: none(),
+    price: none(),
+    expiry: tomorrow(),
+    nonce: 42,
+  };
+  const mint = generateSigner(umi);
+  await transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(await signVoucher({ signer: voucherSigner, voucher }))
+    .add(
+      mintV1(umi, {
+        candyMachine,
+        asset: mint,
+        collection,
+        mintArgs: { signedVoucher: some({ nonce: 42 }) },
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then minting was successful.
+  await assertSuccessfulMint(t, umi, { mint, owner: umi.identity });
+
+  // And the nonce of the voucher was recorded.
+  const voucherNonce = await fetchVoucherNonceFromSeeds(umi, {
+    nonce: 42,
+    candyGuard: findCandyGuardPda(umi, { base: candyMachine })[0],
+    candyMachine,
+  });
+  t.is(voucherNonce.minter, umi.identity.publicKey);
+});
+
+test('it charges the price of the voucher', async (t) => {
+  // Given a loaded Candy Machine with a signedVoucher guard.
+  const umi = await createUmi();
+  const voucherSigner = generateSigner(umi);
+  const destination = generateSigner(umi).publicKey;
+  const { candyMachine, collection } = await createVoucherCandyMachine(
+    umi,
+    voucherSigner.publicKey,
+    destination
+  );
+
+  // When a minter uses a voucher that costs 1 SOL.
+  const minter = await generateSignerWithSol(umi, sol(10));
+  const voucher: VoucherArgs = {
+    minter: minter.publicKey,
+    candyMachine,
+    label: none(),
+    price: some(sol(1).basisPoints),
+    expiry: tomorrow(),
+    nonce: 1,
+  };
+  const mint = generateSigner(umi);
+  await transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(await signVoucher({ signer: voucherSigner, voucher }))
+    .add(
+      mintV1(umi, {
+        candyMachine,
+        asset: mint,
+        minter,
+        payer: minter,
+        collection,
+        mintArgs: { signedVoucher: some({ nonce: 1, destination }) },
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then minting was successful.
+  await assertSuccessfulMint(t, umi, { mint, owner: minter });
+
+  // And the destination received the price of the voucher.
+  const destinationBalance = await umi.rpc.getBalance(destination);
+  t.true(isEqualToAmount(destinationBalance, sol(1)), 'destination got SOLs');
+});
+
+test('it forbids using a voucher twice', async (t) => {
+  // Given a loaded Candy Machine with a signedVoucher guard.
+  const umi = await createUmi();
+  const voucherSigner = generateSigner(umi);
+  const destination = generateSigner(umi).publicKey;
+  const { candyMachine, collection } = await createVoucherCandyMachine(
+    umi,
+    voucherSigner.publicKey,
+    destination
+  );
+
+  // And a voucher that was already used to mint.
+  const voucher: VoucherArgs = {
+    minter: umi.identity.publicKey,
+    candyMachine,
+    label: none(),
+    price: none(),
+    expiry: tomorrow(),
+    nonce: 7,
+  };
+  await transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(await signVoucher({ signer: voucherSigner, voucher }))
+    .add(
+      mintV1(umi, {
+        candyMachine,
+        asset: generateSigner(umi),
+        collection,
+        mintArgs: { signedVoucher: some({ nonce: 7 }) },
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // When we try to mint again using the same voucher.
+  const promise = transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(await signVoucher({ signer: voucherSigner, voucher }))
+    .add(
+      mintV1(umi, {
+        candyMachine,
+        asset: generateSigner(umi),
+        collection,
+        mintArgs: { signedVoucher: some({ nonce: 7 }) },
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, { message: /VoucherAlreadyUsed/ });
+});
+
+test('it forbids minting with a voucher signed by another key', async (t) => {
+  // Given a loaded Candy Machine with a signedVoucher guard.
+  const umi = await createUmi();
+  const voucherSigner = generateSigner(umi);
+  const destination = generateSigner(umi).publicKey;
+  const { candyMachine, collection } = await createVoucherCandyMachine(
+    umi,
+    voucherSigner.publicKey,
+    destination
+  );
+
+  // When we try to mint from it using a voucher signed by another key.
+  const voucher: VoucherArgs = {
+    minter: umi.identity.publicKey,
+    candyMachine,
+    label: none(),
+    price: none(),
+    expiry: tomorrow(),
+    nonce: 42,
+  };
+  const promise = transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(await signVoucher({ signer: generateSigner(umi), voucher }))
+    .add(
+      mintV1(umi, {
+        candyMachine,
+        asset: generateSigner(umi),
+        collection,
+        mintArgs: { signedVoucher: some({ nonce: 42 }) },
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, { message: /InvalidVoucher/ });
+});
+
+test('it forbids minting with an expired voucher', async (t) => {
+  // Given a loaded Candy Machine with a signedVoucher guard.
+  const umi = await createUmi();
+  const voucherSigner = generateSigner(umi);
+  const destination = generateSigner(umi).publicKey;
+  const { candyMachine, collection } = await createVoucherCandyMachine(
+    umi,
+    voucherSigner.publicKey,
+    destination
+  );
+
+  // When we try to mint from it using a voucher that expired yesterday.
+  const voucher: VoucherArgs = {
+    minter: umi.identity.publicKey,
+    candyMachine,
+    label: none(),
+    price: none(),
+    expiry: yesterday(),
+    nonce: 42,
+  };
+  const promise = transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(await signVoucher({ signer: voucherSigner, voucher }))
+    .add(
+      mintV1(umi, {
+        candyMachine,
+        asset: generateSigner(umi),
+        collection,
+        mintArgs: { signedVoucher: some({ nonce: 42 }) },
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, { message: /VoucherExpired/ });
+});
+
+test('it forbids minting without a voucher', async (t) => {
+  // Given a loaded Candy Machine with a signedVoucher guard.
+  const umi = await createUmi();
+  const voucherSigner = generateSigner(umi);
+  const destination = generateSigner(umi).publicKey;
+  const { candyMachine, collection } = await createVoucherCandyMachine(
+    umi,
+    voucherSigner.publicKey,
+    destination
+  );
+
+  // When we try to mint from it without the Ed25519 instruction.
+  const promise = transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(
+      mintV1(umi, {
+        candyMachine,
+        asset: generateSigner(umi),
+        collection,
+        mintArgs: { signedVoucher: some({ nonce: 42 }) },
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, { message: /MissingVoucher/ });
+});
@@ -0,0 +1,404 @@
+import { setComputeUnitLimit } from '@metaplex-foundation/mpl-toolbox';
+import {
+  generateSigner,
+  none,
+  sol,
+  some,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import { generateSignerWithSol } from '@metaplex-foundation/umi-bundle-tests';
+import test from 'ava';
+import {
+  fetchSolGateRegistrationFromSeeds,
+  findCandyGuardPda,
+  findSolGateRegistrationPda,
+  mintV1,
+  route,
+} from '../../src';
+import {
+  assertBotTax,
+  assertSuccessfulMint,
+  createCollection,
+  createUmi,
+  createV2,
+} from '../_setup';
+
+test('it allows minting when the minter has the minimum balance', async (t) => {
+  // Given a loaded Candy Machine with a solGate guard of 1 SOL.
+  const umi = await createUmi();
+  const collection = (await createCollection(umi)).publicKey;
+  const { publicKey: candyMachine } = await createV2(umi, {
+    collection,
+    configLines: [{ name: 'Degen #1', uri: 'https://example.com/degen/1' }],
+    guards: {
+      solGate: some({ minimumBalance: sol(1), minimumAge: none() }),
+    },
+  });
+
+  // When a minter with 10 SOL mints from it.
+  const minter = await generateSignerWithSol(umi, sol(10));
+  const mint = generateSigner(umi);
+  await transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(
+      mintV1(umi, {
+        candyMachine,
+        asset: mint,
+        minter,
+        payer: minter,
+        collection,
+        mintArgs: { solGate: some({ minimumAge: none() }) },
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then minting was successful.
+  await assertSuccessfulMint(t, umi, { mint, owner: minter });
+});
+
+test('it forbids minting when the minter is below the minimum balance', async (t) => {
+  // Given a loaded Candy Machine with a solGate guard of 5 SOL.
+  const umi = await createUmi();
+  const collection = (await createCollection(umi)).publicKey;
+  const { publicKey: candyMachine } = await createV2(umi, {
+    collection,
+    configLines: [{ name: 'Degen #1', uri: 'https://example.com/degen/1' }],
+    guards: {
+      solGate: some({ minimumBalance: sol(5), minimumAge: none() }),
+    },
+  });
+
+  // When a minter with 1 SOL tries to mint from it.
+  const minter = await generateSignerWithSol(umi, sol(1));
+  const promise = transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(
+      mintV1(umi, {
+        candyMachine,
+        asset: generateSigner(umi),
+        minter,
+        payer: minter,
+        collection,
+        mintArgs: { solGate: some({ minimumAge: none() }) },
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, { message: /BelowMinimumBalance/ });
+});
+
+test('it forbids minting when the payments bring the minter below the minimum balance', async (t) => {
+  // Given a loaded Candy Machine with a solGate guard of 5 SOL
+  // and a solPayment guard of 2 SOL.
+  const umi = await createUmi();
+  const destination = generateSigner(umi).publicKey;
+  const collection = (await createCollection(umi)).publicKey;
+  const { publicKey: candyMachine } = await createV2(umi, {
+    collection,
+    configLines: [{ name: 'Degen #1', uri: 'https://example.com/degen/1' }],
+    guards: {
+      solPayment: some({ lamports: sol(2), destination }),
+      solGate: some({ minimumBalance: sol(5), minimumAge: none() }),
+    },
+  });
+
+  // When a minter with 6 SOL tries to mint from it.
+  const minter = await generateSignerWithSol(umi, sol(6));
+  const promise = transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(
+      mintV1(umi, {
+        candyMachine,
+        asset: generateSigner(umi),
+        minter,
+        payer: minter,
+        collection,
+        mintArgs: {
+          solPayment: some({ destination }),
+          solGate: some({ minimumAge: none() }),
+        },
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, { message: /BelowMinimumBalance/ });
+});
+
+test('it forbids minting when only the payer has the minimum balance', async (t) => {
+  // Given a loaded Candy Machine with a solGate guard of 5 SOL.
+  const umi = await createUmi();
+  const collection = (await createCollection(umi)).publicKey;
+  const { publicKey: candyMachine } = await createV2(umi, {
+    collection,
+    configLines: [{ name: 'Degen #1', uri: 'https://example.com/degen/1' }],
+    guards: {
+      solGate: some({ minimumBalance: sol(5), minimumAge: none() }),
+    },
+  });
+
+  // When an unfunded minter tries to mint from it using a payer with 10 SOL.
+  const payer = await generateSignerWithSol(umi, sol(10));
+  const minter = generateSigner(umi);
+  const promise = transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(
+      mintV1(umi, {
+        candyMachine,
+        asset: generateSigner(umi),
+        minter,
+        payer,
+        collection,
+        mintArgs: { solGate: some({ minimumAge: none() }) },
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, { message: /BelowMinimumBalance/ });
+});
+
+test('it allows minting once the wallet was registered for long enough', async (t) => {
+  // Given a loaded Candy Machine with a solGate guard
+  // with a minimum age of zero seconds.
+  const umi = await createUmi();
+  const collection = (await createCollection(umi)).publicKey;
+  const { publicKey: candyMachine } = await createV2(umi, {
+    collection,
+    configLines: [{ name: 'Degen #1', uri: 'https://example.com/degen/1' }],
+    guards: {
+      solGate: some({ minimumBalance: sol(1), minimumAge: some(0) }),
+    },
+  });
+
+  // When a minter registers its wallet and mints from it.
+  const minter = await generateSignerWithSol(umi, sol(10));
+  await transactionBuilder()
+    .add(
+      route(umi, {
+        candyMachine,
+        guard: 'solGate',
+        payer: minter,
+        routeArgs: { path: 'register' },
+      })
+    )
+    .sendAndConfirm(umi);
+
+  const mint = generateSigner(umi);
+  await transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(
+      mintV1(umi, {
+        candyMachine,
+        asset: mint,
+        minter,
+        payer: minter,
+        collection,
+        mintArgs: { solGate: some({ minimumAge: some(0) }) },
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then minting was successful.
+  await assertSuccessfulMint(t, umi, { mint, owner: minter });
+
+  // And the registration of the minter was recorded.
+  const registration = await fetchSolGateRegistrationFromSeeds(umi, {
+    user: minter.publicKey,
+    candyGuard: findCandyGuardPda(umi, { base: candyMachine })[0],
+    candyMachine,
+  });
+  t.true(registration.timestamp > 0n);
+});
+
+test('it forbids minting when the wallet was registered too recently', async (t) => {
+  // Given a loaded Candy Machine with a solGate guard
+  // with a minimum age of a day.
+  const umi = await createUmi();
+  const collection = (await createCollection(umi)).publicKey;
+  const { publicKey: candyMachine } = await createV2(umi, {
+    collection,
+    configLines: [{ name: 'Degen #1', uri: 'https://example.com/degen/1' }],
+    guards: {
+      solGate: some({ minimumBalance: sol(1), minimumAge: some(86_400) }),
+    },
+  });
+
+  // And the identity just registered its wallet.
+  await transactionBuilder()
+    .add(
+      route(umi, {
+        candyMachine,
+        guard: 'solGate',
+        routeArgs: { path: 'register' },
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // When the identity tries to mint from it.
+  const promise = transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(
+      mintV1(umi, {
+        candyMachine,
+        asset: generateSigner(umi),
+        collection,
+        mintArgs: { solGate: some({ minimumAge: some(86_400) }) },
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, { message: /WalletRegistrationTooRecent/ });
+});
+
+test('it forbids minting when the wallet is not registered', async (t) => {
+  // Given a loaded Candy Machine with a solGate guard with a minimum age.
+  const umi = await createUmi();
+  const collection = (await createCollection(umi)).publicKey;
+  const { publicKey: candyMachine } = await createV2(umi, {
+    collection,
+    configLines: [{ name: 'Degen #1', uri: 'https://example.com/degen/1' }],
+    guards: {
+      solGate: some({ minimumBalance: sol(1), minimumAge: some(0) }),
+    },
+  });
+
+  // When the identity tries to mint from it without registering.
+  const promise = transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(
+      mintV1(umi, {
+        candyMachine,
+        asset: generateSigner(umi),
+        collection,
+        mintArgs: { solGate: some({ minimumAge: some(0) }) },
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, { message: /WalletNotRegistered/ });
+});
+
+test('it forbids registering a wallet twice', async (t) => {
+  // Given a loaded Candy Machine with a solGate guard with a minimum age.
+  const umi = await createUmi();
+  const collection = (await createCollection(umi)).publicKey;
+  const { publicKey: candyMachine } = await createV2(umi, {
+    collection,
+    configLines: [{ name: 'Degen #1', uri: 'https://example.com/degen/1' }],
+    guards: {
+      solGate: some({ minimumBalance: sol(1), minimumAge: some(60) }),
+    },
+  });
+
+  // And a minter that registered its wallet.
+  const minter = await generateSignerWithSol(umi, sol(10));
+  const register = () =>
+    transactionBuilder()
+      .add(
+        route(umi, {
+          candyMachine,
+          guard: 'solGate',
+          routeArgs: { path: 'register', minter },
+        })
+      )
+      .sendAndConfirm(umi);
+  await register();
+
+  // When the minter tries to register again.
+  const promise = register();
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, { message: /WalletAlreadyRegistered/ });
+});
+
+test('it charges a bot tax when the minter is below the minimum balance', async (t) => {
+  // Given a loaded Candy Machine with a bot tax and a solGate guard of 5 SOL.
+  const umi = await createUmi();
+  const collection = (await createCollection(umi)).publicKey;
+  const { publicKey: candyMachine } = await createV2(umi, {
+    collection,
+    configLines: [{ name: 'Degen #1', uri: 'https://example.com/degen/1' }],
+    guards: {
+      botTax: some({ lamports: sol(0.01), lastInstruction: true }),
+      solGate: some({ minimumBalance: sol(5), minimumAge: none() }),
+    },
+  });
+
+  // When a minter with 1 SOL mints from it.
+  const minter = await generateSignerWithSol(umi, sol(1));
+  const mint = generateSigner(umi);
+  const { signature } = await transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(
+      mintV1(umi, {
+        candyMachine,
+        asset: mint,
+        minter,
+        payer: minter,
+        collection,
+        mintArgs: { solGate: some({ minimumAge: none() }) },
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then we expect a silent bot tax error.
+  await assertBotTax(t, umi, mint, signature, /BelowMinimumBalance/);
+});
+
+test('it allows the minter to close its registration once the candy machine is sold out', async (t) => {
+  // Given a loaded Candy Machine with a solGate guard and a single item.
+  const umi = await createUmi();
+  const collection = (await createCollection(umi)).publicKey;
+  const { publicKey: candyMachine } = await createV2(umi, {
+    collection,
+    configLines: [{ name: 'Degen #1', uri: 'https://example.com/degen/1' }],
+    guards: {
+      solGate: some({ minimumBalance: sol(1), minimumAge: some(0) }),
+    },
+  });
+
+  // And the identity registered and minted the last item.
+  await transactionBuilder()
+    .add(
+      route(umi, {
+        candyMachine,
+        guard: 'solGate',
+        routeArgs: { path: 'register' },
+      })
+    )
+    .sendAndConfirm(umi);
+  await transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(
+      mintV1(umi, {
+        candyMachine,
+        asset: generateSigner(umi),
+        collection,
+        mintArgs: { solGate: some({ minimumAge: some(0) }) },
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // When the identity closes its registration.
+  await transactionBuilder()
+    .add(
+      route(umi, {
+        candyMachine,
+        guard: 'solGate',
+        routeArgs: { path: 'close', authority: umi.identity },
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then the registration no longer exists.
+  const [registrationPda] = findSolGateRegistrationPda(umi, {
+    user: umi.identity.publicKey,
+    candyMachine,
+    candyGuard: findCandyGuardPda(umi, { base: candyMachine })[0],
+  });
+  t.false(await umi.rpc.accountExists(registrationPda));
+});
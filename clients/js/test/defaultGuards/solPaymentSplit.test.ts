@@ -0,0 +1,170 @@
+import { setComputeUnitLimit } from '@metaplex-foundation/mpl-toolbox';
+import {
+  generateSigner,
+  isEqualToAmount,
+  lamports,
+  sol,
+  some,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import { generateSignerWithSol } from '@metaplex-foundation/umi-bundle-tests';
+import test from 'ava';
+import { mintV1 } from '../../src';
+import {
+  assertSuccessfulMint,
+  createCollection,
+  createUmi,
+  createV2,
+} from '../_setup';
+
+test('it splits the SOL payment across the destinations', async (t) => {
+  // Given a loaded Candy Machine with a solPaymentSplit guard
+  // charging 1 SOL split 60/40 between two destinations.
+  const umi = await createUmi();
+  const destinationA = generateSigner(umi).publicKey;
+  const destinationB = generateSigner(umi).publicKey;
+  const destinations = [
+    { address: destinationA, basisPoints: 6_000 },
+    { address: destinationB, basisPoints: 4_000 },
+  ];
+  const collection = (await createCollection(umi)).publicKey;
+  const { publicKey: candyMachine } = await createV2(umi, {
+    collection,
+    configLines: [{ name: 'Degen #1', uri: 'https://example.com/degen/1' }],
+    guards: {
+      solPaymentSplit: some({ lamports: sol(1), destinations }),
+    },
+  });
+
+  // When we mint from it using an explicit payer.
+  const payer = await generateSignerWithSol(umi, sol(10));
+  const mint = generateSigner(umi);
+  await transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(
+      mintV1(umi, {
+        candyMachine,
+        asset: mint,
+        payer,
+        collection,
+        mintArgs: { solPaymentSplit: some({ destinations }) },
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then minting was successful.
+  await assertSuccessfulMint(t, umi, { mint, owner: umi.identity });
+
+  // And each destination received its share.
+  const balanceA = await umi.rpc.getBalance(destinationA);
+  t.true(isEqualToAmount(balanceA, sol(0.6)), 'destination A received SOLs');
+  const balanceB = await umi.rpc.getBalance(destinationB);
+  t.true(isEqualToAmount(balanceB, sol(0.4)), 'destination B received SOLs');
+
+  // And the payer lost 1 SOL.
+  const payerBalance = await umi.rpc.getBalance(payer.publicKey);
+  t.true(isEqualToAmount(payerBalance, sol(9), sol(0.1)), 'payer lost SOLs');
+});
+
+test('it adds the remainder of the split to the first destination', async (t) => {
+  // Given a loaded Candy Machine with a solPaymentSplit guard charging
+  // 1 SOL and 1 lamport split evenly between two destinations.
+  const umi = await createUmi();
+  const destinationA = generateSigner(umi).publicKey;
+  const destinationB = generateSigner(umi).publicKey;
+  const destinations = [
+    { address: destinationA, basisPoints: 5_000 },
+    { address: destinationB, basisPoints: 5_000 },
+  ];
+  const collection = (await createCollection(umi)).publicKey;
+  const { publicKey: candyMachine } = await createV2(umi, {
+    collection,
+    configLines: [{ name: 'Degen #1', uri: 'https://example.com/degen/1' }],
+    guards: {
+      solPaymentSplit: some({
+        lamports: lamports(1_000_000_001),
+        destinations,
+      }),
+    },
+  });
+
+  // When we mint from it.
+  const mint = generateSigner(umi);
+  await transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(
+      mintV1(umi, {
+        candyMachine,
+        asset: mint,
+        collection,
+        mintArgs: { solPaymentSplit: some({ destinations }) },
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then minting was successful.
+  await assertSuccessfulMint(t, umi, { mint, owner: umi.identity });
+
+  // And the first destination received the extra lamport.
+  const balanceA = await umi.rpc.getBalance(destinationA);
+  t.is(balanceA.basisPoints, 500_000_001n);
+  const balanceB = await umi.rpc.getBalance(destinationB);
+  t.is(balanceB.basisPoints, 500_000_000n);
+});
+
+test('it fails if the shares do not add up to 100%', async (t) => {
+  // Given a solPaymentSplit guard whose shares only add up to 90%.
+  const umi = await createUmi();
+  const destinations = [
+    { address: generateSigner(umi).publicKey, basisPoints: 5_000 },
+    { address: generateSigner(umi).publicKey, basisPoints: 4_000 },
+  ];
+  const collection = (await createCollection(umi)).publicKey;
+
+  // When we try to create a Candy Machine with it.
+  const promise = createV2(umi, {
+    collection,
+    configLines: [{ name: 'Degen #1', uri: 'https://example.com/degen/1' }],
+    guards: {
+      solPaymentSplit: some({ lamports: sol(1), destinations }),
+    },
+  });
+
+  // Then we expect a program error.
+  await t.throwsAsync(promise, { message: /InvalidPaymentSplit/ });
+});
+
+test('it fails if the payer does not have enough funds', async (t) => {
+  // Given a loaded Candy Machine with a solPaymentSplit guard costing 5 SOLs.
+  const umi = await createUmi();
+  const destinations = [
+    { address: generateSigner(umi).publicKey, basisPoints: 10_000 },
+  ];
+  const collection = (await createCollection(umi)).publicKey;
+  const { publicKey: candyMachine } = await createV2(umi, {
+    collection,
+    configLines: [{ name: 'Degen #1', uri: 'https://example.com/degen/1' }],
+    guards: {
+      solPaymentSplit: some({ lamports: sol(5), destinations }),
+    },
+  });
+
+  // When we mint from it using a payer that only has 4 SOL.
+  const payer = await generateSignerWithSol(umi, sol(4));
+  const mint = generateSigner(umi);
+  const promise = transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(
+      mintV1(umi, {
+        candyMachine,
+        asset: mint,
+        payer,
+        collection,
+        mintArgs: { solPaymentSplit: some({ destinations }) },
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then we expect an error.
+  await t.throwsAsync(promise, { message: /NotEnoughSOL/ });
+});
@@ -0,0 +1,127 @@
+import {
+  fetchToken,
+  setComputeUnitLimit,
+} from '@metaplex-foundation/mpl-toolbox';
+import {
+  generateSigner,
+  some,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import test from 'ava';
+import { mintV1 } from '../../src';
+import {
+  assertSuccessfulMint,
+  createCollection,
+  createMintWithHolders,
+  createUmi,
+  createV2,
+} from '../_setup';
+
+test('it splits the token payment across the destinations', async (t) => {
+  // Given a mint account such that:
+  // - Two destination wallets have an empty token account.
+  // - The payer has 12 tokens.
+  const umi = await createUmi();
+  const destinationA = generateSigner(umi).publicKey;
+  const destinationB = generateSigner(umi).publicKey;
+  const [tokenMint, destinationAtaA, destinationAtaB, identityAta] =
+    await createMintWithHolders(umi, {
+      holders: [
+        { owner: destinationA, amount: 0 },
+        { owner: destinationB, amount: 0 },
+        { owner: umi.identity, amount: 12 },
+      ],
+    });
+
+  // And a loaded Candy Machine with a tokenPaymentSplit guard
+  // that requires 5 tokens split 60/40 between the destinations.
+  const destinations = [
+    { address: destinationA, basisPoints: 6_000 },
+    { address: destinationB, basisPoints: 4_000 },
+  ];
+  const collection = (await createCollection(umi)).publicKey;
+  const { publicKey: candyMachine } = await createV2(umi, {
+    collection,
+    configLines: [{ name: 'Degen #1', uri: 'https://example.com/degen/1' }],
+    guards: {
+      tokenPaymentSplit: some({
+        amount: 5,
+        mint: tokenMint.publicKey,
+        destinations,
+      }),
+    },
+  });
+
+  // When we mint from it.
+  const mint = generateSigner(umi);
+  await transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(
+      mintV1(umi, {
+        candyMachine,
+        asset: mint,
+        collection,
+        mintArgs: {
+          tokenPaymentSplit: some({ mint: tokenMint.publicKey, destinations }),
+        },
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then minting was successful.
+  await assertSuccessfulMint(t, umi, { mint, owner: umi.identity });
+
+  // And each destination received its share.
+  t.is((await fetchToken(umi, destinationAtaA)).amount, 3n);
+  t.is((await fetchToken(umi, destinationAtaB)).amount, 2n);
+
+  // And the payer lost 5 tokens.
+  t.is((await fetchToken(umi, identityAta)).amount, 7n);
+});
+
+test('it fails if the payer does not have enough tokens', async (t) => {
+  // Given a mint account such that the payer has 4 tokens.
+  const umi = await createUmi();
+  const destination = generateSigner(umi).publicKey;
+  const [tokenMint] = await createMintWithHolders(umi, {
+    holders: [
+      { owner: destination, amount: 0 },
+      { owner: umi.identity, amount: 4 },
+    ],
+  });
+
+  // And a loaded Candy Machine with a tokenPaymentSplit guard
+  // that requires 5 tokens.
+  const destinations = [{ address: destination, basisPoints: 10_000 }];
+  const collection = (await createCollection(umi)).publicKey;
+  const { publicKey: candyMachine } = await createV2(umi, {
+    collection,
+    configLines: [{ name: 'Degen #1', uri: 'https://example.com/degen/1' }],
+    guards: {
+      tokenPaymentSplit: some({
+        amount: 5,
+        mint: tokenMint.publicKey,
+        destinations,
+      }),
+    },
+  });
+
+  // When we try to mint from it.
+  const mint = generateSigner(umi);
+  const promise = transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(
+      mintV1(umi, {
+        candyMachine,
+        asset: mint,
+        collection,
+        mintArgs: {
+          tokenPaymentSplit: some({ mint: tokenMint.publicKey, destinations }),
+        },
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then we expect an error.
+  await t.throwsAsync(promise, { message: /NotEnoughTokens/ });
+});
@@ -0,0 +1,129 @@
+import { isNone, none, some } from '@metaplex-foundation/umi';
+import { i64, u64, u8 } from '@metaplex-foundation/umi/serializers';
+import test from 'ava';
+import {
+  CandyGuardProgram,
+  DefaultGuardRepository,
+  DefaultGuardSet,
+  DefaultGuardSetArgs,
+  GuardSet,
+  GuardSetArgs,
+  getFeaturesSerializer,
+  getGuardSetSerializer,
+  noopParser,
+} from '../src';
+import { createUmi } from './_setup';
+
+test('it serializes the features of the first 63 guards in a single word', async (t) => {
+  // Given features with the first and last flags of the word enabled.
+  const features = Array.from({ length: 63 }, (_, i) => i === 0 || i === 62);
+
+  // When we serialize them.
+  const bytes = getFeaturesSerializer().serialize(features);
+
+  // Then they use a single word without the continuation bit.
+  t.deepEqual(bytes, u64().serialize(BigInt('0x4000000000000001')));
+  t.deepEqual(getFeaturesSerializer().deserialize(bytes)[0], features);
+});
+
+test('it serializes the features of more than 63 guards in continuation words', async (t) => {
+  // Given features with flags enabled in the first, second and third words.
+  const features = Array.from({ length: 140 }, (_, i) =>
+    [1, 63, 130].includes(i)
+  );
+
+  // When we serialize them.
+  const bytes = getFeaturesSerializer().serialize(features);
+
+  // Then each word but the last one has the continuation bit set.
+  t.is(bytes.length, 24);
+  t.deepEqual(
+    bytes.slice(0, 8),
+    u64().serialize(BigInt('0x8000000000000002'))
+  );
+  t.deepEqual(
+    bytes.slice(8, 16),
+    u64().serialize(BigInt('0x8000000000000001'))
+  );
+  t.deepEqual(bytes.slice(16, 24), u64().serialize(BigInt(16)));
+
+  // And they can be deserialized back.
+  const [deserialized, offset] = getFeaturesSerializer().deserialize(bytes);
+  t.is(offset, 24);
+  t.deepEqual(
+    deserialized.flatMap((enabled, i) => (enabled ? [i] : [])),
+    [1, 63, 130]
+  );
+});
+
+test('it does not serialize trailing empty words', async (t) => {
+  // Given features without flags enabled after the first word.
+  const features = Array.from({ length: 140 }, (_, i) => i === 5);
+
+  // When we serialize them.
+  const bytes = getFeaturesSerializer().serialize(features);
+
+  // Then a single word is used.
+  t.deepEqual(bytes, u64().serialize(32));
+});
+
+test('it serializes guard sets of programs with more than 63 guards', async (t) => {
+  // Given a program with 70 guards.
+  const repository = new DefaultGuardRepository();
+  const names = Array.from({ length: 70 }, (_, i) => `guard${i}`);
+  names.forEach((name) =>
+    repository.add({
+      name,
+      serializer: u8,
+      mintParser: noopParser,
+      routeParser: noopParser,
+    })
+  );
+  const program = { availableGuards: names } as CandyGuardProgram;
+  const serializer = getGuardSetSerializer<GuardSetArgs, GuardSet>(
+    { coreGuards: repository },
+    program
+  );
+
+  // When we serialize a guard set enabling guards of both words.
+  const bytes = serializer.serialize({ guard2: some(7), guard65: some(9) });
+
+  // Then the guards data follows the two features words.
+  t.is(bytes.length, 16 + 2);
+  t.deepEqual(Array.from(bytes.slice(16)), [7, 9]);
+
+  // And the guard set can be deserialized back.
+  const [guardSet] = serializer.deserialize(bytes);
+  t.deepEqual(guardSet.guard2, some(7));
+  t.deepEqual(guardSet.guard65, some(9));
+  t.deepEqual(guardSet.guard64, none());
+});
+
+test('it deserializes guard sets saved with a single features word', async (t) => {
+  // Given the data of a guard set with the start and end date guards
+  // saved using a legacy single features word.
+  const umi = await createUmi();
+  const program = umi.programs.get<CandyGuardProgram>('mplCoreCandyGuard');
+  const startDateIndex = program.availableGuards.indexOf('startDate');
+  const endDateIndex = program.availableGuards.indexOf('endDate');
+  const features = BigInt(2 ** startDateIndex + 2 ** endDateIndex);
+  const bytes = new Uint8Array([
+    ...u64().serialize(features),
+    ...i64().serialize(1000),
+    ...i64().serialize(2000),
+  ]);
+
+  // When we deserialize it.
+  const [guardSet, offset] = getGuardSetSerializer<
+    DefaultGuardSetArgs,
+    DefaultGuardSet
+  >(umi, program).deserialize(bytes);
+
+  // Then we get the expected guards.
+  t.is(offset, bytes.length);
+  t.like(guardSet, {
+    startDate: some({ date: BigInt(1000) }),
+    endDate: some({ date: BigInt(2000) }),
+  });
+  t.true(isNone(guardSet.botTax));
+});
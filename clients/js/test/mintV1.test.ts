@@ -12,7 +12,7 @@ import {
 import { generateSignerWithSol } from '@metaplex-foundation/umi-bundle-tests';
 import test from 'ava';
 import { fetchAssetV1 } from '@metaplex-foundation/mpl-core';
-import { CandyMachine, fetchCandyMachine, mintV1 } from '../src';
+import { CandyMachine, GuardType, fetchCandyMachine, mintV1 } from '../src';
 import {
   assertSuccessfulMint,
   createCollection,
@@ -193,6 +193,57 @@ test('it can mint from a candy guard with groups', async (t) => {
   await assertSuccessfulMint(t, umi, { mint, owner: minter });
 });
 
+test('it can mint from a group that disables a default guard', async (t) => {
+  // Given a candy machine with a default start date in the future
+  // and a group that disables it.
+  const umi = await createUmi();
+  const collection = (await createCollection(umi)).publicKey;
+  const candyMachineSigner = await createV2(umi, {
+    collection,
+    configLines: [{ name: 'Degen #1', uri: 'https://example.com/degen/1' }],
+    guards: { startDate: { date: tomorrow() } },
+    groups: [
+      { label: 'EARLY', guards: {}, disabled: [GuardType.StartDate] },
+      { label: 'PUBLIC', guards: {} },
+    ],
+  });
+  const candyMachine = candyMachineSigner.publicKey;
+
+  // When we mint from it using the group that disables the start date.
+  const mint = generateSigner(umi);
+  const minter = generateSigner(umi);
+  await transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(
+      mintV1(umi, {
+        candyMachine,
+        asset: mint,
+        minter,
+        collection,
+        group: 'EARLY',
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then the mint was successful.
+  await assertSuccessfulMint(t, umi, { mint, owner: minter });
+
+  // And minting from the other group still requires the start date.
+  const promise = transactionBuilder()
+    .add(setComputeUnitLimit(umi, { units: 600_000 }))
+    .add(
+      mintV1(umi, {
+        candyMachine,
+        asset: generateSigner(umi),
+        minter,
+        collection,
+        group: 'PUBLIC',
+      })
+    )
+    .sendAndConfirm(umi);
+  await t.throwsAsync(promise, { message: /MintNotLive/ });
+});
+
 test('it cannot mint using the default guards if the candy guard has groups', async (t) => {
   // Given a candy machine with guard groups.
   const umi = await createUmi();
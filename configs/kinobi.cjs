@@ -27,6 +27,14 @@ kinobi.update(
     "allocationTracker",
     "nftMintCounter",
     "assetMintCounter",
+    "auctionBid",
+    "voucherNonce",
+    "allotmentProof",
+    "cooldownTracker",
+    "rateLimitTracker",
+    "solGateRegistration",
+    "discountUsage",
+    "gateUsage",
   ])
 );
 
@@ -43,6 +51,11 @@ const userSeed = k.publicKeySeed(
   "user",
   "The address of the wallet trying to mint"
 );
+const groupSeed = k.variableSeed(
+  "group",
+  k.stringTypeNode({ size: k.remainderSize() }),
+  "The label of the group (empty for the default guard set)"
+);
 
 // Update accounts.
 kinobi.update(
@@ -142,6 +155,130 @@ kinobi.update(
         candyMachineSeed,
       ],
     },
+    mintReceipt: {
+      seeds: [
+        k.stringConstantSeed("mint_receipt"),
+        candyMachineSeed,
+        k.publicKeySeed("asset", "The address of the Asset minted"),
+      ],
+    },
+    auctionEscrow: {
+      seeds: [
+        k.stringConstantSeed("dutch_auction"),
+        k.publicKeySeed(
+          "destination",
+          "The address that receives the auction proceeds"
+        ),
+        candyGuardSeed,
+        candyMachineSeed,
+        groupSeed,
+      ],
+    },
+    auctionBid: {
+      size: 44,
+      discriminator: k.sizeAccountDiscriminator(),
+      seeds: [
+        k.stringConstantSeed("dutch_auction_bid"),
+        k.publicKeySeed("minter", "The address of the wallet that minted"),
+        candyGuardSeed,
+        candyMachineSeed,
+        groupSeed,
+      ],
+    },
+    voucherNonce: {
+      size: 32,
+      discriminator: k.sizeAccountDiscriminator(),
+      seeds: [
+        k.stringConstantSeed("signed_voucher"),
+        k.variableSeed(
+          "nonce",
+          k.numberTypeNode("u64"),
+          "The nonce of the voucher"
+        ),
+        candyGuardSeed,
+        candyMachineSeed,
+      ],
+    },
+    allotmentProof: {
+      size: 13,
+      discriminator: k.sizeAccountDiscriminator(),
+      seeds: [
+        k.stringConstantSeed("allow_list_allotment"),
+        k.variableSeed(
+          "merkleRoot",
+          k.bytesTypeNode(k.fixedSize(32)),
+          "The Merkle Root used when verifying the user"
+        ),
+        userSeed,
+        candyGuardSeed,
+        candyMachineSeed,
+      ],
+    },
+    cooldownTracker: {
+      size: 8,
+      discriminator: k.sizeAccountDiscriminator(),
+      seeds: [
+        k.stringConstantSeed("cooldown"),
+        k.variableSeed(
+          "id",
+          k.numberTypeNode("u8"),
+          "A unique identifier in the context of a Candy Machine/Candy Guard combo"
+        ),
+        userSeed,
+        candyGuardSeed,
+        candyMachineSeed,
+      ],
+    },
+    rateLimitTracker: {
+      size: 12,
+      discriminator: k.sizeAccountDiscriminator(),
+      seeds: [
+        k.stringConstantSeed("rate_limit"),
+        k.variableSeed(
+          "id",
+          k.numberTypeNode("u8"),
+          "A unique identifier in the context of a Candy Machine/Candy Guard combo"
+        ),
+        candyGuardSeed,
+        candyMachineSeed,
+      ],
+    },
+    solGateRegistration: {
+      size: 8,
+      discriminator: k.sizeAccountDiscriminator(),
+      seeds: [
+        k.stringConstantSeed("sol_gate"),
+        userSeed,
+        candyGuardSeed,
+        candyMachineSeed,
+      ],
+    },
+    discountUsage: {
+      size: 32,
+      discriminator: k.sizeAccountDiscriminator(),
+      seeds: [
+        k.stringConstantSeed("holder_discount"),
+        k.publicKeySeed(
+          "asset",
+          "The address of the gating Asset or NFT mint"
+        ),
+        candyGuardSeed,
+        candyMachineSeed,
+      ],
+    },
+    gateUsage: {
+      size: 2,
+      discriminator: k.sizeAccountDiscriminator(),
+      seeds: [
+        k.stringConstantSeed("gate_usage"),
+        k.publicKeySeed(
+          "asset",
+          "The address of the gating Asset or NFT mint"
+        ),
+        candyGuardSeed,
+        candyMachineSeed,
+      ],
+    },
   })
 );
 
@@ -396,6 +533,18 @@ kinobi.update(
         candyMachineProgram: { defaultsTo: defaultsToCandyMachineAssetProgram() },
       }
     },
+    "mplCoreCandyGuard.checkEligibility": {
+      internal: true,
+      args: {
+        label: { name: "group" },
+      },
+      accounts: {
+        candyGuard: { defaultsTo: defaultsToCandyGuardPda("candyMachine") },
+        asset: { isSigner: "either" },
+        minter: { defaultsTo: k.identityDefault() },
+        candyMachineProgram: { defaultsTo: defaultsToCandyMachineAssetProgram() },
+      },
+    },
     "mplCoreCandyGuard.mintV1": {
       internal: true,
       args: {
@@ -475,6 +624,17 @@ kinobi.update(
     "solPayment.lamports": { kind: "SolAmount" },
     "freezeSolPayment.lamports": { kind: "SolAmount" },
     "solFixedFee.lamports": { kind: "SolAmount" },
+    "dutchAuction.startPrice": { kind: "SolAmount" },
+    "dutchAuction.floorPrice": { kind: "SolAmount" },
+    "dutchAuction.startTime": { kind: "DateTime" },
+    "dutchAuction.decayStep": { kind: "SolAmount" },
+    "solPaymentSplit.lamports": { kind: "SolAmount" },
+    "phase.start": { kind: "DateTime" },
+    "phase.end": { kind: "DateTime" },
+    "phaseScheduleStatus.timestamp": { kind: "DateTime" },
+    "voucher.expiry": { kind: "DateTime" },
+    "recurringWindow.offset": { kind: "DateTime" },
+    "solGate.minimumBalance": { kind: "SolAmount" },
   })
 );
 
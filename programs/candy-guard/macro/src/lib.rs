@@ -55,7 +55,7 @@ pub fn derive(input: TokenStream) -> TokenStream {
         if is_option_t(&f.ty) {
             let ty = unwrap_option_t(&f.ty);
             quote! {
-                let #name = if #ty::is_enabled(features) {
+                let #name = if #ty::is_enabled(&features) {
                     cursor += #ty::size();
                     #ty::load(data, cursor)?
                 } else {
@@ -77,7 +77,6 @@ pub fn derive(input: TokenStream) -> TokenStream {
                     cursor += #ty::size();
                     if cursor <= data.len() {
                         #name.save(data, cursor - #ty::size())?;
-                        features = #ty::enable(features);
                     } else {
                         return err!(crate::errors::CandyGuardError::InvalidAccountSize);
                     }
@@ -102,6 +101,19 @@ pub fn derive(input: TokenStream) -> TokenStream {
         }
     });
 
+    let disable_arm = fields.iter().map(|f| {
+        let name = &f.ident;
+
+        if is_option_t(&f.ty) {
+            let ty = unwrap_option_t(&f.ty);
+            quote! {
+                GuardType::#ty => self.#name = None
+            }
+        } else {
+            quote! {}
+        }
+    });
+
     let struct_fields = fields.iter().map(|f| {
         let name = &f.ident;
         quote! { #name }
@@ -111,9 +123,25 @@ pub fn derive(input: TokenStream) -> TokenStream {
         let name = &f.ident;
 
         if is_option_t(&f.ty) {
+            let ty = unwrap_option_t(&f.ty);
             quote! {
                 if let Some(#name) = &self.#name {
-                    conditions.push(#name);
+                    guards.push((GuardType::#ty, #name));
+                }
+            }
+        } else {
+            quote! {}
+        }
+    });
+
+    let enable = fields.iter().map(|f| {
+        let name = &f.ident;
+
+        if is_option_t(&f.ty) {
+            let ty = unwrap_option_t(&f.ty);
+            quote! {
+                if self.#name.is_some() {
+                    #ty::enable(&mut features);
                 }
             }
         } else {
@@ -182,11 +210,11 @@ pub fn derive(input: TokenStream) -> TokenStream {
 
     let expanded = quote! {
         impl #name {
-            pub fn from_data(data: &[u8]) -> anchor_lang::Result<(Self, u64)> {
+            pub fn from_data(data: &[u8]) -> anchor_lang::Result<(Self, crate::state::Features)> {
                 let mut cursor = 0;
 
-                let features = u64::from_le_bytes(*arrayref::array_ref![data, cursor, 8]);
-                cursor += 8;
+                let features = crate::state::Features::from_data(data)?;
+                cursor += features.size();
 
                 #(#from_data)*
 
@@ -195,39 +223,59 @@ pub fn derive(input: TokenStream) -> TokenStream {
                 }, features))
             }
 
-            pub fn bytes_count(features: u64) -> usize {
-                let mut count = 8; // features (u64)
+            pub fn bytes_count(features: &crate::state::Features) -> usize {
+                let mut count = features.size();
                 #(#bytes_count)*
                 count
             }
 
-            pub fn to_data(&self, data: &mut [u8]) -> anchor_lang::Result<u64> {
-                let mut features = 0;
+            pub fn to_data(&self, data: &mut [u8]) -> anchor_lang::Result<crate::state::Features> {
+                let features = self.features();
                 // leave space to write the features flag at the end
-                let mut cursor = 8;
+                let mut cursor = features.size();
 
                 #(#to_data)*
 
                 // features
-                data[0..8].copy_from_slice(&u64::to_le_bytes(features));
+                features.to_data(data)?;
 
                 Ok(features)
             }
 
+            pub fn features(&self) -> crate::state::Features {
+                let mut features = crate::state::Features::default();
+                #(#enable)*
+                features
+            }
+
             pub fn merge(&mut self, other: GuardSet) {
                 #(#merge_data)*
             }
 
+            pub fn disable(&mut self, guard_type: &GuardType) {
+                match guard_type {
+                    #(#disable_arm,)*
+                }
+            }
+
             pub fn enabled_conditions(&self) -> Vec<&dyn Condition> {
                 // list of condition trait objects
-                let mut conditions: Vec<&dyn Condition> = vec![];
+                self.enabled_guards()
+                    .into_iter()
+                    .map(|(_, condition)| condition)
+                    .collect()
+            }
+
+            pub fn enabled_guards(&self) -> Vec<(GuardType, &dyn Condition)> {
+                // list of guard types and their condition trait objects
+                let mut guards: Vec<(GuardType, &dyn Condition)> = vec![];
                 #(#enabled)*
 
-                conditions
+                guards
             }
 
             pub fn size(&self) -> usize {
-                let mut size = 8; // features (u64)
+                let mut size = self.features().size();
                 #(#struct_size)*
                 size
             }
@@ -166,4 +166,142 @@ pub enum CandyGuardError {
 
     #[msg("Invalid regex")]
     InvalidRegex,
+
+    #[msg("The mint receipt account already exists")]
+    ReceiptAlreadyExists,
+
+    #[msg("Mint receipt cannot be closed before the mint is over")]
+    ReceiptCloseNotAllowed,
+
+    #[msg("Invalid dutch auction configuration")]
+    InvalidAuctionConfig,
+
+    #[msg("Dutch auction guard must be enabled")]
+    AuctionGuardNotEnabled,
+
+    #[msg("Dutch auction escrow must be initialized")]
+    AuctionNotInitialized,
+
+    #[msg("The dutch auction escrow account already exists")]
+    AuctionEscrowAlreadyExists,
+
+    #[msg("Dutch auction is not settled")]
+    AuctionNotSettled,
+
+    #[msg("Invalid bonding curve configuration")]
+    InvalidBondingCurveConfig,
+
+    #[msg("Invalid payment split configuration")]
+    InvalidPaymentSplit,
+
+    #[msg("Invalid AnyOf guard configuration")]
+    InvalidAnyOfConfig,
+
+    #[msg("None of the AnyOf guards validated")]
+    AnyOfConditionsNotMet,
+
+    #[msg("AutoGroup guard can only be enabled on the default guard set")]
+    AutoGroupNotInDefault,
+
+    #[msg("None of the groups is eligible for the mint")]
+    NoEligibleGroup,
+
+    #[msg("Eligibility result exceeds the maximum return data size")]
+    EligibilityResultTooLarge,
+
+    #[msg("Invalid group label")]
+    InvalidGroupLabel,
+
+    #[msg("Invalid disabled default guard")]
+    InvalidDisabledGuard,
+
+    #[msg("Invalid phase schedule")]
+    InvalidPhaseSchedule,
+
+    #[msg("PhaseSchedule guard can only be enabled on the default guard set")]
+    PhaseScheduleNotInDefault,
+
+    #[msg("Phase schedule guard must be enabled")]
+    PhaseScheduleNotEnabled,
+
+    #[msg("The phase of the group is not active")]
+    PhaseNotActive,
+
+    #[msg("Invalid external guard configuration")]
+    InvalidExternalGuardConfig,
+
+    #[msg("Missing signed voucher instruction")]
+    MissingVoucher,
+
+    #[msg("Invalid signed voucher")]
+    InvalidVoucher,
+
+    #[msg("Signed voucher expired")]
+    VoucherExpired,
+
+    #[msg("Signed voucher already used")]
+    VoucherAlreadyUsed,
+
+    #[msg("The mint is not over")]
+    MintNotOver,
+
+    #[msg("The guard is not enabled")]
+    GuardNotEnabled,
+
+    #[msg("Invalid cooldown configuration")]
+    InvalidCooldownConfig,
+
+    #[msg("The cooldown interval has not elapsed")]
+    CooldownNotElapsed,
+
+    #[msg("Invalid rate limit configuration")]
+    InvalidRateLimitConfig,
+
+    #[msg("The rate limit was reached, retry once the window elapses")]
+    RateLimitExceeded,
+
+    #[msg("Current slot is after the set end slot")]
+    AfterEndSlot,
+
+    #[msg("The end slot must be after the start slot")]
+    InvalidSlotRange,
+
+    #[msg("Invalid recurring window configuration")]
+    InvalidRecurringWindow,
+
+    #[msg("Current time is outside of the recurring window")]
+    OutsideRecurringWindow,
+
+    #[msg("Minter balance is below the required minimum")]
+    BelowMinimumBalance,
+
+    #[msg("Wallet is not registered")]
+    WalletNotRegistered,
+
+    #[msg("Wallet is already registered")]
+    WalletAlreadyRegistered,
+
+    #[msg("Wallet registration is too recent")]
+    WalletRegistrationTooRecent,
+
+    #[msg("Invalid asset trait gate configuration")]
+    InvalidAssetTraitGate,
+
+    #[msg("Asset does not have the required trait")]
+    MissingAssetTrait,
+
+    #[msg("Invalid holder discount configuration")]
+    InvalidHolderDiscount,
+
+    #[msg("Discount already used for the asset")]
+    DiscountAlreadyUsed,
+
+    #[msg("Invalid gate usage limit configuration")]
+    InvalidGateUsageLimit,
+
+    #[msg("Gating asset usage limit reached")]
+    GateUsageLimitReached,
+
+    #[msg("Auction rebates are not enabled")]
+    AuctionRebateNotEnabled,
 }
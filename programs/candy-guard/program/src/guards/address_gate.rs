@@ -13,8 +13,8 @@ impl Guard for AddressGate {
         32 // address
     }
 
-    fn mask() -> u64 {
-        GuardType::as_mask(GuardType::AddressGate)
+    fn guard_type() -> GuardType {
+        GuardType::AddressGate
     }
 }
 
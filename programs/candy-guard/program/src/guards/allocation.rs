@@ -33,8 +33,8 @@ impl Guard for Allocation {
         + 4 // count
     }
 
-    fn mask() -> u64 {
-        GuardType::as_mask(GuardType::Allocation)
+    fn guard_type() -> GuardType {
+        GuardType::Allocation
     }
 
     /// Instruction to initialize the allocation PDA.
@@ -45,14 +45,24 @@ impl Guard for Allocation {
     ///                   candy guard pubkey, candy machine pubkey]`).
     ///   1. `[signer]` Candy Guard authority.
     ///   2. `[]` System program account.
+    ///
+    /// When the instruction data is `CLOSE_PDA_INSTRUCTION`, the allocation PDA is closed once
+    /// the mint is over (see `close_guard_pda`) with the following accounts:
+    ///
+    ///   0. `[writable]` Pda to track the number of mints.
+    ///   1. `[signer, writable]` Candy Guard authority (receives the lamports).
     fn instruction<'c, 'info>(
         ctx: &Context<'_, '_, '_, 'info, Route<'info>>,
         route_context: RouteContext<'info>,
-        _data: Vec<u8>,
+        data: Vec<u8>,
     ) -> Result<()>
     where
         'c: 'info,
     {
+        if data == CLOSE_PDA_INSTRUCTION {
+            return close(ctx, route_context);
+        }
+
         msg!("Instruction: Initialize (Allocation guard)");
 
         let allocation = try_get_account_info(ctx.remaining_accounts, 0)?;
@@ -204,3 +214,36 @@ impl Condition for Allocation {
         Ok(())
     }
 }
+
+/// Helper function to close the allocation PDA.
+fn close<'info>(
+    ctx: &Context<'_, '_, '_, 'info, Route<'info>>,
+    route_context: RouteContext<'info>,
+) -> Result<()> {
+    msg!("Instruction: Close (Allocation guard)");
+
+    let allocation_id = route_context
+        .guard_set
+        .as_ref()
+        .and_then(|guard_set| guard_set.allocation.as_ref())
+        .map(|allocation| allocation.id)
+        .ok_or(CandyGuardError::AllocationGuardNotEnabled)?;
+
+    let candy_guard_key = &ctx.accounts.candy_guard.key();
+    let candy_machine_key = &ctx.accounts.candy_machine.key();
+
+    let seeds = [
+        b"allocation".as_ref(),
+        &[allocation_id],
+        candy_guard_key.as_ref(),
+        candy_machine_key.as_ref(),
+    ];
+
+    let authority = route_context
+        .candy_guard
+        .as_ref()
+        .map(|candy_guard| candy_guard.authority);
+
+    // the allocation is created (and paid for) by the authority
+    close_guard_pda(ctx, &route_context, &seeds, authority.as_ref(), None)
+}
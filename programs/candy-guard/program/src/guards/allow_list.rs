@@ -11,10 +11,25 @@ use super::*;
 
 /// Guard that uses a merkle tree to specify the addresses allowed to mint.
 ///
+/// The merkle proof can either be validated by the route instruction, which creates a proof
+/// PDA, or be included in the mint arguments. Proofs included in the mint arguments are
+/// validated on every mint and do not create a PDA, but large proofs might not fit in the
+/// mint transaction.
+///
+/// Mint arguments (a borsh-serialized `Option<Vec<[u8; 32]>>`, read at the current position
+/// of the arguments):
+///
+///   0. `u8` Option tag: `0` when the proof PDA is used, `1` when the proof follows.
+///   1. `Vec<[u8; 32]>` Merkle proof (only when the tag is `1`).
+///
+/// The arguments can be omitted when no other guard reads mint arguments after this guard;
+/// otherwise, the option tag must be present.
+///
 /// List of accounts required:
 ///
 ///   0. `[]` Pda created by the merkle proof instruction (seeds `["allow_list", merke tree root,
-///           payer key, candy guard pubkey, candy machine pubkey]`).
+///      payer key, candy guard pubkey, candy machine pubkey]`). Only required when the proof is
+///      not included in the mint arguments.
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
 pub struct AllowList {
     /// Merkle root of the addresses allowed to mint.
@@ -26,7 +41,7 @@ impl AllowList {
     /// defined by `root`. For this, a `proof` must be provided, containing
     /// sibling hashes on the branch from the leaf to the root of the tree. Each
     /// pair of leaves and each pair of pre-images are assumed to be sorted.
-    fn verify(proof: &[[u8; 32]], root: &[u8; 32], leaf: &[u8; 32]) -> bool {
+    pub(crate) fn verify_proof(proof: &[[u8; 32]], root: &[u8; 32], leaf: &[u8; 32]) -> bool {
         let mut computed_hash = *leaf;
         for proof_element in proof.iter() {
             if computed_hash <= *proof_element {
@@ -40,6 +55,67 @@ impl AllowList {
         // check if the computed hash (root) is equal to the provided root
         computed_hash == *root
     }
+
+    /// Closes the proof PDA of a minter.
+    fn close<'info>(
+        ctx: &Context<'_, '_, '_, 'info, Route<'info>>,
+        route_context: RouteContext<'info>,
+    ) -> Result<()> {
+        msg!("AllowList: close proof instruction");
+
+        let merkle_root = route_context
+            .guard_set
+            .as_ref()
+            .and_then(|guard_set| guard_set.allow_list.as_ref())
+            .map(|allow_list| allow_list.merkle_root)
+            .ok_or(CandyGuardError::AllowedListNotEnabled)?;
+
+        let minter = if let Some(minter) = get_account_info(ctx.remaining_accounts, 2) {
+            minter.key()
+        } else {
+            try_get_account_info(ctx.remaining_accounts, 1)?.key()
+        };
+
+        let candy_guard_key = &ctx.accounts.candy_guard.key();
+        let candy_machine_key = &ctx.accounts.candy_machine.key();
+
+        let seeds = [
+            AllowListProof::PREFIX_SEED,
+            &merkle_root[..],
+            minter.as_ref(),
+            candy_guard_key.as_ref(),
+            candy_machine_key.as_ref(),
+        ];
+
+        close_guard_pda(ctx, &route_context, &seeds, Some(&minter), None)
+    }
+
+    /// Returns the merkle proof included in the mint arguments at the current position,
+    /// if any. The guard always consumes an `Option` tag when the mint arguments have more
+    /// bytes, so guards reading arguments after it are not affected by the proof.
+    fn inline_proof(
+        ctx: &mut EvaluationContext,
+        mint_args: &[u8],
+    ) -> Result<Option<Vec<[u8; 32]>>> {
+        let remaining = mint_args.get(ctx.args_cursor..).unwrap_or_default();
+
+        // no arguments: the proof pda is used
+        let Some((tag, mut slice)) = remaining.split_first() else {
+            return Ok(None);
+        };
+
+        let proof = match tag {
+            0 => None,
+            1 => Some(
+                Vec::<[u8; 32]>::deserialize(&mut slice)
+                    .map_err(|_| CandyGuardError::MissingAllowedListProof)?,
+            ),
+            _ => return err!(CandyGuardError::MissingAllowedListProof),
+        };
+        ctx.args_cursor += remaining.len() - slice.len();
+
+        Ok(proof)
+    }
 }
 
 impl Guard for AllowList {
@@ -47,8 +123,8 @@ impl Guard for AllowList {
         32 // merkle_root
     }
 
-    fn mask() -> u64 {
-        GuardType::as_mask(GuardType::AllowList)
+    fn guard_type() -> GuardType {
+        GuardType::AllowList
     }
 
     /// Instruction to validate an address against the merkle tree.
@@ -59,6 +135,13 @@ impl Guard for AllowList {
     ///                   payer/minter key, candy guard pubkey, candy machine pubkey]`).
     ///   1. `[]` System program account.
     ///   2. `[optional]` Minter account.
+    ///
+    /// When the instruction data is `CLOSE_PDA_INSTRUCTION`, the proof PDA is closed once the
+    /// mint is over (see `close_guard_pda`) with the following accounts:
+    ///
+    ///   0. `[writable]` Pda to represent the merkle proof.
+    ///   1. `[signer, writable]` Candy guard authority or minter (receives the lamports).
+    ///   2. `[optional]` Minter account (required when the authority closes the PDA).
     fn instruction<'c, 'info>(
         ctx: &Context<'_, '_, '_, 'info, Route<'info>>,
         route_context: RouteContext<'info>,
@@ -67,6 +150,10 @@ impl Guard for AllowList {
     where
         'c: 'info,
     {
+        if data == CLOSE_PDA_INSTRUCTION {
+            return Self::close(ctx, route_context);
+        }
+
         msg!("AllowList: validate proof instruction");
 
         let candy_guard = route_context
@@ -116,7 +203,7 @@ impl Guard for AllowList {
             return err!(CandyGuardError::AllowedListNotEnabled);
         };
 
-        if !Self::verify(&merkle_proof[..], merkle_root, &leaf.0) {
+        if !Self::verify_proof(&merkle_proof[..], merkle_root, &leaf.0) {
             return err!(CandyGuardError::AddressNotFoundInAllowedList);
         }
 
@@ -182,11 +269,24 @@ impl Condition for AllowList {
         &self,
         ctx: &mut EvaluationContext,
         _guard_set: &GuardSet,
-        _mint_args: &[u8],
+        mint_args: &[u8],
     ) -> Result<()> {
+        let minter = ctx.accounts.minter.key();
+
+        // validates the proof included in the mint arguments, if any
+
+        if let Some(merkle_proof) = Self::inline_proof(ctx, mint_args)? {
+            let leaf = solana_program::keccak::hashv(&[minter.to_string().as_bytes()]);
+
+            if !Self::verify_proof(&merkle_proof[..], &self.merkle_root, &leaf.0) {
+                return err!(CandyGuardError::AddressNotFoundInAllowedList);
+            }
+
+            return Ok(());
+        }
+
         let proof_pda = try_get_account_info(ctx.accounts.remaining, ctx.account_cursor)?;
         ctx.account_cursor += 1;
-        let minter = ctx.accounts.minter.key();
 
         // validates the pda
 
@@ -0,0 +1,388 @@
+use anchor_lang::system_program;
+use solana_program::{
+    program::{invoke, invoke_signed},
+    system_instruction,
+};
+
+use crate::{
+    instructions::Route,
+    state::GuardType,
+    utils::{assert_keys_equal, assert_owned_by, cmp_pubkeys},
+};
+
+use super::*;
+
+/// Guard that uses a merkle tree to specify the addresses allowed to mint, where each
+/// address has its own allotment (maximum number of mints) and optional price in lamports.
+///
+/// The leaves of the merkle tree are the keccak hash of the borsh-serialized
+/// `AllotmentEntry` of each address.
+///
+/// List of accounts required:
+///
+///   0. `[writable]` Pda created by the merkle proof instruction (seeds `["allow_list_allotment",
+///      merke tree root, minter key, candy guard pubkey, candy machine pubkey]`).
+///   1. `[writable]` Account to receive the funds (only when the entry specifies a price).
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct AllowListAllotment {
+    /// Merkle root of the entries of the addresses allowed to mint.
+    pub merkle_root: [u8; 32],
+    /// Account to receive the price of the mints, if any.
+    pub destination: Pubkey,
+}
+
+/// Entry of an address on the merkle tree.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct AllotmentEntry {
+    /// Address allowed to mint.
+    pub address: Pubkey,
+    /// Maximum number of mints of the address.
+    pub allotment: u16,
+    /// Price of each mint in lamports, if any.
+    pub price: Option<u64>,
+}
+
+/// Arguments of the merkle proof instruction.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct AllotmentProofArgs {
+    /// Maximum number of mints of the address.
+    pub allotment: u16,
+    /// Price of each mint in lamports, if any.
+    pub price: Option<u64>,
+    /// Merkle proof of the entry.
+    pub proof: Vec<[u8; 32]>,
+}
+
+impl Guard for AllowListAllotment {
+    fn size() -> usize {
+        32   // merkle_root
+        + 32 // destination
+    }
+
+    fn guard_type() -> GuardType {
+        GuardType::AllowListAllotment
+    }
+
+    /// Instruction to validate an address entry against the merkle tree.
+    ///
+    /// List of accounts required:
+    ///
+    ///   0. `[writable]` Pda to represent the merkle proof (seeds `["allow_list_allotment",
+    ///      merke tree root, payer/minter key, candy guard pubkey, candy machine pubkey]`).
+    ///   1. `[]` System program account.
+    ///   2. `[optional]` Minter account.
+    ///
+    /// When the instruction data is `CLOSE_PDA_INSTRUCTION`, the proof PDA is closed once the
+    /// mint is over (see `close_guard_pda`) with the following accounts:
+    ///
+    ///   0. `[writable]` Pda to represent the merkle proof.
+    ///   1. `[signer, writable]` Candy guard authority or minter (receives the lamports).
+    ///   2. `[optional]` Minter account (required when the authority closes the PDA).
+    fn instruction<'c, 'info>(
+        ctx: &Context<'_, '_, '_, 'info, Route<'info>>,
+        route_context: RouteContext<'info>,
+        data: Vec<u8>,
+    ) -> Result<()>
+    where
+        'c: 'info,
+    {
+        if data == CLOSE_PDA_INSTRUCTION {
+            return close(ctx, route_context);
+        }
+
+        msg!("AllowListAllotment: validate proof instruction");
+
+        let candy_guard = route_context
+            .candy_guard
+            .as_ref()
+            .ok_or(CandyGuardError::Uninitialized)?;
+
+        let candy_machine = route_context
+            .candy_machine
+            .as_ref()
+            .ok_or(CandyGuardError::Uninitialized)?;
+
+        // and the candy guard and candy machine must be linked
+        if !cmp_pubkeys(&candy_machine.mint_authority, &candy_guard.key()) {
+            return err!(CandyGuardError::InvalidMintAuthority);
+        }
+
+        let proof_pda = try_get_account_info(ctx.remaining_accounts, 0)?;
+        let system_program_info = try_get_account_info(ctx.remaining_accounts, 1)?;
+        assert_keys_equal(system_program_info.key, &system_program::ID)?;
+
+        let minter = if let Some(minter) = get_account_info(ctx.remaining_accounts, 2) {
+            minter.key()
+        } else {
+            ctx.accounts.payer.key()
+        };
+
+        // validates the proof
+
+        let args = if let Ok(args) = AllotmentProofArgs::try_from_slice(&data[..]) {
+            args
+        } else {
+            return err!(CandyGuardError::MissingAllowedListProof);
+        };
+
+        let entry = AllotmentEntry {
+            address: minter,
+            allotment: args.allotment,
+            price: args.price,
+        };
+        let leaf = solana_program::keccak::hashv(&[&entry.try_to_vec()?]);
+
+        let merkle_root = if let Some(allow_list_allotment) = route_context
+            .guard_set
+            .as_ref()
+            .and_then(|guard_set| guard_set.allow_list_allotment.as_ref())
+        {
+            allow_list_allotment.merkle_root
+        } else {
+            return err!(CandyGuardError::AllowedListNotEnabled);
+        };
+
+        if !AllowList::verify_proof(&args.proof[..], &merkle_root, &leaf.0) {
+            return err!(CandyGuardError::AddressNotFoundInAllowedList);
+        }
+
+        // creates the proof PDA
+
+        let candy_guard_key = &ctx.accounts.candy_guard.key();
+        let candy_machine_key = &ctx.accounts.candy_machine.key();
+
+        let seeds = [
+            AllotmentProof::PREFIX_SEED,
+            &merkle_root[..],
+            minter.as_ref(),
+            candy_guard_key.as_ref(),
+            candy_machine_key.as_ref(),
+        ];
+        let (pda, bump) = Pubkey::find_program_address(&seeds, &crate::ID);
+
+        assert_keys_equal(proof_pda.key, &pda)?;
+
+        if proof_pda.data_is_empty() {
+            let signer = [
+                AllotmentProof::PREFIX_SEED,
+                &merkle_root[..],
+                minter.as_ref(),
+                candy_guard_key.as_ref(),
+                candy_machine_key.as_ref(),
+                &[bump],
+            ];
+            let rent = Rent::get()?;
+
+            invoke_signed(
+                &system_instruction::create_account(
+                    &ctx.accounts.payer.key(),
+                    &pda,
+                    rent.minimum_balance(AllotmentProof::SIZE),
+                    AllotmentProof::SIZE as u64,
+                    &crate::ID,
+                ),
+                &[
+                    ctx.accounts.payer.to_account_info(),
+                    proof_pda.to_account_info(),
+                ],
+                &[&signer],
+            )?;
+
+            let proof = AllotmentProof {
+                allotment: args.allotment,
+                price: args.price,
+                minted: 0,
+            };
+
+            let mut account_data = proof_pda.try_borrow_mut_data()?;
+            // saves the entry to the pda
+            let data = &mut proof.try_to_vec()?;
+            account_data[0..data.len()].copy_from_slice(data);
+        } else {
+            // the entry of an address is validated only once
+            assert_owned_by(proof_pda, &crate::ID)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Condition for AllowListAllotment {
+    fn validate<'info>(
+        &self,
+        ctx: &mut EvaluationContext,
+        _guard_set: &GuardSet,
+        _mint_args: &[u8],
+    ) -> Result<()> {
+        let proof_pda = try_get_account_info(ctx.accounts.remaining, ctx.account_cursor)?;
+        ctx.indices
+            .insert("allow_list_allotment_index", ctx.account_cursor);
+        ctx.account_cursor += 1;
+        let minter = ctx.accounts.minter.key();
+
+        // validates the pda
+
+        let candy_guard_key = &ctx.accounts.candy_guard.key();
+        let candy_machine_key = &ctx.accounts.candy_machine.key();
+
+        let seeds = [
+            AllotmentProof::PREFIX_SEED,
+            &self.merkle_root[..],
+            minter.as_ref(),
+            candy_guard_key.as_ref(),
+            candy_machine_key.as_ref(),
+        ];
+        let (pda, _) = Pubkey::find_program_address(&seeds, &crate::ID);
+
+        assert_keys_equal(proof_pda.key, &pda)?;
+
+        if proof_pda.data_is_empty() {
+            return err!(CandyGuardError::MissingAllowedListProof);
+        }
+
+        assert_owned_by(proof_pda, &crate::ID)?;
+
+        let proof = AllotmentProof::deserialize(&mut &proof_pda.data.borrow()[..])?;
+
+        if proof.minted >= proof.allotment {
+            return err!(CandyGuardError::AllowedMintLimitReached);
+        }
+
+        if let Some(price) = proof.price {
+            let destination = try_get_account_info(ctx.accounts.remaining, ctx.account_cursor)?;
+            ctx.indices
+                .insert("allow_list_allotment_destination", ctx.account_cursor);
+            ctx.account_cursor += 1;
+
+            assert_keys_equal(destination.key, &self.destination)?;
+
+            if ctx.accounts.payer.lamports() < price {
+                msg!(
+                    "Require {} lamports, accounts has {} lamports",
+                    price,
+                    ctx.accounts.payer.lamports(),
+                );
+                return err!(CandyGuardError::NotEnoughSOL);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn payment(
+        &self,
+        ctx: &EvaluationContext,
+        _guard_set: &GuardSet,
+    ) -> Result<Option<MintPayment>> {
+        let proof_pda = try_get_account_info(
+            ctx.accounts.remaining,
+            ctx.indices["allow_list_allotment_index"],
+        )?;
+        let proof = AllotmentProof::deserialize(&mut &proof_pda.data.borrow()[..])?;
+
+        Ok(proof.price.map(|price| MintPayment {
+            guard: GuardType::AllowListAllotment,
+            mint: None,
+            amount: price,
+        }))
+    }
+
+    fn pre_actions<'info>(
+        &self,
+        ctx: &mut EvaluationContext,
+        _guard_set: &GuardSet,
+        _mint_args: &[u8],
+    ) -> Result<()> {
+        let proof_pda = try_get_account_info(
+            ctx.accounts.remaining,
+            ctx.indices["allow_list_allotment_index"],
+        )?;
+
+        let mut account_data = proof_pda.try_borrow_mut_data()?;
+        let mut proof = AllotmentProof::deserialize(&mut &account_data[..])?;
+        proof.minted += 1;
+        // saves the changes back to the pda
+        let data = &mut proof.try_to_vec()?;
+        account_data[0..data.len()].copy_from_slice(data);
+
+        if let Some(price) = proof.price {
+            let destination = try_get_account_info(
+                ctx.accounts.remaining,
+                ctx.indices["allow_list_allotment_destination"],
+            )?;
+
+            invoke(
+                &system_instruction::transfer(&ctx.accounts.payer.key(), destination.key, price),
+                &[
+                    ctx.accounts.payer.to_account_info(),
+                    destination.to_account_info(),
+                    ctx.accounts.system_program.to_account_info(),
+                ],
+            )?;
+
+            ctx.payments.push(MintPayment {
+                guard: GuardType::AllowListAllotment,
+                mint: None,
+                amount: price,
+            });
+        }
+
+        Ok(())
+    }
+}
+
+/// Helper function to close the proof PDA of a minter.
+fn close<'info>(
+    ctx: &Context<'_, '_, '_, 'info, Route<'info>>,
+    route_context: RouteContext<'info>,
+) -> Result<()> {
+    msg!("AllowListAllotment: close proof instruction");
+
+    let merkle_root = route_context
+        .guard_set
+        .as_ref()
+        .and_then(|guard_set| guard_set.allow_list_allotment.as_ref())
+        .map(|allow_list_allotment| allow_list_allotment.merkle_root)
+        .ok_or(CandyGuardError::AllowedListNotEnabled)?;
+
+    let minter = if let Some(minter) = get_account_info(ctx.remaining_accounts, 2) {
+        minter.key()
+    } else {
+        try_get_account_info(ctx.remaining_accounts, 1)?.key()
+    };
+
+    let candy_guard_key = &ctx.accounts.candy_guard.key();
+    let candy_machine_key = &ctx.accounts.candy_machine.key();
+
+    let seeds = [
+        AllotmentProof::PREFIX_SEED,
+        &merkle_root[..],
+        minter.as_ref(),
+        candy_guard_key.as_ref(),
+        candy_machine_key.as_ref(),
+    ];
+
+    close_guard_pda(ctx, &route_context, &seeds, Some(&minter), None)
+}
+
+/// PDA to track the entry of a validated address and the number of mints used. The data
+/// is padded to `SIZE` bytes when the entry has no price.
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct AllotmentProof {
+    /// Maximum number of mints of the address.
+    pub allotment: u16,
+    /// Price of each mint in lamports, if any.
+    pub price: Option<u64>,
+    /// Number of mints used.
+    pub minted: u16,
+}
+
+impl AllotmentProof {
+    /// Prefix used as seed.
+    pub const PREFIX_SEED: &'static [u8] = b"allow_list_allotment";
+
+    /// Size of the account data.
+    pub const SIZE: usize = 2 // allotment
+        + 1 + 8               // price
+        + 2; // minted
+}
@@ -0,0 +1,190 @@
+use super::*;
+
+use crate::{errors::CandyGuardError, state::GuardType};
+
+// Maximum number of guards in the list of alternatives.
+const MAXIMUM_SIZE: usize = 5;
+
+/// Meta-guard that evaluates a list of guards as alternatives: the mint is allowed when
+/// any of the guards validates. The guards listed must be enabled on the guard set (or
+/// on the default guard set for groups) and are only evaluated by this guard.
+///
+/// The minter selects the alternative to satisfy in the mint arguments, followed by the
+/// accounts and arguments of that alternative. Only the selected alternative is evaluated,
+/// so the error of the alternative is returned when it fails, and only the alternative
+/// executes its `pre_actions` and `post_actions`.
+///
+/// List of accounts required:
+///
+///   0. `[]` Accounts of the alternative guard (if any).
+///
+/// Mint arguments:
+///
+///   0. `GuardType` Guard type of the selected alternative.
+///   1. Arguments of the alternative guard (if any).
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct AnyOf {
+    pub guards: Vec<GuardType>,
+}
+
+impl AnyOf {
+    /// Returns the guard type of the alternative that validated, if any.
+    pub fn validated_alternative(&self, ctx: &EvaluationContext) -> Option<GuardType> {
+        ctx.indices
+            .get("any_of_index")
+            .and_then(|index| self.guards.get(*index))
+            .cloned()
+    }
+
+    /// Returns whether the guard is one of the alternatives.
+    pub fn contains(&self, guard_type: &GuardType) -> bool {
+        self.guards.contains(guard_type)
+    }
+
+    fn verify_config(&self, guard_set: &GuardSet) -> Result<()> {
+        if self.guards.is_empty() || self.guards.len() > MAXIMUM_SIZE {
+            return err!(CandyGuardError::InvalidAnyOfConfig);
+        }
+
+        let enabled = guard_set.features();
+
+        for (index, guard_type) in self.guards.iter().enumerate() {
+            // the bot tax applies to the whole set and the meta-guard cannot be nested
+            if matches!(guard_type, GuardType::BotTax | GuardType::AnyOf)
+                || self.guards[..index].contains(guard_type)
+            {
+                msg!("Invalid AnyOf guard: {:?}", guard_type);
+                return err!(CandyGuardError::InvalidAnyOfConfig);
+            }
+
+            if !enabled.is_enabled(guard_type.index()) {
+                msg!("AnyOf guard not enabled: {:?}", guard_type);
+                return err!(CandyGuardError::InvalidAnyOfConfig);
+            }
+        }
+
+        Ok(())
+    }
+
+    // Returns the index of the alternative selected in the mint arguments.
+    fn selected(&self, ctx: &mut EvaluationContext, mint_args: &[u8]) -> Result<usize> {
+        let remaining = mint_args.get(ctx.args_cursor..).unwrap_or_default();
+        let mut slice = remaining;
+
+        let guard_type = GuardType::deserialize(&mut slice).map_err(|_| {
+            msg!("AnyOf: missing selected alternative");
+            error!(CandyGuardError::AnyOfConditionsNotMet)
+        })?;
+        ctx.args_cursor += remaining.len() - slice.len();
+
+        self.guards
+            .iter()
+            .position(|alternative| *alternative == guard_type)
+            .ok_or_else(|| {
+                msg!("AnyOf: {:?} is not an alternative", guard_type);
+                error!(CandyGuardError::AnyOfConditionsNotMet)
+            })
+    }
+
+    // Returns the condition of the alternative at the specified index.
+    fn alternative<'a>(&self, guard_set: &'a GuardSet, index: usize) -> Result<&'a dyn Condition> {
+        let guard_type = &self.guards[index];
+
+        guard_set
+            .enabled_guards()
+            .into_iter()
+            .find(|(enabled, _)| enabled == guard_type)
+            .map(|(_, condition)| condition)
+            .ok_or_else(|| {
+                msg!("AnyOf guard not enabled: {:?}", guard_type);
+                error!(CandyGuardError::InvalidAnyOfConfig)
+            })
+    }
+}
+
+impl Guard for AnyOf {
+    fn size() -> usize {
+        4 + MAXIMUM_SIZE // guard types
+    }
+
+    fn guard_type() -> GuardType {
+        GuardType::AnyOf
+    }
+
+    fn verify(data: &CandyGuardData) -> Result<()> {
+        if let Some(any_of) = &data.default.any_of {
+            any_of.verify_config(&data.default)?;
+        }
+
+        if let Some(groups) = &data.groups {
+            for group in groups {
+                // the alternatives must be enabled on the guard set of the group
+                let guard_set = data.group_set(group);
+
+                if let Some(any_of) = &guard_set.any_of {
+                    any_of.verify_config(&guard_set)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Condition for AnyOf {
+    fn validate<'info>(
+        &self,
+        ctx: &mut EvaluationContext,
+        guard_set: &GuardSet,
+        mint_args: &[u8],
+    ) -> Result<()> {
+        let index = self.selected(ctx, mint_args)?;
+        let condition = self.alternative(guard_set, index)?;
+
+        // the error of the alternative is returned so the minter knows why it failed
+        condition
+            .validate(ctx, guard_set, mint_args)
+            .inspect_err(|_| msg!("AnyOf: {:?} failed", self.guards[index]))?;
+
+        msg!("AnyOf: {:?} validated", self.guards[index]);
+        ctx.indices.insert("any_of_index", index);
+
+        Ok(())
+    }
+
+    fn payment(
+        &self,
+        ctx: &EvaluationContext,
+        guard_set: &GuardSet,
+    ) -> Result<Option<MintPayment>> {
+        // the payment is the one of the alternative that validated
+        if let Some(index) = ctx.indices.get("any_of_index") {
+            self.alternative(guard_set, *index)?.payment(ctx, guard_set)
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn pre_actions<'info>(
+        &self,
+        ctx: &mut EvaluationContext,
+        guard_set: &GuardSet,
+        mint_args: &[u8],
+    ) -> Result<()> {
+        let condition = self.alternative(guard_set, ctx.indices["any_of_index"])?;
+        condition.pre_actions(ctx, guard_set, mint_args)
+    }
+
+    fn post_actions<'c, 'info>(
+        &self,
+        ctx: &mut EvaluationContext<'_, 'c, 'info>,
+        guard_set: &GuardSet,
+        mint_args: &[u8],
+    ) -> Result<()>
+    where
+        'c: 'info,
+    {
+        let condition = self.alternative(guard_set, ctx.indices["any_of_index"])?;
+        condition.post_actions(ctx, guard_set, mint_args)
+    }
+}
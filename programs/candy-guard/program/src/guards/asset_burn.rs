@@ -20,8 +20,8 @@ impl Guard for AssetBurn {
         32 // required_collection
     }
 
-    fn mask() -> u64 {
-        GuardType::as_mask(GuardType::AssetBurn)
+    fn guard_type() -> GuardType {
+        GuardType::AssetBurn
     }
 }
 
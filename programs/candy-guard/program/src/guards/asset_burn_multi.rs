@@ -22,8 +22,8 @@ impl Guard for AssetBurnMulti {
         + 1 // num of assets to burn
     }
 
-    fn mask() -> u64 {
-        GuardType::as_mask(GuardType::AssetBurnMulti)
+    fn guard_type() -> GuardType {
+        GuardType::AssetBurnMulti
     }
 }
 
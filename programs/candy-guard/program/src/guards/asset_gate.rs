@@ -18,8 +18,8 @@ impl Guard for AssetGate {
         32 // required_collection
     }
 
-    fn mask() -> u64 {
-        GuardType::as_mask(GuardType::AssetGate)
+    fn guard_type() -> GuardType {
+        GuardType::AssetGate
     }
 }
 
@@ -34,6 +34,8 @@ impl Condition for AssetGate {
         // validates that we received all required accounts
         let asset_account = try_get_account_info(ctx.accounts.remaining, index)?;
         ctx.account_cursor += 1;
+        // records the gating account for the gate usage limit
+        ctx.indices.insert("asset_gate_index", index);
 
         Self::verify_collection(
             asset_account,
@@ -24,8 +24,8 @@ impl Guard for AssetPayment {
         + 32 // destination
     }
 
-    fn mask() -> u64 {
-        GuardType::as_mask(GuardType::AssetPayment)
+    fn guard_type() -> GuardType {
+        GuardType::AssetPayment
     }
 }
 
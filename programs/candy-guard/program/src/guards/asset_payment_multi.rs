@@ -25,8 +25,8 @@ impl Guard for AssetPaymentMulti {
         + 1 // num of assets to pay
     }
 
-    fn mask() -> u64 {
-        GuardType::as_mask(GuardType::AssetPaymentMulti)
+    fn guard_type() -> GuardType {
+        GuardType::AssetPaymentMulti
     }
 }
 
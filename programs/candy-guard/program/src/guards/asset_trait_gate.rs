@@ -0,0 +1,144 @@
+use mpl_core::{
+    accounts::{BaseAssetV1, BaseCollectionV1},
+    fetch_plugin,
+    types::{Attribute, Attributes, PluginType},
+    DataBlob, SolanaAccount,
+};
+
+use super::*;
+use crate::{errors::CandyGuardError, state::GuardType, utils::assert_keys_equal};
+
+// Maximum number of required traits.
+const MAXIMUM_TRAITS: usize = 4;
+
+// Maximum size of a trait key or value.
+const MAXIMUM_TRAIT_SIZE: usize = 32;
+
+/// Guard that restricts the transaction to holders of an asset from a specified collection
+/// with the required traits. Traits are read from the `Attributes` plugin of the asset and,
+/// when `include_collection` is set, from the `Attributes` plugin of the collection, with
+/// the asset attributes taking precedence over the collection ones.
+///
+/// List of accounts required:
+///
+///   0. `[]` Account of the Asset.
+///   1. `[optional]` Account of the Collection (required when `include_collection` is set).
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct AssetTraitGate {
+    /// Collection of the gating asset.
+    pub required_collection: Pubkey,
+    /// Whether the attributes of the collection are considered.
+    pub include_collection: bool,
+    /// Traits (key/value pairs) that the gating asset must have.
+    pub traits: Vec<TraitRequirement>,
+}
+
+/// A trait (key/value pair) of an asset.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct TraitRequirement {
+    /// Key of the attribute.
+    pub key: String,
+    /// Required value of the attribute.
+    pub value: String,
+}
+
+impl AssetTraitGate {
+    fn verify_config(&self) -> Result<()> {
+        if self.traits.is_empty() || self.traits.len() > MAXIMUM_TRAITS {
+            return err!(CandyGuardError::InvalidAssetTraitGate);
+        }
+
+        for requirement in &self.traits {
+            if requirement.key.is_empty()
+                || requirement.key.len() > MAXIMUM_TRAIT_SIZE
+                || requirement.value.len() > MAXIMUM_TRAIT_SIZE
+            {
+                msg!("Invalid trait: {:?}", requirement);
+                return err!(CandyGuardError::InvalidAssetTraitGate);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Guard for AssetTraitGate {
+    fn size() -> usize {
+        32  // required_collection
+        + 1 // include_collection
+        + 4 // traits
+        + MAXIMUM_TRAITS * (4 + MAXIMUM_TRAIT_SIZE + 4 + MAXIMUM_TRAIT_SIZE) // key + value
+    }
+
+    fn guard_type() -> GuardType {
+        GuardType::AssetTraitGate
+    }
+
+    fn verify(data: &CandyGuardData) -> Result<()> {
+        let guard_sets = std::iter::once(&data.default)
+            .chain(data.groups.iter().flatten().map(|group| &group.guards));
+
+        for guard_set in guard_sets {
+            if let Some(asset_trait_gate) = &guard_set.asset_trait_gate {
+                asset_trait_gate.verify_config()?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Condition for AssetTraitGate {
+    fn validate<'info>(
+        &self,
+        ctx: &mut EvaluationContext,
+        _guard_set: &GuardSet,
+        _mint_args: &[u8],
+    ) -> Result<()> {
+        let asset_account = try_get_account_info(ctx.accounts.remaining, ctx.account_cursor)?;
+        ctx.account_cursor += 1;
+
+        AssetGate::verify_collection(
+            asset_account,
+            &self.required_collection,
+            ctx.accounts.minter.key,
+        )?;
+
+        let mut attributes = asset_attributes::<BaseAssetV1>(asset_account);
+
+        if self.include_collection {
+            let collection_account =
+                try_get_account_info(ctx.accounts.remaining, ctx.account_cursor)?;
+            ctx.account_cursor += 1;
+
+            assert_keys_equal(collection_account.key, &self.required_collection)?;
+            assert_keys_equal(collection_account.owner, &mpl_core::ID)?;
+
+            // asset attributes are checked first, so they override the collection ones
+            attributes.extend(asset_attributes::<BaseCollectionV1>(collection_account));
+        }
+
+        for required in &self.traits {
+            let value = attributes
+                .iter()
+                .find(|attribute| attribute.key == required.key)
+                .map(|attribute| &attribute.value);
+
+            if value != Some(&required.value) {
+                msg!("Missing trait: {} = {}", required.key, required.value);
+                return err!(CandyGuardError::MissingAssetTrait);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns the attributes of an asset or collection account, or an empty list when the
+/// account does not have the `Attributes` plugin.
+fn asset_attributes<T: DataBlob + SolanaAccount>(account: &AccountInfo) -> Vec<Attribute> {
+    match fetch_plugin::<T, Attributes>(account, PluginType::Attributes) {
+        Ok((_, attributes, _)) => attributes.attribute_list,
+        _ => vec![],
+    }
+}
@@ -0,0 +1,52 @@
+use super::*;
+
+use crate::{errors::CandyGuardError, state::GuardType};
+
+/// Guard that enables the automatic selection of a group when a mint transaction does
+/// not specify a label. The groups are attempted in the order they are declared and the
+/// mint goes through the first group whose guards validate; failing groups are skipped
+/// without charging the bot tax. The bot tax of the default guard set is only charged when
+/// none of the groups validates, unless one of them failed because of the rate limit, in
+/// which case the mint fails with a `RateLimitExceeded` error instead.
+///
+/// Each group is attempted from the same position in the remaining accounts and mint
+/// arguments, therefore the transaction must include the accounts and arguments expected
+/// by the guards of the group the minter intends to use.
+///
+/// This guard can only be enabled on the default guard set.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct AutoGroup {}
+
+impl Guard for AutoGroup {
+    fn size() -> usize {
+        0 // no configuration
+    }
+
+    fn guard_type() -> GuardType {
+        GuardType::AutoGroup
+    }
+
+    fn verify(data: &CandyGuardData) -> Result<()> {
+        if let Some(groups) = &data.groups {
+            for group in groups {
+                if group.guards.auto_group.is_some() {
+                    return err!(CandyGuardError::AutoGroupNotInDefault);
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Condition for AutoGroup {
+    fn validate<'info>(
+        &self,
+        _ctx: &mut EvaluationContext,
+        _guard_set: &GuardSet,
+        _mint_args: &[u8],
+    ) -> Result<()> {
+        // the group selection happens before the guards are evaluated
+        Ok(())
+    }
+}
@@ -0,0 +1,410 @@
+use super::*;
+
+use solana_program::{program::invoke, system_instruction};
+use spl_token_2022::{
+    extension::StateWithExtensions,
+    state::{Account, Mint},
+};
+
+use crate::{
+    errors::CandyGuardError,
+    state::GuardType,
+    utils::{
+        assert_initialized, assert_is_token_account, assert_keys_equal, assert_owned_by,
+        cmp_pubkeys, spl_token_transfer, spl_token_transfer_checked, TokenTransferCheckedParams,
+        TokenTransferParams,
+    },
+};
+
+// Scale used for the fixed-point calculation of exponential prices.
+const PRICE_SCALE: u128 = 1_000_000_000_000;
+
+// Basis points representing 100%.
+const BASIS_POINTS: u128 = 10_000;
+
+/// Guard that charges a price that increases as the candy machine sells out. The price is
+/// a function of the ratio between the items redeemed and the items available, so the same
+/// configuration results in the same curve for candy machines of different sizes. The price
+/// can be paid in SOL (lamports) or in a spl-token (legacy token program or Token-2022).
+///
+/// List of accounts required when paying in SOL:
+///
+///   0. `[writable]` Account to receive the funds.
+///
+/// List of accounts required when paying with the legacy token program:
+///
+///   0. `[writable]` Token account holding the required amount.
+///   1. `[writable]` Address of the ATA to receive the tokens.
+///   2. `[]` SPL token program.
+///
+/// List of accounts required when paying with Token-2022:
+///
+///   0. `[writable]` Token account holding the required amount.
+///   1. `[writable]` Address of the ATA to receive the tokens.
+///   2. `[]` Mint account.
+///   3. `[]` SPL Token-2022 program account.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct BondingCurve {
+    /// Shape of the price curve.
+    pub curve: BondingCurveType,
+    /// Price of the first item.
+    pub start_price: u64,
+    /// Amount added to the price once all items are redeemed, proportionally to the ratio
+    /// of items redeemed (linear curve), or the growth of the price for each percent of
+    /// items redeemed in basis points (exponential curve).
+    pub factor: u64,
+    /// Maximum price of an item.
+    pub max_price: Option<u64>,
+    /// Token mint used for the payment (`None` to pay in SOL).
+    pub mint: Option<Pubkey>,
+    /// Address to receive the funds (wallet when paying in SOL, ATA otherwise).
+    pub destination: Pubkey,
+}
+
+/// Available price curves.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub enum BondingCurveType {
+    /// `price = start_price + factor * items_redeemed / items_available`
+    Linear,
+    /// `price = start_price * (1 + factor / 10_000) ^ (100 * items_redeemed / items_available)`,
+    /// where the exponent is the whole percentage of items redeemed
+    Exponential,
+}
+
+impl BondingCurve {
+    /// Returns the price of the next item based on the ratio of items redeemed.
+    pub fn price(&self, items_redeemed: u64, items_available: u64) -> u64 {
+        // ratio of items redeemed in basis points
+        let ratio = if items_available == 0 {
+            0
+        } else {
+            (items_redeemed.min(items_available) as u128).saturating_mul(BASIS_POINTS)
+                / items_available as u128
+        };
+
+        let price = match self.curve {
+            BondingCurveType::Linear => (self.start_price as u128)
+                .saturating_add((self.factor as u128).saturating_mul(ratio) / BASIS_POINTS),
+            BondingCurveType::Exponential => {
+                // fixed-point exponentiation by squaring of the growth rate
+                let mut base = PRICE_SCALE
+                    .saturating_mul(BASIS_POINTS.saturating_add(self.factor as u128))
+                    / BASIS_POINTS;
+                let mut growth = PRICE_SCALE;
+                // whole percentage of items redeemed
+                let mut exponent = ratio / 100;
+
+                while exponent > 0 {
+                    if exponent & 1 == 1 {
+                        growth = growth.saturating_mul(base) / PRICE_SCALE;
+                    }
+                    exponent >>= 1;
+
+                    if exponent > 0 {
+                        base = base.saturating_mul(base) / PRICE_SCALE;
+                    }
+                }
+
+                (self.start_price as u128).saturating_mul(growth) / PRICE_SCALE
+            }
+        };
+
+        let price = u64::try_from(price).unwrap_or(u64::MAX);
+
+        if let Some(max_price) = self.max_price {
+            price.min(max_price)
+        } else {
+            price
+        }
+    }
+
+    fn verify_config(&self) -> Result<()> {
+        if let Some(max_price) = self.max_price {
+            if max_price < self.start_price {
+                return err!(CandyGuardError::InvalidBondingCurveConfig);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Guard for BondingCurve {
+    fn size() -> usize {
+        1        // curve
+        + 8      // start price
+        + 8      // factor
+        + 1 + 8  // option + max price
+        + 1 + 32 // option + mint
+        + 32 // destination
+    }
+
+    fn guard_type() -> GuardType {
+        GuardType::BondingCurve
+    }
+
+    fn verify(data: &CandyGuardData) -> Result<()> {
+        if let Some(bonding_curve) = &data.default.bonding_curve {
+            bonding_curve.verify_config()?;
+        }
+
+        if let Some(groups) = &data.groups {
+            for group in groups {
+                if let Some(bonding_curve) = &group.guards.bonding_curve {
+                    bonding_curve.verify_config()?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Condition for BondingCurve {
+    fn validate<'info>(
+        &self,
+        ctx: &mut EvaluationContext,
+        _guard_set: &GuardSet,
+        _mint_args: &[u8],
+    ) -> Result<()> {
+        let index = ctx.account_cursor;
+        let price = self.price(
+            ctx.accounts.candy_machine.items_redeemed,
+            ctx.accounts.candy_machine.data.items_available,
+        );
+
+        if let Some(mint) = &self.mint {
+            let token_account_info = try_get_account_info(ctx.accounts.remaining, index)?;
+            let destination_ata = try_get_account_info(ctx.accounts.remaining, index + 1)?;
+
+            assert_keys_equal(destination_ata.key, &self.destination)?;
+
+            let amount = if cmp_pubkeys(token_account_info.owner, &spl_token_2022::ID) {
+                let mint_info = try_get_account_info(ctx.accounts.remaining, index + 2)?;
+                let spl_token_2022_program =
+                    try_get_account_info(ctx.accounts.remaining, index + 3)?;
+                ctx.account_cursor += 4;
+
+                // destination
+                let data = destination_ata.data.borrow();
+                let ata_account = StateWithExtensions::<Account>::unpack(&data)?;
+                assert_keys_equal(&ata_account.base.mint, mint)?;
+
+                // token
+                let data = token_account_info.data.borrow();
+                let token_account = StateWithExtensions::<Account>::unpack(&data)?;
+                assert_keys_equal(&token_account.base.owner, ctx.accounts.minter.key)?;
+                assert_keys_equal(&token_account.base.mint, mint)?;
+
+                // mint and program
+                assert_keys_equal(mint_info.key, mint)?;
+                assert_keys_equal(spl_token_2022_program.key, &spl_token_2022::ID)?;
+
+                token_account.base.amount
+            } else {
+                let spl_token_program = try_get_account_info(ctx.accounts.remaining, index + 2)?;
+                ctx.account_cursor += 3;
+
+                assert_keys_equal(spl_token_program.key, &spl_token::ID)?;
+                let ata_account: spl_token::state::Account = assert_initialized(destination_ata)?;
+                assert_keys_equal(&ata_account.mint, mint)?;
+
+                let token_account =
+                    assert_is_token_account(token_account_info, ctx.accounts.minter.key, mint)?;
+
+                token_account.amount
+            };
+
+            if amount < price {
+                return err!(CandyGuardError::NotEnoughTokens);
+            }
+        } else {
+            let destination = try_get_account_info(ctx.accounts.remaining, index)?;
+            ctx.account_cursor += 1;
+
+            assert_keys_equal(destination.key, &self.destination)?;
+
+            if ctx.accounts.payer.lamports() < price {
+                msg!(
+                    "Require {} lamports, accounts has {} lamports",
+                    price,
+                    ctx.accounts.payer.lamports(),
+                );
+                return err!(CandyGuardError::NotEnoughSOL);
+            }
+        }
+
+        ctx.indices.insert("bonding_curve_index", index);
+
+        Ok(())
+    }
+
+    fn payment(
+        &self,
+        ctx: &EvaluationContext,
+        _guard_set: &GuardSet,
+    ) -> Result<Option<MintPayment>> {
+        Ok(Some(MintPayment {
+            guard: GuardType::BondingCurve,
+            mint: self.mint,
+            amount: self.price(
+                ctx.accounts.candy_machine.items_redeemed,
+                ctx.accounts.candy_machine.data.items_available,
+            ),
+        }))
+    }
+
+    fn pre_actions<'info>(
+        &self,
+        ctx: &mut EvaluationContext,
+        _guard_set: &GuardSet,
+        _mint_args: &[u8],
+    ) -> Result<()> {
+        let index = ctx.indices["bonding_curve_index"];
+        let price = self.price(
+            ctx.accounts.candy_machine.items_redeemed,
+            ctx.accounts.candy_machine.data.items_available,
+        );
+
+        if let Some(mint) = &self.mint {
+            // the accounts have already been validated
+            let token_account_info = try_get_account_info(ctx.accounts.remaining, index)?;
+            let destination_ata = try_get_account_info(ctx.accounts.remaining, index + 1)?;
+
+            if cmp_pubkeys(token_account_info.owner, &spl_token_2022::ID) {
+                let mint_info = try_get_account_info(ctx.accounts.remaining, index + 2)?;
+                let spl_token_2022_program =
+                    try_get_account_info(ctx.accounts.remaining, index + 3)?;
+
+                assert_owned_by(mint_info, &spl_token_2022::ID)?;
+                let decimals = {
+                    let data = mint_info.data.borrow();
+                    StateWithExtensions::<Mint>::unpack(&data)?.base.decimals
+                };
+
+                spl_token_transfer_checked(TokenTransferCheckedParams {
+                    source: token_account_info.to_account_info(),
+                    destination: destination_ata.to_account_info(),
+                    mint: mint_info.to_account_info(),
+                    authority: ctx.accounts.minter.to_account_info(),
+                    authority_signer_seeds: &[],
+                    token_program: spl_token_2022_program.to_account_info(),
+                    amount: price,
+                    decimals,
+                })?;
+            } else {
+                let spl_token_program = try_get_account_info(ctx.accounts.remaining, index + 2)?;
+
+                spl_token_transfer(TokenTransferParams {
+                    source: token_account_info.to_account_info(),
+                    destination: destination_ata.to_account_info(),
+                    authority: ctx.accounts.minter.to_account_info(),
+                    authority_signer_seeds: &[],
+                    token_program: spl_token_program.to_account_info(),
+                    amount: price,
+                })?;
+            }
+
+            ctx.payments.push(MintPayment {
+                guard: GuardType::BondingCurve,
+                mint: Some(*mint),
+                amount: price,
+            });
+        } else {
+            let destination = try_get_account_info(ctx.accounts.remaining, index)?;
+
+            invoke(
+                &system_instruction::transfer(&ctx.accounts.payer.key(), &destination.key(), price),
+                &[
+                    ctx.accounts.payer.to_account_info(),
+                    destination.to_account_info(),
+                    ctx.accounts.system_program.to_account_info(),
+                ],
+            )?;
+
+            ctx.payments.push(MintPayment {
+                guard: GuardType::BondingCurve,
+                mint: None,
+                amount: price,
+            });
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn curve(curve: BondingCurveType, start_price: u64, factor: u64) -> BondingCurve {
+        BondingCurve {
+            curve,
+            start_price,
+            factor,
+            max_price: None,
+            mint: None,
+            destination: Pubkey::default(),
+        }
+    }
+
+    #[test]
+    fn linear_price() {
+        let curve = curve(BondingCurveType::Linear, 1_000, 500);
+        assert_eq!(curve.price(0, 10), 1_000);
+        assert_eq!(curve.price(1, 10), 1_050);
+        assert_eq!(curve.price(5, 10), 1_250);
+        assert_eq!(curve.price(10, 10), 1_500);
+        // the ratio is capped at 100%
+        assert_eq!(curve.price(20, 10), 1_500);
+        // empty candy machines charge the start price
+        assert_eq!(curve.price(0, 0), 1_000);
+    }
+
+    #[test]
+    fn linear_price_is_relative_to_the_supply() {
+        let curve = curve(BondingCurveType::Linear, 1_000, 500);
+        assert_eq!(curve.price(5, 10), curve.price(500, 1_000));
+        assert_eq!(curve.price(1, 3), 1_166);
+    }
+
+    #[test]
+    fn exponential_price() {
+        // 1% growth for each percent of items redeemed
+        let curve = curve(BondingCurveType::Exponential, 1_000_000, 100);
+        assert_eq!(curve.price(0, 100), 1_000_000);
+        assert_eq!(curve.price(1, 100), 1_010_000);
+        assert_eq!(curve.price(2, 100), 1_020_100);
+        assert_eq!(curve.price(3, 100), 1_030_301);
+        assert_eq!(curve.price(50, 100), 1_644_631);
+        assert_eq!(curve.price(100, 100), 2_704_813);
+    }
+
+    #[test]
+    fn exponential_price_uses_whole_percentages() {
+        let curve = curve(BondingCurveType::Exponential, 1_000_000, 100);
+        // 1.5% and 1.9% of the items redeemed round down to 1%
+        assert_eq!(curve.price(3, 200), 1_010_000);
+        assert_eq!(curve.price(19, 1_000), 1_010_000);
+    }
+
+    #[test]
+    fn price_is_capped() {
+        let mut curve = curve(BondingCurveType::Exponential, 1_000_000, 100);
+        curve.max_price = Some(1_500_000);
+        assert_eq!(curve.price(10, 100), 1_104_622);
+        assert_eq!(curve.price(50, 100), 1_500_000);
+    }
+
+    #[test]
+    fn price_saturates() {
+        let linear = curve(BondingCurveType::Linear, u64::MAX, u64::MAX);
+        assert_eq!(linear.price(10, 10), u64::MAX);
+
+        let mut exponential = curve(BondingCurveType::Exponential, u64::MAX, u64::MAX);
+        assert_eq!(exponential.price(10, 10), u64::MAX);
+
+        exponential.max_price = Some(5_000);
+        assert_eq!(exponential.price(10, 10), 5_000);
+    }
+}
@@ -26,8 +26,8 @@ impl Guard for BotTax {
         8 + 1 // u64 + bool
     }
 
-    fn mask() -> u64 {
-        GuardType::as_mask(GuardType::BotTax)
+    fn guard_type() -> GuardType {
+        GuardType::BotTax
     }
 }
 
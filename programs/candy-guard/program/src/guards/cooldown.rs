@@ -0,0 +1,228 @@
+use std::collections::HashSet;
+
+use solana_program::{program::invoke_signed, system_instruction};
+
+use super::*;
+use crate::{
+    instructions::Route,
+    state::GuardType,
+    utils::{assert_keys_equal, assert_owned_by},
+};
+
+/// Guard to set a minimum interval between the mints of a wallet.
+///
+/// List of accounts required:
+///
+///   0. `[writable]` Cooldown tracker PDA. The PDA is derived using the seed
+///      `["cooldown", cooldown guard id, minter key, candy guard pubkey, candy machine pubkey]`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct Cooldown {
+    /// Unique identifier of the cooldown.
+    pub id: u8,
+    /// Minimum interval between mints of a wallet (in seconds).
+    pub interval: i64,
+}
+
+impl Guard for Cooldown {
+    fn size() -> usize {
+        1   // id
+        + 8 // interval
+    }
+
+    fn guard_type() -> GuardType {
+        GuardType::Cooldown
+    }
+
+    fn verify(data: &CandyGuardData) -> Result<()> {
+        let mut ids = HashSet::new();
+
+        let guard_sets = std::iter::once(&data.default)
+            .chain(data.groups.iter().flatten().map(|group| &group.guards));
+
+        for guard_set in guard_sets {
+            if let Some(cooldown) = &guard_set.cooldown {
+                if cooldown.interval <= 0 || !ids.insert(cooldown.id) {
+                    return err!(CandyGuardError::InvalidCooldownConfig);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Instruction to close the cooldown tracker PDA once the mint is over.
+    ///
+    /// List of accounts required:
+    ///
+    ///   0. `[writable]` Cooldown tracker PDA (seeds `["cooldown", cooldown guard id, minter key,
+    ///      candy guard pubkey, candy machine pubkey]`).
+    ///   1. `[signer, writable]` Candy guard authority or minter (receives the lamports).
+    ///   2. `[optional]` Minter account (required when the authority closes the PDA).
+    fn instruction<'c, 'info>(
+        ctx: &Context<'_, '_, 'c, 'info, Route<'info>>,
+        route_context: RouteContext<'info>,
+        data: Vec<u8>,
+    ) -> Result<()>
+    where
+        'c: 'info,
+    {
+        if data != CLOSE_PDA_INSTRUCTION {
+            return err!(CandyGuardError::InstructionNotFound);
+        }
+
+        msg!("Instruction: Close (Cooldown guard)");
+
+        let cooldown = route_context
+            .guard_set
+            .as_ref()
+            .and_then(|guard_set| guard_set.cooldown.as_ref())
+            .ok_or(CandyGuardError::GuardNotEnabled)?;
+
+        let minter = if let Some(minter) = get_account_info(ctx.remaining_accounts, 2) {
+            minter.key()
+        } else {
+            try_get_account_info(ctx.remaining_accounts, 1)?.key()
+        };
+
+        let candy_guard_key = &ctx.accounts.candy_guard.key();
+        let candy_machine_key = &ctx.accounts.candy_machine.key();
+
+        let seeds = [
+            CooldownTracker::PREFIX_SEED,
+            &[cooldown.id],
+            minter.as_ref(),
+            candy_guard_key.as_ref(),
+            candy_machine_key.as_ref(),
+        ];
+
+        close_guard_pda(ctx, &route_context, &seeds, Some(&minter), None)
+    }
+}
+
+impl Condition for Cooldown {
+    fn validate<'info>(
+        &self,
+        ctx: &mut EvaluationContext,
+        _guard_set: &GuardSet,
+        _mint_args: &[u8],
+    ) -> Result<()> {
+        let tracker = try_get_account_info(ctx.accounts.remaining, ctx.account_cursor)?;
+        ctx.indices.insert("cooldown_index", ctx.account_cursor);
+        ctx.account_cursor += 1;
+
+        let minter = ctx.accounts.minter.key();
+        let candy_guard_key = &ctx.accounts.candy_guard.key();
+        let candy_machine_key = &ctx.accounts.candy_machine.key();
+
+        let seeds = [
+            CooldownTracker::PREFIX_SEED,
+            &[self.id],
+            minter.as_ref(),
+            candy_guard_key.as_ref(),
+            candy_machine_key.as_ref(),
+        ];
+        let (pda, _) = Pubkey::find_program_address(&seeds, &crate::ID);
+
+        assert_keys_equal(tracker.key, &pda)?;
+
+        if !tracker.data_is_empty() {
+            // check the owner of the account
+            assert_owned_by(tracker, &crate::ID)?;
+
+            let account_data = tracker.data.borrow();
+            let cooldown_tracker = CooldownTracker::try_from_slice(&account_data)?;
+
+            let clock = Clock::get()?;
+            let next_mint = cooldown_tracker.last_mint.saturating_add(self.interval);
+
+            if clock.unix_timestamp < next_mint {
+                msg!(
+                    "Cooldown until {}, current time {}",
+                    next_mint,
+                    clock.unix_timestamp
+                );
+                return err!(CandyGuardError::CooldownNotElapsed);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn pre_actions<'info>(
+        &self,
+        ctx: &mut EvaluationContext,
+        _guard_set: &GuardSet,
+        _mint_args: &[u8],
+    ) -> Result<()> {
+        let tracker = try_get_account_info(ctx.accounts.remaining, ctx.indices["cooldown_index"])?;
+
+        if tracker.data_is_empty() {
+            let minter = ctx.accounts.minter.key();
+            let candy_guard_key = &ctx.accounts.candy_guard.key();
+            let candy_machine_key = &ctx.accounts.candy_machine.key();
+
+            let seeds = [
+                CooldownTracker::PREFIX_SEED,
+                &[self.id],
+                minter.as_ref(),
+                candy_guard_key.as_ref(),
+                candy_machine_key.as_ref(),
+            ];
+            let (pda, bump) = Pubkey::find_program_address(&seeds, &crate::ID);
+
+            let rent = Rent::get()?;
+            let signer = [
+                CooldownTracker::PREFIX_SEED,
+                &[self.id],
+                minter.as_ref(),
+                candy_guard_key.as_ref(),
+                candy_machine_key.as_ref(),
+                &[bump],
+            ];
+
+            invoke_signed(
+                &system_instruction::create_account(
+                    ctx.accounts.payer.key,
+                    &pda,
+                    rent.minimum_balance(CooldownTracker::SIZE),
+                    CooldownTracker::SIZE as u64,
+                    &crate::ID,
+                ),
+                &[
+                    ctx.accounts.payer.to_account_info(),
+                    tracker.to_account_info(),
+                ],
+                &[&signer],
+            )?;
+        } else {
+            assert_owned_by(tracker, &crate::ID)?;
+        }
+
+        let clock = Clock::get()?;
+        let cooldown_tracker = CooldownTracker {
+            last_mint: clock.unix_timestamp,
+        };
+
+        let mut account_data = tracker.try_borrow_mut_data()?;
+        // saves the changes back to the pda
+        let data = &mut cooldown_tracker.try_to_vec()?;
+        account_data[0..data.len()].copy_from_slice(data);
+
+        Ok(())
+    }
+}
+
+/// PDA to track the last mint of an individual address.
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct CooldownTracker {
+    /// Timestamp of the last mint.
+    pub last_mint: i64,
+}
+
+impl CooldownTracker {
+    /// Prefix used as seed.
+    pub const PREFIX_SEED: &'static [u8] = b"cooldown";
+
+    /// Size of the account data.
+    pub const SIZE: usize = 8; // last_mint
+}
@@ -0,0 +1,850 @@
+use super::*;
+
+use anchor_lang::AccountsClose;
+use mpl_core_candy_machine_core::CandyMachine;
+use solana_program::{
+    program::{invoke, invoke_signed},
+    system_instruction, system_program,
+};
+
+use crate::{
+    errors::CandyGuardError,
+    state::GuardType,
+    utils::{assert_keys_equal, assert_owned_by, cmp_pubkeys},
+};
+
+/// Guard that charges a descending price in SOL (lamports) for the mint. The price starts
+/// at `start_price` and drops by `decay_step` lamports every `interval` seconds until it
+/// reaches the `floor_price`.
+///
+/// When `rebate` is enabled, payments are held in an escrow PDA and buyers can claim the
+/// difference between the price they paid and the final clearing price once the auction
+/// is settled.
+///
+/// List of accounts required:
+///
+///   0. `[writable]` Destination or, for rebates, escrow PDA (seeds `["dutch_auction",
+///           destination pubkey, candy guard pubkey, candy machine pubkey, group label]`).
+///   1. `[writable]` (rebates only) Auction bid PDA (seeds `["dutch_auction_bid",
+///           minter pubkey, candy guard pubkey, candy machine pubkey, group label]`).
+///
+/// The group label seed is empty for the default guard set, so each group has its own
+/// escrow and bids.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct DutchAuction {
+    /// Price at the start of the auction.
+    pub start_price: u64,
+    /// Minimum price of the auction.
+    pub floor_price: u64,
+    /// Time when the auction starts.
+    pub start_time: i64,
+    /// Amount (in lamports) that the price drops at each interval.
+    pub decay_step: u64,
+    /// Interval (in seconds) between price drops.
+    pub interval: i64,
+    /// Address to receive the funds.
+    pub destination: Pubkey,
+    /// Whether buyers can claim the difference to the clearing price or not.
+    pub rebate: bool,
+}
+
+impl DutchAuction {
+    /// Returns the price of the auction at the specified timestamp.
+    pub fn price_at(&self, timestamp: i64) -> u64 {
+        if timestamp <= self.start_time || self.interval <= 0 {
+            return self.start_price;
+        }
+
+        let steps = (timestamp.saturating_sub(self.start_time) / self.interval) as u64;
+
+        self.start_price
+            .saturating_sub(steps.saturating_mul(self.decay_step))
+            .max(self.floor_price)
+    }
+
+    /// Returns the clearing price of the auction, if the auction is settled.
+    ///
+    /// The auction is settled once the candy machine is sold out (or closed), in which
+    /// case the clearing price is the last price paid, or once the price reaches the floor.
+    pub fn clearing_price(
+        &self,
+        escrow: &AuctionEscrow,
+        candy_machine: Option<&CandyMachine>,
+        timestamp: i64,
+    ) -> Option<u64> {
+        let sold_out = candy_machine
+            .map(|candy_machine| candy_machine.items_redeemed >= candy_machine.data.items_available)
+            .unwrap_or(true);
+
+        if sold_out && escrow.total_count > 0 {
+            Some(escrow.last_price)
+        } else if self.price_at(timestamp) <= self.floor_price {
+            Some(self.floor_price)
+        } else {
+            None
+        }
+    }
+
+    fn verify_config(&self) -> Result<()> {
+        if self.floor_price > self.start_price || self.interval <= 0 {
+            return err!(CandyGuardError::InvalidAuctionConfig);
+        }
+
+        Ok(())
+    }
+}
+
+impl Guard for DutchAuction {
+    fn size() -> usize {
+        8    // start price
+        + 8  // floor price
+        + 8  // start time
+        + 8  // decay step
+        + 8  // interval
+        + 32 // destination
+        + 1 // rebate
+    }
+
+    fn guard_type() -> GuardType {
+        GuardType::DutchAuction
+    }
+
+    fn verify(data: &CandyGuardData) -> Result<()> {
+        if let Some(dutch_auction) = &data.default.dutch_auction {
+            dutch_auction.verify_config()?;
+        }
+
+        if let Some(groups) = &data.groups {
+            for group in groups {
+                if let Some(dutch_auction) = &group.guards.dutch_auction {
+                    dutch_auction.verify_config()?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Instructions to interact with the rebate feature:
+    ///
+    ///  * initialize
+    ///  * claim
+    ///  * withdraw
+    fn instruction<'c, 'info>(
+        ctx: &Context<'_, '_, 'c, 'info, Route<'info>>,
+        route_context: RouteContext<'info>,
+        data: Vec<u8>,
+    ) -> Result<()>
+    where
+        'c: 'info,
+    {
+        // determines the instruction to execute
+        let instruction: DutchAuctionInstruction =
+            if let Ok(instruction) = DutchAuctionInstruction::try_from_slice(&data[0..1]) {
+                instruction
+            } else {
+                return err!(CandyGuardError::InstructionNotFound);
+            };
+
+        let dutch_auction = route_context
+            .guard_set
+            .as_ref()
+            .and_then(|guard_set| guard_set.dutch_auction.clone())
+            .ok_or(CandyGuardError::AuctionGuardNotEnabled)?;
+
+        match instruction {
+            // Initializes the auction escrow PDA.
+            //
+            // List of accounts required:
+            //
+            //   0. `[writable]` Auction escrow PDA (seeds `["dutch_auction", destination
+            //                   pubkey, candy guard pubkey, candy machine pubkey, group label]`).
+            //   1. `[signer]` Candy Guard authority.
+            //   2. `[]` System program account.
+            //
+            // Only available when `rebate` is enabled.
+            DutchAuctionInstruction::Initialize => {
+                msg!("Instruction: Initialize (DutchAuction guard)");
+                initialize_escrow(ctx, route_context, &dutch_auction)
+            }
+            // Claims the rebate of a buyer.
+            //
+            // List of accounts required:
+            //
+            //   0. `[writable]` Auction escrow PDA (seeds `["dutch_auction", destination
+            //                   pubkey, candy guard pubkey, candy machine pubkey, group label]`).
+            //   1. `[writable]` Auction bid PDA (seeds `["dutch_auction_bid", minter pubkey,
+            //                   candy guard pubkey, candy machine pubkey, group label]`).
+            //   2. `[writable]` Minter account (receives the rebate).
+            //   3. `[writable]` Payer of the bid account (receives the rent).
+            DutchAuctionInstruction::Claim => {
+                msg!("Instruction: Claim (DutchAuction guard)");
+                claim_rebate(ctx, route_context, &dutch_auction)
+            }
+            // Withdraws the auction proceeds at the clearing price.
+            //
+            // List of accounts required:
+            //
+            //   0. `[writable]` Auction escrow PDA (seeds `["dutch_auction", destination
+            //                   pubkey, candy guard pubkey, candy machine pubkey, group label]`).
+            //   1. `[signer]` Candy Guard authority.
+            //   2. `[writable]` Address to receive the funds (must match the `destination`
+            //                   address of the guard configuration).
+            DutchAuctionInstruction::Withdraw => {
+                msg!("Instruction: Withdraw (DutchAuction guard)");
+                withdraw_proceeds(ctx, route_context, &dutch_auction)
+            }
+        }
+    }
+}
+
+impl Condition for DutchAuction {
+    fn validate<'info>(
+        &self,
+        ctx: &mut EvaluationContext,
+        _guard_set: &GuardSet,
+        _mint_args: &[u8],
+    ) -> Result<()> {
+        let clock = Clock::get()?;
+
+        if clock.unix_timestamp < self.start_time {
+            return err!(CandyGuardError::MintNotLive);
+        }
+
+        let index = ctx.account_cursor;
+        let destination = try_get_account_info(ctx.accounts.remaining, index)?;
+        ctx.account_cursor += 1;
+
+        if self.rebate {
+            let candy_guard_key = &ctx.accounts.candy_guard.key();
+            let candy_machine_key = &ctx.accounts.candy_machine.key();
+
+            let label = label_seed(&ctx.label);
+
+            let seeds = [
+                AuctionEscrow::PREFIX_SEED,
+                self.destination.as_ref(),
+                candy_guard_key.as_ref(),
+                candy_machine_key.as_ref(),
+                label,
+            ];
+            let (pda, _) = Pubkey::find_program_address(&seeds, &crate::ID);
+            assert_keys_equal(destination.key, &pda)?;
+
+            if destination.data_is_empty() {
+                return err!(CandyGuardError::AuctionNotInitialized);
+            }
+
+            let bid = try_get_account_info(ctx.accounts.remaining, index + 1)?;
+            ctx.account_cursor += 1;
+
+            let minter = ctx.accounts.minter.key();
+            let seeds = [
+                AuctionBid::PREFIX_SEED,
+                minter.as_ref(),
+                candy_guard_key.as_ref(),
+                candy_machine_key.as_ref(),
+                label,
+            ];
+            let (pda, _) = Pubkey::find_program_address(&seeds, &crate::ID);
+            assert_keys_equal(bid.key, &pda)?;
+
+            if !bid.data_is_empty() {
+                assert_owned_by(bid, &crate::ID)?;
+            }
+        } else {
+            assert_keys_equal(destination.key, &self.destination)?;
+        }
+
+        ctx.indices.insert("dutch_auction_index", index);
+
+        let price = self.price_at(clock.unix_timestamp);
+
+        if ctx.accounts.payer.lamports() < price {
+            msg!(
+                "Require {} lamports, accounts has {} lamports",
+                price,
+                ctx.accounts.payer.lamports(),
+            );
+            return err!(CandyGuardError::NotEnoughSOL);
+        }
+
+        Ok(())
+    }
+
+    fn payment(
+        &self,
+        _ctx: &EvaluationContext,
+        _guard_set: &GuardSet,
+    ) -> Result<Option<MintPayment>> {
+        Ok(Some(MintPayment {
+            guard: GuardType::DutchAuction,
+            mint: None,
+            amount: self.price_at(Clock::get()?.unix_timestamp),
+        }))
+    }
+
+    fn pre_actions<'info>(
+        &self,
+        ctx: &mut EvaluationContext,
+        _guard_set: &GuardSet,
+        _mint_args: &[u8],
+    ) -> Result<()> {
+        let index = ctx.indices["dutch_auction_index"];
+        let destination = try_get_account_info(ctx.accounts.remaining, index)?;
+        let price = self.price_at(Clock::get()?.unix_timestamp);
+
+        invoke(
+            &system_instruction::transfer(&ctx.accounts.payer.key(), &destination.key(), price),
+            &[
+                ctx.accounts.payer.to_account_info(),
+                destination.to_account_info(),
+                ctx.accounts.system_program.to_account_info(),
+            ],
+        )?;
+
+        if self.rebate {
+            let bid = try_get_account_info(ctx.accounts.remaining, index + 1)?;
+            assert_owned_by(destination, &crate::ID)?;
+            let mut escrow = AuctionEscrow::try_deserialize(&mut &destination.data.borrow()[..])?;
+
+            if bid.data_is_empty() {
+                let minter = ctx.accounts.minter.key();
+                let candy_guard_key = &ctx.accounts.candy_guard.key();
+                let candy_machine_key = &ctx.accounts.candy_machine.key();
+                let label = label_seed(&ctx.label);
+
+                let seeds = [
+                    AuctionBid::PREFIX_SEED,
+                    minter.as_ref(),
+                    candy_guard_key.as_ref(),
+                    candy_machine_key.as_ref(),
+                    label,
+                ];
+                let (pda, bump) = Pubkey::find_program_address(&seeds, &crate::ID);
+
+                let signer = [
+                    AuctionBid::PREFIX_SEED,
+                    minter.as_ref(),
+                    candy_guard_key.as_ref(),
+                    candy_machine_key.as_ref(),
+                    label,
+                    &[bump],
+                ];
+                let rent = Rent::get()?;
+
+                invoke_signed(
+                    &system_instruction::create_account(
+                        ctx.accounts.payer.key,
+                        &pda,
+                        rent.minimum_balance(AuctionBid::SIZE),
+                        AuctionBid::SIZE as u64,
+                        &crate::ID,
+                    ),
+                    &[ctx.accounts.payer.to_account_info(), bid.to_account_info()],
+                    &[&signer],
+                )?;
+
+                escrow.open_bids += 1;
+
+                // records the payer of the bid account to refund its rent
+                let auction_bid = AuctionBid {
+                    count: 0,
+                    total_paid: 0,
+                    payer: ctx.accounts.payer.key(),
+                };
+                let data = &mut auction_bid.try_to_vec()?;
+                bid.try_borrow_mut_data()?[0..data.len()].copy_from_slice(data);
+            }
+
+            let mut account_data = bid.try_borrow_mut_data()?;
+            let mut auction_bid = AuctionBid::try_from_slice(&account_data)?;
+            auction_bid.count += 1;
+            auction_bid.total_paid = auction_bid
+                .total_paid
+                .checked_add(price)
+                .ok_or(CandyGuardError::NumericalOverflowError)?;
+            // saves the changes back to the pda
+            let data = &mut auction_bid.try_to_vec()?;
+            account_data[0..data.len()].copy_from_slice(data);
+
+            // prices only go down, so the last price is always the lowest
+            escrow.last_price = price;
+            escrow.total_count += 1;
+            escrow.total_paid = escrow
+                .total_paid
+                .checked_add(price)
+                .ok_or(CandyGuardError::NumericalOverflowError)?;
+            // saves the changes back to the pda
+            escrow.try_serialize(&mut &mut destination.try_borrow_mut_data()?[..])?;
+        }
+
+        ctx.payments.push(MintPayment {
+            guard: GuardType::DutchAuction,
+            mint: None,
+            amount: price,
+        });
+
+        Ok(())
+    }
+}
+
+/// PDA to hold the funds of an auction with rebates.
+#[account]
+#[derive(Default, Debug, PartialEq, Eq)]
+pub struct AuctionEscrow {
+    /// Candy guard address associated with this escrow.
+    pub candy_guard: Pubkey,
+
+    /// Candy machine address associated with this escrow.
+    pub candy_machine: Pubkey,
+
+    /// The destination address for the auction proceeds.
+    pub destination: Pubkey,
+
+    /// The authority that initialized the escrow.
+    pub authority: Pubkey,
+
+    /// Price paid on the most recent mint.
+    pub last_price: u64,
+
+    /// Number of items minted through the auction.
+    pub total_count: u64,
+
+    /// Total amount paid by buyers.
+    pub total_paid: u64,
+
+    /// Amount of proceeds already withdrawn.
+    pub withdrawn: u64,
+
+    /// Number of bids that have not claimed their rebate.
+    pub open_bids: u64,
+}
+
+impl AuctionEscrow {
+    /// Maximum account size.
+    pub const SIZE: usize = 8 // discriminator
+        + 32 // candy guard
+        + 32 // candy machine
+        + 32 // destination
+        + 32 // authority
+        + 8  // last price
+        + 8  // total count
+        + 8  // total paid
+        + 8  // withdrawn
+        + 8; // open bids
+
+    /// Prefix used as seed.
+    pub const PREFIX_SEED: &'static [u8] = b"dutch_auction";
+}
+
+/// PDA to track the amount paid by an individual address.
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct AuctionBid {
+    /// Number of items minted.
+    pub count: u32,
+    /// Total amount paid.
+    pub total_paid: u64,
+    /// Address that paid for the rent of the account.
+    pub payer: Pubkey,
+}
+
+impl AuctionBid {
+    /// Account size.
+    pub const SIZE: usize = 4 // count
+        + 8  // total paid
+        + 32; // payer
+
+    /// Prefix used as seed.
+    pub const PREFIX_SEED: &'static [u8] = b"dutch_auction_bid";
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub enum DutchAuctionInstruction {
+    Initialize,
+    Claim,
+    Withdraw,
+}
+
+/// Returns the group label seed of the escrow and bid PDAs (empty for the default guard set).
+fn label_seed(label: &Option<String>) -> &[u8] {
+    label.as_deref().unwrap_or_default().as_bytes()
+}
+
+/// Helper function to validate the escrow PDA of the route transaction.
+fn load_escrow<'info>(
+    ctx: &Context<'_, '_, '_, 'info, Route<'info>>,
+    route_context: &RouteContext<'info>,
+    escrow_pda: &'info AccountInfo<'info>,
+    destination: &Pubkey,
+) -> Result<Account<'info, AuctionEscrow>> {
+    let candy_guard_key = &ctx.accounts.candy_guard.key();
+    let candy_machine_key = &ctx.accounts.candy_machine.key();
+
+    let seeds = [
+        AuctionEscrow::PREFIX_SEED,
+        destination.as_ref(),
+        candy_guard_key.as_ref(),
+        candy_machine_key.as_ref(),
+        label_seed(&route_context.label),
+    ];
+    let (pda, _) = Pubkey::find_program_address(&seeds, &crate::ID);
+    assert_keys_equal(escrow_pda.key, &pda)?;
+
+    Account::try_from(escrow_pda)
+}
+
+/// Helper function to initialize the escrow PDA.
+fn initialize_escrow<'c, 'info>(
+    ctx: &Context<'_, '_, 'c, 'info, Route<'info>>,
+    route_context: RouteContext<'info>,
+    dutch_auction: &DutchAuction,
+) -> Result<()>
+where
+    'c: 'info,
+{
+    // the escrow is only used by auctions with rebates
+    if !dutch_auction.rebate {
+        return err!(CandyGuardError::AuctionRebateNotEnabled);
+    }
+
+    let escrow_pda = try_get_account_info(ctx.remaining_accounts, 0)?;
+    let authority = try_get_account_info(ctx.remaining_accounts, 1)?;
+    let system_program = try_get_account_info(ctx.remaining_accounts, 2)?;
+    assert_keys_equal(system_program.key, &system_program::ID)?;
+
+    let candy_guard = route_context
+        .candy_guard
+        .as_ref()
+        .ok_or(CandyGuardError::Uninitialized)?;
+
+    let candy_machine = route_context
+        .candy_machine
+        .as_ref()
+        .ok_or(CandyGuardError::Uninitialized)?;
+
+    // only the authority can initialize the escrow
+    if !(cmp_pubkeys(authority.key, &candy_guard.authority) && authority.is_signer) {
+        return err!(CandyGuardError::MissingRequiredSignature);
+    }
+
+    // and the candy guard and candy machine must be linked
+    if !cmp_pubkeys(&candy_machine.mint_authority, &candy_guard.key()) {
+        return err!(CandyGuardError::InvalidMintAuthority);
+    }
+
+    let candy_guard_key = &ctx.accounts.candy_guard.key();
+    let candy_machine_key = &ctx.accounts.candy_machine.key();
+
+    let label = label_seed(&route_context.label);
+
+    let seeds = [
+        AuctionEscrow::PREFIX_SEED,
+        dutch_auction.destination.as_ref(),
+        candy_guard_key.as_ref(),
+        candy_machine_key.as_ref(),
+        label,
+    ];
+    let (pda, bump) = Pubkey::find_program_address(&seeds, &crate::ID);
+    assert_keys_equal(escrow_pda.key, &pda)?;
+
+    if !escrow_pda.data_is_empty() {
+        return err!(CandyGuardError::AuctionEscrowAlreadyExists);
+    }
+
+    let signer = [
+        AuctionEscrow::PREFIX_SEED,
+        dutch_auction.destination.as_ref(),
+        candy_guard_key.as_ref(),
+        candy_machine_key.as_ref(),
+        label,
+        &[bump],
+    ];
+    let rent = Rent::get()?;
+
+    invoke_signed(
+        &system_instruction::create_account(
+            &ctx.accounts.payer.key(),
+            &pda,
+            rent.minimum_balance(AuctionEscrow::SIZE),
+            AuctionEscrow::SIZE as u64,
+            &crate::ID,
+        ),
+        &[
+            ctx.accounts.payer.to_account_info(),
+            escrow_pda.to_account_info(),
+        ],
+        &[&signer],
+    )?;
+
+    // initilializes the escrow account (safe to be unchecked since the account
+    // must be empty at this point)
+    let mut escrow: Account<AuctionEscrow> = Account::try_from_unchecked(escrow_pda)?;
+    escrow.candy_guard = *candy_guard_key;
+    escrow.candy_machine = *candy_machine_key;
+    escrow.destination = dutch_auction.destination;
+    escrow.authority = authority.key();
+
+    escrow.exit(&crate::ID)
+}
+
+/// Helper function to claim the rebate of a buyer.
+fn claim_rebate<'c, 'info>(
+    ctx: &Context<'_, '_, 'c, 'info, Route<'info>>,
+    route_context: RouteContext<'info>,
+    dutch_auction: &DutchAuction,
+) -> Result<()>
+where
+    'c: 'info,
+{
+    let escrow_pda = try_get_account_info(ctx.remaining_accounts, 0)?;
+    let bid_pda = try_get_account_info(ctx.remaining_accounts, 1)?;
+    let minter = try_get_account_info(ctx.remaining_accounts, 2)?;
+    let payer = try_get_account_info(ctx.remaining_accounts, 3)?;
+
+    let mut escrow = load_escrow(ctx, &route_context, escrow_pda, &dutch_auction.destination)?;
+
+    let candy_guard_key = &ctx.accounts.candy_guard.key();
+    let candy_machine_key = &ctx.accounts.candy_machine.key();
+
+    let seeds = [
+        AuctionBid::PREFIX_SEED,
+        minter.key.as_ref(),
+        candy_guard_key.as_ref(),
+        candy_machine_key.as_ref(),
+        label_seed(&route_context.label),
+    ];
+    let (pda, _) = Pubkey::find_program_address(&seeds, &crate::ID);
+    assert_keys_equal(bid_pda.key, &pda)?;
+    assert_owned_by(bid_pda, &crate::ID)?;
+
+    let clearing_price = dutch_auction
+        .clearing_price(
+            &escrow,
+            route_context
+                .candy_machine
+                .as_deref()
+                .map(|account| &**account),
+            Clock::get()?.unix_timestamp,
+        )
+        .ok_or(CandyGuardError::AuctionNotSettled)?;
+
+    let auction_bid = AuctionBid::try_from_slice(&bid_pda.try_borrow_data()?)?;
+    // the rent of the bid account is refunded to its payer
+    assert_keys_equal(payer.key, &auction_bid.payer)?;
+    let rebate = auction_bid
+        .total_paid
+        .saturating_sub((auction_bid.count as u64).saturating_mul(clearing_price));
+
+    msg!(
+        "Rebate of {} lamports ({} items at {} lamports)",
+        rebate,
+        auction_bid.count,
+        clearing_price
+    );
+
+    escrow.open_bids = escrow.open_bids.saturating_sub(1);
+    escrow.exit(&crate::ID)?;
+
+    // transfers the rebate and closes the bid account
+    let bid_lamports = bid_pda.lamports();
+    **escrow_pda.try_borrow_mut_lamports()? = escrow_pda
+        .lamports()
+        .checked_sub(rebate)
+        .ok_or(CandyGuardError::NumericalOverflowError)?;
+    **bid_pda.try_borrow_mut_lamports()? = 0;
+    **minter.try_borrow_mut_lamports()? = minter
+        .lamports()
+        .checked_add(rebate)
+        .ok_or(CandyGuardError::NumericalOverflowError)?;
+    **payer.try_borrow_mut_lamports()? = payer
+        .lamports()
+        .checked_add(bid_lamports)
+        .ok_or(CandyGuardError::NumericalOverflowError)?;
+
+    bid_pda.assign(&system_program::ID);
+    bid_pda.realloc(0, false)?;
+
+    Ok(())
+}
+
+/// Helper function to withdraw the auction proceeds.
+fn withdraw_proceeds<'c, 'info>(
+    ctx: &Context<'_, '_, 'c, 'info, Route<'info>>,
+    route_context: RouteContext<'info>,
+    dutch_auction: &DutchAuction,
+) -> Result<()>
+where
+    'c: 'info,
+{
+    let escrow_pda = try_get_account_info(ctx.remaining_accounts, 0)?;
+    let authority = try_get_account_info(ctx.remaining_accounts, 1)?;
+    let destination = try_get_account_info(ctx.remaining_accounts, 2)?;
+
+    let mut escrow = load_escrow(ctx, &route_context, escrow_pda, &dutch_auction.destination)?;
+
+    // if the candy guard account is present, we check the authority against
+    // the candy guard authority; otherwise we use the escrow authority
+    let authority_check = if let Some(candy_guard) = &route_context.candy_guard {
+        candy_guard.authority
+    } else {
+        escrow.authority
+    };
+
+    if !(cmp_pubkeys(authority.key, &authority_check) && authority.is_signer) {
+        return err!(CandyGuardError::MissingRequiredSignature);
+    }
+
+    // funds should go to the destination account
+    assert_keys_equal(destination.key, &escrow.destination)?;
+
+    let clearing_price = dutch_auction
+        .clearing_price(
+            &escrow,
+            route_context
+                .candy_machine
+                .as_deref()
+                .map(|account| &**account),
+            Clock::get()?.unix_timestamp,
+        )
+        .ok_or(CandyGuardError::AuctionNotSettled)?;
+
+    let mint_over = route_context
+        .candy_machine
+        .as_ref()
+        .map(|candy_machine| candy_machine.items_redeemed >= candy_machine.data.items_available)
+        .unwrap_or(true);
+
+    // mint is over and all rebates were claimed: the remaining funds belong
+    // to the destination
+    if mint_over && escrow.open_bids == 0 {
+        return escrow.close(destination.to_account_info());
+    }
+
+    let proceeds = escrow
+        .total_count
+        .saturating_mul(clearing_price)
+        .saturating_sub(escrow.withdrawn);
+
+    msg!("Withdrawing {} lamports of proceeds", proceeds);
+
+    escrow.withdrawn = escrow
+        .withdrawn
+        .checked_add(proceeds)
+        .ok_or(CandyGuardError::NumericalOverflowError)?;
+    escrow.exit(&crate::ID)?;
+
+    **escrow_pda.try_borrow_mut_lamports()? = escrow_pda
+        .lamports()
+        .checked_sub(proceeds)
+        .ok_or(CandyGuardError::NumericalOverflowError)?;
+    **destination.try_borrow_mut_lamports()? = destination
+        .lamports()
+        .checked_add(proceeds)
+        .ok_or(CandyGuardError::NumericalOverflowError)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn auction() -> DutchAuction {
+        DutchAuction {
+            start_price: 1_000,
+            floor_price: 400,
+            start_time: 100,
+            decay_step: 150,
+            interval: 10,
+            destination: Pubkey::default(),
+            rebate: true,
+        }
+    }
+
+    fn candy_machine(items_redeemed: u64, items_available: u64) -> CandyMachine {
+        let mut candy_machine = CandyMachine {
+            items_redeemed,
+            ..Default::default()
+        };
+        candy_machine.data.items_available = items_available;
+        candy_machine
+    }
+
+    #[test]
+    fn price_before_start() {
+        let auction = auction();
+        assert_eq!(auction.price_at(0), 1_000);
+        assert_eq!(auction.price_at(100), 1_000);
+    }
+
+    #[test]
+    fn price_decays_at_each_interval() {
+        let auction = auction();
+        assert_eq!(auction.price_at(109), 1_000);
+        assert_eq!(auction.price_at(110), 850);
+        assert_eq!(auction.price_at(119), 850);
+        assert_eq!(auction.price_at(120), 700);
+        assert_eq!(auction.price_at(130), 550);
+    }
+
+    #[test]
+    fn price_stops_at_floor() {
+        let auction = auction();
+        assert_eq!(auction.price_at(150), 400);
+        assert_eq!(auction.price_at(10_000), 400);
+        assert_eq!(auction.price_at(i64::MAX), 400);
+    }
+
+    #[test]
+    fn clearing_price_when_sold_out() {
+        let auction = auction();
+        let escrow = AuctionEscrow {
+            last_price: 700,
+            total_count: 2,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            auction.clearing_price(&escrow, Some(&candy_machine(2, 2)), 120),
+            Some(700)
+        );
+        // a closed candy machine also settles the auction
+        assert_eq!(auction.clearing_price(&escrow, None, 120), Some(700));
+    }
+
+    #[test]
+    fn clearing_price_at_floor() {
+        let auction = auction();
+        let escrow = AuctionEscrow {
+            last_price: 850,
+            total_count: 1,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            auction.clearing_price(&escrow, Some(&candy_machine(1, 2)), 150),
+            Some(400)
+        );
+    }
+
+    #[test]
+    fn clearing_price_while_running() {
+        let auction = auction();
+        let escrow = AuctionEscrow {
+            last_price: 850,
+            total_count: 1,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            auction.clearing_price(&escrow, Some(&candy_machine(1, 2)), 130),
+            None
+        );
+        // nothing minted yet, so a sold out check cannot settle the auction
+        assert_eq!(
+            auction.clearing_price(&AuctionEscrow::default(), Some(&candy_machine(0, 0)), 130),
+            None
+        );
+    }
+}
@@ -15,8 +15,8 @@ impl Guard for Edition {
         4 // edition_start_offset
     }
 
-    fn mask() -> u64 {
-        GuardType::as_mask(GuardType::Edition)
+    fn guard_type() -> GuardType {
+        GuardType::Edition
     }
 }
 
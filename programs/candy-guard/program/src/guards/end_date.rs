@@ -13,8 +13,8 @@ impl Guard for EndDate {
         8 // date
     }
 
-    fn mask() -> u64 {
-        GuardType::as_mask(GuardType::EndDate)
+    fn guard_type() -> GuardType {
+        GuardType::EndDate
     }
 }
 
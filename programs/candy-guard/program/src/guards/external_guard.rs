@@ -0,0 +1,184 @@
+use solana_program::{
+    hash::hash,
+    instruction::{AccountMeta, Instruction},
+    program::invoke,
+};
+
+use super::*;
+
+use crate::{errors::CandyGuardError, state::GuardType, utils::assert_keys_equal};
+
+// Maximum number of bytes of the configuration forwarded to the external program.
+const MAXIMUM_CONFIG_SIZE: usize = 64;
+
+/// Guard that delegates the validation of the mint to an external program. The external
+/// program is invoked (CPI) during the `pre_actions` step with the following instructions,
+/// using Anchor's instruction discriminators:
+///
+///   * `validate_mint(args: ExternalGuardArgs)`: the accounts are forwarded as read-only.
+///   * `pre_mint(args: ExternalGuardArgs)`: the accounts are forwarded with the
+///     writable flag of the transaction.
+///
+/// The accounts of the instructions are the candy guard, candy machine, payer, minter and
+/// asset accounts followed by the accounts forwarded by the guard; signer privileges are
+/// not forwarded. An error returned by the external program fails the mint transaction;
+/// since a failed CPI aborts the transaction, the bot tax is not charged in this case.
+///
+/// The `validate` step only checks the accounts of the guard: a failed CPI cannot be
+/// recovered from, so the external program is not invoked while alternatives are still
+/// being evaluated (`AnyOf` alternatives, `AutoGroup` groups and `check_eligibility`).
+///
+/// List of accounts required:
+///
+///   0. `[]` External program.
+///
+/// x. `[]` Accounts forwarded to the external program (`accounts` guard setting).
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct ExternalGuard {
+    /// Program invoked to validate the mint.
+    pub program_id: Pubkey,
+    /// Configuration bytes forwarded to the external program.
+    pub config: Vec<u8>,
+    /// Number of remaining accounts forwarded to the external program.
+    pub accounts: u8,
+}
+
+/// Arguments of the instructions of the external program.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct ExternalGuardArgs {
+    /// Configuration bytes of the guard.
+    pub config: Vec<u8>,
+    /// Mint arguments of the transaction.
+    pub mint_args: Vec<u8>,
+    /// Label of the group used for the mint, if any.
+    pub label: Option<String>,
+}
+
+impl ExternalGuard {
+    /// Invokes the specified instruction of the external program.
+    fn invoke_program(
+        &self,
+        ctx: &EvaluationContext,
+        instruction: &str,
+        mint_args: &[u8],
+        writable: bool,
+    ) -> Result<()> {
+        let index = ctx.indices["external_guard_index"];
+        let program = try_get_account_info(ctx.accounts.remaining, index)?;
+
+        let mut infos = vec![
+            ctx.accounts.candy_guard.to_account_info(),
+            ctx.accounts.candy_machine.to_account_info(),
+            ctx.accounts.payer.clone(),
+            ctx.accounts.minter.clone(),
+            ctx.accounts.asset.clone(),
+        ];
+
+        for offset in 1..=self.accounts as usize {
+            infos.push(try_get_account_info(ctx.accounts.remaining, index + offset)?.clone());
+        }
+
+        let accounts = infos
+            .iter()
+            .map(|info| AccountMeta {
+                pubkey: *info.key,
+                is_signer: false,
+                is_writable: writable && info.is_writable,
+            })
+            .collect();
+
+        let args = ExternalGuardArgs {
+            config: self.config.clone(),
+            mint_args: mint_args.to_vec(),
+            label: ctx.label.clone(),
+        };
+
+        let mut data = sighash(instruction).to_vec();
+        data.extend_from_slice(&args.try_to_vec()?);
+
+        infos.push(program.clone());
+
+        invoke(
+            &Instruction {
+                program_id: self.program_id,
+                accounts,
+                data,
+            },
+            &infos,
+        )?;
+
+        Ok(())
+    }
+}
+
+impl Guard for ExternalGuard {
+    fn size() -> usize {
+        32                        // program_id
+        + 4 + MAXIMUM_CONFIG_SIZE // config
+        + 1 // accounts
+    }
+
+    fn guard_type() -> GuardType {
+        GuardType::ExternalGuard
+    }
+
+    fn verify(data: &CandyGuardData) -> Result<()> {
+        let guard_sets = std::iter::once(&data.default)
+            .chain(data.groups.iter().flatten().map(|group| &group.guards));
+
+        for guard_set in guard_sets {
+            if let Some(external_guard) = &guard_set.external_guard {
+                // the candy guard cannot invoke itself
+                if external_guard.config.len() > MAXIMUM_CONFIG_SIZE
+                    || external_guard.program_id == crate::ID
+                {
+                    return err!(CandyGuardError::InvalidExternalGuardConfig);
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Condition for ExternalGuard {
+    fn validate<'info>(
+        &self,
+        ctx: &mut EvaluationContext,
+        _guard_set: &GuardSet,
+        _mint_args: &[u8],
+    ) -> Result<()> {
+        let index = ctx.account_cursor;
+        // validates that we received all required accounts
+        let program = try_get_account_info(ctx.accounts.remaining, index)?;
+        ctx.account_cursor += 1 + self.accounts as usize;
+        // validates the account information
+        assert_keys_equal(program.key, &self.program_id)?;
+
+        if !program.executable {
+            return err!(CandyGuardError::InvalidExternalGuardConfig);
+        }
+
+        ctx.indices.insert("external_guard_index", index);
+
+        Ok(())
+    }
+
+    fn pre_actions<'info>(
+        &self,
+        ctx: &mut EvaluationContext,
+        _guard_set: &GuardSet,
+        mint_args: &[u8],
+    ) -> Result<()> {
+        self.invoke_program(ctx, "validate_mint", mint_args, false)?;
+        self.invoke_program(ctx, "pre_mint", mint_args, true)
+    }
+}
+
+/// Returns the Anchor discriminator of an instruction.
+fn sighash(name: &str) -> [u8; 8] {
+    let preimage = format!("global:{name}");
+    let mut discriminator = [0u8; 8];
+    discriminator.copy_from_slice(&hash(preimage.as_bytes()).to_bytes()[..8]);
+    discriminator
+}
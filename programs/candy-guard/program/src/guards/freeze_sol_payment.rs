@@ -49,8 +49,8 @@ impl Guard for FreezeSolPayment {
         + 32 // destination
     }
 
-    fn mask() -> u64 {
-        GuardType::as_mask(GuardType::FreezeSolPayment)
+    fn guard_type() -> GuardType {
+        GuardType::FreezeSolPayment
     }
 
     /// Instructions to interact with the freeze feature:
@@ -178,6 +178,18 @@ impl Condition for FreezeSolPayment {
         Ok(())
     }
 
+    fn payment(
+        &self,
+        _ctx: &EvaluationContext,
+        _guard_set: &GuardSet,
+    ) -> Result<Option<MintPayment>> {
+        Ok(Some(MintPayment {
+            guard: GuardType::FreezeSolPayment,
+            mint: None,
+            amount: self.lamports,
+        }))
+    }
+
     fn pre_actions<'info>(
         &self,
         ctx: &mut EvaluationContext,
@@ -200,6 +212,12 @@ impl Condition for FreezeSolPayment {
             ],
         )?;
 
+        ctx.payments.push(MintPayment {
+            guard: GuardType::FreezeSolPayment,
+            mint: None,
+            amount: self.lamports,
+        });
+
         Ok(())
     }
 
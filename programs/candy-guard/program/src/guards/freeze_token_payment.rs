@@ -46,8 +46,8 @@ impl Guard for FreezeTokenPayment {
         + 32 // destination ata
     }
 
-    fn mask() -> u64 {
-        GuardType::as_mask(GuardType::FreezeTokenPayment)
+    fn guard_type() -> GuardType {
+        GuardType::FreezeTokenPayment
     }
 
     /// Instructions to interact with the freeze feature:
@@ -249,6 +249,18 @@ impl Condition for FreezeTokenPayment {
         Ok(())
     }
 
+    fn payment(
+        &self,
+        _ctx: &EvaluationContext,
+        _guard_set: &GuardSet,
+    ) -> Result<Option<MintPayment>> {
+        Ok(Some(MintPayment {
+            guard: GuardType::FreezeTokenPayment,
+            mint: Some(self.mint),
+            amount: self.amount,
+        }))
+    }
+
     fn pre_actions<'info>(
         &self,
         ctx: &mut EvaluationContext,
@@ -284,6 +296,12 @@ impl Condition for FreezeTokenPayment {
             ],
         )?;
 
+        ctx.payments.push(MintPayment {
+            guard: GuardType::FreezeTokenPayment,
+            mint: Some(self.mint),
+            amount: self.amount,
+        });
+
         Ok(())
     }
 
@@ -0,0 +1,254 @@
+use mpl_token_metadata::accounts::Metadata;
+use solana_program::{program::invoke_signed, system_instruction};
+
+use super::*;
+use crate::{
+    instructions::Route,
+    state::GuardType,
+    utils::{assert_keys_equal, assert_owned_by},
+};
+
+// Indices of the gating accounts recorded by the gate guards, in the order in which the
+// usage PDAs are expected.
+const GATE_INDICES: [&str; 2] = ["asset_gate_index", "nft_gate_index"];
+
+/// Guard that limits the number of mints each gating asset of the `AssetGate` and `NftGate`
+/// guards of the guard set can be used for, regardless of who holds it. The usage is
+/// recorded per asset (`AssetGate`) or NFT mint (`NftGate`), so transferring the gating
+/// asset to another wallet does not reset it.
+///
+/// The limit is a separate guard, instead of a setting of the gate guards, to keep the
+/// (fixed) size of the existing gate guards, which would otherwise break the deserialization
+/// of existing candy guard accounts, and to share the same limit between both gate guards.
+/// `TokenGate` is not supported since fungible tokens cannot be identified: a usage recorded
+/// per token account could be bypassed by moving the tokens to a new account.
+///
+/// List of accounts required:
+///
+///   0. `[writable]` Gate usage PDA for each enabled gate guard, in the order `AssetGate`
+///      and `NftGate`. The PDA is derived using the seed `["gate_usage", asset/mint key,
+///      candy guard pubkey, candy machine pubkey]`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct GateUsageLimit {
+    /// Maximum number of mints per gating asset.
+    pub limit: u16,
+}
+
+impl GateUsageLimit {
+    // Returns the keys of the gating accounts validated by the gate guards.
+    fn gating_keys(ctx: &EvaluationContext) -> Result<Vec<Pubkey>> {
+        let mut keys = Vec::with_capacity(GATE_INDICES.len());
+
+        for name in GATE_INDICES {
+            if let Some(index) = ctx.indices.get(name) {
+                let key = if name == "nft_gate_index" {
+                    let nft_metadata = try_get_account_info(ctx.accounts.remaining, index + 1)?;
+                    Metadata::try_from(nft_metadata)?.mint
+                } else {
+                    try_get_account_info(ctx.accounts.remaining, *index)?.key()
+                };
+
+                keys.push(key);
+            }
+        }
+
+        Ok(keys)
+    }
+}
+
+impl Guard for GateUsageLimit {
+    fn size() -> usize {
+        2 // limit
+    }
+
+    fn guard_type() -> GuardType {
+        GuardType::GateUsageLimit
+    }
+
+    fn verify(data: &CandyGuardData) -> Result<()> {
+        let guard_sets = match &data.groups {
+            Some(groups) => groups.iter().map(|group| data.group_set(group)).collect(),
+            None => vec![Box::new(data.default.clone())],
+        };
+
+        for guard_set in guard_sets {
+            if let Some(gate_usage_limit) = &guard_set.gate_usage_limit {
+                // requires at least one supported gate guard in the same guard set
+                let gated = guard_set.asset_gate.is_some() || guard_set.nft_gate.is_some();
+
+                if gate_usage_limit.limit == 0 || !gated {
+                    return err!(CandyGuardError::InvalidGateUsageLimit);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Instruction to close a gate usage PDA once the candy machine was withdrawn.
+    ///
+    /// List of accounts required:
+    ///
+    ///   0. `[writable]` Gate usage PDA (seeds `["gate_usage", asset/mint key, candy guard
+    ///      pubkey, candy machine pubkey]`).
+    ///   1. `[signer, writable]` Candy guard authority (receives the lamports).
+    ///   2. `[]` Asset or NFT mint account.
+    fn instruction<'c, 'info>(
+        ctx: &Context<'_, '_, 'c, 'info, Route<'info>>,
+        route_context: RouteContext<'info>,
+        data: Vec<u8>,
+    ) -> Result<()>
+    where
+        'c: 'info,
+    {
+        if data != CLOSE_PDA_INSTRUCTION {
+            return err!(CandyGuardError::InstructionNotFound);
+        }
+
+        msg!("Instruction: Close (GateUsageLimit guard)");
+
+        if route_context
+            .guard_set
+            .as_ref()
+            .and_then(|guard_set| guard_set.gate_usage_limit.as_ref())
+            .is_none()
+        {
+            return err!(CandyGuardError::GuardNotEnabled);
+        }
+
+        let gating_key = try_get_account_info(ctx.remaining_accounts, 2)?.key();
+        let candy_guard_key = &ctx.accounts.candy_guard.key();
+        let candy_machine_key = &ctx.accounts.candy_machine.key();
+
+        let seeds = [
+            GateUsage::PREFIX_SEED,
+            gating_key.as_ref(),
+            candy_guard_key.as_ref(),
+            candy_machine_key.as_ref(),
+        ];
+
+        // the usage is not associated with the minter
+        close_guard_pda(ctx, &route_context, &seeds, None, None)
+    }
+}
+
+impl Condition for GateUsageLimit {
+    fn validate<'info>(
+        &self,
+        ctx: &mut EvaluationContext,
+        _guard_set: &GuardSet,
+        _mint_args: &[u8],
+    ) -> Result<()> {
+        let gating_keys = Self::gating_keys(ctx)?;
+
+        if gating_keys.is_empty() {
+            return err!(CandyGuardError::InvalidGateUsageLimit);
+        }
+
+        ctx.indices.insert("gate_usage_index", ctx.account_cursor);
+
+        let candy_guard_key = ctx.accounts.candy_guard.key();
+        let candy_machine_key = ctx.accounts.candy_machine.key();
+
+        for gating_key in gating_keys {
+            let usage = try_get_account_info(ctx.accounts.remaining, ctx.account_cursor)?;
+            ctx.account_cursor += 1;
+
+            let seeds = [
+                GateUsage::PREFIX_SEED,
+                gating_key.as_ref(),
+                candy_guard_key.as_ref(),
+                candy_machine_key.as_ref(),
+            ];
+            let (pda, _) = Pubkey::find_program_address(&seeds, &crate::ID);
+
+            assert_keys_equal(usage.key, &pda)?;
+
+            if !usage.data_is_empty() {
+                // check the owner of the account
+                assert_owned_by(usage, &crate::ID)?;
+
+                let gate_usage = GateUsage::try_from_slice(&usage.data.borrow())?;
+
+                if gate_usage.count >= self.limit {
+                    msg!("Gating account {} already used", gating_key);
+                    return err!(CandyGuardError::GateUsageLimitReached);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn pre_actions<'info>(
+        &self,
+        ctx: &mut EvaluationContext,
+        _guard_set: &GuardSet,
+        _mint_args: &[u8],
+    ) -> Result<()> {
+        let index = ctx.indices["gate_usage_index"];
+        let candy_guard_key = ctx.accounts.candy_guard.key();
+        let candy_machine_key = ctx.accounts.candy_machine.key();
+
+        for (offset, gating_key) in Self::gating_keys(ctx)?.iter().enumerate() {
+            let usage = try_get_account_info(ctx.accounts.remaining, index + offset)?;
+
+            let mut gate_usage = if usage.data_is_empty() {
+                let seeds = [
+                    GateUsage::PREFIX_SEED,
+                    gating_key.as_ref(),
+                    candy_guard_key.as_ref(),
+                    candy_machine_key.as_ref(),
+                ];
+                let (pda, bump) = Pubkey::find_program_address(&seeds, &crate::ID);
+
+                let rent = Rent::get()?;
+                let signer = [seeds[0], seeds[1], seeds[2], seeds[3], &[bump]];
+
+                invoke_signed(
+                    &system_instruction::create_account(
+                        ctx.accounts.payer.key,
+                        &pda,
+                        rent.minimum_balance(GateUsage::SIZE),
+                        GateUsage::SIZE as u64,
+                        &crate::ID,
+                    ),
+                    &[
+                        ctx.accounts.payer.to_account_info(),
+                        usage.to_account_info(),
+                    ],
+                    &[&signer],
+                )?;
+
+                GateUsage { count: 0 }
+            } else {
+                assert_owned_by(usage, &crate::ID)?;
+                GateUsage::try_from_slice(&usage.data.borrow())?
+            };
+
+            gate_usage.count += 1;
+
+            let mut account_data = usage.try_borrow_mut_data()?;
+            // saves the changes back to the pda
+            let data = &mut gate_usage.try_to_vec()?;
+            account_data[0..data.len()].copy_from_slice(data);
+        }
+
+        Ok(())
+    }
+}
+
+/// PDA to track the number of mints of a gating asset.
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct GateUsage {
+    /// Number of mints.
+    pub count: u16,
+}
+
+impl GateUsage {
+    /// Prefix used as seed.
+    pub const PREFIX_SEED: &'static [u8] = b"gate_usage";
+
+    /// Size of the account data.
+    pub const SIZE: usize = 2; // count
+}
@@ -32,8 +32,8 @@ impl Guard for Gatekeeper {
         + 1 // expire on use
     }
 
-    fn mask() -> u64 {
-        GuardType::as_mask(GuardType::Gatekeeper)
+    fn guard_type() -> GuardType {
+        GuardType::Gatekeeper
     }
 }
 
@@ -0,0 +1,342 @@
+use mpl_token_metadata::accounts::Metadata;
+use solana_program::{program::invoke_signed, system_instruction};
+
+use super::*;
+use crate::{instructions::Route, state::GuardType, utils::assert_keys_equal};
+
+// Maximum percentage (in basis points).
+const MAXIMUM_PERCENTAGE: u16 = 10_000;
+
+/// Guard that applies a discount to the payment guards (`SolPayment`, `TokenPayment` and
+/// `Token2022Payment`) of the guard set when the minter holds an asset from the specified
+/// collection, either an mpl-core asset or a legacy NFT with a verified collection.
+///
+/// The discount is only applied when the mint arguments include a borsh `bool` set to
+/// `true`; otherwise, no accounts are required and the full price is charged. A fixed
+/// discount is applied to the amount of each payment guard, in the units of that guard.
+/// The guard set must include one of the supported payment guards and none of the other
+/// payment guards (e.g., `DutchAuction` or `SolPaymentSplit`), which do not apply discounts.
+/// Since the discount is only known once this guard validates, the balance checks of the
+/// payment guards use the full price.
+///
+/// List of accounts required (when the discount is claimed):
+///
+///   0. `[]` Account of the Asset or token account of the NFT.
+///   1. `[optional]` Metadata account of the NFT (only for legacy NFTs).
+///   2. `[writable, optional]` Discount usage PDA (only when `single_use` is set). The PDA
+///      is derived using the seed `["holder_discount", asset/mint key, candy guard pubkey,
+///      candy machine pubkey]`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct HolderDiscount {
+    /// Collection of the gating asset.
+    pub required_collection: Pubkey,
+    /// Discount applied to the payment guards.
+    pub discount: Discount,
+    /// Whether each gating asset can only be used once for the discount.
+    pub single_use: bool,
+}
+
+/// Discount applied to a payment.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Discount {
+    /// Percentage of the amount (in basis points).
+    Percentage(u16),
+    /// Fixed amount deducted.
+    Amount(u64),
+}
+
+impl Discount {
+    /// Returns the amount after applying the discount.
+    pub fn apply(&self, amount: u64) -> u64 {
+        match self {
+            Discount::Percentage(basis_points) => {
+                let discount =
+                    (amount as u128 * *basis_points as u128) / MAXIMUM_PERCENTAGE as u128;
+                amount.saturating_sub(discount as u64)
+            }
+            Discount::Amount(discount) => amount.saturating_sub(*discount),
+        }
+    }
+}
+
+impl HolderDiscount {
+    // Returns whether the discount is claimed in the mint arguments.
+    fn claimed(ctx: &mut EvaluationContext, mint_args: &[u8]) -> Result<bool> {
+        let remaining = mint_args.get(ctx.args_cursor..).unwrap_or_default();
+
+        if remaining.is_empty() {
+            return Ok(false);
+        }
+
+        let mut slice = remaining;
+        let claimed = bool::deserialize(&mut slice)?;
+        ctx.args_cursor += remaining.len() - slice.len();
+
+        Ok(claimed)
+    }
+
+    // Returns the key of the gating asset (or NFT mint) and the number of accounts used by
+    // it, starting at the specified index of the remaining accounts.
+    fn asset_key(ctx: &EvaluationContext, index: usize) -> Result<(Pubkey, usize)> {
+        let asset_account = try_get_account_info(ctx.accounts.remaining, index)?;
+
+        if cmp_pubkeys(asset_account.owner, &mpl_core::ID) {
+            Ok((asset_account.key(), 1))
+        } else {
+            let nft_metadata = try_get_account_info(ctx.accounts.remaining, index + 1)?;
+            let metadata = Metadata::try_from(nft_metadata)?;
+
+            Ok((metadata.mint, 2))
+        }
+    }
+
+    // Validates that the minter holds the gating asset (or NFT), returning its key.
+    fn verify_holder(&self, ctx: &mut EvaluationContext) -> Result<Pubkey> {
+        let index = ctx.account_cursor;
+        let asset_account = try_get_account_info(ctx.accounts.remaining, index)?;
+
+        if cmp_pubkeys(asset_account.owner, &mpl_core::ID) {
+            AssetGate::verify_collection(
+                asset_account,
+                &self.required_collection,
+                ctx.accounts.minter.key,
+            )?;
+        } else {
+            let nft_metadata = try_get_account_info(ctx.accounts.remaining, index + 1)?;
+
+            NftGate::verify_collection(
+                asset_account,
+                nft_metadata,
+                &self.required_collection,
+                ctx.accounts.minter.key,
+            )?;
+        }
+
+        let (asset_key, count) = Self::asset_key(ctx, index)?;
+        ctx.indices.insert("holder_discount_index", index);
+        ctx.account_cursor += count;
+
+        Ok(asset_key)
+    }
+}
+
+impl Guard for HolderDiscount {
+    fn size() -> usize {
+        32      // required_collection
+        + 1 + 8 // discount
+        + 1 // single_use
+    }
+
+    fn guard_type() -> GuardType {
+        GuardType::HolderDiscount
+    }
+
+    fn verify(data: &CandyGuardData) -> Result<()> {
+        let guard_sets = match &data.groups {
+            Some(groups) => groups.iter().map(|group| data.group_set(group)).collect(),
+            None => vec![Box::new(data.default.clone())],
+        };
+
+        for guard_set in guard_sets {
+            if let Some(holder_discount) = &guard_set.holder_discount {
+                let valid = match holder_discount.discount {
+                    Discount::Percentage(basis_points) => {
+                        basis_points > 0 && basis_points <= MAXIMUM_PERCENTAGE
+                    }
+                    Discount::Amount(amount) => amount > 0,
+                };
+
+                // the discount must apply to a payment guard of the guard set
+                let supported = guard_set.sol_payment.is_some()
+                    || guard_set.token_payment.is_some()
+                    || guard_set.token2022_payment.is_some();
+                // and no other payment guard would charge the full price
+                let unsupported = guard_set.dutch_auction.is_some()
+                    || guard_set.bonding_curve.is_some()
+                    || guard_set.sol_payment_split.is_some()
+                    || guard_set.token_payment_split.is_some()
+                    || guard_set.freeze_sol_payment.is_some()
+                    || guard_set.freeze_token_payment.is_some()
+                    || guard_set.sol_fixed_fee.is_some()
+                    || guard_set.nft_payment.is_some()
+                    || guard_set.asset_payment.is_some()
+                    || guard_set.asset_payment_multi.is_some()
+                    || guard_set.signed_voucher.is_some()
+                    || guard_set.allow_list_allotment.is_some();
+
+                if !valid || !supported || unsupported {
+                    return err!(CandyGuardError::InvalidHolderDiscount);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Instruction to close a discount usage PDA once the candy machine was withdrawn.
+    ///
+    /// List of accounts required:
+    ///
+    ///   0. `[writable]` Discount usage PDA (seeds `["holder_discount", asset/mint key,
+    ///      candy guard pubkey, candy machine pubkey]`).
+    ///   1. `[signer, writable]` Candy guard authority (receives the lamports).
+    ///   2. `[]` Asset account (or NFT mint account).
+    fn instruction<'c, 'info>(
+        ctx: &Context<'_, '_, 'c, 'info, Route<'info>>,
+        route_context: RouteContext<'info>,
+        data: Vec<u8>,
+    ) -> Result<()>
+    where
+        'c: 'info,
+    {
+        if data != CLOSE_PDA_INSTRUCTION {
+            return err!(CandyGuardError::InstructionNotFound);
+        }
+
+        msg!("Instruction: Close (HolderDiscount guard)");
+
+        if route_context
+            .guard_set
+            .as_ref()
+            .and_then(|guard_set| guard_set.holder_discount.as_ref())
+            .is_none()
+        {
+            return err!(CandyGuardError::GuardNotEnabled);
+        }
+
+        let asset_key = try_get_account_info(ctx.remaining_accounts, 2)?.key();
+        let candy_guard_key = &ctx.accounts.candy_guard.key();
+        let candy_machine_key = &ctx.accounts.candy_machine.key();
+
+        let seeds = [
+            DiscountUsage::PREFIX_SEED,
+            asset_key.as_ref(),
+            candy_guard_key.as_ref(),
+            candy_machine_key.as_ref(),
+        ];
+
+        // the usage is not associated with the minter
+        close_guard_pda(ctx, &route_context, &seeds, None, None)
+    }
+}
+
+impl Condition for HolderDiscount {
+    fn validate<'info>(
+        &self,
+        ctx: &mut EvaluationContext,
+        _guard_set: &GuardSet,
+        mint_args: &[u8],
+    ) -> Result<()> {
+        if !Self::claimed(ctx, mint_args)? {
+            return Ok(());
+        }
+
+        let asset_key = self.verify_holder(ctx)?;
+
+        if self.single_use {
+            let usage = try_get_account_info(ctx.accounts.remaining, ctx.account_cursor)?;
+            ctx.account_cursor += 1;
+
+            let candy_guard_key = &ctx.accounts.candy_guard.key();
+            let candy_machine_key = &ctx.accounts.candy_machine.key();
+
+            let seeds = [
+                DiscountUsage::PREFIX_SEED,
+                asset_key.as_ref(),
+                candy_guard_key.as_ref(),
+                candy_machine_key.as_ref(),
+            ];
+            let (pda, _) = Pubkey::find_program_address(&seeds, &crate::ID);
+
+            assert_keys_equal(usage.key, &pda)?;
+
+            if !usage.data_is_empty() {
+                return err!(CandyGuardError::DiscountAlreadyUsed);
+            }
+        }
+
+        ctx.discount = Some(self.discount);
+
+        Ok(())
+    }
+
+    fn pre_actions<'info>(
+        &self,
+        ctx: &mut EvaluationContext,
+        _guard_set: &GuardSet,
+        _mint_args: &[u8],
+    ) -> Result<()> {
+        if !self.single_use {
+            return Ok(());
+        }
+
+        let Some(index) = ctx.indices.get("holder_discount_index").copied() else {
+            // the discount was not claimed
+            return Ok(());
+        };
+
+        let (asset_key, count) = Self::asset_key(ctx, index)?;
+        let usage = try_get_account_info(ctx.accounts.remaining, index + count)?;
+
+        let candy_guard_key = &ctx.accounts.candy_guard.key();
+        let candy_machine_key = &ctx.accounts.candy_machine.key();
+
+        let seeds = [
+            DiscountUsage::PREFIX_SEED,
+            asset_key.as_ref(),
+            candy_guard_key.as_ref(),
+            candy_machine_key.as_ref(),
+        ];
+        let (pda, bump) = Pubkey::find_program_address(&seeds, &crate::ID);
+        assert_keys_equal(usage.key, &pda)?;
+
+        let rent = Rent::get()?;
+        let signer = [
+            DiscountUsage::PREFIX_SEED,
+            asset_key.as_ref(),
+            candy_guard_key.as_ref(),
+            candy_machine_key.as_ref(),
+            &[bump],
+        ];
+
+        invoke_signed(
+            &system_instruction::create_account(
+                ctx.accounts.payer.key,
+                &pda,
+                rent.minimum_balance(DiscountUsage::SIZE),
+                DiscountUsage::SIZE as u64,
+                &crate::ID,
+            ),
+            &[
+                ctx.accounts.payer.to_account_info(),
+                usage.to_account_info(),
+            ],
+            &[&signer],
+        )?;
+
+        let mut account_data = usage.try_borrow_mut_data()?;
+        let discount_usage = DiscountUsage {
+            minter: ctx.accounts.minter.key(),
+        };
+        // saves the minter that used the discount to the pda
+        let data = &mut discount_usage.try_to_vec()?;
+        account_data[0..data.len()].copy_from_slice(data);
+
+        Ok(())
+    }
+}
+
+/// PDA to record that a gating asset was used for the discount.
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct DiscountUsage {
+    /// Address that used the discount.
+    pub minter: Pubkey,
+}
+
+impl DiscountUsage {
+    /// Prefix used as seed.
+    pub const PREFIX_SEED: &'static [u8] = b"holder_discount";
+
+    /// Size of the account data.
+    pub const SIZE: usize = 32;
+}
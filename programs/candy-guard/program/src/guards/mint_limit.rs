@@ -4,6 +4,7 @@ use solana_program::{program::invoke_signed, system_instruction};
 
 use super::*;
 use crate::{
+    instructions::Route,
     state::GuardType,
     utils::{assert_keys_equal, assert_owned_by},
 };
@@ -29,8 +30,56 @@ impl Guard for MintLimit {
         + 2 // limit
     }
 
-    fn mask() -> u64 {
-        GuardType::as_mask(GuardType::MintLimit)
+    fn guard_type() -> GuardType {
+        GuardType::MintLimit
+    }
+
+    /// Instruction to close the mint counter PDA once the mint is over.
+    ///
+    /// List of accounts required:
+    ///
+    ///   0. `[writable]` Mint counter PDA (seeds `["mint_limit", mint guard id, minter key,
+    ///      candy guard pubkey, candy machine pubkey]`).
+    ///   1. `[signer, writable]` Candy guard authority or minter (receives the lamports).
+    ///   2. `[optional]` Minter account (required when the authority closes the PDA).
+    fn instruction<'c, 'info>(
+        ctx: &Context<'_, '_, 'c, 'info, Route<'info>>,
+        route_context: RouteContext<'info>,
+        data: Vec<u8>,
+    ) -> Result<()>
+    where
+        'c: 'info,
+    {
+        if data != CLOSE_PDA_INSTRUCTION {
+            return err!(CandyGuardError::InstructionNotFound);
+        }
+
+        msg!("Instruction: Close (MintLimit guard)");
+
+        let mint_limit = route_context
+            .guard_set
+            .as_ref()
+            .and_then(|guard_set| guard_set.mint_limit.as_ref())
+            .ok_or(CandyGuardError::GuardNotEnabled)?;
+
+        let minter = if let Some(minter) = get_account_info(ctx.remaining_accounts, 2) {
+            minter.key()
+        } else {
+            try_get_account_info(ctx.remaining_accounts, 1)?.key()
+        };
+
+        let candy_guard_key = &ctx.accounts.candy_guard.key();
+        let candy_machine_key = &ctx.accounts.candy_machine.key();
+
+        let seeds = [
+            MintCounter::PREFIX_SEED,
+            &[mint_limit.id],
+            minter.as_ref(),
+            candy_guard_key.as_ref(),
+            candy_machine_key.as_ref(),
+        ];
+
+        close_guard_pda(ctx, &route_context, &seeds, Some(&minter), None)
     }
 
     fn verify(data: &CandyGuardData) -> Result<()> {
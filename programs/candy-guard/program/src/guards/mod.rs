@@ -9,72 +9,114 @@ use mpl_core::{
 pub use crate::{errors::CandyGuardError, state::GuardSet};
 use crate::{
     instructions::{MintAccounts, Route, RouteContext},
-    state::CandyGuardData,
-    utils::assert_keys_equal,
+    state::{CandyGuardData, Features, GuardType},
+    utils::{assert_keys_equal, assert_owned_by, cmp_pubkeys},
 };
 
 pub use address_gate::AddressGate;
 pub use allocation::Allocation;
 pub use allow_list::AllowList;
+pub use allow_list_allotment::{
+    AllotmentEntry, AllotmentProof, AllotmentProofArgs, AllowListAllotment,
+};
+pub use any_of::AnyOf;
 pub use asset_burn::AssetBurn;
 pub use asset_burn_multi::AssetBurnMulti;
 pub use asset_gate::AssetGate;
 pub use asset_mint_limit::AssetMintLimit;
 pub use asset_payment::AssetPayment;
 pub use asset_payment_multi::AssetPaymentMulti;
+pub use asset_trait_gate::{AssetTraitGate, TraitRequirement};
+pub use auto_group::AutoGroup;
+pub use bonding_curve::{BondingCurve, BondingCurveType};
 pub use bot_tax::BotTax;
+pub use cooldown::{Cooldown, CooldownTracker};
+pub use dutch_auction::{AuctionBid, AuctionEscrow, DutchAuction, DutchAuctionInstruction};
 pub use edition::Edition;
 pub use end_date::EndDate;
+pub use end_slot::EndSlot;
+pub use external_guard::{ExternalGuard, ExternalGuardArgs};
 pub use freeze_sol_payment::{FreezeEscrow, FreezeInstruction, FreezeSolPayment};
 pub use freeze_token_payment::FreezeTokenPayment;
+pub use gate_usage_limit::{GateUsage, GateUsageLimit};
 pub use gatekeeper::Gatekeeper;
+pub use holder_discount::{Discount, DiscountUsage, HolderDiscount};
 pub use mint_limit::{MintCounter, MintLimit};
 pub use nft_burn::NftBurn;
 pub use nft_gate::NftGate;
 pub use nft_mint_limit::NftMintLimit;
 pub use nft_payment::NftPayment;
+pub use phase_schedule::{Phase, PhaseSchedule, PhaseScheduleStatus};
 pub use program_gate::ProgramGate;
+pub use rate_limit::{RateLimit, RateLimitTracker};
+pub use receipt::{MintPayment, MintReceipt, Receipt};
+pub use recurring_window::RecurringWindow;
 pub use redeemed_amount::RedeemedAmount;
+pub use signed_voucher::{SignedVoucher, Voucher, VoucherNonce};
 pub use sol_fixed_fee::SolFixedFee;
+pub use sol_gate::{SolGate, SolGateRegistration};
 pub use sol_payment::SolPayment;
+pub use sol_payment_split::{SolPaymentSplit, SplitDestination};
 pub use start_date::StartDate;
+pub use start_slot::StartSlot;
 pub use third_party_signer::ThirdPartySigner;
 pub use token2022_payment::Token2022Payment;
 pub use token_burn::TokenBurn;
 pub use token_gate::TokenGate;
 pub use token_payment::TokenPayment;
+pub use token_payment_split::TokenPaymentSplit;
 pub use vanity_mint::VanityMint;
 
 mod address_gate;
 mod allocation;
 mod allow_list;
+mod allow_list_allotment;
+mod any_of;
 mod asset_burn;
 mod asset_burn_multi;
 mod asset_gate;
 mod asset_mint_limit;
 mod asset_payment;
 mod asset_payment_multi;
+mod asset_trait_gate;
+mod auto_group;
+mod bonding_curve;
 mod bot_tax;
+mod cooldown;
+mod dutch_auction;
 mod edition;
 mod end_date;
+mod end_slot;
+mod external_guard;
 mod freeze_sol_payment;
 mod freeze_token_payment;
+mod gate_usage_limit;
 mod gatekeeper;
+mod holder_discount;
 mod mint_limit;
 mod nft_burn;
 mod nft_gate;
 mod nft_mint_limit;
 mod nft_payment;
+mod phase_schedule;
 mod program_gate;
+mod rate_limit;
+mod receipt;
+mod recurring_window;
 mod redeemed_amount;
+mod signed_voucher;
 mod sol_fixed_fee;
+mod sol_gate;
 mod sol_payment;
+mod sol_payment_split;
 mod start_date;
+mod start_slot;
 mod third_party_signer;
 mod token2022_payment;
 mod token_burn;
 mod token_gate;
 mod token_payment;
+mod token_payment_split;
 mod vanity_mint;
 
 pub trait Condition {
@@ -94,6 +136,18 @@ pub trait Condition {
         mint_args: &[u8],
     ) -> Result<()>;
 
+    /// Returns the amount charged by the guard on a mint, if the guard charges a payment.
+    ///
+    /// This function is used to report prices without executing the mint, therefore it
+    /// should not perform any modification to accounts.
+    fn payment(
+        &self,
+        _ctx: &EvaluationContext,
+        _guard_set: &GuardSet,
+    ) -> Result<Option<MintPayment>> {
+        Ok(None)
+    }
+
     /// Perform the action associated with the guard before the CPI `mint` instruction.
     ///
     /// This function only gets called when all guards have been successfuly validated.
@@ -128,8 +182,8 @@ pub trait Guard: Condition + AnchorSerialize + AnchorDeserialize {
     /// Returns the number of bytes used by the guard configuration.
     fn size() -> usize;
 
-    /// Returns the feature mask for the guard.
-    fn mask() -> u64;
+    /// Returns the type of the guard, which determines its feature flag.
+    fn guard_type() -> GuardType;
 
     /// Executes an instruction. This function is called from the `route` instruction
     /// handler.
@@ -145,18 +199,18 @@ pub trait Guard: Condition + AnchorSerialize + AnchorDeserialize {
     }
 
     /// Returns whether the guards is enabled or not on the specified features.
-    fn is_enabled(features: u64) -> bool {
-        features & Self::mask() > 0
+    fn is_enabled(features: &Features) -> bool {
+        features.is_enabled(Self::guard_type().index())
     }
 
     /// Enables the guard on the specified `features` value.
-    fn enable(features: u64) -> u64 {
-        features | Self::mask()
+    fn enable(features: &mut Features) {
+        features.enable(Self::guard_type().index());
     }
 
     /// Disables the guard on the specified `features` value.
-    fn disable(features: u64) -> u64 {
-        features & !Self::mask()
+    fn disable(features: &mut Features) {
+        features.disable(Self::guard_type().index());
     }
 
     /// Serializes the guard into the specified data array.
@@ -196,7 +250,8 @@ pub struct EvaluationContext<'b, 'c, 'info> {
     pub account_cursor: usize,
 
     /// The cursor for the remaining bytes on the mint args. When a guard "consumes" one
-    /// argument, it should increment the number of bytes read.
+    /// argument, it should increment the number of bytes read. Guards read their arguments
+    /// in the order they are evaluated, each one from the end of the previous guard arguments.
     pub args_cursor: usize,
 
     /// Convenience mapping of remaining account indices.
@@ -204,6 +259,27 @@ pub struct EvaluationContext<'b, 'c, 'info> {
 
     /// Any plugins to be used when minting
     pub plugins: Vec<PluginAuthorityPair>,
+
+    /// Label of the group used for the mint, if any.
+    pub label: Option<String>,
+
+    /// Index of the config line minted, as reported by the candy machine. This is
+    /// only available in the `post_actions` step.
+    pub config_index: Option<u32>,
+
+    /// Amounts charged by payment guards in their `pre_actions` step.
+    pub payments: Vec<MintPayment>,
+
+    /// Discount applied to the payment guards, set by the `HolderDiscount` guard.
+    pub discount: Option<Discount>,
+}
+
+impl EvaluationContext<'_, '_, '_> {
+    /// Returns the amount to charge after applying the discount, if any.
+    pub fn discounted_amount(&self, amount: u64) -> u64 {
+        self.discount
+            .map_or(amount, |discount| discount.apply(amount))
+    }
 }
 
 /// Utility function to try to get the account from the remaining accounts
@@ -226,6 +302,87 @@ pub fn get_account_info<T>(remaining_accounts: &[T], index: usize) -> Option<&T>
     }
 }
 
+/// Route instruction data to close the PDA created by a guard.
+pub const CLOSE_PDA_INSTRUCTION: &[u8] = &[u8::MAX];
+
+/// Utility function to close the PDA created by a guard (derived from the specified seeds).
+///
+/// The `owner` (the address the PDA was created for, if any) can close its own PDA once the
+/// mint is over: the candy machine is sold out or was withdrawn. Since the rent of the PDA
+/// is usually paid by the minter, the candy guard authority (when it is not the owner) can
+/// only close PDAs once the candy machine was withdrawn.
+///
+/// The lamports of the PDA are transferred to the `payer` of its rent when the PDA records
+/// it; otherwise they are transferred to the signer of the instruction. PDAs that do not
+/// record their payer are created for the minter (the owner), which pays their rent unless
+/// a different payer is used for the mint, or are shared by all minters.
+///
+/// List of accounts required:
+///
+///   0. `[writable]` PDA to close.
+///   1. `[signer, writable]` Candy guard authority or owner of the PDA.
+///   2. `[writable]` Payer of the rent of the PDA (only when recorded by the PDA).
+pub fn close_guard_pda<'info>(
+    ctx: &Context<'_, '_, '_, 'info, Route<'info>>,
+    route_context: &RouteContext<'info>,
+    seeds: &[&[u8]],
+    owner: Option<&Pubkey>,
+    payer: Option<&Pubkey>,
+) -> Result<()> {
+    let pda = try_get_account_info(ctx.remaining_accounts, 0)?;
+    let authority = try_get_account_info(ctx.remaining_accounts, 1)?;
+
+    let candy_guard = route_context
+        .candy_guard
+        .as_ref()
+        .ok_or(CandyGuardError::Uninitialized)?;
+
+    let is_owner = owner.is_some_and(|owner| cmp_pubkeys(authority.key, owner));
+    let is_authority = cmp_pubkeys(authority.key, &candy_guard.authority);
+
+    if !((is_owner || is_authority) && authority.is_signer) {
+        return err!(CandyGuardError::MissingRequiredSignature);
+    }
+
+    // counters cannot be reset while the mint is in progress
+    if let Some(candy_machine) = &route_context.candy_machine {
+        if !cmp_pubkeys(&candy_machine.mint_authority, &candy_guard.key()) {
+            return err!(CandyGuardError::InvalidMintAuthority);
+        }
+
+        // the authority can only close PDAs of a withdrawn candy machine
+        if !is_owner || candy_machine.items_redeemed < candy_machine.data.items_available {
+            return err!(CandyGuardError::MintNotOver);
+        }
+    }
+
+    let (address, _) = Pubkey::find_program_address(seeds, &crate::ID);
+    assert_keys_equal(pda.key, &address)?;
+    assert_owned_by(pda, &crate::ID)?;
+
+    // rent goes back to whoever paid for it, when known
+    let recipient = if let Some(payer) = payer {
+        let recipient = try_get_account_info(ctx.remaining_accounts, 2)?;
+        assert_keys_equal(recipient.key, payer)?;
+        recipient
+    } else {
+        authority
+    };
+
+    msg!("Closing PDA {} ({} lamports)", pda.key, pda.lamports());
+
+    **recipient.try_borrow_mut_lamports()? = recipient
+        .lamports()
+        .checked_add(pda.lamports())
+        .ok_or(CandyGuardError::NumericalOverflowError)?;
+    **pda.try_borrow_mut_lamports()? = 0;
+
+    pda.assign(&solana_program::system_program::ID);
+    pda.realloc(0, false)?;
+
+    Ok(())
+}
+
 pub fn verify_core_collection(asset: &AccountInfo, collection: &Pubkey) -> Result<()> {
     let asset = BaseAssetV1::try_from(asset)?;
 
@@ -30,8 +30,8 @@ impl Guard for NftBurn {
         32 // required_collection
     }
 
-    fn mask() -> u64 {
-        GuardType::as_mask(GuardType::NftBurn)
+    fn guard_type() -> GuardType {
+        GuardType::NftBurn
     }
 }
 
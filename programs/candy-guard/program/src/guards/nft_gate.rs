@@ -23,8 +23,8 @@ impl Guard for NftGate {
         32 // required_collection
     }
 
-    fn mask() -> u64 {
-        GuardType::as_mask(GuardType::NftGate)
+    fn guard_type() -> GuardType {
+        GuardType::NftGate
     }
 }
 
@@ -40,6 +40,8 @@ impl Condition for NftGate {
         let nft_account = try_get_account_info(ctx.accounts.remaining, index)?;
         let nft_metadata = try_get_account_info(ctx.accounts.remaining, index + 1)?;
         ctx.account_cursor += 2;
+        // records the gating account for the gate usage limit
+        ctx.indices.insert("nft_gate_index", index);
 
         Self::verify_collection(
             nft_account,
@@ -5,6 +5,7 @@ use solana_program::{program::invoke_signed, system_instruction};
 
 use super::*;
 use crate::{
+    instructions::Route,
     state::GuardType,
     utils::{assert_keys_equal, assert_owned_by},
 };
@@ -35,8 +36,52 @@ impl Guard for NftMintLimit {
         + 32 // required_collection
     }
 
-    fn mask() -> u64 {
-        GuardType::as_mask(GuardType::NftMintLimit)
+    fn guard_type() -> GuardType {
+        GuardType::NftMintLimit
+    }
+
+    /// Instruction to close the mint counter PDA once the candy machine was withdrawn.
+    ///
+    /// List of accounts required:
+    ///
+    ///   0. `[writable]` Mint counter PDA (seeds `["nft_mint_limit", nft mint guard id, mint key,
+    ///      candy guard pubkey, candy machine pubkey]`).
+    ///   1. `[signer, writable]` Candy guard authority (receives the lamports).
+    ///   2. `[]` NFT mint account.
+    fn instruction<'c, 'info>(
+        ctx: &Context<'_, '_, 'c, 'info, Route<'info>>,
+        route_context: RouteContext<'info>,
+        data: Vec<u8>,
+    ) -> Result<()>
+    where
+        'c: 'info,
+    {
+        if data != CLOSE_PDA_INSTRUCTION {
+            return err!(CandyGuardError::InstructionNotFound);
+        }
+
+        msg!("Instruction: Close (NftMintLimit guard)");
+
+        let nft_mint_limit = route_context
+            .guard_set
+            .as_ref()
+            .and_then(|guard_set| guard_set.nft_mint_limit.as_ref())
+            .ok_or(CandyGuardError::GuardNotEnabled)?;
+
+        let mint_key = try_get_account_info(ctx.remaining_accounts, 2)?.key();
+        let candy_guard_key = &ctx.accounts.candy_guard.key();
+        let candy_machine_key = &ctx.accounts.candy_machine.key();
+
+        let seeds = [
+            NftMintCounter::PREFIX_SEED,
+            &[nft_mint_limit.id],
+            mint_key.as_ref(),
+            candy_guard_key.as_ref(),
+            candy_machine_key.as_ref(),
+        ];
+
+        // the counter is not associated with the minter
+        close_guard_pda(ctx, &route_context, &seeds, None, None)
     }
 
     fn verify(data: &CandyGuardData) -> Result<()> {
@@ -38,8 +38,8 @@ impl Guard for NftPayment {
         + 32 // destination
     }
 
-    fn mask() -> u64 {
-        GuardType::as_mask(GuardType::NftPayment)
+    fn guard_type() -> GuardType {
+        GuardType::NftPayment
     }
 }
 
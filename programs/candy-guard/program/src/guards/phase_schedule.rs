@@ -0,0 +1,188 @@
+use solana_program::program::set_return_data;
+
+use super::*;
+
+use crate::{
+    errors::CandyGuardError,
+    instructions::Route,
+    state::{GuardType, MAX_LABEL_SIZE},
+};
+
+// Maximum number of phases in the schedule.
+const MAXIMUM_PHASES: usize = 5;
+
+/// Guard that specifies a schedule of phases, each one mapping a time range to a group.
+/// When a mint transaction does not specify a label, the group of the active phase is
+/// used; groups that are part of the schedule can only be used while one of their phases
+/// is active.
+///
+/// Phases must be ordered by their start date and must not overlap. Groups that are not
+/// part of the schedule are not affected by it.
+///
+/// This guard can only be enabled on the default guard set.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct PhaseSchedule {
+    pub phases: Vec<Phase>,
+}
+
+/// A time range during which a group is active.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct Phase {
+    /// Label of the group.
+    pub label: String,
+    /// Start of the phase (inclusive, unix timestamp).
+    pub start: i64,
+    /// End of the phase (exclusive, unix timestamp).
+    pub end: i64,
+}
+
+impl Phase {
+    /// Returns whether the phase is active at the specified timestamp.
+    pub fn is_active(&self, timestamp: i64) -> bool {
+        self.start <= timestamp && timestamp < self.end
+    }
+}
+
+/// Status of the phase schedule, returned by the route instruction.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct PhaseScheduleStatus {
+    /// Timestamp used to evaluate the schedule.
+    pub timestamp: i64,
+    /// Index of the active phase, if any.
+    pub active: Option<u8>,
+    /// Phases of the schedule.
+    pub phases: Vec<Phase>,
+}
+
+impl PhaseSchedule {
+    /// Returns the phase active at the specified timestamp, if any.
+    pub fn active_phase(&self, timestamp: i64) -> Option<&Phase> {
+        self.phases.iter().find(|phase| phase.is_active(timestamp))
+    }
+
+    fn verify_config(&self, data: &CandyGuardData) -> Result<()> {
+        if self.phases.is_empty() || self.phases.len() > MAXIMUM_PHASES {
+            return err!(CandyGuardError::InvalidPhaseSchedule);
+        }
+
+        for (index, phase) in self.phases.iter().enumerate() {
+            if phase.start >= phase.end || phase.label.len() > MAX_LABEL_SIZE {
+                msg!("Invalid phase: {:?}", phase);
+                return err!(CandyGuardError::InvalidPhaseSchedule);
+            }
+
+            // phases must be ordered and must not overlap
+            if index > 0 && self.phases[index - 1].end > phase.start {
+                msg!("Overlapping phase: {:?}", phase);
+                return err!(CandyGuardError::InvalidPhaseSchedule);
+            }
+
+            let group_found = data
+                .groups
+                .iter()
+                .flatten()
+                .any(|group| group.name() == phase.label);
+
+            if !group_found {
+                msg!("Phase group not found: {}", phase.label);
+                return err!(CandyGuardError::InvalidPhaseSchedule);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Guard for PhaseSchedule {
+    fn size() -> usize {
+        4 // phases
+        + MAXIMUM_PHASES * (4 + MAX_LABEL_SIZE + 8 + 8) // label + start + end
+    }
+
+    fn guard_type() -> GuardType {
+        GuardType::PhaseSchedule
+    }
+
+    fn verify(data: &CandyGuardData) -> Result<()> {
+        if let Some(groups) = &data.groups {
+            for group in groups {
+                if group.guards.phase_schedule.is_some() {
+                    return err!(CandyGuardError::PhaseScheduleNotInDefault);
+                }
+            }
+        }
+
+        if let Some(phase_schedule) = &data.default.phase_schedule {
+            phase_schedule.verify_config(data)?;
+        }
+
+        Ok(())
+    }
+
+    /// Instruction to inspect the phase schedule. The status of the schedule is set as the
+    /// return data of the instruction as a borsh-serialized `PhaseScheduleStatus`.
+    ///
+    /// When none of the phases is active, the label of a group must be specified to route
+    /// the instruction.
+    fn instruction<'c, 'info>(
+        _ctx: &Context<'_, '_, 'c, 'info, Route<'info>>,
+        route_context: RouteContext<'info>,
+        _data: Vec<u8>,
+    ) -> Result<()>
+    where
+        'c: 'info,
+    {
+        msg!("PhaseSchedule: inspect instruction");
+
+        let phase_schedule = route_context
+            .guard_set
+            .and_then(|guard_set| guard_set.phase_schedule)
+            .ok_or(CandyGuardError::PhaseScheduleNotEnabled)?;
+
+        let timestamp = Clock::get()?.unix_timestamp;
+        let active = phase_schedule
+            .phases
+            .iter()
+            .position(|phase| phase.is_active(timestamp))
+            .map(|index| index as u8);
+
+        let status = PhaseScheduleStatus {
+            timestamp,
+            active,
+            phases: phase_schedule.phases,
+        };
+
+        set_return_data(&status.try_to_vec()?);
+
+        Ok(())
+    }
+}
+
+impl Condition for PhaseSchedule {
+    fn validate<'info>(
+        &self,
+        ctx: &mut EvaluationContext,
+        _guard_set: &GuardSet,
+        _mint_args: &[u8],
+    ) -> Result<()> {
+        if let Some(label) = &ctx.label {
+            let mut phases = self
+                .phases
+                .iter()
+                .filter(|phase| phase.label == *label)
+                .peekable();
+
+            // groups that are not part of the schedule are not affected
+            if phases.peek().is_some() {
+                let timestamp = Clock::get()?.unix_timestamp;
+
+                if !phases.any(|phase| phase.is_active(timestamp)) {
+                    msg!("Phase of group {} is not active", label);
+                    return err!(CandyGuardError::PhaseNotActive);
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -16,6 +16,7 @@ pub static DEFAULT_PROGRAMS: &[Pubkey] = &[
     spl_associated_token_account::ID,
     pubkey!("ComputeBudget111111111111111111111111111111"),
     pubkey!("SysExL2WDyJi9aRZrXorrjHJut3JwHQ7R9bTyctbNNG"),
+    pubkey!("Ed25519SigVerify111111111111111111111111111"),
 ];
 
 // Maximum number of programs in the additional list.
@@ -33,8 +34,8 @@ impl Guard for ProgramGate {
         4 + (MAXIMUM_SIZE * 32) // programs
     }
 
-    fn mask() -> u64 {
-        GuardType::as_mask(GuardType::ProgramGate)
+    fn guard_type() -> GuardType {
+        GuardType::ProgramGate
     }
 
     fn verify(data: &CandyGuardData) -> Result<()> {
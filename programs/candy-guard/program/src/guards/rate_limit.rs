@@ -0,0 +1,236 @@
+use std::collections::HashSet;
+
+use solana_program::{program::invoke_signed, system_instruction};
+
+use super::*;
+use crate::{
+    instructions::Route,
+    state::GuardType,
+    utils::{assert_keys_equal, assert_owned_by},
+};
+
+/// Guard to limit the number of mints across all minters within a fixed time window.
+///
+/// When the limit of the current window is reached, the mint fails with a
+/// `RateLimitExceeded` error, which is not subject to the bot tax since the transaction
+/// can be retried once the window elapses.
+///
+/// List of accounts required:
+///
+///   0. `[writable]` Rate limit tracker PDA. The PDA is derived using the seed
+///      `["rate_limit", rate limit guard id, candy guard pubkey, candy machine pubkey]`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct RateLimit {
+    /// Unique identifier of the rate limit.
+    pub id: u8,
+    /// Maximum number of mints within a window.
+    pub limit: u32,
+    /// Duration of the window (in seconds).
+    pub window: i64,
+}
+
+impl RateLimit {
+    // Returns the seeds of the tracker PDA.
+    fn seeds<'a>(
+        &'a self,
+        candy_guard_key: &'a Pubkey,
+        candy_machine_key: &'a Pubkey,
+    ) -> [&'a [u8]; 4] {
+        [
+            RateLimitTracker::PREFIX_SEED,
+            std::slice::from_ref(&self.id),
+            candy_guard_key.as_ref(),
+            candy_machine_key.as_ref(),
+        ]
+    }
+}
+
+impl Guard for RateLimit {
+    fn size() -> usize {
+        1   // id
+        + 4 // limit
+        + 8 // window
+    }
+
+    fn guard_type() -> GuardType {
+        GuardType::RateLimit
+    }
+
+    fn verify(data: &CandyGuardData) -> Result<()> {
+        let mut ids = HashSet::new();
+
+        let guard_sets = std::iter::once(&data.default)
+            .chain(data.groups.iter().flatten().map(|group| &group.guards));
+
+        for guard_set in guard_sets {
+            if let Some(rate_limit) = &guard_set.rate_limit {
+                if rate_limit.limit == 0 || rate_limit.window <= 0 || !ids.insert(rate_limit.id) {
+                    return err!(CandyGuardError::InvalidRateLimitConfig);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Instruction to close the rate limit tracker PDA once the candy machine was withdrawn.
+    ///
+    /// List of accounts required:
+    ///
+    ///   0. `[writable]` Rate limit tracker PDA (seeds `["rate_limit", rate limit guard id,
+    ///      candy guard pubkey, candy machine pubkey]`).
+    ///   1. `[signer, writable]` Candy guard authority (receives the lamports).
+    fn instruction<'c, 'info>(
+        ctx: &Context<'_, '_, 'c, 'info, Route<'info>>,
+        route_context: RouteContext<'info>,
+        data: Vec<u8>,
+    ) -> Result<()>
+    where
+        'c: 'info,
+    {
+        if data != CLOSE_PDA_INSTRUCTION {
+            return err!(CandyGuardError::InstructionNotFound);
+        }
+
+        msg!("Instruction: Close (RateLimit guard)");
+
+        let rate_limit = route_context
+            .guard_set
+            .as_ref()
+            .and_then(|guard_set| guard_set.rate_limit.as_ref())
+            .ok_or(CandyGuardError::GuardNotEnabled)?;
+
+        let candy_guard_key = ctx.accounts.candy_guard.key();
+        let candy_machine_key = ctx.accounts.candy_machine.key();
+        let seeds = rate_limit.seeds(&candy_guard_key, &candy_machine_key);
+
+        // the tracker is shared by all minters
+        close_guard_pda(ctx, &route_context, &seeds, None, None)
+    }
+}
+
+impl Condition for RateLimit {
+    fn validate<'info>(
+        &self,
+        ctx: &mut EvaluationContext,
+        _guard_set: &GuardSet,
+        _mint_args: &[u8],
+    ) -> Result<()> {
+        let tracker = try_get_account_info(ctx.accounts.remaining, ctx.account_cursor)?;
+        ctx.indices.insert("rate_limit_index", ctx.account_cursor);
+        ctx.account_cursor += 1;
+
+        let candy_guard_key = ctx.accounts.candy_guard.key();
+        let candy_machine_key = ctx.accounts.candy_machine.key();
+        let seeds = self.seeds(&candy_guard_key, &candy_machine_key);
+        let (pda, _) = Pubkey::find_program_address(&seeds, &crate::ID);
+
+        assert_keys_equal(tracker.key, &pda)?;
+
+        if !tracker.data_is_empty() {
+            // check the owner of the account
+            assert_owned_by(tracker, &crate::ID)?;
+
+            let account_data = tracker.data.borrow();
+            let rate_limit_tracker = RateLimitTracker::try_from_slice(&account_data)?;
+
+            let timestamp = Clock::get()?.unix_timestamp;
+
+            if rate_limit_tracker.count(self, timestamp) >= self.limit {
+                msg!(
+                    "Rate limit reached, window ends at {}",
+                    rate_limit_tracker.window_start.saturating_add(self.window)
+                );
+                return err!(CandyGuardError::RateLimitExceeded);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn pre_actions<'info>(
+        &self,
+        ctx: &mut EvaluationContext,
+        _guard_set: &GuardSet,
+        _mint_args: &[u8],
+    ) -> Result<()> {
+        let tracker =
+            try_get_account_info(ctx.accounts.remaining, ctx.indices["rate_limit_index"])?;
+        let timestamp = Clock::get()?.unix_timestamp;
+
+        let mut rate_limit_tracker = if tracker.data_is_empty() {
+            let candy_guard_key = ctx.accounts.candy_guard.key();
+            let candy_machine_key = ctx.accounts.candy_machine.key();
+            let seeds = self.seeds(&candy_guard_key, &candy_machine_key);
+            let (pda, bump) = Pubkey::find_program_address(&seeds, &crate::ID);
+
+            let rent = Rent::get()?;
+            let signer = [seeds[0], seeds[1], seeds[2], seeds[3], &[bump]];
+
+            invoke_signed(
+                &system_instruction::create_account(
+                    ctx.accounts.payer.key,
+                    &pda,
+                    rent.minimum_balance(RateLimitTracker::SIZE),
+                    RateLimitTracker::SIZE as u64,
+                    &crate::ID,
+                ),
+                &[
+                    ctx.accounts.payer.to_account_info(),
+                    tracker.to_account_info(),
+                ],
+                &[&signer],
+            )?;
+
+            RateLimitTracker {
+                window_start: timestamp,
+                count: 0,
+            }
+        } else {
+            assert_owned_by(tracker, &crate::ID)?;
+            RateLimitTracker::try_from_slice(&tracker.data.borrow())?
+        };
+
+        // starts a new window when the current one has elapsed
+        if rate_limit_tracker.count(self, timestamp) == 0 {
+            rate_limit_tracker.window_start = timestamp;
+            rate_limit_tracker.count = 0;
+        }
+
+        rate_limit_tracker.count += 1;
+
+        let mut account_data = tracker.try_borrow_mut_data()?;
+        // saves the changes back to the pda
+        let data = &mut rate_limit_tracker.try_to_vec()?;
+        account_data[0..data.len()].copy_from_slice(data);
+
+        Ok(())
+    }
+}
+
+/// PDA to track the number of mints within the current window.
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct RateLimitTracker {
+    /// Start of the current window.
+    pub window_start: i64,
+    /// Number of mints within the current window.
+    pub count: u32,
+}
+
+impl RateLimitTracker {
+    /// Prefix used as seed.
+    pub const PREFIX_SEED: &'static [u8] = b"rate_limit";
+
+    /// Size of the account data.
+    pub const SIZE: usize = 8 // window_start
+        + 4; // count
+
+    /// Returns the number of mints within the window active at the specified timestamp.
+    pub fn count(&self, rate_limit: &RateLimit, timestamp: i64) -> u32 {
+        if timestamp < self.window_start.saturating_add(rate_limit.window) {
+            self.count
+        } else {
+            0
+        }
+    }
+}
@@ -0,0 +1,248 @@
+use super::*;
+
+use solana_program::{program::invoke_signed, system_instruction};
+
+use crate::{errors::CandyGuardError, state::GuardType, utils::assert_keys_equal};
+
+/// Guard that creates a receipt PDA for each mint, recording who minted which item, in
+/// which group and for what price.
+///
+/// List of accounts required:
+///
+///   0. `[writable]` Mint receipt PDA (seeds `["mint_receipt", candy machine pubkey,
+///                   asset pubkey]`).
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct Receipt {}
+
+impl Guard for Receipt {
+    fn size() -> usize {
+        0 // no configuration
+    }
+
+    fn guard_type() -> GuardType {
+        GuardType::Receipt
+    }
+
+    /// Instruction to close a mint receipt, returning the rent to the original payer.
+    ///
+    /// The receipt follows the same policy as the other guard PDAs (see `close_guard_pda`):
+    /// the original payer can close it once the candy machine is fully minted or has been
+    /// withdrawn; the candy guard authority can close it once the candy machine has been
+    /// withdrawn.
+    ///
+    /// List of accounts required:
+    ///
+    ///   0. `[writable]` Mint receipt PDA (seeds `["mint_receipt", candy machine pubkey,
+    ///                   asset pubkey]`).
+    ///   1. `[signer]` Candy guard authority or original payer of the receipt.
+    ///   2. `[writable]` Original payer of the receipt (receives the rent).
+    fn instruction<'c, 'info>(
+        ctx: &Context<'_, '_, 'c, 'info, Route<'info>>,
+        route_context: RouteContext<'info>,
+        _data: Vec<u8>,
+    ) -> Result<()>
+    where
+        'c: 'info,
+    {
+        msg!("Instruction: Close (Receipt guard)");
+
+        let receipt_pda = try_get_account_info(ctx.remaining_accounts, 0)?;
+        let receipt: Account<MintReceipt> = Account::try_from(receipt_pda)?;
+
+        // the receipt must belong to the candy guard and candy machine of the route
+        assert_keys_equal(&ctx.accounts.candy_guard.key(), &receipt.candy_guard)?;
+        assert_keys_equal(&ctx.accounts.candy_machine.key(), &receipt.candy_machine)?;
+
+        let seeds = [
+            MintReceipt::PREFIX_SEED,
+            receipt.candy_machine.as_ref(),
+            receipt.asset.as_ref(),
+        ];
+
+        // rent always goes back to whoever paid for it
+        close_guard_pda(
+            ctx,
+            &route_context,
+            &seeds,
+            Some(&receipt.payer),
+            Some(&receipt.payer),
+        )
+    }
+}
+
+impl Condition for Receipt {
+    fn validate<'info>(
+        &self,
+        ctx: &mut EvaluationContext,
+        _guard_set: &GuardSet,
+        _mint_args: &[u8],
+    ) -> Result<()> {
+        let index = ctx.account_cursor;
+        let receipt_pda = try_get_account_info(ctx.accounts.remaining, index)?;
+        ctx.account_cursor += 1;
+
+        let candy_machine_key = ctx.accounts.candy_machine.key();
+        let asset_key = ctx.accounts.asset.key();
+
+        let seeds = [
+            MintReceipt::PREFIX_SEED,
+            candy_machine_key.as_ref(),
+            asset_key.as_ref(),
+        ];
+        let (pda, _) = Pubkey::find_program_address(&seeds, &crate::ID);
+        assert_keys_equal(receipt_pda.key, &pda)?;
+
+        if !receipt_pda.data_is_empty() {
+            return err!(CandyGuardError::ReceiptAlreadyExists);
+        }
+
+        ctx.indices.insert("receipt_index", index);
+
+        Ok(())
+    }
+
+    fn post_actions<'c, 'info>(
+        &self,
+        ctx: &mut EvaluationContext<'_, 'c, 'info>,
+        _guard_set: &GuardSet,
+        _mint_args: &[u8],
+    ) -> Result<()>
+    where
+        'c: 'info,
+    {
+        let receipt_pda =
+            try_get_account_info(ctx.accounts.remaining, ctx.indices["receipt_index"])?;
+
+        let candy_machine_key = ctx.accounts.candy_machine.key();
+        let asset_key = ctx.accounts.asset.key();
+
+        let seeds = [
+            MintReceipt::PREFIX_SEED,
+            candy_machine_key.as_ref(),
+            asset_key.as_ref(),
+        ];
+        let (pda, bump) = Pubkey::find_program_address(&seeds, &crate::ID);
+
+        let signer = [
+            MintReceipt::PREFIX_SEED,
+            candy_machine_key.as_ref(),
+            asset_key.as_ref(),
+            &[bump],
+        ];
+
+        let size = MintReceipt::size(ctx.label.as_ref(), &ctx.payments);
+        let rent = Rent::get()?;
+
+        invoke_signed(
+            &system_instruction::create_account(
+                ctx.accounts.payer.key,
+                &pda,
+                rent.minimum_balance(size),
+                size as u64,
+                &crate::ID,
+            ),
+            &[
+                ctx.accounts.payer.to_account_info(),
+                receipt_pda.to_account_info(),
+            ],
+            &[&signer],
+        )?;
+
+        // the candy machine account holds the state before the mint, so the
+        // number of the item minted is the next one
+        let mint_number = ctx
+            .accounts
+            .candy_machine
+            .items_redeemed
+            .checked_add(1)
+            .ok_or(CandyGuardError::NumericalOverflowError)?;
+
+        // safe to be unchecked since the account must be empty at this point
+        let mut receipt: Account<MintReceipt> = Account::try_from_unchecked(receipt_pda)?;
+        receipt.candy_guard = ctx.accounts.candy_guard.key();
+        receipt.candy_machine = candy_machine_key;
+        receipt.asset = asset_key;
+        receipt.minter = ctx.accounts.minter.key();
+        receipt.owner = ctx.accounts.owner.key();
+        receipt.payer = ctx.accounts.payer.key();
+        receipt.group = ctx.label.clone();
+        receipt.config_index = ctx.config_index;
+        receipt.mint_number = mint_number;
+        receipt.timestamp = Clock::get()?.unix_timestamp;
+        receipt.payments = ctx.payments.clone();
+
+        receipt.exit(&crate::ID)
+    }
+}
+
+/// Amount charged by a payment guard during a mint.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct MintPayment {
+    /// Guard that charged the amount.
+    pub guard: GuardType,
+    /// Mint of the token charged (`None` when the amount is in lamports).
+    pub mint: Option<Pubkey>,
+    /// Amount charged, in lamports or in the smallest unit of the token.
+    pub amount: u64,
+}
+
+impl MintPayment {
+    /// Account size of a serialized payment.
+    pub const SIZE: usize = 1 // guard type
+        + 1 + 32 // option + mint
+        + 8; // amount
+}
+
+/// PDA to record the details of a mint.
+#[account]
+#[derive(Default, Debug, PartialEq, Eq)]
+pub struct MintReceipt {
+    /// Candy guard address used for the mint.
+    pub candy_guard: Pubkey,
+
+    /// Candy machine address the asset was minted from.
+    pub candy_machine: Pubkey,
+
+    /// Address of the asset minted.
+    pub asset: Pubkey,
+
+    /// Address of the minter.
+    pub minter: Pubkey,
+
+    /// Address of the owner of the asset.
+    pub owner: Pubkey,
+
+    /// Address that paid for the mint (and for the receipt rent).
+    pub payer: Pubkey,
+
+    /// Label of the group used for the mint.
+    pub group: Option<String>,
+
+    /// Index of the config line minted, when reported by the candy machine.
+    pub config_index: Option<u32>,
+
+    /// Number of the item minted (starting at 1).
+    pub mint_number: u64,
+
+    /// Timestamp of the mint.
+    pub timestamp: i64,
+
+    /// Amounts charged by payment guards.
+    pub payments: Vec<MintPayment>,
+}
+
+impl MintReceipt {
+    /// Prefix used as seed.
+    pub const PREFIX_SEED: &'static [u8] = b"mint_receipt";
+
+    /// Returns the account size required to store a receipt.
+    pub fn size(group: Option<&String>, payments: &[MintPayment]) -> usize {
+        8 // discriminator
+        + 32 * 6 // candy guard, candy machine, asset, minter, owner, payer
+        + 1 + group.map(|label| 4 + label.len()).unwrap_or(0) // option + group
+        + 1 + 4 // option + config index
+        + 8 // mint number
+        + 8 // timestamp
+        + 4 + payments.len() * MintPayment::SIZE // payments
+    }
+}
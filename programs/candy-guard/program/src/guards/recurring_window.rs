@@ -0,0 +1,132 @@
+use super::*;
+
+use crate::{errors::CandyGuardError, state::GuardType};
+
+/// Guard that allows the mint only during windows that repeat on a fixed schedule.
+///
+/// The windows start every `period` seconds, counting from the `offset` timestamp, and
+/// last `duration` seconds. For example, a daily window from 18:00 to 20:00 UTC uses a
+/// `period` of `86_400`, an `offset` of `64_800` and a `duration` of `7_200`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct RecurringWindow {
+    /// Interval between the start of consecutive windows (in seconds).
+    pub period: i64,
+    /// Start of a window (unix timestamp), used to align the windows.
+    pub offset: i64,
+    /// Duration of each window (in seconds).
+    pub duration: i64,
+}
+
+impl RecurringWindow {
+    /// Returns whether a window is active at the specified timestamp.
+    pub fn is_active(&self, timestamp: i64) -> bool {
+        (timestamp as i128 - self.offset as i128).rem_euclid(self.period as i128)
+            < self.duration as i128
+    }
+}
+
+impl Guard for RecurringWindow {
+    fn size() -> usize {
+        8   // period
+        + 8 // offset
+        + 8 // duration
+    }
+
+    fn guard_type() -> GuardType {
+        GuardType::RecurringWindow
+    }
+
+    fn verify(data: &CandyGuardData) -> Result<()> {
+        let guard_sets = std::iter::once(&data.default)
+            .chain(data.groups.iter().flatten().map(|group| &group.guards));
+
+        for guard_set in guard_sets {
+            if let Some(window) = &guard_set.recurring_window {
+                if window.period <= 0 || window.duration <= 0 || window.duration > window.period {
+                    return err!(CandyGuardError::InvalidRecurringWindow);
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Condition for RecurringWindow {
+    fn validate<'info>(
+        &self,
+        _ctx: &mut EvaluationContext,
+        _guard_set: &GuardSet,
+        _mint_args: &[u8],
+    ) -> Result<()> {
+        let clock = Clock::get()?;
+
+        if !self.is_active(clock.unix_timestamp) {
+            return err!(CandyGuardError::OutsideRecurringWindow);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // daily window from 18:00 to 20:00 UTC
+    fn daily_window() -> RecurringWindow {
+        RecurringWindow {
+            period: 86_400,
+            offset: 64_800,
+            duration: 7_200,
+        }
+    }
+
+    #[test]
+    fn active_within_window() {
+        let window = daily_window();
+        // 18:00, 19:00 and 19:59:59 on 1970-01-01
+        assert!(window.is_active(64_800));
+        assert!(window.is_active(68_400));
+        assert!(window.is_active(71_999));
+        // same times 30 days later
+        assert!(window.is_active(30 * 86_400 + 64_800));
+        assert!(window.is_active(30 * 86_400 + 71_999));
+    }
+
+    #[test]
+    fn inactive_outside_window() {
+        let window = daily_window();
+        // the end of the window is exclusive
+        assert!(!window.is_active(72_000));
+        assert!(!window.is_active(64_799));
+        assert!(!window.is_active(0));
+        assert!(!window.is_active(30 * 86_400 + 72_000));
+    }
+
+    #[test]
+    fn active_before_offset() {
+        // windows repeat before the offset timestamp as well
+        let window = RecurringWindow {
+            period: 3_600,
+            offset: 1_700_000_000,
+            duration: 600,
+        };
+        assert!(window.is_active(1_700_000_000 - 3_600));
+        assert!(window.is_active(1_700_000_000 - 3_001));
+        assert!(!window.is_active(1_700_000_000 - 3_000));
+        assert!(!window.is_active(1_700_000_000 - 1));
+    }
+
+    #[test]
+    fn no_overflow_at_extremes() {
+        let window = RecurringWindow {
+            period: i64::MAX,
+            offset: i64::MIN,
+            duration: 1,
+        };
+        assert!(!window.is_active(i64::MAX));
+        assert!(!window.is_active(0));
+        assert!(window.is_active(i64::MIN));
+    }
+}
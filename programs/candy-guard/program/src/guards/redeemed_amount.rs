@@ -14,8 +14,8 @@ impl Guard for RedeemedAmount {
         8 // maximum
     }
 
-    fn mask() -> u64 {
-        GuardType::as_mask(GuardType::RedeemedAmount)
+    fn guard_type() -> GuardType {
+        GuardType::RedeemedAmount
     }
 }
 
@@ -0,0 +1,299 @@
+use solana_program::{
+    ed25519_program,
+    program::{invoke, invoke_signed},
+    system_instruction,
+    sysvar::instructions::get_instruction_relative,
+};
+
+use super::*;
+use crate::{
+    state::GuardType,
+    utils::{assert_keys_equal, cmp_pubkeys},
+};
+
+// Size of the signature offsets of the Ed25519 instruction data (7 x u16).
+const SIGNATURE_OFFSETS_SIZE: usize = 14;
+
+// Start of the signature offsets:
+//     1 (number of signatures)
+//  +  1 (padding)
+const SIGNATURE_OFFSETS_START: usize = 2;
+
+/// Guard that requires a voucher signed by a configured key. The voucher is the message
+/// of an Ed25519 program instruction, which must immediately precede the mint instruction
+/// in the transaction; the signature is verified by the Ed25519 precompile, so the signer
+/// does not need to co-sign the mint transaction.
+///
+/// Each voucher can only be used once: its nonce is recorded in a PDA on mint.
+///
+/// List of accounts required:
+///
+///   0. `[writable]` Voucher nonce PDA. The PDA is derived using the seed
+///      `["signed_voucher", nonce, candy guard pubkey, candy machine pubkey]`.
+///   1. `[writable]` Account to receive the funds (only when the voucher specifies a price).
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct SignedVoucher {
+    /// Key that signs the vouchers.
+    pub signer: Pubkey,
+    /// Account to receive the price of the voucher, if any.
+    pub destination: Pubkey,
+}
+
+/// Voucher signed off-chain authorizing a mint. This is the (borsh-serialized) message
+/// signed by the voucher signer.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct Voucher {
+    /// Address allowed to mint.
+    pub minter: Pubkey,
+    /// Candy machine of the mint.
+    pub candy_machine: Pubkey,
+    /// Label of the group of the mint, if any.
+    pub label: Option<String>,
+    /// Price of the mint in lamports, if any.
+    pub price: Option<u64>,
+    /// Expiry of the voucher (unix timestamp).
+    pub expiry: i64,
+    /// Unique identifier of the voucher.
+    pub nonce: u64,
+}
+
+impl SignedVoucher {
+    /// Returns the voucher of the Ed25519 instruction preceding the mint instruction,
+    /// validating that it was signed by the voucher signer.
+    fn voucher(&self, ctx: &EvaluationContext) -> Result<Voucher> {
+        let instruction = get_instruction_relative(-1, &ctx.accounts.sysvar_instructions)
+            .map_err(|_| CandyGuardError::MissingVoucher)?;
+
+        if !cmp_pubkeys(&instruction.program_id, &ed25519_program::ID) {
+            return err!(CandyGuardError::MissingVoucher);
+        }
+
+        let data = &instruction.data;
+
+        // only a single signature is supported
+        if data.len() < SIGNATURE_OFFSETS_START + SIGNATURE_OFFSETS_SIZE || data[0] != 1 {
+            return err!(CandyGuardError::InvalidVoucher);
+        }
+
+        let offsets: Vec<u16> = data
+            [SIGNATURE_OFFSETS_START..SIGNATURE_OFFSETS_START + SIGNATURE_OFFSETS_SIZE]
+            .chunks_exact(2)
+            .map(|offset| u16::from_le_bytes([offset[0], offset[1]]))
+            .collect();
+
+        // signature, public key and message must be in the Ed25519 instruction
+        if offsets[1] != u16::MAX || offsets[3] != u16::MAX || offsets[6] != u16::MAX {
+            return err!(CandyGuardError::InvalidVoucher);
+        }
+
+        let public_key_offset = offsets[2] as usize;
+        let message_offset = offsets[4] as usize;
+        let message_size = offsets[5] as usize;
+
+        let public_key = data
+            .get(public_key_offset..public_key_offset + 32)
+            .ok_or(CandyGuardError::InvalidVoucher)?;
+
+        if public_key != self.signer.as_ref() {
+            msg!("Voucher not signed by {}", self.signer);
+            return err!(CandyGuardError::InvalidVoucher);
+        }
+
+        let message = data
+            .get(message_offset..message_offset + message_size)
+            .ok_or(CandyGuardError::InvalidVoucher)?;
+
+        Voucher::try_from_slice(message).map_err(|_| error!(CandyGuardError::InvalidVoucher))
+    }
+}
+
+impl Guard for SignedVoucher {
+    fn size() -> usize {
+        32   // signer
+        + 32 // destination
+    }
+
+    fn guard_type() -> GuardType {
+        GuardType::SignedVoucher
+    }
+}
+
+impl Condition for SignedVoucher {
+    fn validate<'info>(
+        &self,
+        ctx: &mut EvaluationContext,
+        _guard_set: &GuardSet,
+        _mint_args: &[u8],
+    ) -> Result<()> {
+        let voucher = self.voucher(ctx)?;
+
+        // validates that the voucher applies to the mint
+
+        if !cmp_pubkeys(&voucher.minter, &ctx.accounts.minter.key())
+            || !cmp_pubkeys(&voucher.candy_machine, &ctx.accounts.candy_machine.key())
+            || voucher.label != ctx.label
+        {
+            return err!(CandyGuardError::InvalidVoucher);
+        }
+
+        let clock = Clock::get()?;
+
+        if clock.unix_timestamp >= voucher.expiry {
+            return err!(CandyGuardError::VoucherExpired);
+        }
+
+        // validates that the voucher was not used
+
+        let nonce = try_get_account_info(ctx.accounts.remaining, ctx.account_cursor)?;
+        ctx.indices
+            .insert("voucher_nonce_index", ctx.account_cursor);
+        ctx.account_cursor += 1;
+
+        let candy_guard_key = &ctx.accounts.candy_guard.key();
+        let candy_machine_key = &ctx.accounts.candy_machine.key();
+
+        let seeds = [
+            VoucherNonce::PREFIX_SEED,
+            &voucher.nonce.to_le_bytes(),
+            candy_guard_key.as_ref(),
+            candy_machine_key.as_ref(),
+        ];
+        let (pda, _) = Pubkey::find_program_address(&seeds, &crate::ID);
+
+        assert_keys_equal(nonce.key, &pda)?;
+
+        if !nonce.data_is_empty() {
+            return err!(CandyGuardError::VoucherAlreadyUsed);
+        }
+
+        if let Some(price) = voucher.price {
+            let destination = try_get_account_info(ctx.accounts.remaining, ctx.account_cursor)?;
+            ctx.indices
+                .insert("voucher_destination_index", ctx.account_cursor);
+            ctx.account_cursor += 1;
+
+            assert_keys_equal(destination.key, &self.destination)?;
+
+            if ctx.accounts.payer.lamports() < price {
+                msg!(
+                    "Require {} lamports, accounts has {} lamports",
+                    price,
+                    ctx.accounts.payer.lamports(),
+                );
+                return err!(CandyGuardError::NotEnoughSOL);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn payment(
+        &self,
+        ctx: &EvaluationContext,
+        _guard_set: &GuardSet,
+    ) -> Result<Option<MintPayment>> {
+        Ok(self.voucher(ctx)?.price.map(|price| MintPayment {
+            guard: GuardType::SignedVoucher,
+            mint: None,
+            amount: price,
+        }))
+    }
+
+    fn pre_actions<'info>(
+        &self,
+        ctx: &mut EvaluationContext,
+        _guard_set: &GuardSet,
+        _mint_args: &[u8],
+    ) -> Result<()> {
+        let voucher = self.voucher(ctx)?;
+        let nonce =
+            try_get_account_info(ctx.accounts.remaining, ctx.indices["voucher_nonce_index"])?;
+
+        // records the nonce of the voucher
+
+        let candy_guard_key = &ctx.accounts.candy_guard.key();
+        let candy_machine_key = &ctx.accounts.candy_machine.key();
+        let nonce_bytes = voucher.nonce.to_le_bytes();
+
+        let seeds = [
+            VoucherNonce::PREFIX_SEED,
+            &nonce_bytes,
+            candy_guard_key.as_ref(),
+            candy_machine_key.as_ref(),
+        ];
+        let (pda, bump) = Pubkey::find_program_address(&seeds, &crate::ID);
+
+        let rent = Rent::get()?;
+        let signer = [
+            VoucherNonce::PREFIX_SEED,
+            &nonce_bytes,
+            candy_guard_key.as_ref(),
+            candy_machine_key.as_ref(),
+            &[bump],
+        ];
+
+        invoke_signed(
+            &system_instruction::create_account(
+                ctx.accounts.payer.key,
+                &pda,
+                rent.minimum_balance(VoucherNonce::SIZE),
+                VoucherNonce::SIZE as u64,
+                &crate::ID,
+            ),
+            &[
+                ctx.accounts.payer.to_account_info(),
+                nonce.to_account_info(),
+            ],
+            &[&signer],
+        )?;
+
+        let mut account_data = nonce.try_borrow_mut_data()?;
+        let voucher_nonce = VoucherNonce {
+            minter: voucher.minter,
+        };
+        // saves the minter of the voucher to the pda
+        let data = &mut voucher_nonce.try_to_vec()?;
+        account_data[0..data.len()].copy_from_slice(data);
+
+        // charges the price of the voucher
+
+        if let Some(price) = voucher.price {
+            let destination = try_get_account_info(
+                ctx.accounts.remaining,
+                ctx.indices["voucher_destination_index"],
+            )?;
+
+            invoke(
+                &system_instruction::transfer(&ctx.accounts.payer.key(), destination.key, price),
+                &[
+                    ctx.accounts.payer.to_account_info(),
+                    destination.to_account_info(),
+                    ctx.accounts.system_program.to_account_info(),
+                ],
+            )?;
+
+            ctx.payments.push(MintPayment {
+                guard: GuardType::SignedVoucher,
+                mint: None,
+                amount: price,
+            });
+        }
+
+        Ok(())
+    }
+}
+
+/// PDA to record that a voucher was used.
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct VoucherNonce {
+    /// Address that used the voucher.
+    pub minter: Pubkey,
+}
+
+impl VoucherNonce {
+    /// Prefix used as seed.
+    pub const PREFIX_SEED: &'static [u8] = b"signed_voucher";
+
+    /// Size of the account data.
+    pub const SIZE: usize = 32;
+}
@@ -21,8 +21,8 @@ impl Guard for SolFixedFee {
         + 32 // fee destination
     }
 
-    fn mask() -> u64 {
-        GuardType::as_mask(GuardType::SolFixedFee)
+    fn guard_type() -> GuardType {
+        GuardType::SolFixedFee
     }
 }
 
@@ -54,6 +54,18 @@ impl Condition for SolFixedFee {
         Ok(())
     }
 
+    fn payment(
+        &self,
+        _ctx: &EvaluationContext,
+        _guard_set: &GuardSet,
+    ) -> Result<Option<MintPayment>> {
+        Ok(Some(MintPayment {
+            guard: GuardType::SolFixedFee,
+            mint: None,
+            amount: self.lamports,
+        }))
+    }
+
     fn pre_actions<'info>(
         &self,
         ctx: &mut EvaluationContext,
@@ -78,6 +90,12 @@ impl Condition for SolFixedFee {
             ],
         )?;
 
+        ctx.payments.push(MintPayment {
+            guard: GuardType::SolFixedFee,
+            mint: None,
+            amount: self.lamports,
+        });
+
         Ok(())
     }
 }
@@ -0,0 +1,268 @@
+use anchor_lang::system_program;
+use solana_program::{program::invoke_signed, system_instruction};
+
+use crate::{
+    instructions::Route,
+    state::GuardType,
+    utils::{assert_keys_equal, assert_owned_by, cmp_pubkeys},
+};
+
+use super::*;
+
+/// Guard that requires the minter to hold a minimum balance of lamports after paying for
+/// the mint and, optionally, the minter to have registered its wallet a minimum time before
+/// the mint.
+///
+/// The balance of the minter is checked, since it is the wallet being gated; funding a
+/// separate payer does not satisfy the guard. The balance is checked before the payments
+/// (validation) and after them, since the payments are only charged once all guards
+/// validate.
+///
+/// List of accounts required:
+///
+///   0. `[]` Registration PDA (seeds `["sol_gate", minter key, candy guard pubkey,
+///      candy machine pubkey]`). Only required when `minimum_age` is specified.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct SolGate {
+    /// Minimum balance of the minter (in lamports).
+    pub minimum_balance: u64,
+    /// Minimum time between the registration of the wallet and the mint (in seconds).
+    pub minimum_age: Option<i64>,
+}
+
+impl SolGate {
+    // Validates the balance of the minter.
+    fn verify_balance(&self, ctx: &EvaluationContext) -> Result<()> {
+        if ctx.accounts.minter.lamports() < self.minimum_balance {
+            msg!(
+                "Require {} lamports, minter has {} lamports",
+                self.minimum_balance,
+                ctx.accounts.minter.lamports(),
+            );
+            return err!(CandyGuardError::BelowMinimumBalance);
+        }
+
+        Ok(())
+    }
+}
+
+impl Guard for SolGate {
+    fn size() -> usize {
+        8       // minimum_balance
+        + 1 + 8 // minimum_age
+    }
+
+    fn guard_type() -> GuardType {
+        GuardType::SolGate
+    }
+
+    /// Instruction to register a wallet.
+    ///
+    /// List of accounts required:
+    ///
+    ///   0. `[writable]` Registration PDA (seeds `["sol_gate", payer/minter key, candy guard
+    ///      pubkey, candy machine pubkey]`).
+    ///   1. `[]` System program account.
+    ///   2. `[signer, optional]` Minter account (the payer is registered when not present).
+    ///
+    /// When the instruction data is `CLOSE_PDA_INSTRUCTION`, the registration PDA is closed
+    /// once the mint is over (see `close_guard_pda`) with the following accounts:
+    ///
+    ///   0. `[writable]` Registration PDA.
+    ///   1. `[signer, writable]` Candy guard authority or minter (receives the lamports).
+    ///   2. `[optional]` Minter account (required when the authority closes the PDA).
+    fn instruction<'c, 'info>(
+        ctx: &Context<'_, '_, 'c, 'info, Route<'info>>,
+        route_context: RouteContext<'info>,
+        data: Vec<u8>,
+    ) -> Result<()>
+    where
+        'c: 'info,
+    {
+        let candy_guard_key = &ctx.accounts.candy_guard.key();
+        let candy_machine_key = &ctx.accounts.candy_machine.key();
+
+        if data == CLOSE_PDA_INSTRUCTION {
+            msg!("Instruction: Close (SolGate guard)");
+
+            let minter = if let Some(minter) = get_account_info(ctx.remaining_accounts, 2) {
+                minter.key()
+            } else {
+                try_get_account_info(ctx.remaining_accounts, 1)?.key()
+            };
+
+            let seeds = [
+                SolGateRegistration::PREFIX_SEED,
+                minter.as_ref(),
+                candy_guard_key.as_ref(),
+                candy_machine_key.as_ref(),
+            ];
+
+            return close_guard_pda(ctx, &route_context, &seeds, Some(&minter), None);
+        }
+
+        msg!("Instruction: Register (SolGate guard)");
+
+        let candy_guard = route_context
+            .candy_guard
+            .as_ref()
+            .ok_or(CandyGuardError::Uninitialized)?;
+
+        let candy_machine = route_context
+            .candy_machine
+            .as_ref()
+            .ok_or(CandyGuardError::Uninitialized)?;
+
+        // and the candy guard and candy machine must be linked
+        if !cmp_pubkeys(&candy_machine.mint_authority, &candy_guard.key()) {
+            return err!(CandyGuardError::InvalidMintAuthority);
+        }
+
+        if route_context
+            .guard_set
+            .as_ref()
+            .and_then(|guard_set| guard_set.sol_gate.as_ref())
+            .is_none()
+        {
+            return err!(CandyGuardError::GuardNotEnabled);
+        }
+
+        let registration = try_get_account_info(ctx.remaining_accounts, 0)?;
+        let system_program_info = try_get_account_info(ctx.remaining_accounts, 1)?;
+        assert_keys_equal(system_program_info.key, &system_program::ID)?;
+
+        let minter = if let Some(minter) = get_account_info(ctx.remaining_accounts, 2) {
+            // only the owner of the wallet can register it
+            if !minter.is_signer {
+                return err!(CandyGuardError::MissingRequiredSignature);
+            }
+            minter.key()
+        } else {
+            ctx.accounts.payer.key()
+        };
+
+        let seeds = [
+            SolGateRegistration::PREFIX_SEED,
+            minter.as_ref(),
+            candy_guard_key.as_ref(),
+            candy_machine_key.as_ref(),
+        ];
+        let (pda, bump) = Pubkey::find_program_address(&seeds, &crate::ID);
+
+        assert_keys_equal(registration.key, &pda)?;
+
+        // the registration time is never updated
+        if !registration.data_is_empty() {
+            return err!(CandyGuardError::WalletAlreadyRegistered);
+        }
+
+        let signer = [
+            SolGateRegistration::PREFIX_SEED,
+            minter.as_ref(),
+            candy_guard_key.as_ref(),
+            candy_machine_key.as_ref(),
+            &[bump],
+        ];
+        let rent = Rent::get()?;
+
+        invoke_signed(
+            &system_instruction::create_account(
+                &ctx.accounts.payer.key(),
+                &pda,
+                rent.minimum_balance(SolGateRegistration::SIZE),
+                SolGateRegistration::SIZE as u64,
+                &crate::ID,
+            ),
+            &[
+                ctx.accounts.payer.to_account_info(),
+                registration.to_account_info(),
+            ],
+            &[&signer],
+        )?;
+
+        let sol_gate_registration = SolGateRegistration {
+            timestamp: Clock::get()?.unix_timestamp,
+        };
+
+        let mut account_data = registration.try_borrow_mut_data()?;
+        // saves the registration time to the pda
+        let data = &mut sol_gate_registration.try_to_vec()?;
+        account_data[0..data.len()].copy_from_slice(data);
+
+        Ok(())
+    }
+}
+
+impl Condition for SolGate {
+    fn validate<'info>(
+        &self,
+        ctx: &mut EvaluationContext,
+        _guard_set: &GuardSet,
+        _mint_args: &[u8],
+    ) -> Result<()> {
+        self.verify_balance(ctx)?;
+
+        if let Some(minimum_age) = self.minimum_age {
+            let registration = try_get_account_info(ctx.accounts.remaining, ctx.account_cursor)?;
+            ctx.account_cursor += 1;
+
+            let minter = ctx.accounts.minter.key();
+            let candy_guard_key = &ctx.accounts.candy_guard.key();
+            let candy_machine_key = &ctx.accounts.candy_machine.key();
+
+            let seeds = [
+                SolGateRegistration::PREFIX_SEED,
+                minter.as_ref(),
+                candy_guard_key.as_ref(),
+                candy_machine_key.as_ref(),
+            ];
+            let (pda, _) = Pubkey::find_program_address(&seeds, &crate::ID);
+
+            assert_keys_equal(registration.key, &pda)?;
+
+            if registration.data_is_empty() {
+                return err!(CandyGuardError::WalletNotRegistered);
+            }
+
+            assert_owned_by(registration, &crate::ID)?;
+
+            let sol_gate_registration =
+                SolGateRegistration::try_from_slice(&registration.data.borrow())?;
+            let clock = Clock::get()?;
+
+            if clock.unix_timestamp < sol_gate_registration.timestamp.saturating_add(minimum_age) {
+                return err!(CandyGuardError::WalletRegistrationTooRecent);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn post_actions<'c, 'info>(
+        &self,
+        ctx: &mut EvaluationContext<'_, 'c, 'info>,
+        _guard_set: &GuardSet,
+        _mint_args: &[u8],
+    ) -> Result<()>
+    where
+        'c: 'info,
+    {
+        // the balance must remain above the minimum after the payments
+        self.verify_balance(ctx)
+    }
+}
+
+/// PDA to track the registration time of a wallet.
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct SolGateRegistration {
+    /// Timestamp of the registration.
+    pub timestamp: i64,
+}
+
+impl SolGateRegistration {
+    /// Prefix used as seed.
+    pub const PREFIX_SEED: &'static [u8] = b"sol_gate";
+
+    /// Size of the account data.
+    pub const SIZE: usize = 8; // timestamp
+}
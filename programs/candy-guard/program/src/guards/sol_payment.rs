@@ -21,8 +21,8 @@ impl Guard for SolPayment {
         + 32 // destination
     }
 
-    fn mask() -> u64 {
-        GuardType::as_mask(GuardType::SolPayment)
+    fn guard_type() -> GuardType {
+        GuardType::SolPayment
     }
 }
 
@@ -54,21 +54,31 @@ impl Condition for SolPayment {
         Ok(())
     }
 
+    fn payment(
+        &self,
+        ctx: &EvaluationContext,
+        _guard_set: &GuardSet,
+    ) -> Result<Option<MintPayment>> {
+        Ok(Some(MintPayment {
+            guard: GuardType::SolPayment,
+            mint: None,
+            amount: ctx.discounted_amount(self.lamports),
+        }))
+    }
+
     fn pre_actions<'info>(
         &self,
         ctx: &mut EvaluationContext,
         _guard_set: &GuardSet,
         _mint_args: &[u8],
     ) -> Result<()> {
+        // applies the holder discount, if any
+        let amount = ctx.discounted_amount(self.lamports);
         let destination =
             try_get_account_info(ctx.accounts.remaining, ctx.indices["lamports_destination"])?;
 
         invoke(
-            &system_instruction::transfer(
-                &ctx.accounts.payer.key(),
-                &destination.key(),
-                self.lamports,
-            ),
+            &system_instruction::transfer(&ctx.accounts.payer.key(), &destination.key(), amount),
             &[
                 ctx.accounts.payer.to_account_info(),
                 destination.to_account_info(),
@@ -76,6 +86,12 @@ impl Condition for SolPayment {
             ],
         )?;
 
+        ctx.payments.push(MintPayment {
+            guard: GuardType::SolPayment,
+            mint: None,
+            amount,
+        });
+
         Ok(())
     }
 }
@@ -0,0 +1,247 @@
+use super::*;
+
+use solana_program::{program::invoke, system_instruction};
+
+use crate::{errors::CandyGuardError, state::GuardType, utils::assert_keys_equal};
+
+// Maximum number of destinations of a split.
+pub(crate) const MAXIMUM_SPLIT_DESTINATIONS: usize = 5;
+
+// Basis points representing 100%.
+const TOTAL_BASIS_POINTS: u64 = 10_000;
+
+/// Guard that charges an amount in SOL (lamports) for the mint, splitting it across
+/// multiple destinations according to their share in basis points.
+///
+/// List of accounts required:
+///
+///   x. `[writable]` Account to receive the funds of each destination (in the same order
+///      as the configuration).
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct SolPaymentSplit {
+    pub lamports: u64,
+    pub destinations: Vec<SplitDestination>,
+}
+
+/// Destination of a split payment.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct SplitDestination {
+    /// Address to receive the funds.
+    pub address: Pubkey,
+    /// Share of the payment in basis points (10,000 = 100%).
+    pub basis_points: u16,
+}
+
+impl SplitDestination {
+    /// Account size of a serialized destination.
+    pub const SIZE: usize = 32 // address
+        + 2; // basis points
+}
+
+/// Validates that the destinations of a split are not empty, do not exceed the
+/// maximum number of destinations and have shares that sum to 10,000 basis points.
+pub(crate) fn verify_split(destinations: &[SplitDestination]) -> Result<()> {
+    if destinations.is_empty() || destinations.len() > MAXIMUM_SPLIT_DESTINATIONS {
+        return err!(CandyGuardError::InvalidPaymentSplit);
+    }
+
+    let total = destinations.iter().fold(0u64, |total, destination| {
+        total + destination.basis_points as u64
+    });
+
+    if total != TOTAL_BASIS_POINTS {
+        msg!("Split shares sum to {} basis points", total);
+        return err!(CandyGuardError::InvalidPaymentSplit);
+    }
+
+    Ok(())
+}
+
+/// Returns the amount of each destination of a split. Any remainder of the division is
+/// added to the first destination so that the amounts always sum to the total.
+pub(crate) fn split_amounts(amount: u64, destinations: &[SplitDestination]) -> Result<Vec<u64>> {
+    let mut amounts = destinations
+        .iter()
+        .map(|destination| {
+            ((amount as u128 * destination.basis_points as u128) / TOTAL_BASIS_POINTS as u128)
+                as u64
+        })
+        .collect::<Vec<u64>>();
+
+    let distributed = amounts
+        .iter()
+        .try_fold(0u64, |total, amount| total.checked_add(*amount))
+        .ok_or(CandyGuardError::NumericalOverflowError)?;
+
+    if let Some(first) = amounts.first_mut() {
+        *first = first
+            .checked_add(amount - distributed)
+            .ok_or(CandyGuardError::NumericalOverflowError)?;
+    }
+
+    Ok(amounts)
+}
+
+impl Guard for SolPaymentSplit {
+    fn size() -> usize {
+        8    // lamports
+        + 4 + (MAXIMUM_SPLIT_DESTINATIONS * SplitDestination::SIZE) // destinations
+    }
+
+    fn guard_type() -> GuardType {
+        GuardType::SolPaymentSplit
+    }
+
+    fn verify(data: &CandyGuardData) -> Result<()> {
+        if let Some(sol_payment_split) = &data.default.sol_payment_split {
+            verify_split(&sol_payment_split.destinations)?;
+        }
+
+        if let Some(groups) = &data.groups {
+            for group in groups {
+                if let Some(sol_payment_split) = &group.guards.sol_payment_split {
+                    verify_split(&sol_payment_split.destinations)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Condition for SolPaymentSplit {
+    fn validate<'info>(
+        &self,
+        ctx: &mut EvaluationContext,
+        _guard_set: &GuardSet,
+        _mint_args: &[u8],
+    ) -> Result<()> {
+        let index = ctx.account_cursor;
+
+        for (offset, destination) in self.destinations.iter().enumerate() {
+            // validates that we received all required accounts
+            let destination_account = try_get_account_info(ctx.accounts.remaining, index + offset)?;
+            // validates the account information
+            assert_keys_equal(destination_account.key, &destination.address)?;
+        }
+
+        ctx.account_cursor += self.destinations.len();
+        ctx.indices.insert("sol_payment_split_index", index);
+
+        if ctx.accounts.payer.lamports() < self.lamports {
+            msg!(
+                "Require {} lamports, accounts has {} lamports",
+                self.lamports,
+                ctx.accounts.payer.lamports(),
+            );
+            return err!(CandyGuardError::NotEnoughSOL);
+        }
+
+        Ok(())
+    }
+
+    fn payment(
+        &self,
+        _ctx: &EvaluationContext,
+        _guard_set: &GuardSet,
+    ) -> Result<Option<MintPayment>> {
+        Ok(Some(MintPayment {
+            guard: GuardType::SolPaymentSplit,
+            mint: None,
+            amount: self.lamports,
+        }))
+    }
+
+    fn pre_actions<'info>(
+        &self,
+        ctx: &mut EvaluationContext,
+        _guard_set: &GuardSet,
+        _mint_args: &[u8],
+    ) -> Result<()> {
+        let index = ctx.indices["sol_payment_split_index"];
+        let amounts = split_amounts(self.lamports, &self.destinations)?;
+
+        for (offset, amount) in amounts.into_iter().enumerate() {
+            if amount == 0 {
+                continue;
+            }
+
+            let destination = try_get_account_info(ctx.accounts.remaining, index + offset)?;
+
+            invoke(
+                &system_instruction::transfer(
+                    &ctx.accounts.payer.key(),
+                    &destination.key(),
+                    amount,
+                ),
+                &[
+                    ctx.accounts.payer.to_account_info(),
+                    destination.to_account_info(),
+                    ctx.accounts.system_program.to_account_info(),
+                ],
+            )?;
+        }
+
+        ctx.payments.push(MintPayment {
+            guard: GuardType::SolPaymentSplit,
+            mint: None,
+            amount: self.lamports,
+        });
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn destinations(basis_points: &[u16]) -> Vec<SplitDestination> {
+        basis_points
+            .iter()
+            .map(|basis_points| SplitDestination {
+                address: Pubkey::new_unique(),
+                basis_points: *basis_points,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn split_exact_amounts() {
+        let destinations = destinations(&[5_000, 3_000, 2_000]);
+        assert_eq!(
+            split_amounts(1_000, &destinations).unwrap(),
+            vec![500, 300, 200]
+        );
+    }
+
+    #[test]
+    fn split_remainder_to_first_destination() {
+        let destinations = destinations(&[3_333, 3_333, 3_334]);
+        assert_eq!(split_amounts(10, &destinations).unwrap(), vec![4, 3, 3]);
+        assert_eq!(split_amounts(1, &destinations).unwrap(), vec![1, 0, 0]);
+    }
+
+    #[test]
+    fn split_large_amounts() {
+        let destinations = destinations(&[9_999, 1]);
+        let amounts = split_amounts(u64::MAX, &destinations).unwrap();
+        assert_eq!(
+            amounts.iter().map(|amount| *amount as u128).sum::<u128>(),
+            u64::MAX as u128
+        );
+        assert_eq!(amounts[1], u64::MAX / 10_000);
+    }
+
+    #[test]
+    fn verify_split_shares() {
+        assert!(verify_split(&destinations(&[10_000])).is_ok());
+        assert!(verify_split(&destinations(&[2_000; 5])).is_ok());
+        // shares must sum to 100%
+        assert!(verify_split(&destinations(&[5_000, 4_999])).is_err());
+        assert!(verify_split(&destinations(&[5_000, 5_001])).is_err());
+        // number of destinations must be between 1 and the maximum
+        assert!(verify_split(&[]).is_err());
+        assert!(verify_split(&destinations(&[1_000, 1_000, 2_000, 2_000, 2_000, 2_000])).is_err());
+    }
+}
@@ -13,8 +13,8 @@ impl Guard for StartDate {
         8 // date
     }
 
-    fn mask() -> u64 {
-        GuardType::as_mask(GuardType::StartDate)
+    fn guard_type() -> GuardType {
+        GuardType::StartDate
     }
 }
 
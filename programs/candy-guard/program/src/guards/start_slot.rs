@@ -0,0 +1,36 @@
+use crate::state::GuardType;
+
+use super::*;
+
+/// Guard that sets a specific slot for the mint to start.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct StartSlot {
+    pub slot: u64,
+}
+
+impl Guard for StartSlot {
+    fn size() -> usize {
+        8 // slot
+    }
+
+    fn guard_type() -> GuardType {
+        GuardType::StartSlot
+    }
+}
+
+impl Condition for StartSlot {
+    fn validate<'info>(
+        &self,
+        _ctx: &mut EvaluationContext,
+        _guard_set: &GuardSet,
+        _mint_args: &[u8],
+    ) -> Result<()> {
+        let clock = Clock::get()?;
+
+        if clock.slot < self.slot {
+            return err!(CandyGuardError::MintNotLive);
+        }
+
+        Ok(())
+    }
+}
@@ -16,8 +16,8 @@ impl Guard for ThirdPartySigner {
         32 // Pubkey
     }
 
-    fn mask() -> u64 {
-        GuardType::as_mask(GuardType::ThirdPartySigner)
+    fn guard_type() -> GuardType {
+        GuardType::ThirdPartySigner
     }
 }
 
@@ -34,8 +34,8 @@ impl Guard for Token2022Payment {
         + 32 // destination ata
     }
 
-    fn mask() -> u64 {
-        GuardType::as_mask(GuardType::Token2022Payment)
+    fn guard_type() -> GuardType {
+        GuardType::Token2022Payment
     }
 }
 
@@ -85,12 +85,26 @@ impl Condition for Token2022Payment {
         Ok(())
     }
 
+    fn payment(
+        &self,
+        ctx: &EvaluationContext,
+        _guard_set: &GuardSet,
+    ) -> Result<Option<MintPayment>> {
+        Ok(Some(MintPayment {
+            guard: GuardType::Token2022Payment,
+            mint: Some(self.mint),
+            amount: ctx.discounted_amount(self.amount),
+        }))
+    }
+
     fn pre_actions<'info>(
         &self,
         ctx: &mut EvaluationContext,
         _guard_set: &GuardSet,
         _mint_args: &[u8],
     ) -> Result<()> {
+        // applies the holder discount, if any
+        let amount = ctx.discounted_amount(self.amount);
         let index = ctx.indices["token2022_payment_index"];
         // the accounts have already been validated
         let token_account_info = try_get_account_info(ctx.accounts.remaining, index)?;
@@ -109,7 +123,7 @@ impl Condition for Token2022Payment {
                 destination_ata.key,
                 ctx.accounts.minter.key,
                 &[],
-                self.amount,
+                amount,
                 mint.base.decimals,
             )?,
             &[
@@ -121,6 +135,12 @@ impl Condition for Token2022Payment {
             ],
         )?;
 
+        ctx.payments.push(MintPayment {
+            guard: GuardType::Token2022Payment,
+            mint: Some(self.mint),
+            amount,
+        });
+
         Ok(())
     }
 }
@@ -22,8 +22,8 @@ impl Guard for TokenBurn {
         + 32 // mint
     }
 
-    fn mask() -> u64 {
-        GuardType::as_mask(GuardType::TokenBurn)
+    fn guard_type() -> GuardType {
+        GuardType::TokenBurn
     }
 }
 
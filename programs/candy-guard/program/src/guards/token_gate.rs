@@ -19,8 +19,8 @@ impl Guard for TokenGate {
         + 32 // mint
     }
 
-    fn mask() -> u64 {
-        GuardType::as_mask(GuardType::TokenGate)
+    fn guard_type() -> GuardType {
+        GuardType::TokenGate
     }
 }
 
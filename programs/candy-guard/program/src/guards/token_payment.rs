@@ -30,8 +30,8 @@ impl Guard for TokenPayment {
         + 32 // destination ata
     }
 
-    fn mask() -> u64 {
-        GuardType::as_mask(GuardType::TokenPayment)
+    fn guard_type() -> GuardType {
+        GuardType::TokenPayment
     }
 }
 
@@ -69,12 +69,26 @@ impl Condition for TokenPayment {
         Ok(())
     }
 
+    fn payment(
+        &self,
+        ctx: &EvaluationContext,
+        _guard_set: &GuardSet,
+    ) -> Result<Option<MintPayment>> {
+        Ok(Some(MintPayment {
+            guard: GuardType::TokenPayment,
+            mint: Some(self.mint),
+            amount: ctx.discounted_amount(self.amount),
+        }))
+    }
+
     fn pre_actions<'info>(
         &self,
         ctx: &mut EvaluationContext,
         _guard_set: &GuardSet,
         _mint_args: &[u8],
     ) -> Result<()> {
+        // applies the holder discount, if any
+        let amount = ctx.discounted_amount(self.amount);
         let index = ctx.indices["token_payment_index"];
         // the accounts have already been validated
         let token_account_info = try_get_account_info(ctx.accounts.remaining, index)?;
@@ -87,9 +101,15 @@ impl Condition for TokenPayment {
             authority: ctx.accounts.minter.to_account_info(),
             authority_signer_seeds: &[],
             token_program: spl_token_program.to_account_info(),
-            amount: self.amount,
+            amount,
         })?;
 
+        ctx.payments.push(MintPayment {
+            guard: GuardType::TokenPayment,
+            mint: Some(self.mint),
+            amount,
+        });
+
         Ok(())
     }
 }
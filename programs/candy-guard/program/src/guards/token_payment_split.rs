@@ -0,0 +1,180 @@
+use spl_token_2022::{extension::StateWithExtensions, state::Mint};
+
+use super::*;
+
+use crate::{
+    errors::CandyGuardError,
+    guards::sol_payment_split::{split_amounts, verify_split, MAXIMUM_SPLIT_DESTINATIONS},
+    state::GuardType,
+    utils::{
+        assert_is_ata, assert_is_token_2022_ata, assert_is_token_account, assert_keys_equal,
+        assert_owned_by, cmp_pubkeys, spl_token_transfer_checked, TokenTransferCheckedParams,
+    },
+};
+
+/// Guard that charges an amount in a specified spl-token as payment for the mint, splitting
+/// it across multiple destinations according to their share in basis points. The mint can
+/// belong to the legacy token program or to Token-2022; when the mint has a transfer fee, the
+/// fee is withheld from the amount received by each destination.
+///
+/// List of accounts required:
+///
+///   0. `[writable]` Token account holding the required amount.
+///   1. `[]` Mint account.
+///   2. `[]` SPL token program or SPL Token-2022 program (owner of the mint).
+///   3. `[writable]` ATA of the first destination wallet, followed by the ATA of each
+///      remaining destination (in the same order as the configuration).
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct TokenPaymentSplit {
+    pub amount: u64,
+    pub mint: Pubkey,
+    /// Destination wallets; the tokens are transferred to their ATAs.
+    pub destinations: Vec<SplitDestination>,
+}
+
+impl Guard for TokenPaymentSplit {
+    fn size() -> usize {
+        8    // amount
+        + 32 // token mint
+        + 4 + (MAXIMUM_SPLIT_DESTINATIONS * SplitDestination::SIZE) // destinations
+    }
+
+    fn guard_type() -> GuardType {
+        GuardType::TokenPaymentSplit
+    }
+
+    fn verify(data: &CandyGuardData) -> Result<()> {
+        if let Some(token_payment_split) = &data.default.token_payment_split {
+            verify_split(&token_payment_split.destinations)?;
+        }
+
+        if let Some(groups) = &data.groups {
+            for group in groups {
+                if let Some(token_payment_split) = &group.guards.token_payment_split {
+                    verify_split(&token_payment_split.destinations)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Condition for TokenPaymentSplit {
+    fn validate<'info>(
+        &self,
+        ctx: &mut EvaluationContext,
+        _guard_set: &GuardSet,
+        _mint_args: &[u8],
+    ) -> Result<()> {
+        // required accounts
+        let index = ctx.account_cursor;
+        let token_account_info = try_get_account_info(ctx.accounts.remaining, index)?;
+        let mint_info = try_get_account_info(ctx.accounts.remaining, index + 1)?;
+        let token_program = try_get_account_info(ctx.accounts.remaining, index + 2)?;
+        ctx.account_cursor += 3;
+
+        // mint and program
+        assert_keys_equal(mint_info.key, &self.mint)?;
+        assert_owned_by(mint_info, token_program.key)?;
+
+        let is_token_2022 = cmp_pubkeys(token_program.key, &spl_token_2022::ID);
+
+        if !is_token_2022 {
+            assert_keys_equal(token_program.key, &spl_token::ID)?;
+        }
+
+        // token
+        let amount = if is_token_2022 {
+            assert_owned_by(token_account_info, &spl_token_2022::ID)?;
+            let data = token_account_info.data.borrow();
+            let token_account =
+                StateWithExtensions::<spl_token_2022::state::Account>::unpack(&data)?;
+            assert_keys_equal(&token_account.base.owner, ctx.accounts.minter.key)?;
+            assert_keys_equal(&token_account.base.mint, &self.mint)?;
+
+            token_account.base.amount
+        } else {
+            assert_is_token_account(token_account_info, ctx.accounts.minter.key, &self.mint)?.amount
+        };
+
+        if amount < self.amount {
+            return err!(CandyGuardError::NotEnoughTokens);
+        }
+
+        // destinations
+        for destination in &self.destinations {
+            let destination_ata = try_get_account_info(ctx.accounts.remaining, ctx.account_cursor)?;
+            ctx.account_cursor += 1;
+
+            if is_token_2022 {
+                assert_is_token_2022_ata(destination_ata, &destination.address, &self.mint)?;
+            } else {
+                assert_is_ata(destination_ata, &destination.address, &self.mint)?;
+            }
+        }
+
+        ctx.indices.insert("token_payment_split_index", index);
+
+        Ok(())
+    }
+
+    fn payment(
+        &self,
+        _ctx: &EvaluationContext,
+        _guard_set: &GuardSet,
+    ) -> Result<Option<MintPayment>> {
+        Ok(Some(MintPayment {
+            guard: GuardType::TokenPaymentSplit,
+            mint: Some(self.mint),
+            amount: self.amount,
+        }))
+    }
+
+    fn pre_actions<'info>(
+        &self,
+        ctx: &mut EvaluationContext,
+        _guard_set: &GuardSet,
+        _mint_args: &[u8],
+    ) -> Result<()> {
+        let index = ctx.indices["token_payment_split_index"];
+        // the accounts have already been validated
+        let token_account_info = try_get_account_info(ctx.accounts.remaining, index)?;
+        let mint_info = try_get_account_info(ctx.accounts.remaining, index + 1)?;
+        let token_program = try_get_account_info(ctx.accounts.remaining, index + 2)?;
+
+        let decimals = {
+            let data = mint_info.data.borrow();
+            StateWithExtensions::<Mint>::unpack(&data)?.base.decimals
+        };
+
+        let amounts = split_amounts(self.amount, &self.destinations)?;
+
+        for (offset, amount) in amounts.into_iter().enumerate() {
+            if amount == 0 {
+                continue;
+            }
+
+            let destination_ata = try_get_account_info(ctx.accounts.remaining, index + 3 + offset)?;
+
+            spl_token_transfer_checked(TokenTransferCheckedParams {
+                source: token_account_info.to_account_info(),
+                destination: destination_ata.to_account_info(),
+                mint: mint_info.to_account_info(),
+                authority: ctx.accounts.minter.to_account_info(),
+                authority_signer_seeds: &[],
+                token_program: token_program.to_account_info(),
+                amount,
+                decimals,
+            })?;
+        }
+
+        ctx.payments.push(MintPayment {
+            guard: GuardType::TokenPaymentSplit,
+            mint: Some(self.mint),
+            amount: self.amount,
+        });
+
+        Ok(())
+    }
+}
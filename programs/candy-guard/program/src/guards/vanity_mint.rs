@@ -18,8 +18,8 @@ impl Guard for VanityMint {
         100 // MAXIMUM_LENGTH
     }
 
-    fn mask() -> u64 {
-        GuardType::as_mask(GuardType::VanityMint)
+    fn guard_type() -> GuardType {
+        GuardType::VanityMint
     }
 
     fn verify(data: &CandyGuardData) -> Result<()> {
@@ -0,0 +1,197 @@
+use std::collections::BTreeMap;
+
+use anchor_lang::prelude::*;
+use solana_program::program::{set_return_data, MAX_RETURN_DATA};
+
+use crate::{
+    guards::{CandyGuardError, EvaluationContext, MintPayment},
+    state::{CandyGuardData, GuardSet, GuardType, DATA_OFFSET},
+};
+
+use super::{mint_v1::validate, MintV1};
+
+/// Result of the evaluation of a guard.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct GuardEligibility {
+    /// Guard evaluated.
+    pub guard: GuardType,
+    /// Error code of the validation (`None` when the guard validates).
+    pub error: Option<u64>,
+    /// Amount charged by the guard, if the guard charges a payment.
+    pub payment: Option<MintPayment>,
+}
+
+/// Result of the evaluation of a guard set.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct GroupEligibility {
+    /// Label of the group (`None` for the default guard set).
+    pub label: Option<String>,
+    /// Error code of the first validation that failed (`None` when the mint is allowed).
+    pub error: Option<u64>,
+    /// Results of the enabled guards, in the order they are evaluated. The alternative of an
+    /// `AnyOf` guard that validated is listed after it.
+    pub guards: Vec<GuardEligibility>,
+}
+
+/// Evaluates the guards that apply to a mint without executing the mint. The result is
+/// set as the return data of the instruction as a borsh-serialized `Vec<GroupEligibility>`,
+/// so the instruction is intended to be simulated.
+///
+/// When a label is specified (or resolved from the active phase of a `PhaseSchedule`), only
+/// the corresponding group is evaluated; otherwise all groups are evaluated (or the default
+/// guard set when there are no groups). Guards are
+/// evaluated in the same order and with the same remaining accounts and mint arguments
+/// as `mint_v1`; a guard that fails before consuming its accounts might cause the
+/// guards evaluated after it to report errors.
+pub fn check_eligibility<'c, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, MintV1<'info>>,
+    mint_args: Vec<u8>,
+    label: Option<String>,
+) -> Result<()>
+where
+    'c: 'info,
+{
+    let account_info = ctx
+        .accounts
+        .mint_accounts(ctx.remaining_accounts)
+        .candy_guard
+        .to_account_info();
+    let account_data = account_info.data.borrow();
+    // uses the group of the active phase when no label is specified, as in `mint_v1`
+    let label = CandyGuardData::resolve_label(&account_data[DATA_OFFSET..], label)?;
+
+    // guard sets to evaluate
+    let guard_sets = if label.is_some() {
+        vec![(
+            label.clone(),
+            CandyGuardData::active_set(&account_data[DATA_OFFSET..], label)?,
+        )]
+    } else {
+        let candy_guard_data = CandyGuardData::load(&account_data[DATA_OFFSET..])?;
+
+        match &candy_guard_data.groups {
+            Some(groups) => groups
+                .iter()
+                .map(|group| {
+                    (
+                        Some(group.name().to_string()),
+                        candy_guard_data.group_set(group),
+                    )
+                })
+                .collect(),
+            None => vec![(None, Box::new(candy_guard_data.default.clone()))],
+        }
+    };
+
+    let mut results = Vec::with_capacity(guard_sets.len());
+
+    for (label, guard_set) in guard_sets {
+        // each guard set is evaluated with a new evaluation context
+        let mut evaluation_context = EvaluationContext {
+            accounts: ctx.accounts.mint_accounts(ctx.remaining_accounts),
+            account_cursor: 0,
+            args_cursor: 0,
+            indices: BTreeMap::new(),
+            plugins: vec![],
+            label: label.clone(),
+            config_index: None,
+            payments: vec![],
+            discount: None,
+        };
+
+        results.push(evaluate(
+            &mut evaluation_context,
+            label,
+            &guard_set,
+            &mint_args,
+        ));
+    }
+
+    let data = results.try_to_vec()?;
+
+    if data.len() > MAX_RETURN_DATA {
+        msg!(
+            "Eligibility result has {} bytes, maximum is {} bytes",
+            data.len(),
+            MAX_RETURN_DATA
+        );
+        return err!(CandyGuardError::EligibilityResultTooLarge);
+    }
+
+    set_return_data(&data);
+
+    Ok(())
+}
+
+/// Evaluates the enabled guards of a guard set.
+///
+/// All guards are validated before the payments are computed, since the price of a guard
+/// might depend on guards validated after it (e.g., the `HolderDiscount` guard).
+fn evaluate(
+    ctx: &mut EvaluationContext,
+    label: Option<String>,
+    guard_set: &GuardSet,
+    mint_args: &[u8],
+) -> GroupEligibility {
+    // validates the required transaction data
+    let mut error = validate(ctx).err().map(error_code);
+    let mint_guards = guard_set.mint_guards();
+    let mut errors = Vec::with_capacity(mint_guards.len());
+
+    for (_, condition) in &mint_guards {
+        let guard_error = condition
+            .validate(ctx, guard_set, mint_args)
+            .err()
+            .map(error_code);
+
+        if let Some(code) = guard_error {
+            error.get_or_insert(code);
+        }
+
+        errors.push(guard_error);
+    }
+
+    let mut guards = Vec::with_capacity(mint_guards.len());
+
+    for ((guard_type, condition), guard_error) in mint_guards.into_iter().zip(errors) {
+        // the price is reported even when the validation fails
+        let payment = condition.payment(ctx, guard_set).ok().flatten();
+
+        // the payment of an AnyOf guard is reported on the alternative that validated
+        let alternative = guard_set
+            .any_of
+            .as_ref()
+            .filter(|_| guard_type == GuardType::AnyOf)
+            .and_then(|any_of| any_of.validated_alternative(ctx));
+
+        if let Some(alternative) = alternative {
+            guards.push(GuardEligibility {
+                guard: guard_type,
+                error: guard_error,
+                payment: None,
+            });
+            guards.push(GuardEligibility {
+                guard: alternative,
+                error: None,
+                payment,
+            });
+        } else {
+            guards.push(GuardEligibility {
+                guard: guard_type,
+                error: guard_error,
+                payment,
+            });
+        }
+    }
+
+    GroupEligibility {
+        label,
+        error,
+        guards,
+    }
+}
+
+/// Returns the code of the error as reported on a failed transaction.
+fn error_code(error: Error) -> u64 {
+    u64::from(ProgramError::from(error))
+}
@@ -2,7 +2,10 @@ use std::collections::BTreeMap;
 
 use anchor_lang::{prelude::*, solana_program::sysvar, Discriminator};
 use mpl_core_candy_machine_core::CandyMachine;
-use solana_program::{instruction::Instruction, program::invoke_signed};
+use solana_program::{
+    instruction::Instruction,
+    program::{get_return_data, invoke_signed},
+};
 
 use crate::{
     guards::{CandyGuardError, EvaluationContext},
@@ -20,28 +23,8 @@ pub fn mint_v1<'c, 'info>(
 where
     'c: 'info,
 {
-    let owner_info = if let Some(owner) = ctx.accounts.owner.as_ref() {
-        owner.to_account_info()
-    } else {
-        ctx.accounts.minter.to_account_info()
-    };
-
-    let accounts = MintAccounts {
-        candy_guard: &ctx.accounts.candy_guard,
-        candy_machine: &ctx.accounts.candy_machine,
-        candy_machine_authority_pda: ctx.accounts.candy_machine_authority_pda.to_account_info(),
-        _candy_machine_program: ctx.accounts.candy_machine_program.to_account_info(),
-        collection: ctx.accounts.collection.to_account_info(),
-        asset: ctx.accounts.asset.to_account_info(),
-        payer: ctx.accounts.payer.to_account_info(),
-        minter: ctx.accounts.minter.to_account_info(),
-        owner: owner_info,
-        recent_slothashes: ctx.accounts.recent_slothashes.to_account_info(),
-        system_program: ctx.accounts.system_program.to_account_info(),
-        sysvar_instructions: ctx.accounts.sysvar_instructions.to_account_info(),
-        mpl_core_program: ctx.accounts.mpl_core_program.to_account_info(),
-        remaining: ctx.remaining_accounts,
-    };
+    let accounts = ctx.accounts.mint_accounts(ctx.remaining_accounts);
+    let label = CandyGuardData::normalize_label(label);
 
     // evaluation context for this transaction
     let mut ctx = EvaluationContext {
@@ -50,6 +33,10 @@ where
         args_cursor: 0,
         indices: BTreeMap::new(),
         plugins: vec![],
+        label: label.clone(),
+        config_index: None,
+        payments: vec![],
+        discount: None,
     };
 
     process_mint(&mut ctx, mint_args, label)
@@ -65,32 +52,36 @@ where
 {
     let account_info = ctx.accounts.candy_guard.to_account_info();
     let account_data = account_info.data.borrow();
+    // uses the group of the active phase when no label is specified
+    let label = CandyGuardData::resolve_label(&account_data[DATA_OFFSET..], label)?;
+    ctx.label.clone_from(&label);
     // loads the active guard set
-    let guard_set = match CandyGuardData::active_set(&account_data[DATA_OFFSET..], label) {
-        Ok(guard_set) => guard_set,
+    let guard_set = match CandyGuardData::active_set(&account_data[DATA_OFFSET..], label.clone()) {
+        Ok(guard_set) => {
+            // validates enabled guards (any error at this point is subject to bot tax)
+            if let Err(error) = validate_guards(ctx, &guard_set, &mint_args) {
+                return process_error(ctx, &guard_set, error);
+            }
+            guard_set
+        }
         Err(error) => {
             // load the default guard set to look for the bot_tax since errors only occur
             // when trying to load guard set groups
-            let guard_set = CandyGuardData::load(&account_data[DATA_OFFSET..])?;
-            return process_error(ctx, &guard_set.default, error);
+            let candy_guard_data = CandyGuardData::load(&account_data[DATA_OFFSET..])?;
+
+            if label.is_none() && candy_guard_data.default.auto_group.is_some() {
+                // no bot tax for the groups that fail, only when none of them validates
+                match select_group(ctx, &candy_guard_data, &mint_args) {
+                    Ok(guard_set) => guard_set,
+                    Err(error) => return process_error(ctx, &candy_guard_data.default, error),
+                }
+            } else {
+                return process_error(ctx, &candy_guard_data.default, error);
+            }
         }
     };
 
-    let conditions = guard_set.enabled_conditions();
-
-    // validates the required transaction data
-
-    if let Err(error) = validate(ctx) {
-        return process_error(ctx, &guard_set, error);
-    }
-
-    // validates enabled guards (any error at this point is subject to bot tax)
-
-    for condition in &conditions {
-        if let Err(error) = condition.validate(ctx, &guard_set, &mint_args) {
-            return process_error(ctx, &guard_set, error);
-        }
-    }
+    let conditions = guard_set.mint_conditions();
 
     // after this point, errors might occur, which will cause the transaction to fail
     // no bot tax from this point since the actions must be reverted in case of an error
@@ -100,6 +91,7 @@ where
     }
 
     cpi_mint(ctx)?;
+    ctx.config_index = minted_config_index();
 
     for condition in &conditions {
         condition.post_actions(ctx, &guard_set, &mint_args)?;
@@ -108,8 +100,86 @@ where
     Ok(())
 }
 
+/// Validates the transaction data and the enabled guards of a guard set.
+fn validate_guards(
+    ctx: &mut EvaluationContext,
+    guard_set: &GuardSet,
+    mint_args: &[u8],
+) -> Result<()> {
+    // validates the required transaction data
+    validate(ctx)?;
+
+    for condition in guard_set.mint_conditions() {
+        condition.validate(ctx, guard_set, mint_args)?;
+    }
+
+    Ok(())
+}
+
+/// Selects the first group (in the order declared) whose guards validate. Each group is
+/// validated with its label set in the evaluation context; the evaluation state is restored
+/// after each group that fails, so every group is evaluated from the same remaining accounts
+/// and mint arguments positions.
+///
+/// When no group validates and at least one of them failed because of the rate limit, the
+/// `RateLimitExceeded` error is returned instead of `NoEligibleGroup`, so the transaction
+/// can be retried without being charged the bot tax.
+fn select_group(
+    ctx: &mut EvaluationContext,
+    candy_guard_data: &CandyGuardData,
+    mint_args: &[u8],
+) -> Result<Box<GuardSet>> {
+    // state to restore when a group fails
+    let account_cursor = ctx.account_cursor;
+    let args_cursor = ctx.args_cursor;
+    let indices = ctx.indices.clone();
+    let plugins = ctx.plugins.len();
+    let discount = ctx.discount;
+    let label = ctx.label.clone();
+    // whether a group failed because of the rate limit
+    let mut rate_limited = false;
+
+    for group in candy_guard_data.groups.iter().flatten() {
+        let guard_set = candy_guard_data.group_set(group);
+        // guards are validated with the label of the group being evaluated
+        ctx.label = Some(group.name().to_string());
+
+        match validate_guards(ctx, &guard_set, mint_args) {
+            Ok(()) => {
+                msg!("Group {} selected", group.name());
+                return Ok(guard_set);
+            }
+            Err(error) => {
+                msg!("Group {} failed: {}", group.name(), error);
+
+                if error == CandyGuardError::RateLimitExceeded.into() {
+                    rate_limited = true;
+                }
+
+                ctx.account_cursor = account_cursor;
+                ctx.args_cursor = args_cursor;
+                ctx.indices = indices.clone();
+                ctx.plugins.truncate(plugins);
+                ctx.discount = discount;
+                ctx.label.clone_from(&label);
+            }
+        }
+    }
+
+    if rate_limited {
+        err!(CandyGuardError::RateLimitExceeded)
+    } else {
+        err!(CandyGuardError::NoEligibleGroup)
+    }
+}
+
 // Handles errors + bot tax charge.
 fn process_error(ctx: &EvaluationContext, guard_set: &GuardSet, error: Error) -> Result<()> {
+    // retryable errors are not subject to bot tax
+    if error == CandyGuardError::RateLimitExceeded.into() {
+        return Err(error);
+    }
+
     if let Some(bot_tax) = &guard_set.bot_tax {
         bot_tax.punish_bots(ctx, error)?;
         Ok(())
@@ -119,7 +189,7 @@ fn process_error(ctx: &EvaluationContext, guard_set: &GuardSet, error: Error) ->
 }
 
 /// Performs a validation of the transaction before executing the guards.
-fn validate(ctx: &EvaluationContext) -> Result<()> {
+pub(crate) fn validate(ctx: &EvaluationContext) -> Result<()> {
     if !cmp_pubkeys(
         &ctx.accounts.collection.key(),
         &ctx.accounts.candy_machine.collection_mint,
@@ -191,6 +261,48 @@ fn cpi_mint(ctx: &EvaluationContext) -> Result<()> {
     Ok(())
 }
 
+/// Returns the index of the config line minted, which the candy machine sets as
+/// the return data of the mint instruction.
+fn minted_config_index() -> Option<u32> {
+    match get_return_data() {
+        Some((program_id, data)) if cmp_pubkeys(&program_id, &mpl_core_candy_machine_core::ID) => {
+            data.try_into().ok().map(u32::from_le_bytes)
+        }
+        _ => None,
+    }
+}
+
+impl<'info> MintV1<'info> {
+    /// Returns the accounts used to evaluate the guards.
+    pub(crate) fn mint_accounts<'b, 'c>(
+        &'b self,
+        remaining: &'c [AccountInfo<'info>],
+    ) -> MintAccounts<'b, 'c, 'info> {
+        let owner_info = if let Some(owner) = self.owner.as_ref() {
+            owner.to_account_info()
+        } else {
+            self.minter.to_account_info()
+        };
+
+        MintAccounts {
+            candy_guard: &self.candy_guard,
+            candy_machine: &self.candy_machine,
+            candy_machine_authority_pda: self.candy_machine_authority_pda.to_account_info(),
+            _candy_machine_program: self.candy_machine_program.to_account_info(),
+            collection: self.collection.to_account_info(),
+            asset: self.asset.to_account_info(),
+            payer: self.payer.to_account_info(),
+            minter: self.minter.to_account_info(),
+            owner: owner_info,
+            recent_slothashes: self.recent_slothashes.to_account_info(),
+            system_program: self.system_program.to_account_info(),
+            sysvar_instructions: self.sysvar_instructions.to_account_info(),
+            mpl_core_program: self.mpl_core_program.to_account_info(),
+            remaining,
+        }
+    }
+}
+
 /// Mint an NFT.
 #[derive(Accounts)]
 pub struct MintV1<'info> {
@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+pub use check_eligibility::*;
 pub use initialize::*;
 pub use mint_v1::*;
 use mpl_core_candy_machine_core::CandyMachine;
@@ -11,6 +12,7 @@ pub use wrap::*;
 
 use crate::state::CandyGuard;
 
+pub mod check_eligibility;
 pub mod initialize;
 pub mod mint_v1;
 pub mod route;
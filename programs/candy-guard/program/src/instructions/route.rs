@@ -122,21 +122,24 @@ where
 
     // retrieve the active guard set
 
-    let guard_set = if let Some(account) = &candy_guard_account {
+    let (guard_set, label) = if let Some(account) = &candy_guard_account {
         let account_info = account.to_account_info();
         let data = account_info.data.borrow();
+        // uses the group of the active phase when no label is specified
+        let label = CandyGuardData::resolve_label(&data[DATA_OFFSET..], label)?;
         // loads the active guard set
-        let guard_set = CandyGuardData::active_set(&data[DATA_OFFSET..], label)?;
+        let guard_set = CandyGuardData::active_set(&data[DATA_OFFSET..], label.clone())?;
 
-        Some(guard_set)
+        (Some(guard_set), label)
     } else {
-        None
+        (None, CandyGuardData::normalize_label(label))
     };
 
     let route_context = RouteContext {
         candy_guard: candy_guard_account,
         candy_machine: candy_machine_account,
         guard_set,
+        label,
     };
 
     GuardSet::route(ctx, route_context, args)
@@ -173,4 +176,6 @@ pub struct RouteContext<'info> {
     pub candy_machine: Option<Box<Account<'info, CandyMachine>>>,
     // The active guard set.
     pub guard_set: Option<Box<GuardSet>>,
+    /// Label of the group of the active guard set, if any.
+    pub label: Option<String>,
 }
@@ -16,6 +16,19 @@ declare_id!("CMAGAKJ67e9hRZgfC5SFTbZH8MgEmtqazKXjmkaJjWTJ");
 pub mod candy_guard {
     use super::*;
 
+    /// Evaluate the guards of a mint without minting, setting the result as the return
+    /// data of the transaction.
+    pub fn check_eligibility<'c, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, MintV1<'info>>,
+        mint_args: Vec<u8>,
+        label: Option<String>,
+    ) -> Result<()>
+    where
+        'c: 'info,
+    {
+        instructions::check_eligibility(ctx, mint_args, label)
+    }
+
     /// Create a new candy guard account.
     pub fn initialize(ctx: Context<Initialize>, data: Vec<u8>) -> Result<()> {
         instructions::initialize(ctx, data)
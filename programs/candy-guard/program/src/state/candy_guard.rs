@@ -3,7 +3,7 @@ use std::collections::HashSet;
 use anchor_lang::{prelude::*, AnchorDeserialize};
 use solana_program::program_memory::sol_memcmp;
 
-use crate::{errors::CandyGuardError, guards::*, utils::fixed_length_string};
+use crate::{errors::CandyGuardError, guards::*, state::Features, utils::fixed_length_string};
 use mpl_core_candy_guard_derive::GuardSet;
 
 // Bytes offset for the start of the data section:
@@ -14,7 +14,31 @@ use mpl_core_candy_guard_derive::GuardSet;
 pub const DATA_OFFSET: usize = 8 + 32 + 1 + 32;
 
 // Maximim group label size.
-pub const MAX_LABEL_SIZE: usize = 6;
+pub const MAX_LABEL_SIZE: usize = 32;
+
+// Group label size in the legacy layout (labels are padded with null characters).
+pub const LEGACY_LABEL_SIZE: usize = 6;
+
+// Marker at the start of the guards data indicating a versioned layout. The legacy layout
+// starts with the feature flags of the default guard set, which only match the marker when
+// the first 63 guards are enabled (in which case the data is saved in a versioned layout).
+const VERSIONED_LAYOUT_MARKER: u64 = u64::MAX;
+
+// Size of the versioned layout header:
+//     8 (marker)
+//  +  1 (version)
+const VERSIONED_HEADER_SIZE: usize = 8 + 1;
+
+// Version of the legacy layout (no header).
+const LEGACY_LAYOUT_VERSION: u8 = 0;
+
+// Current version of the guards data layout:
+//   1) length-prefixed group labels up to `MAX_LABEL_SIZE` bytes
+//   2) default guards disabled by each group
+pub const LAYOUT_VERSION: u8 = 2;
+
+// First layout version including the default guards disabled by each group.
+const DISABLED_GUARDS_VERSION: u8 = 2;
 
 // Seed value for PDA.
 pub const SEED: &[u8] = b"candy_guard";
@@ -63,6 +87,28 @@ pub struct CandyGuard {
     // 27) asset mint limit
     // 28) asset burn multi
     // 29) asset payment multi
+    // 30) asset gate
+    // 31) vanity mint
+    // 32) receipt
+    // 33) dutch auction
+    // 34) bonding curve
+    // 35) sol payment split
+    // 36) token payment split
+    // 37) any of
+    // 38) auto group
+    // 39) phase schedule
+    // 40) external guard
+    // 41) signed voucher
+    // 42) allow list allotment
+    // 43) cooldown
+    // 44) rate limit
+    // 45) start slot
+    // 46) end slot
+    // 47) recurring window
+    // 48) sol gate
+    // 49) asset trait gate
+    // 50) holder discount
+    // 51) gate usage limit
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
@@ -77,6 +123,15 @@ pub struct CandyGuardData {
 pub struct Group {
     pub label: String,
     pub guards: GuardSet,
+    /// Guards of the default guard set that do not apply to the group.
+    pub disabled: Vec<GuardType>,
+}
+
+impl Group {
+    /// Returns the label of the group without the null padding of the legacy layout.
+    pub fn name(&self) -> &str {
+        self.label.trim_end_matches('\0')
+    }
 }
 
 /// The set of guards available.
@@ -144,10 +199,50 @@ pub struct GuardSet {
     pub asset_gate: Option<AssetGate>,
     /// Vanity Mint (the address of the new asset must match a pattern).
     pub vanity_mint: Option<VanityMint>,
+    /// Receipt guard (records the details of each mint in a PDA).
+    pub receipt: Option<Receipt>,
+    /// Dutch auction guard (descending price in lamports with optional rebates).
+    pub dutch_auction: Option<DutchAuction>,
+    /// Bonding curve guard (price increases with the number of items redeemed).
+    pub bonding_curve: Option<BondingCurve>,
+    /// Sol payment split guard (charges lamports split across multiple destinations).
+    pub sol_payment_split: Option<SolPaymentSplit>,
+    /// Token payment split guard (charges tokens split across multiple destinations).
+    pub token_payment_split: Option<TokenPaymentSplit>,
+    /// Any of (evaluates a list of guards as alternatives).
+    pub any_of: Option<AnyOf>,
+    /// Auto group (selects the first eligible group when no label is specified).
+    pub auto_group: Option<AutoGroup>,
+    /// Phase schedule (time ranges mapped to groups).
+    pub phase_schedule: Option<PhaseSchedule>,
+    /// External guard (validation delegated to an external program).
+    pub external_guard: Option<ExternalGuard>,
+    /// Signed voucher guard (requires a voucher signed by a configured key).
+    pub signed_voucher: Option<SignedVoucher>,
+    /// Allow list allotment guard (allowed addresses with individual allotments and prices).
+    pub allow_list_allotment: Option<AllowListAllotment>,
+    /// Cooldown guard (minimum interval between the mints of a wallet).
+    pub cooldown: Option<Cooldown>,
+    /// Rate limit guard (maximum number of mints within a time window).
+    pub rate_limit: Option<RateLimit>,
+    /// Start slot guard (controls when minting is allowed based on the slot).
+    pub start_slot: Option<StartSlot>,
+    /// End slot guard (set an end slot to stop the mint).
+    pub end_slot: Option<EndSlot>,
+    /// Recurring window guard (mint allowed during windows that repeat on a schedule).
+    pub recurring_window: Option<RecurringWindow>,
+    /// Sol gate guard (minimum balance and wallet registration age of the minter).
+    pub sol_gate: Option<SolGate>,
+    /// Asset trait gate guard (restrict access to holders of assets with specific traits).
+    pub asset_trait_gate: Option<AssetTraitGate>,
+    /// Holder discount guard (discounted payments for holders of a collection).
+    pub holder_discount: Option<HolderDiscount>,
+    /// Gate usage limit guard (limits the number of mints per gating asset).
+    pub gate_usage_limit: Option<GateUsageLimit>,
 }
 
 /// Available guard types.
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
 pub enum GuardType {
     BotTax,
     SolPayment,
@@ -180,21 +275,77 @@ pub enum GuardType {
     AssetPaymentMulti,
     AssetGate,
     VanityMint,
+    Receipt,
+    DutchAuction,
+    BondingCurve,
+    SolPaymentSplit,
+    TokenPaymentSplit,
+    AnyOf,
+    AutoGroup,
+    PhaseSchedule,
+    ExternalGuard,
+    SignedVoucher,
+    AllowListAllotment,
+    Cooldown,
+    RateLimit,
+    StartSlot,
+    EndSlot,
+    RecurringWindow,
+    SolGate,
+    AssetTraitGate,
+    HolderDiscount,
+    GateUsageLimit,
+}
+
+impl GuardSet {
+    /// Returns the conditions to evaluate on a mint. Guards listed as alternatives of the
+    /// `AnyOf` guard are not included, since they are evaluated by the `AnyOf` guard.
+    pub fn mint_conditions(&self) -> Vec<&dyn Condition> {
+        self.mint_guards()
+            .into_iter()
+            .map(|(_, condition)| condition)
+            .collect()
+    }
+
+    /// Returns the guard types and conditions to evaluate on a mint.
+    pub fn mint_guards(&self) -> Vec<(GuardType, &dyn Condition)> {
+        self.enabled_guards()
+            .into_iter()
+            .filter(|(guard_type, _)| {
+                !self
+                    .any_of
+                    .as_ref()
+                    .is_some_and(|any_of| any_of.contains(guard_type))
+            })
+            .collect()
+    }
 }
 
 impl GuardType {
-    pub fn as_mask(guard_type: GuardType) -> u64 {
-        0b1u64 << (guard_type as u8)
+    /// Returns the index of the feature flag of the guard type.
+    pub fn index(&self) -> usize {
+        self.clone() as usize
     }
 }
 
 impl CandyGuardData {
     /// Serialize the candy guard data into the specified data array.
+    ///
+    /// The data is serialized using the legacy layout when possible, so that existing
+    /// clients can read it; otherwise it uses the current versioned layout.
     pub fn save(&self, data: &mut [u8]) -> Result<()> {
+        let version = self.layout_version();
         let mut cursor = 0;
 
+        // stores the layout header
+        if version > LEGACY_LAYOUT_VERSION {
+            data[0..8].copy_from_slice(&u64::to_le_bytes(VERSIONED_LAYOUT_MARKER));
+            data[8] = version;
+            cursor += VERSIONED_HEADER_SIZE;
+        }
+
         // saves the 'default' guard set
-        let _ = self.default.to_data(data)?;
+        let _ = self.default.to_data(&mut data[cursor..])?;
         cursor += self.default.size();
 
         // stores the number of 'groups' guard set
@@ -210,9 +361,15 @@ impl CandyGuardData {
         if let Some(groups) = &self.groups {
             for group in groups {
                 // label
-                let label = fixed_length_string(group.label.to_string(), MAX_LABEL_SIZE)?;
-                data[cursor..cursor + MAX_LABEL_SIZE].copy_from_slice(label.as_bytes());
-                cursor += MAX_LABEL_SIZE;
+                let label = serialize_label(&group.label, version)?;
+                data[cursor..cursor + label.len()].copy_from_slice(&label);
+                cursor += label.len();
+                // disabled default guards
+                if version >= DISABLED_GUARDS_VERSION {
+                    let disabled = group.disabled.try_to_vec()?;
+                    data[cursor..cursor + disabled.len()].copy_from_slice(&disabled);
+                    cursor += disabled.len();
+                }
                 // guard set
                 let _ = group.guards.to_data(&mut data[cursor..])?;
                 cursor += group.guards.size();
@@ -225,8 +382,10 @@ impl CandyGuardData {
     /// Deserializes the guards. Only attempts the deserialization of individuals guards
     /// if the data slice is large enough.
     pub fn load(data: &[u8]) -> Result<Box<Self>> {
-        let (default, _) = GuardSet::from_data(data)?;
-        let mut cursor = default.size();
+        let (version, mut cursor) = read_header(data)?;
+
+        let (default, _) = GuardSet::from_data(&data[cursor..])?;
+        cursor += default.size();
 
         let group_counter = u32::from_le_bytes(*arrayref::array_ref![data, cursor, 4]);
         cursor += 4;
@@ -235,13 +394,17 @@ impl CandyGuardData {
             let mut groups = Vec::with_capacity(group_counter as usize);
 
             for _i in 0..group_counter {
-                let slice: &[u8] = &data[cursor..cursor + MAX_LABEL_SIZE];
-                let label = String::from_utf8(slice.to_vec())
-                    .map_err(|_| CandyGuardError::DeserializationError)?;
-                cursor += MAX_LABEL_SIZE;
+                let (label, label_size) = deserialize_label(&data[cursor..], version)?;
+                cursor += label_size;
+                let (disabled, disabled_size) = deserialize_disabled(&data[cursor..], version)?;
+                cursor += disabled_size;
                 let (guards, _) = GuardSet::from_data(&data[cursor..])?;
                 cursor += guards.size();
-                groups.push(Group { label, guards });
+                groups.push(Group {
+                    label,
+                    guards,
+                    disabled,
+                });
             }
 
             Some(groups)
@@ -259,9 +422,11 @@ impl CandyGuardData {
     }
 
     pub fn active_set(data: &[u8], label: Option<String>) -> Result<Box<GuardSet>> {
+        let (version, mut cursor) = read_header(data)?;
+
         // default guard set
-        let mut default: Box<GuardSet> = Box::new(GuardSet::from_data(data)?.0);
-        let mut cursor = (*default).size();
+        let mut default: Box<GuardSet> = Box::new(GuardSet::from_data(&data[cursor..])?.0);
+        cursor += (*default).size();
 
         // number of groups
         let group_counter = u32::from_le_bytes(*arrayref::array_ref![data, cursor, 4]);
@@ -269,20 +434,32 @@ impl CandyGuardData {
 
         if group_counter > 0 {
             if let Some(label) = label {
-                let group_label = fixed_length_string(label, MAX_LABEL_SIZE)?;
-                let label_slice = group_label.as_bytes();
+                let label_slice = serialize_label(&label, version)?;
                 // retrieves the selected group
                 for _i in 0..group_counter {
-                    if sol_memcmp(label_slice, &data[cursor..], label_slice.len()) == 0 {
-                        cursor += MAX_LABEL_SIZE;
+                    let label_size = serialized_label_size(&data[cursor..], version)?;
+
+                    if label_size == label_slice.len()
+                        && sol_memcmp(&label_slice, &data[cursor..], label_size) == 0
+                    {
+                        cursor += label_size;
+                        let (disabled, disabled_size) =
+                            deserialize_disabled(&data[cursor..], version)?;
+                        cursor += disabled_size;
                         let (guards, _) = GuardSet::from_data(&data[cursor..])?;
+                        // removes the default guards disabled by the group
+                        for guard_type in &disabled {
+                            default.disable(guard_type);
+                        }
                         default.merge(guards);
                         // we found our group
                         return Ok(default);
                     } else {
-                        cursor += MAX_LABEL_SIZE;
-                        let features = u64::from_le_bytes(*arrayref::array_ref![data, cursor, 8]);
-                        cursor += GuardSet::bytes_count(features);
+                        cursor += label_size;
+                        let (_, disabled_size) = deserialize_disabled(&data[cursor..], version)?;
+                        cursor += disabled_size;
+                        let features = Features::from_data(&data[cursor..])?;
+                        cursor += GuardSet::bytes_count(&features);
                     }
                 }
                 return err!(CandyGuardError::GroupNotFound);
@@ -296,18 +473,110 @@ impl CandyGuardData {
         Ok(default)
     }
 
+    /// Returns the label without its trailing null characters, so that a padded label
+    /// selects the same group, phase and label-derived PDAs as the unpadded label.
+    pub fn normalize_label(label: Option<String>) -> Option<String> {
+        label.map(|label| label.trim_end_matches('\0').to_string())
+    }
+
+    /// Returns the label of the group of the active phase when no label is specified and
+    /// the default guard set has a phase schedule; otherwise the label is returned normalized.
+    pub fn resolve_label(data: &[u8], label: Option<String>) -> Result<Option<String>> {
+        if label.is_some() {
+            return Ok(Self::normalize_label(label));
+        }
+
+        let (_, cursor) = read_header(data)?;
+        let (default, _) = GuardSet::from_data(&data[cursor..])?;
+
+        if let Some(phase_schedule) = &default.phase_schedule {
+            let timestamp = Clock::get()?.unix_timestamp;
+
+            if let Some(phase) = phase_schedule.active_phase(timestamp) {
+                msg!("Phase {} active", phase.label);
+                return Ok(Some(phase.label.clone()));
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Returns the version of the layout used to serialize the data.
+    pub fn layout_version(&self) -> u8 {
+        let legacy_groups = self
+            .groups
+            .iter()
+            .flatten()
+            .all(|group| group.name().len() <= LEGACY_LABEL_SIZE && group.disabled.is_empty());
+
+        if legacy_groups && self.default.features().leading_word() != VERSIONED_LAYOUT_MARKER {
+            LEGACY_LAYOUT_VERSION
+        } else {
+            LAYOUT_VERSION
+        }
+    }
+
+    /// Returns the guard set of a group, which is the default guard set without the guards
+    /// disabled by the group, merged with the guards of the group.
+    pub fn group_set(&self, group: &Group) -> Box<GuardSet> {
+        let mut guard_set = Box::new(self.default.clone());
+
+        for guard_type in &group.disabled {
+            guard_set.disable(guard_type);
+        }
+
+        guard_set.merge(group.guards.clone());
+        guard_set
+    }
+
+    /// Validates that the guards disabled by a group are enabled on the default guard
+    /// set, are not repeated and are not enabled on the group. The `PhaseSchedule` and
+    /// `AutoGroup` guards cannot be disabled, since they select the group to use.
+    fn verify_disabled(&self, group: &Group) -> Result<()> {
+        let default = self.default.features();
+        let guards = group.guards.features();
+
+        for (index, guard_type) in group.disabled.iter().enumerate() {
+            if matches!(guard_type, GuardType::PhaseSchedule | GuardType::AutoGroup)
+                || !default.is_enabled(guard_type.index())
+                || guards.is_enabled(guard_type.index())
+                || group.disabled[..index].contains(guard_type)
+            {
+                msg!(
+                    "Invalid disabled guard {:?} in group {}",
+                    guard_type,
+                    group.name()
+                );
+                return err!(CandyGuardError::InvalidDisabledGuard);
+            }
+        }
+
+        Ok(())
+    }
+
     pub fn account_size(&self) -> usize {
         DATA_OFFSET + self.size()
     }
 
     pub fn size(&self) -> usize {
-        let mut size = self.default.size();
+        let version = self.layout_version();
+
+        let mut size = if version > LEGACY_LAYOUT_VERSION {
+            VERSIONED_HEADER_SIZE
+        } else {
+            0
+        };
+        size += self.default.size();
         size += 4; // u32 (number of groups)
 
         if let Some(groups) = &self.groups {
             size += groups
                 .iter()
-                .map(|group| MAX_LABEL_SIZE + group.guards.size())
+                .map(|group| {
+                    label_size(&group.label, version)
+                        + disabled_size(&group.disabled, version)
+                        + group.guards.size()
+                })
                 .sum::<usize>();
         }
 
@@ -320,15 +589,310 @@ impl CandyGuardData {
 
         if let Some(groups) = &self.groups {
             for group in groups {
-                if labels.contains(&group.label) {
+                verify_label(group.name())?;
+                self.verify_disabled(group)?;
+                verify_slots(&self.group_set(group))?;
+
+                if labels.contains(group.name()) {
                     return err!(CandyGuardError::DuplicatedGroupLabel);
                 }
 
-                labels.insert(group.label.clone());
+                labels.insert(group.name());
             }
         }
 
+        verify_slots(&self.default)?;
+
         // verify the guards configuration
         GuardSet::verify(self)
     }
 }
+
+/// Reads the layout header of the guards data, returning the layout version and the
+/// number of bytes of the header.
+fn read_header(data: &[u8]) -> Result<(u8, usize)> {
+    if data.len() < 8 {
+        return err!(CandyGuardError::DeserializationError);
+    }
+
+    let marker = u64::from_le_bytes(*arrayref::array_ref![data, 0, 8]);
+
+    if marker != VERSIONED_LAYOUT_MARKER {
+        return Ok((LEGACY_LAYOUT_VERSION, 0));
+    }
+
+    match data.get(8) {
+        Some(version) if (1..=LAYOUT_VERSION).contains(version) => {
+            Ok((*version, VERSIONED_HEADER_SIZE))
+        }
+        _ => err!(CandyGuardError::DeserializationError),
+    }
+}
+
+/// Validates that the end slot of a guard set is after its start slot.
+///
+/// Dates are not validated to keep existing guard sets (e.g., an end date before the start
+/// date to disable minting) valid.
+fn verify_slots(guard_set: &GuardSet) -> Result<()> {
+    if let (Some(start_slot), Some(end_slot)) = (&guard_set.start_slot, &guard_set.end_slot) {
+        if end_slot.slot <= start_slot.slot {
+            msg!(
+                "End slot {} before start slot {}",
+                end_slot.slot,
+                start_slot.slot
+            );
+            return err!(CandyGuardError::InvalidSlotRange);
+        }
+    }
+
+    Ok(())
+}
+
+/// Validates that a group label is not empty, fits in `MAX_LABEL_SIZE` bytes and does not
+/// contain control characters.
+fn verify_label(label: &str) -> Result<()> {
+    if label.is_empty() || label.len() > MAX_LABEL_SIZE || label.chars().any(char::is_control) {
+        msg!("Invalid group label: {:?}", label);
+        return err!(CandyGuardError::InvalidGroupLabel);
+    }
+
+    Ok(())
+}
+
+/// Returns the number of bytes of a serialized label.
+fn label_size(label: &str, version: u8) -> usize {
+    if version == LEGACY_LAYOUT_VERSION {
+        LEGACY_LABEL_SIZE
+    } else {
+        1 + label.trim_end_matches('\0').len() // u8 (length) + label
+    }
+}
+
+/// Serializes a label: labels are padded to `LEGACY_LABEL_SIZE` bytes in the legacy
+/// layout and length-prefixed (without padding) in the versioned layout.
+fn serialize_label(label: &str, version: u8) -> Result<Vec<u8>> {
+    if version == LEGACY_LAYOUT_VERSION {
+        Ok(fixed_length_string(label.to_string(), LEGACY_LABEL_SIZE)?.into_bytes())
+    } else {
+        let label = label.trim_end_matches('\0');
+
+        if label.len() > MAX_LABEL_SIZE {
+            return err!(CandyGuardError::ExceededLength);
+        }
+
+        let mut serialized = Vec::with_capacity(1 + label.len());
+        serialized.push(label.len() as u8);
+        serialized.extend_from_slice(label.as_bytes());
+
+        Ok(serialized)
+    }
+}
+
+/// Returns the number of bytes of the serialized label at the start of the data slice.
+fn serialized_label_size(data: &[u8], version: u8) -> Result<usize> {
+    let size = if version == LEGACY_LAYOUT_VERSION {
+        LEGACY_LABEL_SIZE
+    } else {
+        1 + *data.first().ok_or(CandyGuardError::DeserializationError)? as usize
+    };
+
+    if size > data.len() {
+        return err!(CandyGuardError::DeserializationError);
+    }
+
+    Ok(size)
+}
+
+/// Deserializes the label at the start of the data slice, returning the label and the
+/// number of bytes read. Legacy labels are returned with their null padding (see
+/// `Group::name`).
+fn deserialize_label(data: &[u8], version: u8) -> Result<(String, usize)> {
+    let size = serialized_label_size(data, version)?;
+
+    let label = if version == LEGACY_LAYOUT_VERSION {
+        String::from_utf8(data[..size].to_vec())
+            .map_err(|_| CandyGuardError::DeserializationError)?
+    } else {
+        String::from_utf8(data[1..size].to_vec())
+            .map_err(|_| CandyGuardError::DeserializationError)?
+    };
+
+    Ok((label, size))
+}
+
+/// Returns the number of bytes of the serialized list of disabled guards.
+fn disabled_size(disabled: &[GuardType], version: u8) -> usize {
+    if version >= DISABLED_GUARDS_VERSION {
+        4 + disabled.len() // u32 (length) + guard types
+    } else {
+        0
+    }
+}
+
+/// Deserializes the list of disabled guards at the start of the data slice, returning the
+/// list and the number of bytes read.
+fn deserialize_disabled(data: &[u8], version: u8) -> Result<(Vec<GuardType>, usize)> {
+    if version < DISABLED_GUARDS_VERSION {
+        return Ok((vec![], 0));
+    }
+
+    let mut slice = data;
+    let disabled = Vec::<GuardType>::deserialize(&mut slice)?;
+
+    Ok((disabled, data.len() - slice.len()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn empty_set() -> GuardSet {
+        GuardSet::from_data(&[0u8; 8]).unwrap().0
+    }
+
+    fn default_set() -> GuardSet {
+        let mut guard_set = empty_set();
+        guard_set.start_date = Some(StartDate { date: 1 });
+        guard_set
+    }
+
+    fn group(label: &str, disabled: Vec<GuardType>) -> Group {
+        let mut guards = empty_set();
+        guards.end_date = Some(EndDate { date: 2 });
+
+        Group {
+            label: label.to_string(),
+            guards,
+            disabled,
+        }
+    }
+
+    fn save(data: &CandyGuardData) -> Vec<u8> {
+        let mut bytes = vec![0u8; data.size()];
+        data.save(&mut bytes).unwrap();
+        bytes
+    }
+
+    #[test]
+    fn load_legacy_account() {
+        // default guard set without guards, one group with a padded 6-byte label
+        let mut bytes = vec![];
+        bytes.extend_from_slice(&0u64.to_le_bytes());
+        bytes.extend_from_slice(&1u32.to_le_bytes());
+        bytes.extend_from_slice(b"vip\0\0\0");
+        bytes.extend_from_slice(&0u64.to_le_bytes());
+
+        let data = CandyGuardData::load(&bytes).unwrap();
+        let groups = data.groups.as_ref().unwrap();
+
+        assert_eq!(groups[0].label, "vip\0\0\0");
+        assert_eq!(groups[0].name(), "vip");
+        assert!(groups[0].disabled.is_empty());
+        assert_eq!(data.layout_version(), LEGACY_LAYOUT_VERSION);
+        // saved back without changes
+        assert_eq!(save(&data), bytes);
+    }
+
+    #[test]
+    fn save_short_labels_in_legacy_layout() {
+        let data = CandyGuardData {
+            default: default_set(),
+            groups: Some(vec![group("vip", vec![])]),
+        };
+
+        let bytes = save(&data);
+        assert_ne!(&bytes[0..8], &VERSIONED_LAYOUT_MARKER.to_le_bytes());
+
+        let loaded = CandyGuardData::load(&bytes).unwrap();
+        assert_eq!(loaded.groups.unwrap()[0].name(), "vip");
+        assert!(CandyGuardData::active_set(&bytes, Some("vip".to_string()))
+            .unwrap()
+            .end_date
+            .is_some());
+    }
+
+    #[test]
+    fn save_long_label_in_versioned_layout() {
+        let label = "early-supporters-of-the-project";
+        let data = CandyGuardData {
+            default: default_set(),
+            groups: Some(vec![group("vip", vec![]), group(label, vec![])]),
+        };
+
+        let bytes = save(&data);
+        assert_eq!(&bytes[0..8], &VERSIONED_LAYOUT_MARKER.to_le_bytes());
+        assert_eq!(bytes[8], LAYOUT_VERSION);
+
+        let loaded = CandyGuardData::load(&bytes).unwrap();
+        let groups = loaded.groups.unwrap();
+        assert_eq!(groups[0].label, "vip");
+        assert_eq!(groups[1].label, label);
+
+        let active_set = CandyGuardData::active_set(&bytes, Some(label.to_string())).unwrap();
+        assert!(active_set.start_date.is_some());
+        assert!(active_set.end_date.is_some());
+    }
+
+    #[test]
+    fn resolve_padded_label() {
+        let data = CandyGuardData {
+            default: default_set(),
+            groups: Some(vec![group("vip", vec![])]),
+        };
+        let bytes = save(&data);
+
+        let label = CandyGuardData::resolve_label(&bytes, Some("vip\0\0".to_string())).unwrap();
+        assert_eq!(label.as_deref(), Some("vip"));
+        assert!(CandyGuardData::active_set(&bytes, label).is_ok());
+    }
+
+    #[test]
+    fn save_group_with_disabled_guards() {
+        let data = CandyGuardData {
+            default: default_set(),
+            groups: Some(vec![group("vip", vec![GuardType::StartDate])]),
+        };
+        assert!(data
+            .verify_disabled(&data.groups.as_ref().unwrap()[0])
+            .is_ok());
+
+        let bytes = save(&data);
+        assert_eq!(bytes[8], LAYOUT_VERSION);
+
+        let loaded = CandyGuardData::load(&bytes).unwrap();
+        assert_eq!(
+            loaded.groups.unwrap()[0].disabled,
+            vec![GuardType::StartDate]
+        );
+
+        let active_set = CandyGuardData::active_set(&bytes, Some("vip".to_string())).unwrap();
+        assert!(active_set.start_date.is_none());
+        assert!(active_set.end_date.is_some());
+    }
+
+    #[test]
+    fn reject_invalid_disabled_guards() {
+        let mut default = default_set();
+        default.auto_group = Some(AutoGroup {});
+        default.end_date = Some(EndDate { date: 3 });
+
+        for disabled in [
+            vec![GuardType::AutoGroup],
+            vec![GuardType::PhaseSchedule],
+            // not enabled on the default guard set
+            vec![GuardType::BotTax],
+            // enabled on the group
+            vec![GuardType::EndDate],
+            // repeated
+            vec![GuardType::StartDate, GuardType::StartDate],
+        ] {
+            let data = CandyGuardData {
+                default: default.clone(),
+                groups: Some(vec![group("vip", disabled)]),
+            };
+            assert!(data
+                .verify_disabled(&data.groups.as_ref().unwrap()[0])
+                .is_err());
+        }
+    }
+}
@@ -0,0 +1,198 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::CandyGuardError;
+
+// Number of guard flags stored in each features word.
+pub const FLAGS_PER_WORD: usize = 63;
+
+// Bit of a features word indicating that another word follows.
+const CONTINUATION_BIT: u64 = 1 << FLAGS_PER_WORD;
+
+/// Feature flags of a guard set, indicating which guards are enabled.
+///
+/// The features are serialized as a sequence of little-endian `u64` words: the lower
+/// 63 bits of each word are guard flags (indexed by `GuardType`) and the most significant
+/// bit indicates that another word follows. A guard set that only uses the first 63 guards
+/// is serialized as a single `u64`, which is the layout used before the encoding was
+/// extended.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Features {
+    // guard flags of each word (without the continuation bit)
+    words: Vec<u64>,
+}
+
+impl Features {
+    /// Deserializes the features from the start of the data slice.
+    pub fn from_data(data: &[u8]) -> Result<Self> {
+        let mut words = Vec::with_capacity(1);
+        let mut cursor = 0;
+
+        loop {
+            if cursor + 8 > data.len() {
+                return err!(CandyGuardError::DeserializationError);
+            }
+
+            let word = u64::from_le_bytes(*arrayref::array_ref![data, cursor, 8]);
+            cursor += 8;
+            words.push(word & !CONTINUATION_BIT);
+
+            if word & CONTINUATION_BIT == 0 {
+                break;
+            }
+        }
+
+        Ok(Self { words })
+    }
+
+    /// Serializes the features into the start of the data slice, returning the number of
+    /// bytes written.
+    pub fn to_data(&self, data: &mut [u8]) -> Result<usize> {
+        let count = self.word_count();
+
+        if data.len() < count * 8 {
+            return err!(CandyGuardError::InvalidAccountSize);
+        }
+
+        for index in 0..count {
+            let mut word = self.words.get(index).copied().unwrap_or_default();
+
+            if index + 1 < count {
+                word |= CONTINUATION_BIT;
+            }
+
+            data[index * 8..(index + 1) * 8].copy_from_slice(&u64::to_le_bytes(word));
+        }
+
+        Ok(count * 8)
+    }
+
+    /// Returns the first serialized word of the features.
+    pub fn leading_word(&self) -> u64 {
+        let word = self.words.first().copied().unwrap_or_default();
+
+        if self.word_count() > 1 {
+            word | CONTINUATION_BIT
+        } else {
+            word
+        }
+    }
+
+    /// Returns whether the flag at the specified index is set.
+    pub fn is_enabled(&self, index: usize) -> bool {
+        self.words
+            .get(index / FLAGS_PER_WORD)
+            .is_some_and(|word| word & (1 << (index % FLAGS_PER_WORD)) > 0)
+    }
+
+    /// Sets the flag at the specified index.
+    pub fn enable(&mut self, index: usize) {
+        let word = index / FLAGS_PER_WORD;
+
+        if self.words.len() <= word {
+            self.words.resize(word + 1, 0);
+        }
+
+        self.words[word] |= 1 << (index % FLAGS_PER_WORD);
+    }
+
+    /// Clears the flag at the specified index.
+    pub fn disable(&mut self, index: usize) {
+        if let Some(word) = self.words.get_mut(index / FLAGS_PER_WORD) {
+            *word &= !(1 << (index % FLAGS_PER_WORD));
+        }
+
+        self.trim();
+    }
+
+    /// Returns the number of bytes used by the serialized features.
+    pub fn size(&self) -> usize {
+        self.word_count() * 8
+    }
+
+    // Number of words serialized (there is always at least one word).
+    fn word_count(&self) -> usize {
+        self.words.len().max(1)
+    }
+
+    // Removes trailing empty words so that the serialization is as short as possible.
+    fn trim(&mut self) {
+        while self.words.last() == Some(&0) {
+            self.words.pop();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn serialize(features: &Features) -> Vec<u8> {
+        let mut data = vec![0u8; features.size()];
+        features.to_data(&mut data).unwrap();
+        data
+    }
+
+    #[test]
+    fn encode_single_word() {
+        let mut features = Features::default();
+        features.enable(0);
+        features.enable(62);
+
+        let data = serialize(&features);
+        assert_eq!(data, ((1u64 << 62) | 1).to_le_bytes());
+        assert_eq!(features.leading_word(), (1u64 << 62) | 1);
+        assert_eq!(Features::from_data(&data).unwrap(), features);
+    }
+
+    #[test]
+    fn encode_continuation_words() {
+        let mut features = Features::default();
+        features.enable(1);
+        features.enable(63);
+        features.enable(130);
+
+        let data = serialize(&features);
+        assert_eq!(data.len(), 24);
+        assert_eq!(data[0..8], (CONTINUATION_BIT | 2).to_le_bytes());
+        assert_eq!(data[8..16], (CONTINUATION_BIT | 1).to_le_bytes());
+        assert_eq!(data[16..24], (1u64 << 4).to_le_bytes());
+
+        let loaded = Features::from_data(&data).unwrap();
+        assert!(loaded.is_enabled(1));
+        assert!(loaded.is_enabled(63));
+        assert!(loaded.is_enabled(130));
+        assert!(!loaded.is_enabled(64));
+        assert_eq!(loaded, features);
+    }
+
+    #[test]
+    fn load_legacy_word() {
+        // the legacy encoding is a single u64 with the guard flags; trailing bytes belong
+        // to the guards
+        let mut data = ((1u64 << 3) | (1u64 << 40)).to_le_bytes().to_vec();
+        data.extend_from_slice(&[u8::MAX; 8]);
+
+        let features = Features::from_data(&data).unwrap();
+        assert!(features.is_enabled(3));
+        assert!(features.is_enabled(40));
+        assert_eq!(features.size(), 8);
+    }
+
+    #[test]
+    fn trim_disabled_words() {
+        let mut features = Features::default();
+        features.enable(2);
+        features.enable(70);
+        assert_eq!(features.size(), 16);
+
+        features.disable(70);
+        assert_eq!(features.size(), 8);
+        assert_eq!(features.leading_word(), 1 << 2);
+    }
+
+    #[test]
+    fn reject_truncated_words() {
+        let data = (CONTINUATION_BIT | 1).to_le_bytes();
+        assert!(Features::from_data(&data).is_err());
+    }
+}
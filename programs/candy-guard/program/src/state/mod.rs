@@ -1,3 +1,5 @@
 pub use candy_guard::*;
+pub use features::*;
 
 pub mod candy_guard;
+pub mod features;
@@ -5,7 +5,10 @@ use solana_program::{
     program_pack::{IsInitialized, Pack},
     pubkey::PUBKEY_BYTES,
 };
-use spl_associated_token_account::get_associated_token_address;
+use spl_associated_token_account::{
+    get_associated_token_address, get_associated_token_address_with_program_id,
+};
+use spl_token_2022::extension::StateWithExtensions;
 
 use crate::errors::CandyGuardError;
 
@@ -52,6 +55,31 @@ pub struct TokenTransferParams<'a: 'b, 'b> {
     pub token_program: AccountInfo<'a>,
 }
 
+///TokenTransferCheckedParams
+pub struct TokenTransferCheckedParams<'a: 'b, 'b> {
+    /// source
+    /// CHECK: account checked in CPI
+    pub source: AccountInfo<'a>,
+    /// destination
+    /// CHECK: account checked in CPI
+    pub destination: AccountInfo<'a>,
+    /// mint
+    /// CHECK: account checked in CPI
+    pub mint: AccountInfo<'a>,
+    /// amount
+    pub amount: u64,
+    /// decimals
+    pub decimals: u8,
+    /// authority
+    /// CHECK: account checked in CPI
+    pub authority: AccountInfo<'a>,
+    /// authority_signer_seeds
+    pub authority_signer_seeds: &'b [&'b [u8]],
+    /// token_program
+    /// CHECK: account checked in CPI
+    pub token_program: AccountInfo<'a>,
+}
+
 pub fn cmp_pubkeys(a: &Pubkey, b: &Pubkey) -> bool {
     sol_memcmp(a.as_ref(), b.as_ref(), PUBKEY_BYTES) == 0
 }
@@ -78,6 +106,25 @@ pub fn assert_is_ata(
     Ok(ata_account)
 }
 
+/// Asserts that the account is the Token-2022 ATA of the wallet for the mint, returning
+/// the base state of the token account.
+pub fn assert_is_token_2022_ata(
+    ata: &AccountInfo,
+    wallet: &Pubkey,
+    mint: &Pubkey,
+) -> Result<spl_token_2022::state::Account> {
+    assert_owned_by(ata, &spl_token_2022::ID)?;
+    let data = ata.data.borrow();
+    let ata_account = StateWithExtensions::<spl_token_2022::state::Account>::unpack(&data)?.base;
+    assert_keys_equal(&ata_account.owner, wallet)?;
+    assert_keys_equal(&ata_account.mint, mint)?;
+    assert_keys_equal(
+        &get_associated_token_address_with_program_id(wallet, mint, &spl_token_2022::ID),
+        ata.key,
+    )?;
+    Ok(ata_account)
+}
+
 pub fn assert_is_token_account(
     ta: &AccountInfo,
     wallet: &Pubkey,
@@ -176,3 +223,40 @@ pub fn spl_token_transfer(params: TokenTransferParams<'_, '_>) -> Result<()> {
 
     result.map_err(|_| CandyGuardError::TokenTransferFailed.into())
 }
+
+/// Transfers tokens using the `transfer_checked` instruction, which is supported by both
+/// spl-token and spl-token-2022 programs (including mints with extensions).
+pub fn spl_token_transfer_checked(params: TokenTransferCheckedParams<'_, '_>) -> Result<()> {
+    let TokenTransferCheckedParams {
+        source,
+        destination,
+        mint,
+        authority,
+        token_program,
+        amount,
+        decimals,
+        authority_signer_seeds,
+    } = params;
+
+    let mut signer_seeds = vec![];
+    if !authority_signer_seeds.is_empty() {
+        signer_seeds.push(authority_signer_seeds)
+    }
+
+    let result = invoke_signed(
+        &spl_token_2022::instruction::transfer_checked(
+            token_program.key,
+            source.key,
+            mint.key,
+            destination.key,
+            authority.key,
+            &[],
+            amount,
+            decimals,
+        )?,
+        &[source, mint, destination, authority, token_program],
+        &signer_seeds,
+    );
+
+    result.map_err(|_| CandyGuardError::TokenTransferFailed.into())
+}
@@ -7,7 +7,7 @@ use mpl_core::{
     instructions::CreateV1CpiBuilder,
     types::{PluginAuthorityPair, PluginType, UpdateDelegate},
 };
-use solana_program::sysvar;
+use solana_program::{program::set_return_data, sysvar};
 
 use crate::{
     constants::{AUTHORITY_SEED, EMPTY_STR, HIDDEN_SECTION, NULL_STRING},
@@ -57,6 +57,9 @@ pub fn mint_asset<'info>(
 /// The index minted depends on the configuration of the candy machine: it could be
 /// a psuedo-randomly selected one or sequential. In both cases, after minted a
 /// specific index, the candy machine does not allow to mint the same index again.
+///
+/// The index of the config line minted is set as the return data of the instruction
+/// (`u32` little-endian bytes) so callers can record which item was minted.
 pub(crate) fn process_mint_asset(
     candy_machine: &mut Box<Account<'_, CandyMachine>>,
     accounts: MintAccounts,
@@ -107,7 +110,8 @@ pub(crate) fn process_mint_asset(
         .checked_rem(candy_machine.data.items_available - candy_machine.items_redeemed)
         .ok_or(CandyError::NumericalOverflowError)? as usize;
 
-    let config_line = get_config_line(candy_machine, remainder, candy_machine.items_redeemed)?;
+    let (config_line, config_index) =
+        get_config_line(candy_machine, remainder, candy_machine.items_redeemed)?;
 
     candy_machine.items_redeemed = candy_machine
         .items_redeemed
@@ -124,22 +128,30 @@ pub(crate) fn process_mint_asset(
         bump,
         config_line,
         &mint_args.plugins,
-    )
+    )?;
+
+    set_return_data(&config_index.to_le_bytes());
+
+    Ok(())
 }
 
-/// Selects and returns the information of a config line.
+/// Selects and returns the information of a config line together with its index.
 ///
-/// The selection could be either sequential or random.
+/// The selection could be either sequential or random. When hidden settings are used,
+/// the index returned is the mint number.
 pub fn get_config_line(
     candy_machine: &Account<'_, CandyMachine>,
     index: usize,
     mint_number: u64,
-) -> Result<ConfigLine> {
+) -> Result<(ConfigLine, u32)> {
     if let Some(hs) = &candy_machine.data.hidden_settings {
-        return Ok(ConfigLine {
-            name: replace_patterns(hs.name.clone(), mint_number as usize),
-            uri: replace_patterns(hs.uri.clone(), mint_number as usize),
-        });
+        return Ok((
+            ConfigLine {
+                name: replace_patterns(hs.name.clone(), mint_number as usize),
+                uri: replace_patterns(hs.uri.clone(), mint_number as usize),
+            },
+            mint_number as u32,
+        ));
     }
     let settings = if let Some(settings) = &candy_machine.data.config_line_settings {
         settings
@@ -211,10 +223,13 @@ pub fn get_config_line(
     let complete_name = replace_patterns(settings.prefix_name.clone(), value_to_use) + &name;
     let complete_uri = replace_patterns(settings.prefix_uri.clone(), value_to_use) + &uri;
 
-    Ok(ConfigLine {
-        name: complete_name,
-        uri: complete_uri,
-    })
+    Ok((
+        ConfigLine {
+            name: complete_name,
+            uri: complete_uri,
+        },
+        value_to_use as u32,
+    ))
 }
 
 /// Creates the metadata accounts and mint a new token.